@@ -1,7 +1,9 @@
 use crate::prelude::*;
 use crate::models::{AppConfig, MainMenuChoice};
 use crate::views::{MenuDisplay, ConsoleView};
-use crate::controllers::{SearchController, SortController, PathfinderController, TreeTraversalController};
+use crate::controllers::{SearchController, TextSearchController, SortController, SelectionController, PathfinderController, TreeTraversalController};
+use crate::search::workload::{WorkloadConfig, Popularity};
+use crate::search::dataset::{DatasetFormat, LoadOptions, SyntheticConfig, LengthDistribution, AlphabetDistribution};
 use clap::{Command, Arg, ArgMatches};
 
 pub struct AppController {
@@ -9,7 +11,9 @@ pub struct AppController {
     console: ConsoleView,
     menu_display: MenuDisplay,
     search_controller: SearchController,
+    text_search_controller: TextSearchController,
     sort_controller: SortController,
+    selection_controller: SelectionController,
     pathfinder_controller: PathfinderController,
     tree_traversal_controller: TreeTraversalController,
 }
@@ -21,7 +25,9 @@ impl AppController {
             console: ConsoleView::new(),
             menu_display: MenuDisplay::new(),
             search_controller: SearchController::new(),
+            text_search_controller: TextSearchController::new(),
             sort_controller: SortController::new(),
+            selection_controller: SelectionController::new(),
             pathfinder_controller: PathfinderController::new(),
             tree_traversal_controller: TreeTraversalController::new(),
         }
@@ -34,9 +40,15 @@ impl AppController {
             Some(("search", sub_matches)) => {
                 self.handle_search_command(sub_matches).await?;
             }
+            Some(("text", sub_matches)) => {
+                self.handle_text_search_command(sub_matches).await?;
+            }
             Some(("sort", sub_matches)) => {
                 self.handle_sort_command(sub_matches).await?;
             }
+            Some(("select", sub_matches)) => {
+                self.handle_select_command(sub_matches).await?;
+            }
             Some(("pathfinder", sub_matches)) => {
                 self.handle_pathfinder_command(sub_matches).await?;
             }
@@ -84,7 +96,151 @@ impl AppController {
         
         let target_word = matches.get_one::<String>("target").cloned();
         
-        self.search_controller.run_cli(words_file, target_word, iterations).await
+        let fuzzy_distance: Option<usize> = matches.get_one::<String>("fuzzy")
+            .map(|value| value.parse())
+            .transpose()
+            .map_err(|_| Error::validation("Invalid fuzzy distance"))?;
+        
+        let layout_max_size: Option<usize> = matches.get_one::<String>("layouts")
+            .map(|value| value.parse())
+            .transpose()
+            .map_err(|_| Error::validation("Invalid layout maximum size"))?;
+        
+        let workload = self.parse_workload_config(matches)?;
+        
+        let format = matches.get_one::<String>("format")
+            .map(|value| value.parse())
+            .transpose()?;
+        
+        let min_length: usize = matches.get_one::<String>("min-length")
+            .ok_or_else(|| Error::input("Minimum length not specified"))?
+            .parse()
+            .map_err(|_| Error::validation("Invalid minimum word length"))?;
+        
+        let load_options = LoadOptions {
+            lowercase: matches.get_flag("lowercase"),
+            min_length,
+        };
+        
+        let synthetic = self.parse_synthetic_config(matches)?;
+        
+        let filter_fpr: Option<f64> = matches.get_one::<String>("filters")
+            .map(|value| value.parse())
+            .transpose()
+            .map_err(|_| Error::validation("Invalid false-positive rate"))?;
+        
+        let tree_order: Option<usize> = matches.get_one::<String>("trees")
+            .map(|value| value.parse())
+            .transpose()
+            .map_err(|_| Error::validation("Invalid B-tree order"))?;
+        
+        let config = crate::models::SearchConfig {
+            words_file: words_file.clone(),
+            target_word,
+            iterations,
+            fuzzy_distance,
+            layout_max_size,
+            large_layouts: matches.get_flag("large-layouts"),
+            workload,
+            format,
+            column: matches.get_one::<String>("column").cloned(),
+            load_options,
+            synthetic,
+            filter_fpr,
+            tree_order,
+        };
+        
+        self.search_controller.run_cli(config).await
+    }
+    
+    fn parse_synthetic_config(&self, matches: &ArgMatches) -> Result<Option<SyntheticConfig>> {
+        let Some(count) = matches.get_one::<String>("synthetic") else {
+            return Ok(None);
+        };
+        
+        let mut config = SyntheticConfig {
+            count: count.parse().map_err(|_| Error::validation("Invalid synthetic word count"))?,
+            ..SyntheticConfig::default()
+        };
+        
+        if let Some(alphabet) = matches.get_one::<String>("alphabet") {
+            config.alphabet = alphabet.parse()?;
+        }
+        
+        if let Some(lengths) = matches.get_one::<String>("word-lengths") {
+            let (min, max) = lengths.split_once('-')
+                .ok_or_else(|| Error::validation("Word lengths must be given as MIN-MAX"))?;
+            config.min_length = min.trim().parse().map_err(|_| Error::validation("Invalid minimum word length"))?;
+            config.max_length = max.trim().parse().map_err(|_| Error::validation("Invalid maximum word length"))?;
+            config.length_distribution = LengthDistribution::Uniform;
+        }
+        
+        config.seed = matches.get_one::<String>("seed")
+            .map(|value| value.parse())
+            .transpose()
+            .map_err(|_| Error::validation("Invalid seed"))?;
+        
+        Ok(Some(config))
+    }
+    
+    fn parse_workload_config(&self, matches: &ArgMatches) -> Result<Option<WorkloadConfig>> {
+        let Some(targets) = matches.get_one::<String>("workload") else {
+            return Ok(None);
+        };
+        
+        let targets: usize = targets.parse()
+            .map_err(|_| Error::validation("Invalid workload target count"))?;
+        
+        let hit_ratio: f64 = matches.get_one::<String>("hit-ratio")
+            .ok_or_else(|| Error::input("Hit ratio not specified"))?
+            .parse()
+            .map_err(|_| Error::validation("Invalid hit ratio"))?;
+        
+        let popularity = match matches.get_one::<String>("zipf") {
+            Some(exponent) => Popularity::Zipfian(exponent.parse()
+                .map_err(|_| Error::validation("Invalid Zipfian exponent"))?),
+            None => Popularity::Uniform,
+        };
+        
+        let seed: Option<u64> = matches.get_one::<String>("seed")
+            .map(|value| value.parse())
+            .transpose()
+            .map_err(|_| Error::validation("Invalid seed"))?;
+        
+        Ok(Some(WorkloadConfig {
+            targets,
+            hit_ratio,
+            popularity,
+            seed,
+        }))
+    }
+    
+    async fn handle_text_search_command(&mut self, matches: &ArgMatches) -> Result<()> {
+        let text_file = matches.get_one::<String>("file")
+            .ok_or_else(|| Error::input("Text file not specified"))?;
+        
+        let iterations: usize = matches.get_one::<String>("iterations")
+            .ok_or_else(|| Error::input("Iterations not specified"))?
+            .parse()
+            .map_err(|_| Error::validation("Invalid iterations number"))?;
+        
+        let patterns: Vec<String> = matches.get_many::<String>("pattern")
+            .map(|values| values.cloned().collect())
+            .unwrap_or_default();
+        
+        let save_index = matches.get_one::<String>("save-index").cloned();
+        let build_index = matches.get_flag("index") || save_index.is_some();
+        
+        let config = crate::models::TextSearchConfig {
+            text_file: text_file.clone(),
+            patterns,
+            iterations,
+            build_index,
+            save_index,
+            load_index: matches.get_one::<String>("load-index").cloned(),
+        };
+        
+        self.text_search_controller.run_cli(config).await
     }
     
     async fn handle_sort_command(&mut self, matches: &ArgMatches) -> Result<()> {
@@ -98,7 +254,64 @@ impl AppController {
             .parse()
             .map_err(|_| Error::validation("Invalid iterations number"))?;
         
-        self.sort_controller.run_cli(size, iterations).await
+        let defaults = crate::sort::HybridThresholds::default();
+        
+        let small_sort: usize = matches.get_one::<String>("small-sort-threshold")
+            .map(|value| value.parse())
+            .transpose()
+            .map_err(|_| Error::validation("Invalid small sort threshold"))?
+            .unwrap_or(defaults.small_sort);
+        
+        let shell_sort: usize = matches.get_one::<String>("shell-sort-threshold")
+            .map(|value| value.parse())
+            .transpose()
+            .map_err(|_| Error::validation("Invalid shell sort threshold"))?
+            .unwrap_or(defaults.shell_sort);
+        
+        let kernel: crate::sort::SmallSortKernel = matches.get_one::<String>("small-sort")
+            .map(|value| value.parse())
+            .transpose()?
+            .unwrap_or(defaults.kernel);
+        
+        let config = crate::models::SortConfig {
+            array_size: size,
+            iterations,
+            thresholds: crate::sort::HybridThresholds {
+                small_sort,
+                shell_sort,
+                kernel,
+            },
+            tune_thresholds: matches.get_flag("tune-thresholds"),
+            presortedness: matches.get_flag("presortedness"),
+            types: matches.get_flag("types"),
+        };
+        
+        self.sort_controller.run_cli(config).await
+    }
+    
+    async fn handle_select_command(&mut self, matches: &ArgMatches) -> Result<()> {
+        let array_size: usize = matches.get_one::<String>("size")
+            .ok_or_else(|| Error::input("Size not specified"))?
+            .parse()
+            .map_err(|_| Error::validation("Invalid size number"))?;
+        
+        let k: Option<usize> = matches.get_one::<String>("k")
+            .map(|value| value.parse())
+            .transpose()
+            .map_err(|_| Error::validation("Invalid k"))?;
+        
+        let iterations: usize = matches.get_one::<String>("iterations")
+            .ok_or_else(|| Error::input("Iterations not specified"))?
+            .parse()
+            .map_err(|_| Error::validation("Invalid iterations number"))?;
+        
+        let config = crate::models::SelectionConfig {
+            array_size,
+            k,
+            iterations,
+        };
+        
+        self.selection_controller.run_cli(config).await
     }
     
     async fn handle_pathfinder_command(&mut self, matches: &ArgMatches) -> Result<()> {
@@ -122,14 +335,90 @@ impl AppController {
             .parse::<f64>()
             .map_err(|_| Error::validation("Invalid obstacle percentage"))?
             / 100.0;
+
+        let movement = matches.get_one::<String>("movement")
+            .map(|v| v.parse())
+            .transpose()?
+            .unwrap_or_default();
+
+        let graph_nodes: usize = matches.get_one::<String>("nodes")
+            .ok_or_else(|| Error::input("Node count not specified"))?
+            .parse()
+            .map_err(|_| Error::validation("Invalid node count"))?;
+
+        let maze_algorithm: crate::pathfinder::MazeAlgorithm = matches.get_one::<String>("maze")
+            .ok_or_else(|| Error::input("Maze generator not specified"))?
+            .parse()?;
+
+        let braid: f64 = matches.get_one::<String>("braid")
+            .ok_or_else(|| Error::input("Braid factor not specified"))?
+            .parse()
+            .map_err(|_| Error::validation("Invalid braid factor"))?;
+        if !(0.0..=1.0).contains(&braid) {
+            return Err(Error::validation("Braid factor must be between 0 and 1"));
+        }
+
+        let seed: Option<u64> = matches.get_one::<String>("seed")
+            .map(|value| value.parse())
+            .transpose()
+            .map_err(|_| Error::validation("Invalid seed"))?;
         
+        let mut suites = Vec::new();
+        for name in matches.get_many::<String>("suite").into_iter().flatten() {
+            let requested = if name.eq_ignore_ascii_case("all") {
+                crate::pathfinder::BenchmarkSuite::ALL.to_vec()
+            } else {
+                vec![name.parse()?]
+            };
+            for suite in requested {
+                if !suites.contains(&suite) {
+                    suites.push(suite);
+                }
+            }
+        }
+
         let config = crate::models::PathfinderConfig {
             grid_width: width,
             grid_height: height,
             obstacle_percentage,
             iterations,
+            movement,
+            all_pairs: matches.get_flag("all-pairs"),
+            graph_nodes,
+            map_file: matches.get_one::<String>("map").cloned(),
+            scenario_file: matches.get_one::<String>("scen").cloned(),
+            maze: crate::pathfinder::MazeGenerator::new(maze_algorithm).with_braid(braid).with_seed(seed),
+            maze_gif: matches.get_flag("maze-gif"),
+            scaling: matches.get_flag("scaling"),
+            suites,
+            seed,
         };
         
+        let uses_movement = !config.suites.is_empty() || config.scaling || config.map_file.is_some() || config.scenario_file.is_some();
+        if matches.get_one::<String>("movement").is_some() && !uses_movement {
+            return Err(Error::validation("--movement applies to --suite, --scaling and --map/--scen runs"));
+        }
+
+        if config.all_pairs {
+            return self.pathfinder_controller.run_all_pairs(config).await;
+        }
+
+        if config.map_file.is_some() || config.scenario_file.is_some() {
+            return self.pathfinder_controller.run_map(config).await;
+        }
+
+        if config.maze_gif {
+            return self.pathfinder_controller.run_maze_gif(config).await;
+        }
+
+        if config.scaling {
+            return self.pathfinder_controller.run_scaling(config).await;
+        }
+
+        if !config.suites.is_empty() {
+            return self.pathfinder_controller.run_suites(config).await;
+        }
+
         use crate::models::PathfinderAlgorithm;
         self.pathfinder_controller.run_single_algorithm(PathfinderAlgorithm::AStar, config).await
     }
@@ -164,6 +453,157 @@ impl AppController {
                             .help("Number of iterations for benchmarking")
                             .default_value("100")
                     )
+                    .arg(
+                        Arg::new("fuzzy")
+                            .short('f')
+                            .long("fuzzy")
+                            .value_name("DISTANCE")
+                            .help("Maximum edit distance for fuzzy 'did you mean' suggestions")
+                    )
+                    .arg(
+                        Arg::new("layouts")
+                            .long("layouts")
+                            .value_name("MAX_SIZE")
+                            .help("Benchmark cache-friendly layouts on integer keys from 1K up to MAX_SIZE (default 10M)")
+                            .num_args(0..=1)
+                            .default_missing_value("10000000")
+                    )
+                    .arg(
+                        Arg::new("large-layouts")
+                            .long("large-layouts")
+                            .help("Allow --layouts sizes above 10M (the 100M run allocates more than 1 GB)")
+                            .action(clap::ArgAction::SetTrue)
+                    )
+                    .arg(
+                        Arg::new("filters")
+                            .long("filters")
+                            .value_name("FPR")
+                            .help("Benchmark Bloom, cuckoo and quotient filters sized for a target false-positive rate (default 0.01)")
+                            .num_args(0..=1)
+                            .default_missing_value("0.01")
+                    )
+                    .arg(
+                        Arg::new("trees")
+                            .long("trees")
+                            .value_name("ORDER")
+                            .help("Benchmark AVL, red-black, B-tree (of ORDER, default 32), treap and skip list ordered maps")
+                            .num_args(0..=1)
+                            .default_missing_value("32")
+                    )
+                    .arg(
+                        Arg::new("workload")
+                            .long("workload")
+                            .value_name("TARGETS")
+                            .help("Benchmark a batch of TARGETS lookups mixing hits and misses")
+                    )
+                    .arg(
+                        Arg::new("hit-ratio")
+                            .long("hit-ratio")
+                            .value_name("RATIO")
+                            .help("Fraction of workload targets present in the word list")
+                            .default_value("0.8")
+                    )
+                    .arg(
+                        Arg::new("zipf")
+                            .long("zipf")
+                            .value_name("EXPONENT")
+                            .help("Draw workload targets with Zipfian popularity instead of uniformly")
+                    )
+                    .arg(
+                        Arg::new("seed")
+                            .long("seed")
+                            .value_name("SEED")
+                            .help("Seed for reproducible workloads and synthetic datasets")
+                    )
+                    .arg(
+                        Arg::new("format")
+                            .long("format")
+                            .value_name("FORMAT")
+                            .help("Dataset format: lines, csv, tsv or json (detected from the extension by default, .gz is decompressed)")
+                    )
+                    .arg(
+                        Arg::new("column")
+                            .long("column")
+                            .value_name("COLUMN")
+                            .help("CSV/TSV header name (the first row is then a header) or 0-based index, or JSON object field, holding the words")
+                    )
+                    .arg(
+                        Arg::new("lowercase")
+                            .long("lowercase")
+                            .help("Lowercase words while loading")
+                            .action(clap::ArgAction::SetTrue)
+                    )
+                    .arg(
+                        Arg::new("min-length")
+                            .long("min-length")
+                            .value_name("CHARS")
+                            .help("Drop words shorter than CHARS characters")
+                            .default_value("1")
+                    )
+                    .arg(
+                        Arg::new("synthetic")
+                            .long("synthetic")
+                            .value_name("COUNT")
+                            .help("Generate COUNT synthetic words instead of reading a file")
+                    )
+                    .arg(
+                        Arg::new("alphabet")
+                            .long("alphabet")
+                            .value_name("NAME")
+                            .help("Synthetic alphabet: english (letter frequencies), uniform, binary or dna")
+                    )
+                    .arg(
+                        Arg::new("word-lengths")
+                            .long("word-lengths")
+                            .value_name("MIN-MAX")
+                            .help("Uniform synthetic word lengths (default: normal around 7, clamped to 2-12)")
+                    )
+            )
+            .subcommand(
+                Command::new("text")
+                    .about("Text Search Benchmarking System")
+                    .arg(
+                        Arg::new("file")
+                            .short('f')
+                            .long("file")
+                            .value_name("FILE")
+                            .help("Path to text file to search")
+                            .default_value("data/text.txt")
+                    )
+                    .arg(
+                        Arg::new("pattern")
+                            .short('p')
+                            .long("pattern")
+                            .value_name("PATTERN")
+                            .help("Pattern to search for (repeat for multi-pattern search)")
+                            .action(clap::ArgAction::Append)
+                    )
+                    .arg(
+                        Arg::new("iterations")
+                            .short('i')
+                            .long("iterations")
+                            .value_name("NUM")
+                            .help("Number of iterations for benchmarking")
+                            .default_value("10")
+                    )
+                    .arg(
+                        Arg::new("index")
+                            .long("index")
+                            .help("Also benchmark suffix array and FM-index queries")
+                            .action(clap::ArgAction::SetTrue)
+                    )
+                    .arg(
+                        Arg::new("save-index")
+                            .long("save-index")
+                            .value_name("FILE")
+                            .help("Write the built FM-index to FILE for offline reuse")
+                    )
+                    .arg(
+                        Arg::new("load-index")
+                            .long("load-index")
+                            .value_name("FILE")
+                            .help("Query an FM-index saved with --save-index instead of loading the text file")
+                    )
             )
             .subcommand(
                 Command::new("sort")
@@ -190,6 +630,69 @@ impl AppController {
                             .help("Enable GUI visualisation")
                             .action(clap::ArgAction::SetTrue)
                     )
+                    .arg(
+                        Arg::new("small-sort-threshold")
+                            .long("small-sort-threshold")
+                            .value_name("SIZE")
+                            .help("Largest run handed to the small-sort kernel by hybrid sorts (default 16)")
+                    )
+                    .arg(
+                        Arg::new("shell-sort-threshold")
+                            .long("shell-sort-threshold")
+                            .value_name("SIZE")
+                            .help("Largest input Cube Sort handles with Shell Sort (default 100)")
+                    )
+                    .arg(
+                        Arg::new("small-sort")
+                            .long("small-sort")
+                            .value_name("KERNEL")
+                            .help("Small-sort kernel: insertion, network or branchless")
+                    )
+                    .arg(
+                        Arg::new("tune-thresholds")
+                            .long("tune-thresholds")
+                            .help("Sweep small-sort thresholds and kernels for the optimised merge sort")
+                            .action(clap::ArgAction::SetTrue)
+                    )
+                    .arg(
+                        Arg::new("presortedness")
+                            .long("presortedness")
+                            .help("Measure input presortedness and chart comparisons against inversions")
+                            .action(clap::ArgAction::SetTrue)
+                    )
+                    .arg(
+                        Arg::new("types")
+                            .long("types")
+                            .help("Benchmark float (with NaNs), string and multi-field record sorts")
+                            .action(clap::ArgAction::SetTrue)
+                    )
+            )
+            .subcommand(
+                Command::new("select")
+                    .about("Selection Algorithm Benchmarking System")
+                    .arg(
+                        Arg::new("size")
+                            .short('s')
+                            .long("size")
+                            .value_name("SIZE")
+                            .help("Array size for selection benchmarks")
+                            .default_value("100000")
+                    )
+                    .arg(
+                        Arg::new("k")
+                            .short('k')
+                            .long("k")
+                            .value_name("K")
+                            .help("Zero-based rank of the element to select (default: median)")
+                    )
+                    .arg(
+                        Arg::new("iterations")
+                            .short('i')
+                            .long("iterations")
+                            .value_name("NUM")
+                            .help("Number of iterations for benchmarking")
+                            .default_value("10")
+                    )
             )
             .subcommand(
                 Command::new("pathfinder")
@@ -226,6 +729,79 @@ impl AppController {
                             .help("Number of iterations for benchmarking")
                             .default_value("10")
                     )
+                    .arg(
+                        Arg::new("movement")
+                            .short('m')
+                            .long("movement")
+                            .value_name("MODEL")
+                            .help("Movement model for --suite, --scaling and --map/--scen runs: 4, 8, 8-strict (no corner cutting) or hex")
+                    )
+                    .arg(
+                        Arg::new("all-pairs")
+                            .long("all-pairs")
+                            .help("Benchmark Bellman-Ford, SPFA, Floyd-Warshall and Johnson on graphs with negative weights")
+                            .action(clap::ArgAction::SetTrue)
+                    )
+                    .arg(
+                        Arg::new("nodes")
+                            .short('n')
+                            .long("nodes")
+                            .value_name("NUM")
+                            .help("Node count for --all-pairs graphs")
+                            .default_value("100")
+                    )
+                    .arg(
+                        Arg::new("map")
+                            .long("map")
+                            .value_name("FILE")
+                            .help("Benchmark on a MovingAI .map file or an ASCII grid (# . S E)")
+                    )
+                    .arg(
+                        Arg::new("scen")
+                            .long("scen")
+                            .value_name("FILE")
+                            .help("MovingAI .scen file whose optimal lengths are verified (loads its map unless --map is given)")
+                    )
+                    .arg(
+                        Arg::new("maze")
+                            .long("maze")
+                            .value_name("GENERATOR")
+                            .help("Maze generator for the Maze grid: backtracker, prim, kruskal, wilson, eller, division or caves")
+                            .default_value("backtracker")
+                    )
+                    .arg(
+                        Arg::new("braid")
+                            .long("braid")
+                            .value_name("FACTOR")
+                            .help("Fraction of maze dead ends knocked through to add loops (0 = perfect maze, 1 = no dead ends)")
+                            .default_value("0")
+                    )
+                    .arg(
+                        Arg::new("seed")
+                            .long("seed")
+                            .value_name("SEED")
+                            .help("Seed for reproducible mazes and obstacle grids")
+                    )
+                    .arg(
+                        Arg::new("maze-gif")
+                            .long("maze-gif")
+                            .help("Animate the chosen maze generator to a GIF instead of running benchmarks")
+                            .action(clap::ArgAction::SetTrue)
+                    )
+                    .arg(
+                        Arg::new("scaling")
+                            .long("scaling")
+                            .help("Benchmark obstacle grid generation and searches from 100x100 up to 1000x1000 cells")
+                            .action(clap::ArgAction::SetTrue)
+                    )
+                    .arg(
+                        Arg::new("suite")
+                            .long("suite")
+                            .value_name("SUITE")
+                            .help("Run extra suites instead of the basic benchmarks: movement, maze, jump-point, bidirectional, memory-bounded, replanning, graph, queue or all (comma-separated or repeated)")
+                            .value_delimiter(',')
+                            .action(clap::ArgAction::Append)
+                    )
                     .arg(
                         Arg::new("gui")
                             .long("gui")
@@ -1,11 +1,15 @@
 pub mod app_controller;
 pub mod search_controller;
+pub mod text_search_controller;
 pub mod sort_controller;
+pub mod selection_controller;
 pub mod pathfinder_controller;
 pub mod tree_traversal_controller;
 
 pub use app_controller::*;
 pub use search_controller::*;
+pub use text_search_controller::*;
 pub use sort_controller::*;
+pub use selection_controller::*;
 pub use pathfinder_controller::*;
 pub use tree_traversal_controller::*;
@@ -1,5 +1,6 @@
 use crate::prelude::*;
-use crate::pathfinder::PathfinderCoordinator;
+use crate::pathfinder::{Grid, MazeAlgorithm, MazeGenerator, PathfinderCoordinator, Scenario};
+use std::path::PathBuf;
 use crate::models::{PathfinderConfig, PathfinderMenuChoice, PathfinderAlgorithm};
 use crate::views::{MenuDisplay, InputHandler, ConsoleView};
 
@@ -68,9 +69,13 @@ impl PathfinderController {
         println!("3. Breadth-First Search");
         println!("4. Depth-First Search");
         println!("5. Greedy Best-First");
+        println!("6. Bidirectional BFS");
+        println!("7. Bidirectional Dijkstra");
+        println!("8. NBA* (bidirectional A*)");
+        println!("m. Maze Generation");
         println!("a. All Algorithms");
         println!("b. Back");
-        println!("\n💡 You can also type algorithm names like 'astar', 'dijkstra', 'bfs', 'dfs', etc.");
+        println!("\n💡 You can also type algorithm names like 'astar', 'dijkstra', 'bfs', 'dfs', 'nba*', etc.");
         
         let choice = self.input_handler.get_string("Enter choice (number or name)")?;
             
@@ -78,23 +83,33 @@ impl PathfinderController {
             return Ok(());
         }
         
+        if choice.to_lowercase() == "m" || choice.to_lowercase() == "maze" {
+            use crate::gui::pathfinder_visualisation::run_maze_visualisation;
+            let generator = self.get_maze_from_user()?.unwrap_or_else(|| self.coordinator.maze());
+            let grid_size = self.get_grid_size_from_user()?;
+            run_maze_visualisation(generator, grid_size)?;
+            self.console.print_success("Maze generation visualisation completed!");
+            return Ok(());
+        }
+        
         let grid_size = self.get_grid_size_from_user()?;
+        let maze = self.get_maze_from_user()?;
         
         match PathfinderAlgorithm::from_str(&choice) {
             Some(PathfinderAlgorithm::All) => {
                 use crate::gui::pathfinder_visualisation::run_all_pathfinder_visualisations;
                 println!("🎬 Generating visualisations for all pathfinding algorithms...");
-                run_all_pathfinder_visualisations(grid_size)?;
+                run_all_pathfinder_visualisations(grid_size, maze)?;
                 self.console.print_success("All GUI visualisations completed!");
             }
             Some(algorithm) => {
                 use crate::gui::pathfinder_visualisation::run_pathfinder_visualisation;
                 println!("🎬 Generating visualisation for {}...", algorithm.display_name());
-                run_pathfinder_visualisation(algorithm.as_str(), grid_size)?;
+                run_pathfinder_visualisation(algorithm.as_str(), grid_size, maze)?;
                 self.console.print_success("GUI visualisation completed!");
             }
             None => {
-                self.console.print_error("❌ Invalid choice. Please enter 1-5, 'a', or algorithm names like 'astar', 'dijkstra', etc.");
+                self.console.print_error("❌ Invalid choice. Please enter 1-8, 'a', or algorithm names like 'astar', 'dijkstra', etc.");
             }
         }
         
@@ -109,6 +124,7 @@ impl PathfinderController {
         println!("🌟 A* (A-Star)");
         println!("   - Uses heuristic + actual cost");
         println!("   - Guarantees shortest path (optimal)");
+        println!("   - Heuristic scaled by the cheapest terrain cost");
         println!("   - Time complexity: O(b^d)");
         println!("   - Space complexity: O(b^d)");
         println!();
@@ -116,6 +132,7 @@ impl PathfinderController {
         println!("🚀 Dijkstra's Algorithm");
         println!("   - Explores uniformly in all directions");
         println!("   - Guarantees shortest path (optimal)");
+        println!("   - Honours terrain costs (road, mud, water)");
         println!("   - Time complexity: O((V + E) log V)");
         println!("   - Space complexity: O(V)");
         println!();
@@ -123,6 +140,7 @@ impl PathfinderController {
         println!("📊 Breadth-First Search (BFS)");
         println!("   - Explores level by level");
         println!("   - Guarantees shortest path (unweighted)");
+        println!("   - Ignores terrain costs, so may cross mud or water");
         println!("   - Time complexity: O(V + E)");
         println!("   - Space complexity: O(V)");
         println!();
@@ -141,12 +159,87 @@ impl PathfinderController {
         println!("   - Space complexity: O(b^m)");
         println!();
         
+        println!("↔️  Bidirectional BFS / Dijkstra / NBA*");
+        println!("   - Search from the start and the goal at once until the frontiers meet");
+        println!("   - Bidirectional Dijkstra stops once both queue minimums exceed the best meeting cost");
+        println!("   - NBA* prunes nodes whose bounds cannot beat the best meeting cost");
+        println!("   - Time complexity: O(b^(d/2)) per side");
+        println!("   - Run with: pathfinder --suite bidirectional");
+        println!();
+
+        println!("🧠 IDA* / SMA*");
+        println!("   - IDA* deepens a cost threshold and stores only the current path");
+        println!("   - SMA* forgets the worst leaves once its node limit is reached");
+        println!("   - Optimal when memory can hold the solution path");
+        println!("   - Run with: pathfinder --suite memory-bounded");
+        println!();
+
+        println!("🔁 LPA* / D* Lite");
+        println!("   - Keep g and rhs values between plans and repair only what a grid edit affects");
+        println!("   - D* Lite searches from the goal so the robot's start can move");
+        println!("   - Replanning cost compared against full A* reruns");
+        println!("   - Run with: pathfinder --suite replanning");
+        println!();
+
+        println!("📐 Theta* / Lazy Theta*");
+        println!("   - Any-angle: links to the parent's parent when in line of sight");
+        println!("   - Smoothed waypoint paths shorter than grid paths");
+        println!("   - Lazy Theta* defers line-of-sight checks until expansion");
+        println!("   - Time complexity: O(E·L + V log V)");
+        println!();
+
+        println!("🦘 Jump Point Search / JPS+");
+        println!("   - Prunes symmetric paths, expanding only jump points");
+        println!("   - Optimal on uniform-cost 8-way grids (no corner cutting)");
+        println!("   - JPS+ precomputes jump distances in O(V) for faster searches");
+        println!("   - Run with: pathfinder --suite jump-point");
+        println!();
+
+        println!("🕸️  General graphs");
+        println!("   - A*, Dijkstra, BFS, DFS and greedy run on any Graph, not just grids");
+        println!("   - Adjacency list, adjacency matrix and CSR representations");
+        println!("   - Straight-line heuristic from node coordinates");
+        println!("   - Run with: pathfinder --suite graph");
+        println!();
+
+        println!("➖ Bellman-Ford / SPFA / Floyd-Warshall / Johnson");
+        println!("   - Handle negative edge weights and report a negative cycle as a witness");
+        println!("   - SPFA only relaxes edges out of nodes whose distance changed");
+        println!("   - Floyd-Warshall: O(V³); Johnson reweights with Bellman-Ford, then runs Dijkstra from every node");
+        println!("   - Run with: pathfinder --all-pairs --nodes 100");
+        println!();
+
+        println!("🧩 Maze generators");
+        println!("   - Recursive backtracker, Prim's, Kruskal's, Wilson's, Eller's and recursive division carve perfect mazes");
+        println!("   - Cellular-automaton caves smooth random noise and keep the region connected to the start");
+        println!("   - A braid factor from 0 to 1 knocks through dead ends to add loops; seeds make mazes reproducible");
+        println!("   - Run with: pathfinder --suite maze --maze wilson --braid 0.3 --seed 7 (add --maze-gif to animate generation)");
+        println!();
+
+        println!("🧱 Random obstacle grids");
+        println!("   - Obstacles peel leaves off a random spanning tree built with union-find");
+        println!("   - Exact obstacle density, every open cell stays connected, O(V α(V)) generation");
+        println!("   - Run with: pathfinder --scaling --obstacles 35 --seed 7");
+        println!();
+
+        println!("🗂️  Map files");
+        println!("   - MovingAI .map/.scen benchmarks and ASCII grids (# . S E)");
+        println!("   - Scenario path lengths are verified against the recorded optimum");
+        println!("   - Run with: pathfinder --map FILE or pathfinder --scen FILE");
+        println!();
+
+        println!("Movement models: 4-way (Manhattan), 8-way (octile), 8-way without corner cutting, hex");
+        println!("Compare them and their heuristics with: pathfinder --movement 8 --suite movement");
+        println!("Priority queues inside Dijkstra and A*: pathfinder --suite queue");
+        println!();
+
         println!("Legend:");
         println!("  V = number of vertices (grid cells)");
         println!("  E = number of edges (connections)");
         println!("  b = branching factor");
         println!("  d = depth of solution");
         println!("  m = maximum depth");
+        println!("  L = line-of-sight length");
         
         self.console.wait_for_enter("Press Enter to continue...");
         Ok(())
@@ -159,6 +252,20 @@ impl PathfinderController {
         Ok((width, height))
     }
 
+    fn get_maze_from_user(&mut self) -> Result<Option<MazeGenerator>> {
+        println!("Grid layout: random obstacles, or a maze generator (backtracker, prim, kruskal, wilson, eller, division, caves)");
+        let Ok(choice) = self.input_handler.get_string("Layout (press Enter for random obstacles)") else {
+            return Ok(None);
+        };
+
+        if choice.to_lowercase() == "obstacles" {
+            return Ok(None);
+        }
+
+        let algorithm: MazeAlgorithm = choice.parse()?;
+        Ok(Some(MazeGenerator { algorithm, ..self.coordinator.maze() }))
+    }
+
     fn get_iterations_from_user(&mut self) -> Result<usize> {
         self.input_handler.get_positive_number("Number of iterations per algorithm", 1, 100)
     }
@@ -171,6 +278,9 @@ impl PathfinderController {
     pub async fn run_single_algorithm(&mut self, algorithm: PathfinderAlgorithm, config: PathfinderConfig) -> Result<()> {
         println!("Running {} pathfinding algorithm...", algorithm.display_name());
         
+        self.coordinator.set_movement(config.movement);
+        self.coordinator.set_maze(config.maze);
+        self.coordinator.set_seed(config.seed);
         self.coordinator.generate_test_grids((config.grid_width, config.grid_height), config.obstacle_percentage)?;
         
         let _metrics: Vec<crate::pathfinder::PathfindingMetrics> = match algorithm {
@@ -181,7 +291,10 @@ impl PathfinderController {
             PathfinderAlgorithm::Dijkstra |
             PathfinderAlgorithm::BreadthFirst |
             PathfinderAlgorithm::DepthFirst |
-            PathfinderAlgorithm::GreedyBestFirst => {
+            PathfinderAlgorithm::GreedyBestFirst |
+            PathfinderAlgorithm::BidirectionalBreadthFirst |
+            PathfinderAlgorithm::BidirectionalDijkstra |
+            PathfinderAlgorithm::NbaStar => {
                 println!("🚧 Single algorithm benchmarking not yet fully implemented");
                 Vec::new()
             }
@@ -189,4 +302,58 @@ impl PathfinderController {
         
         Ok(())
     }
+
+    pub async fn run_scaling(&mut self, config: PathfinderConfig) -> Result<()> {
+        self.coordinator.set_movement(config.movement);
+        self.coordinator.set_seed(config.seed);
+        self.coordinator.run_scaling_benchmarks(config.obstacle_percentage)?;
+        Ok(())
+    }
+
+    pub async fn run_suites(&mut self, config: PathfinderConfig) -> Result<()> {
+        let grid_size = (config.grid_width, config.grid_height);
+        self.coordinator.set_movement(config.movement);
+        self.coordinator.set_maze(config.maze);
+        self.coordinator.set_seed(config.seed);
+        self.coordinator.generate_test_grids(grid_size, config.obstacle_percentage)?;
+
+        for suite in config.suites {
+            self.coordinator.run_suite(suite, grid_size, config.iterations)?;
+        }
+        Ok(())
+    }
+
+    pub async fn run_maze_gif(&mut self, config: PathfinderConfig) -> Result<()> {
+        use crate::gui::pathfinder_visualisation::run_maze_visualisation;
+        run_maze_visualisation(config.maze, (config.grid_width, config.grid_height))
+    }
+
+    pub async fn run_map(&mut self, config: PathfinderConfig) -> Result<()> {
+        let scenarios = match &config.scenario_file {
+            Some(file) => Scenario::load_all(file)?,
+            None => Vec::new(),
+        };
+
+        let map_file = match (&config.map_file, &config.scenario_file, scenarios.first()) {
+            (Some(map_file), _, _) => PathBuf::from(map_file),
+            (None, Some(scenario_file), Some(scenario)) => scenario.map_path(scenario_file),
+            _ => return Err(Error::input("The scenario file is empty; pass the map with --map")),
+        };
+
+        let mut grid = Grid::load(&map_file)?;
+        if map_file.extension().is_none_or(|extension| extension != "map") {
+            let (start, end) = (grid.start, grid.end);
+            grid = grid.with_movement(config.movement);
+            grid.set_route(start, end);
+        }
+
+        let map_name = map_file.file_name().map_or_else(|| map_file.display().to_string(), |name| name.to_string_lossy().into_owned());
+        self.coordinator.run_map_benchmarks(&map_name, &grid, &scenarios, config.iterations)?;
+        Ok(())
+    }
+
+    pub async fn run_all_pairs(&mut self, config: PathfinderConfig) -> Result<()> {
+        self.coordinator.run_all_pairs_benchmarks(config.graph_nodes, config.iterations)?;
+        Ok(())
+    }
 }
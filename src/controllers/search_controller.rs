@@ -2,6 +2,9 @@ use crate::prelude::*;
 use crate::search::SearchCoordinator;
 use crate::models::{SearchConfig, SearchMenuChoice};
 use crate::views::{MenuDisplay, InputHandler, ConsoleView};
+use crate::search::dataset::{DatasetSource, LoadOptions, SyntheticConfig};
+
+const LAYOUT_QUERIES: usize = 100_000;
 
 pub struct SearchController {
     coordinator: SearchCoordinator,
@@ -44,13 +47,14 @@ impl SearchController {
         Ok(())
     }
     
-    pub async fn run_cli(&mut self, words_file: &str, target_word: Option<String>, iterations: usize) -> Result<()> {
+    pub async fn run_cli(&mut self, config: SearchConfig) -> Result<()> {
         self.console.print_header("Search Algorithm Benchmarking System");
         
-        self.coordinator.load_words(words_file).await?;
-        self.console.print_success(&format!("Loaded words from: {}", words_file));
+        let source = self.dataset_source(&config)?;
+        self.coordinator.load_dataset(&source, &config.load_options).await?;
+        self.console.print_success(&format!("Loaded words from: {}", source.describe()));
         
-        let target = match target_word {
+        let target = match config.target_word {
             Some(word) => word,
             None => {
                 let stats = self.coordinator.get_stats();
@@ -59,10 +63,51 @@ impl SearchController {
             }
         };
         
-        self.coordinator.run_benchmarks(&target, iterations)?;
+        self.coordinator.run_benchmarks(&target, config.iterations)?;
+        
+        if let Some(max_distance) = config.fuzzy_distance {
+            self.coordinator.run_fuzzy_search(&target, max_distance, config.iterations)?;
+        }
+        
+        if let Some(false_positive_rate) = config.filter_fpr {
+            self.coordinator.run_filter_benchmarks(false_positive_rate)?;
+        }
+        
+        if let Some(order) = config.tree_order {
+            self.coordinator.run_tree_benchmarks(order)?;
+        }
+        
+        if let Some(workload) = &config.workload {
+            self.coordinator.run_workload_benchmarks(workload)?;
+        }
+        
+        if let Some(max_size) = config.layout_max_size {
+            self.coordinator.run_layout_benchmarks(max_size, LAYOUT_QUERIES, config.large_layouts)?;
+        }
+        
         Ok(())
     }
     
+    fn dataset_source(&self, config: &SearchConfig) -> Result<DatasetSource> {
+        if let Some(synthetic) = &config.synthetic {
+            return Ok(DatasetSource::Synthetic(synthetic.clone()));
+        }
+        
+        if !std::path::Path::new(&config.words_file).exists() {
+            if config.words_file != SearchConfig::default().words_file {
+                return Err(Error::validation(format!("Words file not found: {}", config.words_file)));
+            }
+            self.console.print_warning(&format!("{} not found, falling back to a synthetic word list", config.words_file));
+            return Ok(DatasetSource::Synthetic(SyntheticConfig::default()));
+        }
+        
+        Ok(DatasetSource::File {
+            path: config.words_file.clone(),
+            format: config.format,
+            column: config.column.clone(),
+        })
+    }
+    
     async fn handle_load_words(&mut self) -> Result<()> {
         self.console.print_subheader("Load Words File");
         
@@ -101,7 +146,8 @@ impl SearchController {
     async fn handle_run_benchmarks(&mut self) -> Result<()> {
         self.console.print_subheader("Run Complete Benchmark Suite");
         
-        match self.coordinator.load_words("data/words.txt").await {
+        let source = self.dataset_source(&SearchConfig::default())?;
+        match self.coordinator.load_dataset(&source, &LoadOptions::default()).await {
             Ok(_) => {
                 self.console.print_success("Words loaded successfully!");
                 let stats = self.coordinator.get_stats();
@@ -138,6 +184,11 @@ impl SearchController {
             }
         }
         
+        if self.console.confirm("Show fuzzy 'did you mean' suggestions?", false)? {
+            let max_distance = self.console.get_number("Enter maximum edit distance", Some(2))?;
+            self.coordinator.run_fuzzy_search(&target, max_distance, iterations)?;
+        }
+        
         Ok(())
     }
     
@@ -161,6 +212,20 @@ impl SearchController {
             ("Interpolation Search", "O(log log n)", "O(1)", "Estimate position", "Uniformly distributed data"),
             ("Exponential Search", "O(log n)", "O(1)", "Find range then binary", "Sorted infinite arrays"),
             ("Jump Search", "O(√n)", "O(1)", "Block-wise jumping", "Sorted arrays"),
+            ("Fuzzy Scan", "O(n·m·k)", "O(m)", "Bounded edit distance", "Misspelled targets"),
+            ("BK-Tree", "O(n^α·m²)", "O(n)", "Metric tree pruning", "Repeated fuzzy lookups"),
+            ("SymSpell", "O(d^k·m)", "O(n·m^k)", "Symmetric delete index", "Low-latency suggestions"),
+            ("Bloom Filter", "O(k)", "O(n·log(1/ε))", "k hashed bits, no false negatives", "Approximate membership"),
+            ("Cuckoo Filter", "O(1)", "O(n·log(1/ε))", "Fingerprints with cuckoo eviction", "Membership with deletes"),
+            ("Quotient Filter", "O(1) avg", "O(n·log(1/ε))", "Quotient/remainder runs", "Cache-local membership"),
+            ("AVL Tree", "O(log n)", "O(n)", "Height-balanced rotations", "Read-heavy ordered maps"),
+            ("Red-Black Tree", "O(log n)", "O(n)", "Colour-balanced rotations", "Write-heavy ordered maps"),
+            ("B-Tree", "O(log_B n)", "O(n)", "Wide nodes split and merge", "Cache and disk friendly maps"),
+            ("Treap", "O(log n) exp", "O(n)", "Random priorities as a heap", "Simple balanced maps"),
+            ("Skip List", "O(log n) exp", "O(n)", "Randomised express lanes", "Ordered maps and ranges"),
+            ("Branchless Binary", "O(log n)", "O(1)", "Conditional moves, no mispredicts", "Integer keys in cache"),
+            ("Eytzinger", "O(log n)", "O(n)", "BFS layout with prefetching", "Large static key sets"),
+            ("S-Tree", "O(log_B n)", "O(n)", "Implicit B-tree nodes of 16 keys", "Keys beyond L3 cache"),
         ];
         
         println!("{:<20} {:<12} {:<12} {:<25} {:<25}", 
@@ -177,7 +242,8 @@ impl SearchController {
         println!("  • Hash search requires good hash function and collision handling");
         println!("  • Binary and interpolation require pre-sorted data");
         println!("  • Jump search optimal block size is √n");
-        println!("  • n = array size");
+        println!("  • Fuzzy search ranks suggestions by edit distance, then alphabetically");
+        println!("  • n = array size, m = word length, k = maximum edit distance");
     }
 }
 
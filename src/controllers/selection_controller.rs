@@ -0,0 +1,32 @@
+use crate::prelude::*;
+use crate::selection::SelectionCoordinator;
+use crate::models::SelectionConfig;
+use crate::views::ConsoleView;
+
+pub struct SelectionController {
+    coordinator: SelectionCoordinator,
+    console: ConsoleView,
+}
+
+impl SelectionController {
+    pub fn new() -> Self {
+        Self {
+            coordinator: SelectionCoordinator::new(),
+            console: ConsoleView::new(),
+        }
+    }
+    
+    pub async fn run_cli(&mut self, config: SelectionConfig) -> Result<()> {
+        self.console.print_header("Selection Algorithm Benchmarking System");
+        
+        self.coordinator.run_benchmarks(config.array_size, config.k, config.iterations)?;
+        
+        Ok(())
+    }
+}
+
+impl Default for SelectionController {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -45,10 +45,23 @@ impl SortController {
         Ok(())
     }
     
-    pub async fn run_cli(&mut self, size: usize, iterations: usize) -> Result<()> {
+    pub async fn run_cli(&mut self, config: SortConfig) -> Result<()> {
         self.console.print_header("Sorting Algorithm Benchmarking System");
         
-        self.coordinator.run_benchmarks(size, iterations)?;
+        self.coordinator.set_thresholds(config.thresholds)?;
+        self.coordinator.run_benchmarks(config.array_size, config.iterations)?;
+        
+        if config.tune_thresholds {
+            self.coordinator.run_threshold_benchmarks(config.array_size, config.iterations)?;
+        }
+        
+        if config.presortedness {
+            self.coordinator.run_presortedness_analysis(config.array_size, config.iterations)?;
+        }
+        
+        if config.types {
+            self.coordinator.run_typed_benchmarks(config.array_size, config.iterations)?;
+        }
         
         Ok(())
     }
@@ -0,0 +1,58 @@
+use crate::prelude::*;
+use crate::text_search::TextSearchCoordinator;
+use crate::models::TextSearchConfig;
+use crate::views::{InputHandler, ConsoleView};
+
+pub struct TextSearchController {
+    coordinator: TextSearchCoordinator,
+    console: ConsoleView,
+    input_handler: InputHandler,
+}
+
+impl TextSearchController {
+    pub fn new() -> Self {
+        Self {
+            coordinator: TextSearchCoordinator::new(),
+            console: ConsoleView::new(),
+            input_handler: InputHandler::new(),
+        }
+    }
+    
+    pub async fn run_cli(&mut self, config: TextSearchConfig) -> Result<()> {
+        self.console.print_header("Text Search Benchmarking System");
+        
+        if let Some(path) = &config.load_index {
+            let patterns = if config.patterns.is_empty() {
+                vec![self.input_handler.get_string("Enter pattern to search for")?]
+            } else {
+                config.patterns.clone()
+            };
+            self.coordinator.run_saved_index_queries(path, &patterns, config.iterations)?;
+            return Ok(());
+        }
+        
+        self.coordinator.load_text(&config.text_file).await?;
+        self.console.print_success(&format!("Loaded text from: {}", config.text_file));
+        
+        let patterns = if config.patterns.is_empty() {
+            self.console.print_info(&self.coordinator.get_stats());
+            vec![self.input_handler.get_string("Enter pattern to search for")?]
+        } else {
+            config.patterns
+        };
+        
+        self.coordinator.run_benchmarks(&patterns, config.iterations)?;
+        
+        if config.build_index {
+            self.console.print_subheader("Full-Text Index Comparison");
+            self.coordinator.run_index_benchmarks(&patterns, config.iterations, config.save_index.as_deref())?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for TextSearchController {
+    fn default() -> Self {
+        Self::new()
+    }
+}
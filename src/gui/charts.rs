@@ -0,0 +1,70 @@
+use crate::prelude::*;
+use plotters::prelude::*;
+
+#[derive(Debug, Clone)]
+pub struct ChartSeries {
+    pub name: String,
+    pub highlighted: bool,
+    pub points: Vec<(f64, f64)>,
+}
+
+const HIGHLIGHT_COLOURS: [RGBColor; 4] = [RED, RGBColor(230, 120, 0), RGBColor(200, 0, 160), RGBColor(150, 90, 0)];
+const MUTED_COLOURS: [RGBColor; 4] = [BLUE, RGBColor(0, 140, 140), RGBColor(90, 90, 90), RGBColor(0, 130, 0)];
+
+pub fn render_log_log_chart(path: &str, title: &str, x_label: &str, y_label: &str, series: &[ChartSeries]) -> Result<()> {
+    if let Some(directory) = std::path::Path::new(path).parent() {
+        std::fs::create_dir_all(directory).map_err(|e| Error::Generic(format!("Failed to create directory: {}", e)))?;
+    }
+
+    let points = series.iter().flat_map(|s| s.points.iter());
+    let (max_x, max_y) = points.fold((10.0f64, 10.0f64), |(x, y), &(px, py)| (x.max(px), y.max(py)));
+
+    let root = SVGBackend::new(path, (900, 600)).into_drawing_area();
+    root.fill(&WHITE).map_err(|e| Error::Generic(format!("Chart error: {}", e)))?;
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption(title, ("sans-serif", 24))
+        .margin(15)
+        .x_label_area_size(45)
+        .y_label_area_size(70)
+        .build_cartesian_2d((1.0..max_x * 2.0).log_scale(), (1.0..max_y * 2.0).log_scale())
+        .map_err(|e| Error::Generic(format!("Chart error: {}", e)))?;
+
+    chart.configure_mesh()
+        .x_desc(x_label)
+        .y_desc(y_label)
+        .draw()
+        .map_err(|e| Error::Generic(format!("Chart error: {}", e)))?;
+
+    let (mut highlighted, mut muted) = (0, 0);
+    for s in series {
+        let colour = if s.highlighted {
+            highlighted += 1;
+            HIGHLIGHT_COLOURS[(highlighted - 1) % HIGHLIGHT_COLOURS.len()]
+        } else {
+            muted += 1;
+            MUTED_COLOURS[(muted - 1) % MUTED_COLOURS.len()]
+        };
+
+        let mut points: Vec<(f64, f64)> = s.points.iter().map(|&(x, y)| (x.max(1.0), y.max(1.0))).collect();
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        chart.draw_series(LineSeries::new(points.clone(), colour.stroke_width(2)))
+            .map_err(|e| Error::Generic(format!("Chart error: {}", e)))?
+            .label(s.name.as_str())
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], colour.stroke_width(2)));
+
+        chart.draw_series(points.into_iter().map(|point| Circle::new(point, 4, colour.filled())))
+            .map_err(|e| Error::Generic(format!("Chart error: {}", e)))?;
+    }
+
+    chart.configure_series_labels()
+        .background_style(WHITE.mix(0.8))
+        .border_style(BLACK)
+        .position(SeriesLabelPosition::UpperLeft)
+        .draw()
+        .map_err(|e| Error::Generic(format!("Chart error: {}", e)))?;
+
+    root.present().map_err(|e| Error::Generic(format!("Chart error: {}", e)))?;
+    Ok(())
+}
@@ -5,6 +5,7 @@ pub mod pathfinder;
 pub mod pathfinder_visualisation;
 pub mod tree_traversal;
 pub mod tree_traversal_visualisation;
+pub mod charts;
 
 pub use sorting::*;
 pub use visualisation::*;
@@ -13,3 +14,4 @@ pub use pathfinder::*;
 pub use pathfinder_visualisation::*;
 pub use tree_traversal::*;
 pub use tree_traversal_visualisation::*;
+pub use charts::*;
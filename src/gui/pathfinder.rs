@@ -1,5 +1,6 @@
 use crate::prelude::*;
-use crate::pathfinder::{Grid, Position, CellType, PerformanceCounter};
+use crate::pathfinder::bidirectional::SearchEvent;
+use crate::pathfinder::{Grid, MazeGenerator, Position, CellType, PerformanceCounter, SearchDirection};
 use std::collections::{VecDeque, HashSet};
 use std::fs::File;
 use std::io::{self, Write};
@@ -20,6 +21,8 @@ pub struct PathfinderStep {
     pub current_position: Option<Position>,
     pub frontier_positions: Vec<Position>,
     pub explored_positions: HashSet<Position>,
+    pub backward_frontier_positions: Vec<Position>,
+    pub backward_explored_positions: HashSet<Position>,
     pub path_positions: Vec<Position>,
     pub step_description: String,
     pub algorithm_name: String,
@@ -36,6 +39,8 @@ pub struct GuiPerformanceCounter {
     pub current_grid: Option<Grid>,
     pub current_frontier: Vec<Position>,
     pub explored_set: HashSet<Position>,
+    pub backward_frontier: Vec<Position>,
+    pub backward_explored_set: HashSet<Position>,
 }
 
 impl GuiPerformanceCounter {
@@ -57,6 +62,8 @@ impl GuiPerformanceCounter {
                 current_position: Some(position),
                 frontier_positions: self.current_frontier.clone(),
                 explored_positions: self.explored_set.clone(),
+                backward_frontier_positions: self.backward_frontier.clone(),
+                backward_explored_positions: self.backward_explored_set.clone(),
                 path_positions: vec![],
                 step_description: description.to_string(),
                 algorithm_name: algorithm.to_string(),
@@ -75,6 +82,8 @@ impl GuiPerformanceCounter {
                 current_position: None,
                 frontier_positions: self.current_frontier.clone(),
                 explored_positions: self.explored_set.clone(),
+                backward_frontier_positions: self.backward_frontier.clone(),
+                backward_explored_positions: self.backward_explored_set.clone(),
                 path_positions: vec![],
                 step_description: description.to_string(),
                 algorithm_name: algorithm.to_string(),
@@ -86,6 +95,46 @@ impl GuiPerformanceCounter {
     pub fn remove_from_frontier(&mut self, position: Position) {
         self.current_frontier.retain(|&p| p != position);
     }
+
+    pub fn record_search_event(&mut self, event: SearchEvent, algorithm: &str) {
+        match event {
+            SearchEvent::Frontier(position, SearchDirection::Forward) => {
+                self.add_to_frontier(position, &format!("Adding {:?} to forward frontier", position), algorithm);
+            }
+            SearchEvent::Explore(position, SearchDirection::Forward) => {
+                self.remove_from_frontier(position);
+                self.explore_node(position, &format!("Exploring {:?} from the start", position), algorithm);
+            }
+            SearchEvent::Frontier(position, SearchDirection::Backward) => {
+                self.nodes_in_frontier += 1;
+                self.backward_frontier.push(position);
+                self.push_backward_step(None, PathfinderStepType::InFrontier, format!("Adding {:?} to backward frontier", position), algorithm);
+            }
+            SearchEvent::Explore(position, SearchDirection::Backward) => {
+                self.nodes_explored += 1;
+                self.backward_frontier.retain(|&p| p != position);
+                self.backward_explored_set.insert(position);
+                self.push_backward_step(Some(position), PathfinderStepType::Exploring, format!("Exploring {:?} from the goal", position), algorithm);
+            }
+        }
+    }
+
+    fn push_backward_step(&mut self, current_position: Option<Position>, step_type: PathfinderStepType, description: String, algorithm: &str) {
+        if let Some(ref grid) = self.current_grid {
+            self.steps.push_back(PathfinderStep {
+                grid: grid.clone(),
+                current_position,
+                frontier_positions: self.current_frontier.clone(),
+                explored_positions: self.explored_set.clone(),
+                backward_frontier_positions: self.backward_frontier.clone(),
+                backward_explored_positions: self.backward_explored_set.clone(),
+                path_positions: vec![],
+                step_description: description,
+                algorithm_name: algorithm.to_string(),
+                step_type,
+            });
+        }
+    }
     
     pub fn compare(&mut self) {
         self.comparisons += 1;
@@ -102,6 +151,8 @@ impl GuiPerformanceCounter {
                 current_position: None,
                 frontier_positions: vec![],
                 explored_positions: self.explored_set.clone(),
+                backward_frontier_positions: vec![],
+                backward_explored_positions: self.backward_explored_set.clone(),
                 path_positions: path,
                 step_description: "Final path found".to_string(),
                 algorithm_name: algorithm.to_string(),
@@ -139,6 +190,8 @@ impl PathfinderVisualiser {
             current_position: current_pos,
             frontier_positions: frontier,
             explored_positions: explored,
+            backward_frontier_positions: vec![],
+            backward_explored_positions: HashSet::new(),
             path_positions: path,
             step_description: description,
             algorithm_name: algorithm,
@@ -202,6 +255,10 @@ impl PathfinderVisualiser {
                         algorithm_name.to_string(),
                     );
                 }
+
+                if let Some(step) = self.steps.back_mut() {
+                    step.backward_explored_positions = gui_counter.backward_explored_set;
+                }
             }
             Err(e) => {
                 println!("❌ Algorithm failed: {}", e);
@@ -213,6 +270,38 @@ impl PathfinderVisualiser {
         self.render_animated_gif()
     }
 
+    pub fn visualise_generation(&mut self, generator: &MazeGenerator) -> Result<Grid> {
+        self.clear();
+
+        let (width, height) = self.grid_size;
+        let name = format!("Maze {}", generator.algorithm.as_str());
+        let stride = (width * height / 200).max(1);
+        let mut changes = 0;
+
+        println!("🎨 Generating {} ({}x{}, braid {:.2})", name, width, height, generator.braid);
+
+        let grid = generator.generate_traced(width, height, &mut |grid, position| {
+            if changes % stride == 0 {
+                self.add_step(
+                    grid.clone(),
+                    Some(position),
+                    vec![],
+                    HashSet::new(),
+                    vec![],
+                    format!("Changed ({}, {})", position.row, position.col),
+                    name.clone(),
+                );
+            }
+            changes += 1;
+        });
+
+        self.add_step(grid.clone(), None, vec![], HashSet::new(), vec![], format!("{} complete after {} cell changes", name, changes), name);
+
+        println!("🎬 Generating animated GIF visualization...");
+        self.render_animated_gif()?;
+        Ok(grid)
+    }
+
     pub fn visualise_algorithm_with_choice<F>(&mut self, algorithm_name: &str, grid: Grid, pathfind_fn: F, use_gif: bool) -> Result<()>
     where
         F: Fn(&Grid, &mut GuiPerformanceCounter) -> Result<(Vec<Position>, PerformanceCounter)>,
@@ -259,6 +348,10 @@ impl PathfinderVisualiser {
                         algorithm_name.to_string(),
                     );
                 }
+
+                if let Some(step) = self.steps.back_mut() {
+                    step.backward_explored_positions = gui_counter.backward_explored_set;
+                }
             }
             Err(e) => {
                 println!("❌ Algorithm failed: {}", e);
@@ -288,7 +381,7 @@ impl PathfinderVisualiser {
 
     fn render_animated_gif(&self) -> Result<()> {
         let algorithm_name = self.steps.front()
-            .map(|s| s.algorithm_name.replace(" ", "_").replace("*", "star").to_lowercase())
+            .map(|s| s.algorithm_name.replace(" ", "_").replace("*", "star").replace("'", "").to_lowercase())
             .unwrap_or_else(|| "pathfinder".to_string());
         
         let filename = format!("assets/gif/pathfinding/{}.gif", algorithm_name);
@@ -357,14 +450,24 @@ impl PathfinderVisualiser {
                     (255, 50, 50)
                 } else if step.frontier_positions.contains(&pos) {
                     (180, 100, 255)
+                } else if step.backward_frontier_positions.contains(&pos) {
+                    (255, 140, 0)
                 } else if step.explored_positions.contains(&pos) {
                     (200, 200, 200)
+                } else if step.backward_explored_positions.contains(&pos) {
+                    (255, 215, 170)
                 } else if step.grid.cells[row][col] == CellType::Blocked {
                     (0, 0, 0)
                 } else if step.grid.cells[row][col] == CellType::Start {
                     (0, 200, 0)
                 } else if step.grid.cells[row][col] == CellType::End {
                     (200, 0, 0)
+                } else if step.grid.cells[row][col] == CellType::Road {
+                    (230, 210, 150)
+                } else if step.grid.cells[row][col] == CellType::Mud {
+                    (120, 80, 40)
+                } else if step.grid.cells[row][col] == CellType::Water {
+                    (30, 60, 160)
                 } else {
                     (100, 150, 255)
                 };
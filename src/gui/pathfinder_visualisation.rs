@@ -1,16 +1,15 @@
 use crate::prelude::*;
 use crate::models::PathfinderAlgorithm;
-use crate::pathfinder::{Grid, Position, CellType, PerformanceCounter};
+use crate::pathfinder::{bidirectional, Grid, MazeGenerator, ObstacleGenerator, Position, CellType, PerformanceCounter};
 use crate::gui::pathfinder::{PathfinderVisualiser, GuiPerformanceCounter};
-use rand::{rng, Rng};
 use std::io::{self, Write};
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{HashMap, HashSet};
 
-pub fn run_pathfinder_visualisation(algorithm: &str, grid_size: (usize, usize)) -> Result<()> {
+pub fn run_pathfinder_visualisation(algorithm: &str, grid_size: (usize, usize), maze: Option<MazeGenerator>) -> Result<()> {
     let mut visualiser = PathfinderVisualiser::new(grid_size);
     
 
-    let grid = create_test_grid(grid_size.0, grid_size.1, 0.25)?;
+    let grid = create_layout(grid_size, maze.as_ref())?;
     
     match algorithm {
         "astar" | "a*" => {
@@ -38,6 +37,21 @@ pub fn run_pathfinder_visualisation(algorithm: &str, grid_size: (usize, usize))
                 greedy_best_first_with_gui(grid, counter)
             })?;
         },
+        "bidirectional-bfs" | "bibfs" => {
+            visualiser.visualise_algorithm("Bidirectional BFS", grid, |grid, counter| {
+                bidirectional_with_gui(grid, counter, "Bidirectional BFS", bidirectional::breadth_first_traced)
+            })?;
+        },
+        "bidirectional-dijkstra" | "bidijkstra" => {
+            visualiser.visualise_algorithm("Bidirectional Dijkstra", grid, |grid, counter| {
+                bidirectional_with_gui(grid, counter, "Bidirectional Dijkstra", bidirectional::dijkstra_traced)
+            })?;
+        },
+        "nba-star" | "nba*" => {
+            visualiser.visualise_algorithm("NBA*", grid, |grid, counter| {
+                bidirectional_with_gui(grid, counter, "NBA*", bidirectional::astar_traced)
+            })?;
+        },
         _ => {
             return Err(Error::validation(format!("Unknown pathfinding algorithm: {}", algorithm)));
         }
@@ -46,21 +60,22 @@ pub fn run_pathfinder_visualisation(algorithm: &str, grid_size: (usize, usize))
     Ok(())
 }
 
-pub fn run_all_pathfinder_visualisations(grid_size: (usize, usize)) -> Result<()> {
-    println!("🎨 Running GUI visualisations for all 5 pathfinding algorithms!");
+pub fn run_all_pathfinder_visualisations(grid_size: (usize, usize), maze: Option<MazeGenerator>) -> Result<()> {
+    println!("🎨 Running GUI visualisations for all 8 pathfinding algorithms!");
     
     println!("📺 Generating animated GIFs for all algorithms...");
     
     let algorithms = vec![
         "A*", "Dijkstra", "Breadth-First Search", 
-        "Depth-First Search", "Greedy Best-First"
+        "Depth-First Search", "Greedy Best-First",
+        "Bidirectional BFS", "Bidirectional Dijkstra", "NBA*"
     ];
     
     for (i, algorithm) in algorithms.iter().enumerate() {
         println!("🔄 Processing {}/{}: {}", i + 1, algorithms.len(), algorithm);
         
 
-        let grid = create_test_grid(grid_size.0, grid_size.1, 0.25)?;
+        let grid = create_layout(grid_size, maze.as_ref())?;
         
         let mut visualiser = PathfinderVisualiser::new(grid_size);
         
@@ -90,6 +105,21 @@ pub fn run_all_pathfinder_visualisations(grid_size: (usize, usize)) -> Result<()
                     greedy_best_first_with_gui(grid, counter)
                 }, true)?;
             },
+            "Bidirectional BFS" => {
+                visualiser.visualise_algorithm_with_choice("Bidirectional BFS", grid, |grid, counter| {
+                    bidirectional_with_gui(grid, counter, "Bidirectional BFS", bidirectional::breadth_first_traced)
+                }, true)?;
+            },
+            "Bidirectional Dijkstra" => {
+                visualiser.visualise_algorithm_with_choice("Bidirectional Dijkstra", grid, |grid, counter| {
+                    bidirectional_with_gui(grid, counter, "Bidirectional Dijkstra", bidirectional::dijkstra_traced)
+                }, true)?;
+            },
+            "NBA*" => {
+                visualiser.visualise_algorithm_with_choice("NBA*", grid, |grid, counter| {
+                    bidirectional_with_gui(grid, counter, "NBA*", bidirectional::astar_traced)
+                }, true)?;
+            },
             _ => {
                 eprintln!("❌ Unknown algorithm: {}", algorithm);
                 continue;
@@ -103,172 +133,24 @@ pub fn run_all_pathfinder_visualisations(grid_size: (usize, usize)) -> Result<()
     Ok(())
 }
 
-fn create_test_grid(width: usize, height: usize, obstacle_percentage: f64) -> Result<Grid> {
-    let start = Position::new(0, 0);
-    let end = Position::new(height.saturating_sub(1), width.saturating_sub(1));
-    let mut grid = Grid::new(width, height, start, end);
-    
-
-    if width < 3 || height < 3 {
-        return Ok(grid);
-    }
-    
-    let mut rng = rand::rng();
-    let total_cells = width * height;
-    let obstacle_count = (total_cells as f64 * obstacle_percentage) as usize;
-    
-
-    let protected_positions = get_protected_positions(&grid);
-    
-    let mut obstacles_placed = 0;
-    let mut attempts = 0;
-    let max_attempts = total_cells * 3;
-    
-    while obstacles_placed < obstacle_count && attempts < max_attempts {
-        attempts += 1;
-        
-        let row = rng.random_range(0..height);
-        let col = rng.random_range(0..width);
-        let pos = Position::new(row, col);
-        
-
-        if protected_positions.contains(&pos) || grid.cells[row][col] != CellType::Open {
-            continue;
-        }
-        
-
-        grid.add_obstacle(pos);
-        
-
-        if is_grid_connected(&grid) {
-            obstacles_placed += 1;
-        } else {
-
-            grid.cells[row][col] = CellType::Open;
-        }
-    }
-    
-
-    if !is_grid_connected(&grid) {
-
-        return create_simple_connected_grid(width, height, obstacle_percentage);
-    }
-    
-    Ok(grid)
-}
-
-fn get_protected_positions(grid: &Grid) -> HashSet<Position> {
-    let mut protected = HashSet::new();
-    
-
-    protected.insert(grid.start);
-    for neighbor in get_eight_directional_neighbors(grid.start, grid.width, grid.height) {
-        protected.insert(neighbor);
-    }
-    
-
-    protected.insert(grid.end);
-    for neighbor in get_eight_directional_neighbors(grid.end, grid.width, grid.height) {
-        protected.insert(neighbor);
-    }
-    
-    protected
-}
-
-fn get_eight_directional_neighbors(pos: Position, width: usize, height: usize) -> Vec<Position> {
-    let mut neighbors = Vec::new();
-    let row = pos.row as i32;
-    let col = pos.col as i32;
-    
+pub fn run_maze_visualisation(generator: MazeGenerator, grid_size: (usize, usize)) -> Result<()> {
+    let mut visualiser = PathfinderVisualiser::new(grid_size);
+    let grid = visualiser.visualise_generation(&generator)?;
 
-    let directions = [
-        (-1, -1), (-1, 0), (-1, 1),
-        ( 0, -1),          ( 0, 1),  
-        ( 1, -1), ( 1, 0), ( 1, 1)
-    ];
-    
-    for (dr, dc) in directions {
-        let new_row = row + dr;
-        let new_col = col + dc;
-        
-        if new_row >= 0 && new_row < height as i32 && 
-           new_col >= 0 && new_col < width as i32 {
-            neighbors.push(Position::new(new_row as usize, new_col as usize));
-        }
-    }
-    
-    neighbors
+    let open_cells = grid.cells.iter().flatten().filter(|cell| **cell != CellType::Blocked).count();
+    println!("🧱 {} open cells, {} dead ends", open_cells, crate::pathfinder::maze::count_dead_ends(&grid));
+    Ok(())
 }
 
-fn is_grid_connected(grid: &Grid) -> bool {
-
-    let mut visited = HashSet::new();
-    let mut queue = VecDeque::new();
-    
-    queue.push_back(grid.start);
-    visited.insert(grid.start);
-    
-    while let Some(current) = queue.pop_front() {
-        if current == grid.end {
-            return true;
-        }
-        
-
-        for neighbor in grid.get_neighbors(&current) {
-            if !visited.contains(&neighbor) {
-                visited.insert(neighbor);
-                queue.push_back(neighbor);
-            }
-        }
+fn create_layout(grid_size: (usize, usize), maze: Option<&MazeGenerator>) -> Result<Grid> {
+    match maze {
+        Some(generator) => Ok(generator.generate(grid_size.0, grid_size.1)),
+        None => create_test_grid(grid_size.0, grid_size.1, 0.25),
     }
-    
-    false
 }
 
-fn create_simple_connected_grid(width: usize, height: usize, obstacle_percentage: f64) -> Result<Grid> {
-    let start = Position::new(0, 0);
-    let end = Position::new(height.saturating_sub(1), width.saturating_sub(1));
-    let mut grid = Grid::new(width, height, start, end);
-    
-
-    let mut current = start;
-    let mut guaranteed_path = HashSet::new();
-    
-
-    while current != end {
-        guaranteed_path.insert(current);
-        
-        if current.col < end.col {
-            current = Position::new(current.row, current.col + 1);
-        } else if current.row < end.row {
-            current = Position::new(current.row + 1, current.col);
-        } else {
-            break;
-        }
-    }
-    guaranteed_path.insert(end);
-    
-
-    let mut rng = rand::rng();
-    let total_cells = width * height;
-    let obstacle_count = ((total_cells - guaranteed_path.len()) as f64 * obstacle_percentage * 0.5) as usize;
-    
-    let mut obstacles_placed = 0;
-    while obstacles_placed < obstacle_count {
-        let row = rng.random_range(0..height);
-        let col = rng.random_range(0..width);
-        let pos = Position::new(row, col);
-        
-
-        if !guaranteed_path.contains(&pos) && 
-           !get_eight_directional_neighbors(pos, width, height).iter().any(|&p| guaranteed_path.contains(&p)) &&
-           grid.cells[row][col] == CellType::Open {
-            grid.add_obstacle(pos);
-            obstacles_placed += 1;
-        }
-    }
-    
-    Ok(grid)
+fn create_test_grid(width: usize, height: usize, obstacle_percentage: f64) -> Result<Grid> {
+    ObstacleGenerator::new(obstacle_percentage).generate(width, height)
 }
 
 
@@ -646,6 +528,19 @@ fn greedy_best_first_with_gui(grid: &Grid, counter: &mut GuiPerformanceCounter)
     Ok((Vec::new(), perf_counter))
 }
 
+fn bidirectional_with_gui<F>(grid: &Grid, counter: &mut GuiPerformanceCounter, algorithm: &str, search: F) -> Result<(Vec<Position>, PerformanceCounter)>
+where
+    F: FnOnce(&Grid, &mut dyn FnMut(bidirectional::SearchEvent)) -> std::result::Result<(Vec<Position>, PerformanceCounter), String>,
+{
+    let (path, perf_counter) = search(grid, &mut |event| counter.record_search_event(event, algorithm))
+        .map_err(Error::Generic)?;
+
+    if !path.is_empty() {
+        counter.record_final_path(path.clone(), algorithm);
+    }
+    Ok((path, perf_counter))
+}
+
 fn heuristic(from: &Position, to: &Position) -> f64 {
     let dx = (from.col as i32 - to.col as i32).abs() as f64;
     let dy = (from.row as i32 - to.row as i32).abs() as f64;
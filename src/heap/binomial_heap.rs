@@ -0,0 +1,181 @@
+use super::{Handle, PriorityQueue, NIL};
+
+#[derive(Debug, Clone)]
+struct BinomialNode {
+    handle: Handle,
+    parent: usize,
+    child: usize,
+    sibling: usize,
+    degree: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct BinomialHeap<K, T> {
+    nodes: Vec<BinomialNode>,
+    entries: Vec<Option<(K, T)>>,
+    locations: Vec<usize>,
+    roots: Vec<usize>,
+    len: usize,
+}
+
+impl<K: Ord, T> BinomialHeap<K, T> {
+    pub fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            entries: Vec::new(),
+            locations: Vec::new(),
+            roots: Vec::new(),
+            len: 0,
+        }
+    }
+
+    fn key(&self, node: usize) -> &K {
+        &self.entries[self.nodes[node].handle].as_ref().expect("live node has an entry").0
+    }
+
+    fn link(&mut self, a: usize, b: usize) -> usize {
+        let (parent, child) = if self.key(b) < self.key(a) { (b, a) } else { (a, b) };
+        self.nodes[child].parent = parent;
+        self.nodes[child].sibling = self.nodes[parent].child;
+        self.nodes[parent].child = child;
+        self.nodes[parent].degree += 1;
+        parent
+    }
+
+    fn add_root(&mut self, mut tree: usize) {
+        self.nodes[tree].parent = NIL;
+        self.nodes[tree].sibling = NIL;
+
+        let mut degree = self.nodes[tree].degree;
+        loop {
+            if degree >= self.roots.len() {
+                self.roots.resize(degree + 1, NIL);
+            }
+            match std::mem::replace(&mut self.roots[degree], NIL) {
+                NIL => {
+                    self.roots[degree] = tree;
+                    return;
+                }
+                existing => {
+                    tree = self.link(tree, existing);
+                    degree += 1;
+                }
+            }
+        }
+    }
+
+    fn min_root(&self) -> Option<usize> {
+        self.roots
+            .iter()
+            .copied()
+            .filter(|&root| root != NIL)
+            .min_by(|&a, &b| self.key(a).cmp(self.key(b)))
+    }
+
+    fn swap_with_parent(&mut self, node: usize, parent: usize) {
+        let node_handle = self.nodes[node].handle;
+        let parent_handle = self.nodes[parent].handle;
+        self.nodes[node].handle = parent_handle;
+        self.nodes[parent].handle = node_handle;
+        self.locations[parent_handle] = node;
+        self.locations[node_handle] = parent;
+    }
+}
+
+impl<K: Ord, T> Default for BinomialHeap<K, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord, T> PriorityQueue<K, T> for BinomialHeap<K, T> {
+    fn name(&self) -> &'static str {
+        "Binomial Heap"
+    }
+
+    fn push(&mut self, key: K, item: T) -> Handle {
+        let handle = self.entries.len();
+        let node = self.nodes.len();
+        self.entries.push(Some((key, item)));
+        self.locations.push(node);
+        self.nodes.push(BinomialNode { handle, parent: NIL, child: NIL, sibling: NIL, degree: 0 });
+        self.add_root(node);
+        self.len += 1;
+        handle
+    }
+
+    fn peek(&self) -> Option<(&K, &T)> {
+        let root = self.min_root()?;
+        self.entries[self.nodes[root].handle].as_ref().map(|(key, item)| (key, item))
+    }
+
+    fn pop(&mut self) -> Option<(K, T)> {
+        let root = self.min_root()?;
+        let degree = self.nodes[root].degree;
+        self.roots[degree] = NIL;
+
+        let mut child = self.nodes[root].child;
+        while child != NIL {
+            let next = self.nodes[child].sibling;
+            self.add_root(child);
+            child = next;
+        }
+
+        let handle = self.nodes[root].handle;
+        self.locations[handle] = NIL;
+        self.len -= 1;
+        self.entries[handle].take()
+    }
+
+    fn decrease_key(&mut self, handle: Handle, key: K) -> bool {
+        let Some(&node) = self.locations.get(handle) else {
+            return false;
+        };
+        if node == NIL {
+            return false;
+        }
+
+        let entry = self.entries[handle].as_mut().expect("live handle has an entry");
+        if key > entry.0 {
+            return false;
+        }
+        entry.0 = key;
+
+        let mut current = node;
+        while self.nodes[current].parent != NIL {
+            let parent = self.nodes[current].parent;
+            if self.key(current) >= self.key(parent) {
+                break;
+            }
+            self.swap_with_parent(current, parent);
+            current = parent;
+        }
+        true
+    }
+
+    fn merge(&mut self, other: Self) -> usize {
+        let offset = self.entries.len();
+        let node_offset = self.nodes.len();
+        let shift = |index: usize| if index == NIL { NIL } else { index + node_offset };
+
+        self.entries.extend(other.entries);
+        self.locations.extend(other.locations.into_iter().map(shift));
+        self.nodes.extend(other.nodes.into_iter().map(|node| BinomialNode {
+            handle: node.handle + offset,
+            parent: shift(node.parent),
+            child: shift(node.child),
+            sibling: shift(node.sibling),
+            degree: node.degree,
+        }));
+
+        for root in other.roots.into_iter().filter(|&root| root != NIL) {
+            self.add_root(root + node_offset);
+        }
+        self.len += other.len;
+        offset
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+}
@@ -0,0 +1,137 @@
+use super::{Handle, PriorityQueue, NIL};
+
+#[derive(Debug, Clone)]
+pub struct DaryHeap<K, T> {
+    arity: usize,
+    heap: Vec<(K, Handle)>,
+    items: Vec<Option<T>>,
+    positions: Vec<usize>,
+}
+
+impl<K: Ord, T> DaryHeap<K, T> {
+    pub fn new(arity: usize) -> Self {
+        Self {
+            arity: arity.max(2),
+            heap: Vec::new(),
+            items: Vec::new(),
+            positions: Vec::new(),
+        }
+    }
+
+    pub fn arity(&self) -> usize {
+        self.arity
+    }
+
+    fn place(&mut self, index: usize) {
+        self.positions[self.heap[index].1] = index;
+    }
+
+    fn sift_up(&mut self, mut index: usize) {
+        while index > 0 {
+            let parent = (index - 1) / self.arity;
+            if self.heap[index].0 >= self.heap[parent].0 {
+                break;
+            }
+            self.heap.swap(index, parent);
+            self.place(index);
+            index = parent;
+        }
+        self.place(index);
+    }
+
+    fn sift_down(&mut self, mut index: usize) {
+        loop {
+            let first = index * self.arity + 1;
+            if first >= self.heap.len() {
+                break;
+            }
+
+            let last = (first + self.arity).min(self.heap.len());
+            let smallest = (first..last)
+                .min_by(|&a, &b| self.heap[a].0.cmp(&self.heap[b].0))
+                .expect("non-empty child range");
+
+            if self.heap[smallest].0 >= self.heap[index].0 {
+                break;
+            }
+            self.heap.swap(index, smallest);
+            self.place(index);
+            index = smallest;
+        }
+        self.place(index);
+    }
+}
+
+impl<K: Ord, T> PriorityQueue<K, T> for DaryHeap<K, T> {
+    fn name(&self) -> &'static str {
+        match self.arity {
+            2 => "Binary Heap",
+            4 => "4-ary Heap",
+            8 => "8-ary Heap",
+            _ => "d-ary Heap",
+        }
+    }
+
+    fn push(&mut self, key: K, item: T) -> Handle {
+        let handle = self.items.len();
+        self.items.push(Some(item));
+        self.positions.push(self.heap.len());
+        self.heap.push((key, handle));
+        self.sift_up(self.heap.len() - 1);
+        handle
+    }
+
+    fn peek(&self) -> Option<(&K, &T)> {
+        let (key, handle) = self.heap.first()?;
+        self.items[*handle].as_ref().map(|item| (key, item))
+    }
+
+    fn pop(&mut self) -> Option<(K, T)> {
+        if self.heap.is_empty() {
+            return None;
+        }
+
+        let (key, handle) = self.heap.swap_remove(0);
+        self.positions[handle] = NIL;
+        if !self.heap.is_empty() {
+            self.sift_down(0);
+        }
+
+        let item = self.items[handle].take().expect("queued handle has an item");
+        Some((key, item))
+    }
+
+    fn decrease_key(&mut self, handle: Handle, key: K) -> bool {
+        let Some(&index) = self.positions.get(handle) else {
+            return false;
+        };
+        if index == NIL || key > self.heap[index].0 {
+            return false;
+        }
+
+        self.heap[index].0 = key;
+        self.sift_up(index);
+        true
+    }
+
+    fn merge(&mut self, other: Self) -> usize {
+        let offset = self.items.len();
+        self.items.extend(other.items);
+        self.positions.extend(other.positions.iter().map(|_| NIL));
+        self.heap.extend(other.heap.into_iter().map(|(key, handle)| (key, handle + offset)));
+
+        for index in 0..self.heap.len() {
+            self.place(index);
+        }
+        if self.heap.len() > 1 {
+            for index in (0..=(self.heap.len() - 2) / self.arity).rev() {
+                self.sift_down(index);
+            }
+        }
+        offset
+    }
+
+    fn len(&self) -> usize {
+        self.heap.len()
+    }
+}
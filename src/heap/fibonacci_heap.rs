@@ -0,0 +1,253 @@
+use super::{Handle, PriorityQueue, NIL};
+
+#[derive(Debug, Clone)]
+struct FibonacciNode<K, T> {
+    entry: Option<(K, T)>,
+    parent: usize,
+    child: usize,
+    left: usize,
+    right: usize,
+    degree: usize,
+    marked: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct FibonacciHeap<K, T> {
+    nodes: Vec<FibonacciNode<K, T>>,
+    min: usize,
+    len: usize,
+}
+
+impl<K: Ord, T> FibonacciHeap<K, T> {
+    pub fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            min: NIL,
+            len: 0,
+        }
+    }
+
+    fn key(&self, node: usize) -> &K {
+        &self.nodes[node].entry.as_ref().expect("live node has an entry").0
+    }
+
+    fn splice(&mut self, a: usize, b: usize) {
+        let a_right = self.nodes[a].right;
+        let b_left = self.nodes[b].left;
+        self.nodes[a].right = b;
+        self.nodes[b].left = a;
+        self.nodes[a_right].left = b_left;
+        self.nodes[b_left].right = a_right;
+    }
+
+    fn detach(&mut self, node: usize) {
+        let left = self.nodes[node].left;
+        let right = self.nodes[node].right;
+        self.nodes[left].right = right;
+        self.nodes[right].left = left;
+        self.nodes[node].left = node;
+        self.nodes[node].right = node;
+    }
+
+    fn add_root(&mut self, node: usize) {
+        self.nodes[node].parent = NIL;
+        self.nodes[node].marked = false;
+        if self.min == NIL {
+            self.min = node;
+        } else {
+            self.splice(self.min, node);
+            if self.key(node) < self.key(self.min) {
+                self.min = node;
+            }
+        }
+    }
+
+    fn link(&mut self, child: usize, parent: usize) {
+        self.detach(child);
+        self.nodes[child].parent = parent;
+        self.nodes[child].marked = false;
+        match self.nodes[parent].child {
+            NIL => self.nodes[parent].child = child,
+            first => self.splice(first, child),
+        }
+        self.nodes[parent].degree += 1;
+    }
+
+    fn consolidate(&mut self) {
+        let mut roots = Vec::new();
+        let start = self.min;
+        let mut current = start;
+        loop {
+            roots.push(current);
+            current = self.nodes[current].right;
+            if current == start {
+                break;
+            }
+        }
+
+        let mut by_degree: Vec<usize> = Vec::new();
+        for mut root in roots {
+            let mut degree = self.nodes[root].degree;
+            loop {
+                if degree >= by_degree.len() {
+                    by_degree.resize(degree + 1, NIL);
+                }
+                let other = std::mem::replace(&mut by_degree[degree], NIL);
+                if other == NIL {
+                    by_degree[degree] = root;
+                    break;
+                }
+                let (parent, child) = if self.key(other) < self.key(root) { (other, root) } else { (root, other) };
+                self.link(child, parent);
+                root = parent;
+                degree += 1;
+            }
+        }
+
+        self.min = NIL;
+        for root in by_degree.into_iter().filter(|&root| root != NIL) {
+            self.nodes[root].left = root;
+            self.nodes[root].right = root;
+            self.add_root(root);
+        }
+    }
+
+    fn cut(&mut self, node: usize, parent: usize) {
+        if self.nodes[node].right == node {
+            self.nodes[parent].child = NIL;
+        } else if self.nodes[parent].child == node {
+            self.nodes[parent].child = self.nodes[node].right;
+        }
+        self.detach(node);
+        self.nodes[parent].degree -= 1;
+        self.add_root(node);
+    }
+
+    fn cascading_cut(&mut self, mut node: usize) {
+        while self.nodes[node].parent != NIL {
+            let parent = self.nodes[node].parent;
+            if !self.nodes[node].marked {
+                self.nodes[node].marked = true;
+                return;
+            }
+            self.cut(node, parent);
+            node = parent;
+        }
+    }
+}
+
+impl<K: Ord, T> Default for FibonacciHeap<K, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord, T> PriorityQueue<K, T> for FibonacciHeap<K, T> {
+    fn name(&self) -> &'static str {
+        "Fibonacci Heap"
+    }
+
+    fn push(&mut self, key: K, item: T) -> Handle {
+        let node = self.nodes.len();
+        self.nodes.push(FibonacciNode {
+            entry: Some((key, item)),
+            parent: NIL,
+            child: NIL,
+            left: node,
+            right: node,
+            degree: 0,
+            marked: false,
+        });
+        self.add_root(node);
+        self.len += 1;
+        node
+    }
+
+    fn peek(&self) -> Option<(&K, &T)> {
+        if self.min == NIL {
+            return None;
+        }
+        self.nodes[self.min].entry.as_ref().map(|(key, item)| (key, item))
+    }
+
+    fn pop(&mut self) -> Option<(K, T)> {
+        if self.min == NIL {
+            return None;
+        }
+
+        let min = self.min;
+        let mut child = self.nodes[min].child;
+        while child != NIL {
+            let next = if self.nodes[child].right == child { NIL } else { self.nodes[child].right };
+            self.detach(child);
+            self.add_root(child);
+            child = next;
+        }
+        self.nodes[min].child = NIL;
+
+        let next_root = self.nodes[min].right;
+        self.detach(min);
+        self.min = if next_root == min { NIL } else { next_root };
+        if self.min != NIL {
+            self.consolidate();
+        }
+
+        self.len -= 1;
+        self.nodes[min].parent = NIL;
+        self.nodes[min].entry.take()
+    }
+
+    fn decrease_key(&mut self, handle: Handle, key: K) -> bool {
+        let Some(node) = self.nodes.get_mut(handle) else {
+            return false;
+        };
+        let Some(entry) = node.entry.as_mut() else {
+            return false;
+        };
+        if key > entry.0 {
+            return false;
+        }
+        entry.0 = key;
+
+        let parent = self.nodes[handle].parent;
+        if parent != NIL && self.key(handle) < self.key(parent) {
+            self.cut(handle, parent);
+            self.cascading_cut(parent);
+        }
+        if self.key(handle) < self.key(self.min) {
+            self.min = handle;
+        }
+        true
+    }
+
+    fn merge(&mut self, other: Self) -> usize {
+        let offset = self.nodes.len();
+        let shift = |index: usize| if index == NIL { NIL } else { index + offset };
+
+        self.nodes.extend(other.nodes.into_iter().map(|node| FibonacciNode {
+            parent: shift(node.parent),
+            child: shift(node.child),
+            left: shift(node.left),
+            right: shift(node.right),
+            ..node
+        }));
+
+        if other.min != NIL {
+            let other_min = other.min + offset;
+            if self.min == NIL {
+                self.min = other_min;
+            } else {
+                self.splice(self.min, other_min);
+                if self.key(other_min) < self.key(self.min) {
+                    self.min = other_min;
+                }
+            }
+        }
+        self.len += other.len;
+        offset
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+}
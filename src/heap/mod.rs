@@ -0,0 +1,116 @@
+pub mod d_ary_heap;
+pub mod binomial_heap;
+pub mod fibonacci_heap;
+pub mod pairing_heap;
+pub mod radix_heap;
+
+pub type Handle = usize;
+
+const NIL: usize = usize::MAX;
+
+pub trait PriorityQueue<K: Ord, T> {
+    fn name(&self) -> &'static str;
+    fn push(&mut self, key: K, item: T) -> Handle;
+    fn peek(&self) -> Option<(&K, &T)>;
+    fn pop(&mut self) -> Option<(K, T)>;
+    fn decrease_key(&mut self, handle: Handle, key: K) -> bool;
+    fn merge(&mut self, other: Self) -> usize
+    where
+        Self: Sized;
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    fn check_against_reference<Q: PriorityQueue<u64, usize>>(mut make: impl FnMut() -> Q) {
+        let mut queue = make();
+        let mut reference = BTreeSet::new();
+        let mut handles = Vec::new();
+        let mut state = 0x9E37_79B9_u64;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+
+        let mut floor = 0;
+        for step in 0..4_000 {
+            match next() % 5 {
+                0 | 1 => {
+                    let key = floor + next() % 10_000;
+                    let handle = queue.push(key, step);
+                    handles.push((handle, key, step));
+                    reference.insert((key, step));
+                }
+                2 if !handles.is_empty() => {
+                    let index = (next() % handles.len() as u64) as usize;
+                    let (handle, key, item) = handles[index];
+                    if reference.contains(&(key, item)) {
+                        let lower = floor + (key - floor) / 2;
+                        assert!(queue.decrease_key(handle, lower), "{} decrease", queue.name());
+                        reference.remove(&(key, item));
+                        reference.insert((lower, item));
+                        handles[index].1 = lower;
+                    }
+                }
+                _ => {
+                    let expected = reference.first().map(|&(key, _)| key);
+                    let actual = queue.pop();
+                    assert_eq!(actual.map(|(key, _)| key), expected, "{} pop", queue.name());
+                    if let Some((key, item)) = actual {
+                        assert!(reference.remove(&(key, item)), "{} popped unknown item", queue.name());
+                        floor = key;
+                    }
+                }
+            }
+            assert_eq!(queue.len(), reference.len(), "{} len", queue.name());
+        }
+
+        let mut other = make();
+        for item in 0..100 {
+            let key = floor + (item as u64 * 37) % 500;
+            other.push(key, 10_000 + item);
+            reference.insert((key, 10_000 + item));
+        }
+        queue.merge(other);
+
+        let mut previous = 0;
+        while let Some((key, _)) = queue.pop() {
+            assert!(key >= previous, "{} order", queue.name());
+            previous = key;
+            reference.pop_first();
+        }
+        assert!(reference.is_empty(), "{} lost items", queue.name());
+    }
+
+    #[test]
+    fn test_heaps_match_reference() {
+        check_against_reference(|| d_ary_heap::DaryHeap::new(2));
+        check_against_reference(|| d_ary_heap::DaryHeap::new(4));
+        check_against_reference(binomial_heap::BinomialHeap::new);
+        check_against_reference(fibonacci_heap::FibonacciHeap::new);
+        check_against_reference(pairing_heap::PairingHeap::new);
+        check_against_reference(radix_heap::RadixHeap::new);
+    }
+
+    #[test]
+    fn test_merge_offsets_handles() {
+        let mut left = pairing_heap::PairingHeap::new();
+        let mut right = pairing_heap::PairingHeap::new();
+        left.push(10, "a");
+        let handle = right.push(20, "b");
+
+        let offset = left.merge(right);
+        assert!(left.decrease_key(handle + offset, 5));
+        assert_eq!(left.pop(), Some((5, "b")));
+        assert_eq!(left.pop(), Some((10, "a")));
+    }
+}
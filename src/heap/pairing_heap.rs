@@ -0,0 +1,165 @@
+use super::{Handle, PriorityQueue, NIL};
+
+#[derive(Debug, Clone)]
+struct PairingNode<K, T> {
+    entry: Option<(K, T)>,
+    child: usize,
+    sibling: usize,
+    prev: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct PairingHeap<K, T> {
+    nodes: Vec<PairingNode<K, T>>,
+    root: usize,
+    len: usize,
+}
+
+impl<K: Ord, T> PairingHeap<K, T> {
+    pub fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            root: NIL,
+            len: 0,
+        }
+    }
+
+    fn key(&self, node: usize) -> &K {
+        &self.nodes[node].entry.as_ref().expect("live node has an entry").0
+    }
+
+    fn meld(&mut self, a: usize, b: usize) -> usize {
+        if a == NIL {
+            return b;
+        }
+        if b == NIL {
+            return a;
+        }
+
+        let (parent, child) = if self.key(b) < self.key(a) { (b, a) } else { (a, b) };
+        let first = self.nodes[parent].child;
+        self.nodes[child].sibling = first;
+        self.nodes[child].prev = parent;
+        if first != NIL {
+            self.nodes[first].prev = child;
+        }
+        self.nodes[parent].child = child;
+        self.nodes[parent].sibling = NIL;
+        self.nodes[parent].prev = NIL;
+        parent
+    }
+
+    fn merge_pairs(&mut self, first: usize) -> usize {
+        let mut pairs = Vec::new();
+        let mut current = first;
+
+        while current != NIL {
+            let second = self.nodes[current].sibling;
+            let next = if second == NIL { NIL } else { self.nodes[second].sibling };
+            for node in [current, second] {
+                if node != NIL {
+                    self.nodes[node].sibling = NIL;
+                    self.nodes[node].prev = NIL;
+                }
+            }
+            pairs.push(self.meld(current, second));
+            current = next;
+        }
+
+        pairs.into_iter().rev().fold(NIL, |acc, tree| self.meld(tree, acc))
+    }
+
+    fn unlink(&mut self, node: usize) {
+        let prev = self.nodes[node].prev;
+        let sibling = self.nodes[node].sibling;
+
+        if self.nodes[prev].child == node {
+            self.nodes[prev].child = sibling;
+        } else {
+            self.nodes[prev].sibling = sibling;
+        }
+        if sibling != NIL {
+            self.nodes[sibling].prev = prev;
+        }
+        self.nodes[node].prev = NIL;
+        self.nodes[node].sibling = NIL;
+    }
+}
+
+impl<K: Ord, T> Default for PairingHeap<K, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord, T> PriorityQueue<K, T> for PairingHeap<K, T> {
+    fn name(&self) -> &'static str {
+        "Pairing Heap"
+    }
+
+    fn push(&mut self, key: K, item: T) -> Handle {
+        let node = self.nodes.len();
+        self.nodes.push(PairingNode { entry: Some((key, item)), child: NIL, sibling: NIL, prev: NIL });
+        self.root = self.meld(self.root, node);
+        self.len += 1;
+        node
+    }
+
+    fn peek(&self) -> Option<(&K, &T)> {
+        if self.root == NIL {
+            return None;
+        }
+        self.nodes[self.root].entry.as_ref().map(|(key, item)| (key, item))
+    }
+
+    fn pop(&mut self) -> Option<(K, T)> {
+        if self.root == NIL {
+            return None;
+        }
+
+        let root = self.root;
+        let first = std::mem::replace(&mut self.nodes[root].child, NIL);
+        self.root = self.merge_pairs(first);
+        self.len -= 1;
+        self.nodes[root].entry.take()
+    }
+
+    fn decrease_key(&mut self, handle: Handle, key: K) -> bool {
+        let Some(node) = self.nodes.get_mut(handle) else {
+            return false;
+        };
+        let Some(entry) = node.entry.as_mut() else {
+            return false;
+        };
+        if key > entry.0 {
+            return false;
+        }
+        entry.0 = key;
+
+        if handle != self.root {
+            self.unlink(handle);
+            self.root = self.meld(self.root, handle);
+        }
+        true
+    }
+
+    fn merge(&mut self, other: Self) -> usize {
+        let offset = self.nodes.len();
+        let shift = |index: usize| if index == NIL { NIL } else { index + offset };
+
+        self.nodes.extend(other.nodes.into_iter().map(|node| PairingNode {
+            child: shift(node.child),
+            sibling: shift(node.sibling),
+            prev: shift(node.prev),
+            ..node
+        }));
+
+        self.root = self.meld(self.root, shift(other.root));
+        self.len += other.len;
+        offset
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+}
@@ -0,0 +1,133 @@
+use super::{Handle, PriorityQueue, NIL};
+
+const BUCKETS: usize = 65;
+
+#[derive(Debug, Clone)]
+pub struct RadixHeap<T> {
+    buckets: Vec<Vec<Handle>>,
+    entries: Vec<Option<(u64, T)>>,
+    locations: Vec<(usize, usize)>,
+    last: u64,
+    len: usize,
+}
+
+impl<T> RadixHeap<T> {
+    pub fn new() -> Self {
+        Self {
+            buckets: vec![Vec::new(); BUCKETS],
+            entries: Vec::new(),
+            locations: Vec::new(),
+            last: 0,
+            len: 0,
+        }
+    }
+
+    fn bucket_for(&self, key: u64) -> usize {
+        if key == self.last { 0 } else { 64 - (key ^ self.last).leading_zeros() as usize }
+    }
+
+    fn key(&self, handle: Handle) -> u64 {
+        self.entries[handle].as_ref().expect("queued handle has an entry").0
+    }
+
+    fn place(&mut self, handle: Handle) {
+        let bucket = self.bucket_for(self.key(handle));
+        self.locations[handle] = (bucket, self.buckets[bucket].len());
+        self.buckets[bucket].push(handle);
+    }
+
+    fn take(&mut self, handle: Handle) {
+        let (bucket, index) = self.locations[handle];
+        self.buckets[bucket].swap_remove(index);
+        if let Some(&moved) = self.buckets[bucket].get(index) {
+            self.locations[moved].1 = index;
+        }
+        self.locations[handle] = (NIL, NIL);
+    }
+
+    fn refill(&mut self) {
+        if !self.buckets[0].is_empty() {
+            return;
+        }
+
+        let Some(bucket) = (1..BUCKETS).find(|&bucket| !self.buckets[bucket].is_empty()) else {
+            return;
+        };
+
+        let handles = std::mem::take(&mut self.buckets[bucket]);
+        self.last = handles.iter().map(|&handle| self.key(handle)).min().expect("non-empty bucket");
+        for handle in handles {
+            self.place(handle);
+        }
+    }
+}
+
+impl<T> Default for RadixHeap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> PriorityQueue<u64, T> for RadixHeap<T> {
+    fn name(&self) -> &'static str {
+        "Radix Heap"
+    }
+
+    fn push(&mut self, key: u64, item: T) -> Handle {
+        let handle = self.entries.len();
+        self.entries.push(Some((key.max(self.last), item)));
+        self.locations.push((NIL, NIL));
+        self.place(handle);
+        self.len += 1;
+        handle
+    }
+
+    fn peek(&self) -> Option<(&u64, &T)> {
+        let handle = self.buckets.iter().find(|bucket| !bucket.is_empty())
+            .and_then(|bucket| bucket.iter().min_by_key(|&&handle| self.key(handle)))?;
+        self.entries[*handle].as_ref().map(|(key, item)| (key, item))
+    }
+
+    fn pop(&mut self) -> Option<(u64, T)> {
+        self.refill();
+        let handle = *self.buckets[0].last()?;
+        self.take(handle);
+        self.len -= 1;
+        self.entries[handle].take()
+    }
+
+    fn decrease_key(&mut self, handle: Handle, key: u64) -> bool {
+        let Some(Some((current, _))) = self.entries.get(handle) else {
+            return false;
+        };
+        if key > *current || key < self.last {
+            return false;
+        }
+
+        self.take(handle);
+        if let Some(entry) = self.entries[handle].as_mut() {
+            entry.0 = key;
+        }
+        self.place(handle);
+        true
+    }
+
+    fn merge(&mut self, other: Self) -> usize {
+        let offset = self.entries.len();
+        self.entries.extend(other.entries);
+        self.locations.extend(other.locations.iter().map(|_| (NIL, NIL)));
+        self.last = self.last.min(other.last);
+        self.len += other.len;
+
+        let mut queued: Vec<Handle> = self.buckets.iter_mut().flat_map(std::mem::take).collect();
+        queued.extend(other.buckets.into_iter().flatten().map(|handle| handle + offset));
+        for handle in queued {
+            self.place(handle);
+        }
+        offset
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+}
@@ -11,6 +11,10 @@ mod views;
 mod controllers;
 mod gui;
 mod search;
+mod text_search;
+mod search_tree;
+mod heap;
+mod selection;
 mod sort;
 mod pathfinder;
 mod tree_traversal;
@@ -1,10 +1,23 @@
 use serde::{Deserialize, Serialize};
+use crate::search::workload::WorkloadConfig;
+use crate::search::dataset::{DatasetFormat, LoadOptions, SyntheticConfig};
+use crate::sort::HybridThresholds;
 
 #[derive(Debug, Clone)]
 pub struct SearchConfig {
     pub words_file: String,
     pub target_word: Option<String>,
     pub iterations: usize,
+    pub fuzzy_distance: Option<usize>,
+    pub layout_max_size: Option<usize>,
+    pub large_layouts: bool,
+    pub workload: Option<WorkloadConfig>,
+    pub format: Option<DatasetFormat>,
+    pub column: Option<String>,
+    pub load_options: LoadOptions,
+    pub synthetic: Option<SyntheticConfig>,
+    pub filter_fpr: Option<f64>,
+    pub tree_order: Option<usize>,
 }
 
 impl Default for SearchConfig {
@@ -13,6 +26,56 @@ impl Default for SearchConfig {
             words_file: "data/words.txt".to_string(),
             target_word: None,
             iterations: 100,
+            fuzzy_distance: None,
+            layout_max_size: None,
+            large_layouts: false,
+            workload: None,
+            format: None,
+            column: None,
+            load_options: LoadOptions::default(),
+            synthetic: None,
+            filter_fpr: None,
+            tree_order: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TextSearchConfig {
+    pub text_file: String,
+    pub patterns: Vec<String>,
+    pub iterations: usize,
+    pub build_index: bool,
+    pub save_index: Option<String>,
+    pub load_index: Option<String>,
+}
+
+impl Default for TextSearchConfig {
+    fn default() -> Self {
+        Self {
+            text_file: "data/text.txt".to_string(),
+            patterns: Vec::new(),
+            iterations: 10,
+            build_index: false,
+            save_index: None,
+            load_index: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SelectionConfig {
+    pub array_size: usize,
+    pub k: Option<usize>,
+    pub iterations: usize,
+}
+
+impl Default for SelectionConfig {
+    fn default() -> Self {
+        Self {
+            array_size: 100_000,
+            k: None,
+            iterations: 10,
         }
     }
 }
@@ -21,6 +84,10 @@ impl Default for SearchConfig {
 pub struct SortConfig {
     pub array_size: usize,
     pub iterations: usize,
+    pub thresholds: HybridThresholds,
+    pub tune_thresholds: bool,
+    pub presortedness: bool,
+    pub types: bool,
 }
 
 impl Default for SortConfig {
@@ -28,6 +95,10 @@ impl Default for SortConfig {
         Self {
             array_size: 1000,
             iterations: 10,
+            thresholds: HybridThresholds::default(),
+            tune_thresholds: false,
+            presortedness: false,
+            types: false,
         }
     }
 }
@@ -38,6 +109,16 @@ pub struct PathfinderConfig {
     pub grid_height: usize,
     pub obstacle_percentage: f64,
     pub iterations: usize,
+    pub movement: crate::pathfinder::MovementModel,
+    pub all_pairs: bool,
+    pub graph_nodes: usize,
+    pub map_file: Option<String>,
+    pub scenario_file: Option<String>,
+    pub maze: crate::pathfinder::MazeGenerator,
+    pub maze_gif: bool,
+    pub scaling: bool,
+    pub suites: Vec<crate::pathfinder::BenchmarkSuite>,
+    pub seed: Option<u64>,
 }
 
 impl Default for PathfinderConfig {
@@ -47,6 +128,16 @@ impl Default for PathfinderConfig {
             grid_height: 20,
             obstacle_percentage: 0.3,
             iterations: 10,
+            movement: crate::pathfinder::MovementModel::FourWay,
+            all_pairs: false,
+            graph_nodes: 100,
+            map_file: None,
+            scenario_file: None,
+            maze: crate::pathfinder::MazeGenerator::default(),
+            maze_gif: false,
+            scaling: false,
+            suites: Vec::new(),
+            seed: None,
         }
     }
 }
@@ -54,6 +145,7 @@ impl Default for PathfinderConfig {
 #[derive(Debug, Clone)]
 pub struct AppConfig {
     pub search: SearchConfig,
+    pub text_search: TextSearchConfig,
     pub sort: SortConfig,
     pub pathfinder: PathfinderConfig,
 }
@@ -62,6 +154,7 @@ impl Default for AppConfig {
     fn default() -> Self {
         Self {
             search: SearchConfig::default(),
+            text_search: TextSearchConfig::default(),
             sort: SortConfig::default(),
             pathfinder: PathfinderConfig::default(),
         }
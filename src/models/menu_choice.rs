@@ -166,6 +166,9 @@ pub enum PathfinderAlgorithm {
     BreadthFirst,
     DepthFirst,
     GreedyBestFirst,
+    BidirectionalBreadthFirst,
+    BidirectionalDijkstra,
+    NbaStar,
     All,
 }
 
@@ -177,6 +180,9 @@ impl PathfinderAlgorithm {
             "3" | "bfs" => Some(Self::BreadthFirst),
             "4" | "dfs" => Some(Self::DepthFirst),
             "5" | "greedy" => Some(Self::GreedyBestFirst),
+            "6" | "bibfs" => Some(Self::BidirectionalBreadthFirst),
+            "7" | "bidijkstra" => Some(Self::BidirectionalDijkstra),
+            "8" | "nba*" | "nbastar" => Some(Self::NbaStar),
             "a" | "all" => Some(Self::All),
             _ => None,
         }
@@ -189,6 +195,9 @@ impl PathfinderAlgorithm {
             Self::BreadthFirst => "breadth-first",
             Self::DepthFirst => "depth-first",
             Self::GreedyBestFirst => "greedy-best-first",
+            Self::BidirectionalBreadthFirst => "bidirectional-bfs",
+            Self::BidirectionalDijkstra => "bidirectional-dijkstra",
+            Self::NbaStar => "nba-star",
             Self::All => "all",
         }
     }
@@ -200,6 +209,9 @@ impl PathfinderAlgorithm {
             Self::BreadthFirst => "Breadth-First Search",
             Self::DepthFirst => "Depth-First Search", 
             Self::GreedyBestFirst => "Greedy Best-First",
+            Self::BidirectionalBreadthFirst => "Bidirectional BFS",
+            Self::BidirectionalDijkstra => "Bidirectional Dijkstra",
+            Self::NbaStar => "NBA*",
             Self::All => "All Algorithms",
         }
     }
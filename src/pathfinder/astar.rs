@@ -1,52 +1,53 @@
-use crate::pathfinder::{Grid, Position, PerformanceCounter};
-use std::collections::{BinaryHeap, HashMap};
+use crate::heap::{Handle, PriorityQueue};
+use crate::pathfinder::{Graph, Grid, Position, PerformanceCounter};
+use std::collections::{BinaryHeap, HashMap, HashSet};
 use std::cmp::Ordering;
+use std::hash::Hash;
 
 #[derive(Debug, Clone)]
-struct Node {
-    position: Position,
-    g_score: f64,
-    f_score: f64,
-    parent: Option<Position>,
+struct Node<N> {
+    position: N,
+    g_score: u64,
+    f_score: u64,
 }
 
-impl PartialEq for Node {
+impl<N: Eq> PartialEq for Node<N> {
     fn eq(&self, other: &Self) -> bool {
         self.position == other.position
     }
 }
 
-impl Eq for Node {}
+impl<N: Eq> Eq for Node<N> {}
 
-impl PartialOrd for Node {
+impl<N: Eq> PartialOrd for Node<N> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-
-        other.f_score.partial_cmp(&self.f_score)
+        Some(self.cmp(other))
     }
 }
 
-impl Ord for Node {
+impl<N: Eq> Ord for Node<N> {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap_or(Ordering::Equal)
+        other.f_score.cmp(&self.f_score)
     }
 }
 
 pub fn find_path(grid: &Grid) -> Result<(Vec<Position>, PerformanceCounter), String> {
+    search(grid, grid.start, grid.end)
+}
+
+pub fn search<G: Graph>(graph: &G, start: G::Node, goal: G::Node) -> Result<(Vec<G::Node>, PerformanceCounter), String> {
     let mut counter = PerformanceCounter::new();
     let mut open_set = BinaryHeap::new();
-    let mut came_from: HashMap<Position, Position> = HashMap::new();
-    let mut g_score: HashMap<Position, f64> = HashMap::new();
-    let mut f_score: HashMap<Position, f64> = HashMap::new();
+    let mut came_from: HashMap<G::Node, G::Node> = HashMap::new();
+    let mut g_score: HashMap<G::Node, u64> = HashMap::new();
+    let heuristic = graph.heuristic_to(goal);
 
-
-    g_score.insert(grid.start, 0.0);
-    f_score.insert(grid.start, heuristic(&grid.start, &grid.end));
+    g_score.insert(start, 0);
     
     open_set.push(Node {
-        position: grid.start,
-        g_score: 0.0,
-        f_score: heuristic(&grid.start, &grid.end),
-        parent: None,
+        position: start,
+        g_score: 0,
+        f_score: heuristic(&start),
     });
     
     counter.add_to_frontier();
@@ -54,37 +55,34 @@ pub fn find_path(grid: &Grid) -> Result<(Vec<Position>, PerformanceCounter), Str
 
     while let Some(current_node) = open_set.pop() {
         let current = current_node.position;
+        let current_g_score = g_score[&current];
+
+        if current_node.g_score > current_g_score {
+            continue;
+        }
         counter.explore_node();
 
 
-        if current == grid.end {
+        if current == goal {
             let path = reconstruct_path(&came_from, current);
             return Ok((path, counter));
         }
 
-
-        for neighbor in grid.get_neighbors(&current) {
+        for (neighbor, weight) in graph.edges(&current) {
             counter.compare();
             
-            let tentative_g_score = g_score.get(&current).unwrap_or(&f64::INFINITY) + 1.0;
-            let neighbor_g_score = *g_score.get(&neighbor).unwrap_or(&f64::INFINITY);
+            let tentative_g_score = current_g_score + weight;
+            let neighbor_g_score = *g_score.get(&neighbor).unwrap_or(&u64::MAX);
 
             if tentative_g_score < neighbor_g_score {
                 came_from.insert(neighbor, current);
                 g_score.insert(neighbor, tentative_g_score);
-                
-                let neighbor_f_score = tentative_g_score + heuristic(&neighbor, &grid.end);
-                f_score.insert(neighbor, neighbor_f_score);
 
-
-                let neighbor_node = Node {
+                open_set.push(Node {
                     position: neighbor,
                     g_score: tentative_g_score,
-                    f_score: neighbor_f_score,
-                    parent: Some(current),
-                };
-
-                open_set.push(neighbor_node);
+                    f_score: tentative_g_score + heuristic(&neighbor),
+                });
                 counter.add_to_frontier();
                 counter.allocate_memory(1);
             }
@@ -95,14 +93,77 @@ pub fn find_path(grid: &Grid) -> Result<(Vec<Position>, PerformanceCounter), Str
     Ok((Vec::new(), counter))
 }
 
-fn heuristic(from: &Position, to: &Position) -> f64 {
+pub fn find_path_with_queue<Q>(grid: &Grid, queue: Q) -> Result<(Vec<Position>, PerformanceCounter), String>
+where
+    Q: PriorityQueue<u64, Position>,
+{
+    search_with_queue(grid, grid.start, grid.end, queue)
+}
+
+pub fn search_with_queue<G, Q>(graph: &G, start: G::Node, goal: G::Node, mut queue: Q) -> Result<(Vec<G::Node>, PerformanceCounter), String>
+where
+    G: Graph,
+    Q: PriorityQueue<u64, G::Node>,
+{
+    let mut counter = PerformanceCounter::new();
+    let mut came_from: HashMap<G::Node, G::Node> = HashMap::new();
+    let mut g_score: HashMap<G::Node, u64> = HashMap::new();
+    let mut handles: HashMap<G::Node, Handle> = HashMap::new();
+    let mut closed: HashSet<G::Node> = HashSet::new();
+    let heuristic = graph.heuristic_to(goal);
+
+    g_score.insert(start, 0);
+    handles.insert(start, queue.push(heuristic(&start), start));
+
+    counter.add_to_frontier();
+    counter.allocate_memory(1);
+
+    while let Some((_, current)) = queue.pop() {
+        handles.remove(&current);
+        closed.insert(current);
+        counter.explore_node();
+
+        if current == goal {
+            let path = reconstruct_path(&came_from, current);
+            return Ok((path, counter));
+        }
+
+        let current_g_score = g_score[&current];
+
+        for (neighbor, weight) in graph.edges(&current) {
+            counter.compare();
+
+            if closed.contains(&neighbor) {
+                continue;
+            }
+
+            let tentative_g_score = current_g_score + weight;
+            let neighbor_g_score = *g_score.get(&neighbor).unwrap_or(&u64::MAX);
+
+            if tentative_g_score < neighbor_g_score {
+                came_from.insert(neighbor, current);
+                g_score.insert(neighbor, tentative_g_score);
+
+                let neighbor_f_score = tentative_g_score + heuristic(&neighbor);
+
+                match handles.get(&neighbor) {
+                    Some(&handle) => {
+                        queue.decrease_key(handle, neighbor_f_score);
+                    }
+                    None => {
+                        handles.insert(neighbor, queue.push(neighbor_f_score, neighbor));
+                        counter.add_to_frontier();
+                        counter.allocate_memory(1);
+                    }
+                }
+            }
+        }
+    }
 
-    let dx = (from.col as i32 - to.col as i32).abs() as f64;
-    let dy = (from.row as i32 - to.row as i32).abs() as f64;
-    dx + dy
+    Ok((Vec::new(), counter))
 }
 
-fn reconstruct_path(came_from: &HashMap<Position, Position>, mut current: Position) -> Vec<Position> {
+fn reconstruct_path<N: Copy + Eq + Hash>(came_from: &HashMap<N, N>, mut current: N) -> Vec<N> {
     let mut path = vec![current];
     
     while let Some(&parent) = came_from.get(&current) {
@@ -174,4 +235,78 @@ mod tests {
         let (path, _) = result.unwrap();
         assert!(path.is_empty());
     }
+
+    #[test]
+    fn test_astar_with_addressable_queues() {
+        use crate::heap::{fibonacci_heap, pairing_heap, radix_heap};
+
+        let start = Position::new(0, 0);
+        let end = Position::new(5, 6);
+        let mut grid = Grid::new(7, 6, start, end);
+        for row in 1..6 {
+            grid.add_obstacle(Position::new(row, 2));
+        }
+
+        let (expected, _) = find_path(&grid).unwrap();
+        let paths = [
+            find_path_with_queue(&grid, fibonacci_heap::FibonacciHeap::new()).unwrap().0,
+            find_path_with_queue(&grid, pairing_heap::PairingHeap::new()).unwrap().0,
+            find_path_with_queue(&grid, radix_heap::RadixHeap::new()).unwrap().0,
+        ];
+
+        for path in paths {
+            assert_eq!(path.len(), expected.len());
+            assert_eq!(path[path.len() - 1], end);
+        }
+    }
+
+    #[test]
+    fn test_astar_matches_dijkstra_cost_on_terrain() {
+        use crate::pathfinder::dijkstra;
+        use crate::heap::radix_heap;
+
+        let start = Position::new(0, 0);
+        let end = Position::new(5, 5);
+        let mut grid = Grid::new(6, 6, start, end);
+        for row in 0..6 {
+            for col in 0..6 {
+                let terrain = match (row + 2 * col) % 4 {
+                    0 => CellType::Road,
+                    1 => CellType::Mud,
+                    2 => CellType::Water,
+                    _ => CellType::Open,
+                };
+                grid.set_terrain(Position::new(row, col), terrain);
+            }
+        }
+
+        let (expected, _) = dijkstra::find_path(&grid).unwrap();
+        let (path, _) = find_path(&grid).unwrap();
+        let (queue_path, _) = find_path_with_queue(&grid, radix_heap::RadixHeap::new()).unwrap();
+
+        assert_eq!(grid.path_cost(&path), grid.path_cost(&expected));
+        assert_eq!(grid.path_cost(&queue_path), grid.path_cost(&expected));
+    }
+
+    #[test]
+    fn test_astar_octile_is_optimal_on_eight_way_grid() {
+        use crate::pathfinder::{dijkstra, MovementModel};
+        use crate::heap::pairing_heap;
+
+        let start = Position::new(0, 0);
+        let end = Position::new(7, 9);
+        let mut grid = Grid::new(10, 8, start, end).with_movement(MovementModel::EightWay);
+        for row in 0..6 {
+            grid.add_obstacle(Position::new(row, 5));
+        }
+        grid.set_terrain(Position::new(6, 5), CellType::Mud);
+
+        let (expected, _) = dijkstra::find_path(&grid).unwrap();
+        let (path, _) = find_path(&grid).unwrap();
+        let (queue_path, _) = find_path_with_queue(&grid, pairing_heap::PairingHeap::new()).unwrap();
+
+        assert_eq!(grid.scaled_path_cost(&path), grid.scaled_path_cost(&expected));
+        assert_eq!(grid.scaled_path_cost(&queue_path), grid.scaled_path_cost(&expected));
+        assert!(path.windows(2).any(|pair| pair[0].row != pair[1].row && pair[0].col != pair[1].col));
+    }
 }
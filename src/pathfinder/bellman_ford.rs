@@ -0,0 +1,83 @@
+use crate::pathfinder::{NegativeCycle, PerformanceCounter, ShortestPathTree, SignedGraph};
+
+pub fn shortest_paths(graph: &SignedGraph, source: usize) -> Result<(ShortestPathTree, PerformanceCounter), NegativeCycle> {
+    let nodes = graph.node_count();
+    let mut counter = PerformanceCounter::new();
+    let mut tree = ShortestPathTree::new(source, nodes);
+
+    for round in 1.. {
+        let mut relaxed = Vec::new();
+
+        for from in 0..nodes {
+            let Some(distance) = tree.distances[from] else {
+                continue;
+            };
+            counter.explore_node();
+
+            for &(to, weight) in graph.edges(from) {
+                counter.compare();
+                let candidate = distance.saturating_add(weight);
+                if tree.distances[to].is_none_or(|current| candidate < current) {
+                    tree.distances[to] = Some(candidate);
+                    tree.predecessors[to] = Some(from);
+                    relaxed.push(to);
+                }
+            }
+        }
+
+        if relaxed.is_empty() {
+            break;
+        }
+
+        if round >= nodes {
+            let witness = relaxed.into_iter().find_map(|node| NegativeCycle::from_predecessors(graph, &tree.predecessors, node));
+            if let Some(cycle) = witness {
+                return Err(cycle);
+            }
+        }
+    }
+
+    Ok((tree, counter))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bellman_ford_negative_edges() {
+        let mut graph = SignedGraph::new(5);
+        graph.add_edge(0, 1, 6);
+        graph.add_edge(0, 2, 7);
+        graph.add_edge(1, 2, 8);
+        graph.add_edge(1, 3, 5);
+        graph.add_edge(1, 4, -4);
+        graph.add_edge(2, 3, -3);
+        graph.add_edge(2, 4, 9);
+        graph.add_edge(3, 1, -2);
+        graph.add_edge(4, 3, 7);
+
+        let (tree, _) = shortest_paths(&graph, 0).unwrap();
+
+        assert_eq!(tree.distances, vec![Some(0), Some(2), Some(7), Some(4), Some(-2)]);
+        assert_eq!(tree.path_to(4), Some(vec![0, 2, 3, 1, 4]));
+    }
+
+    #[test]
+    fn test_bellman_ford_negative_cycle_witness() {
+        let mut graph = SignedGraph::new(6);
+        graph.add_edge(0, 1, 1);
+        graph.add_edge(1, 2, 2);
+        graph.add_edge(2, 3, -4);
+        graph.add_edge(3, 1, 1);
+        graph.add_edge(3, 4, 3);
+
+        let cycle = shortest_paths(&graph, 0).unwrap_err();
+
+        assert_eq!(cycle.weight, -1);
+        assert_eq!(cycle.cycle.first(), cycle.cycle.last());
+        assert_eq!(cycle.cycle.len(), 4);
+        assert_eq!(graph.path_weight(&cycle.cycle), Some(-1));
+        assert!(shortest_paths(&graph, 5).is_ok());
+    }
+}
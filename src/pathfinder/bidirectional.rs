@@ -0,0 +1,382 @@
+use crate::pathfinder::theta_star::Node;
+use crate::pathfinder::{Grid, Position, PerformanceCounter, SearchDirection};
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchEvent {
+    Frontier(Position, SearchDirection),
+    Explore(Position, SearchDirection),
+}
+
+struct Side {
+    direction: SearchDirection,
+    target: Position,
+    g_score: HashMap<Position, u64>,
+    parent: HashMap<Position, Position>,
+    open_set: BinaryHeap<Node>,
+}
+
+impl Side {
+    fn new(direction: SearchDirection, origin: Position, target: Position, f_score: u64) -> Self {
+        let mut open_set = BinaryHeap::new();
+        open_set.push(Node { position: origin, f_score });
+
+        Self {
+            direction,
+            target,
+            g_score: HashMap::from([(origin, 0)]),
+            parent: HashMap::new(),
+            open_set,
+        }
+    }
+
+    fn g(&self, pos: &Position) -> u64 {
+        *self.g_score.get(pos).unwrap_or(&u64::MAX)
+    }
+
+    fn edge_cost(&self, grid: &Grid, from: &Position, to: &Position) -> u64 {
+        match self.direction {
+            SearchDirection::Forward => grid.scaled_move_cost(from, to),
+            SearchDirection::Backward => grid.scaled_move_cost(to, from),
+        }
+    }
+
+    fn top(&self) -> u64 {
+        self.open_set.peek().map_or(u64::MAX, |node| node.f_score)
+    }
+}
+
+fn sides(grid: &Grid, forward_f: u64, backward_f: u64) -> [Side; 2] {
+    [
+        Side::new(SearchDirection::Forward, grid.start, grid.end, forward_f),
+        Side::new(SearchDirection::Backward, grid.end, grid.start, backward_f),
+    ]
+}
+
+fn split(sides: &mut [Side; 2], index: usize) -> (&mut Side, &mut Side) {
+    let [forward, backward] = sides;
+    if index == 0 { (forward, backward) } else { (backward, forward) }
+}
+
+pub fn breadth_first(grid: &Grid) -> Result<(Vec<Position>, PerformanceCounter), String> {
+    breadth_first_traced(grid, &mut |_| {})
+}
+
+pub fn breadth_first_traced(grid: &Grid, trace: &mut dyn FnMut(SearchEvent)) -> Result<(Vec<Position>, PerformanceCounter), String> {
+    let mut counter = PerformanceCounter::new();
+    if grid.start == grid.end {
+        return Ok((vec![grid.start], counter));
+    }
+
+    let mut sides = sides(grid, 0, 0);
+    let mut layers = [vec![grid.start], vec![grid.end]];
+
+    counter.add_to_frontier();
+    counter.add_to_frontier();
+    counter.allocate_memory(2);
+
+    while !layers[0].is_empty() && !layers[1].is_empty() {
+        let index = if layers[0].len() <= layers[1].len() { 0 } else { 1 };
+        let layer = std::mem::take(&mut layers[index]);
+        let (side, other) = split(&mut sides, index);
+        let mut meeting: Option<(u64, Position, Position)> = None;
+
+        for current in layer {
+            counter.explore_node_from(side.direction);
+            trace(SearchEvent::Explore(current, side.direction));
+            let depth = side.g(&current) + 1;
+
+            for neighbor in grid.get_neighbors(&current) {
+                counter.compare();
+
+                if let Some(&other_depth) = other.g_score.get(&neighbor) {
+                    let total = depth + other_depth;
+                    if meeting.is_none_or(|(best, _, _)| total < best) {
+                        meeting = Some((total, current, neighbor));
+                    }
+                    continue;
+                }
+
+                if let Entry::Vacant(entry) = side.g_score.entry(neighbor) {
+                    entry.insert(depth);
+                    side.parent.insert(neighbor, current);
+                    layers[index].push(neighbor);
+                    trace(SearchEvent::Frontier(neighbor, side.direction));
+                    counter.add_to_frontier();
+                    counter.allocate_memory(1);
+                }
+            }
+        }
+
+        if let Some((_, near, far)) = meeting {
+            let (forward_node, backward_node) = if index == 0 { (near, far) } else { (far, near) };
+            return Ok((join_paths(&sides, forward_node, backward_node), counter));
+        }
+    }
+
+    Ok((Vec::new(), counter))
+}
+
+pub fn dijkstra(grid: &Grid) -> Result<(Vec<Position>, PerformanceCounter), String> {
+    dijkstra_traced(grid, &mut |_| {})
+}
+
+pub fn dijkstra_traced(grid: &Grid, trace: &mut dyn FnMut(SearchEvent)) -> Result<(Vec<Position>, PerformanceCounter), String> {
+    let mut counter = PerformanceCounter::new();
+    if grid.start == grid.end {
+        return Ok((vec![grid.start], counter));
+    }
+
+    let mut sides = sides(grid, 0, 0);
+    let mut closed: [HashSet<Position>; 2] = [HashSet::new(), HashSet::new()];
+    let mut best_cost = u64::MAX;
+    let mut meeting = None;
+
+    counter.add_to_frontier();
+    counter.add_to_frontier();
+    counter.allocate_memory(2);
+
+    loop {
+        let (forward_top, backward_top) = (sides[0].top(), sides[1].top());
+        if forward_top == u64::MAX || backward_top == u64::MAX || forward_top.saturating_add(backward_top) >= best_cost {
+            break;
+        }
+
+        let index = if forward_top <= backward_top { 0 } else { 1 };
+        let (side, other) = split(&mut sides, index);
+        let Some(Node { position: current, f_score }) = side.open_set.pop() else {
+            break;
+        };
+
+        if f_score > side.g(&current) || !closed[index].insert(current) {
+            continue;
+        }
+        counter.explore_node_from(side.direction);
+        trace(SearchEvent::Explore(current, side.direction));
+
+        for neighbor in grid.get_neighbors(&current) {
+            counter.compare();
+
+            if closed[index].contains(&neighbor) {
+                continue;
+            }
+
+            let candidate_g = side.g(&current) + side.edge_cost(grid, &current, &neighbor);
+            if candidate_g < side.g(&neighbor) {
+                side.g_score.insert(neighbor, candidate_g);
+                side.parent.insert(neighbor, current);
+                side.open_set.push(Node { position: neighbor, f_score: candidate_g });
+                trace(SearchEvent::Frontier(neighbor, side.direction));
+                counter.add_to_frontier();
+                counter.allocate_memory(1);
+            }
+
+            if let Some(&other_g) = other.g_score.get(&neighbor) {
+                let total = side.g(&neighbor) + other_g;
+                if total < best_cost {
+                    best_cost = total;
+                    meeting = Some(neighbor);
+                }
+            }
+        }
+    }
+
+    let path = meeting.map(|node| join_paths(&sides, node, node)).unwrap_or_default();
+    Ok((path, counter))
+}
+
+pub fn astar(grid: &Grid) -> Result<(Vec<Position>, PerformanceCounter), String> {
+    astar_traced(grid, &mut |_| {})
+}
+
+pub fn astar_traced(grid: &Grid, trace: &mut dyn FnMut(SearchEvent)) -> Result<(Vec<Position>, PerformanceCounter), String> {
+    let mut counter = PerformanceCounter::new();
+    if grid.start == grid.end {
+        return Ok((vec![grid.start], counter));
+    }
+
+    let min_step_cost = grid.min_step_cost() as u64;
+    let heuristic = |pos: &Position, target: &Position| grid.heuristic.scaled_distance(pos, target) * min_step_cost;
+    let mut sides = sides(grid, heuristic(&grid.start, &grid.end), heuristic(&grid.end, &grid.start));
+    let mut totals = [sides[0].top(), sides[1].top()];
+    let mut closed: HashSet<Position> = HashSet::new();
+    let mut best_cost = u64::MAX;
+    let mut meeting = None;
+
+    counter.add_to_frontier();
+    counter.add_to_frontier();
+    counter.allocate_memory(2);
+
+    while !sides[0].open_set.is_empty() && !sides[1].open_set.is_empty() {
+        let index = if sides[0].open_set.len() <= sides[1].open_set.len() { 0 } else { 1 };
+        let other_total = totals[1 - index];
+        let (side, other) = split(&mut sides, index);
+        let Some(Node { position: current, f_score }) = side.open_set.pop() else {
+            break;
+        };
+
+        let current_g = side.g(&current);
+        if closed.contains(&current) || f_score != current_g + heuristic(&current, &side.target) {
+            continue;
+        }
+        closed.insert(current);
+
+        counter.compare();
+        let rejected = f_score >= best_cost
+            || (current_g + other_total).saturating_sub(heuristic(&current, &other.target)) >= best_cost;
+
+        if !rejected {
+            counter.explore_node_from(side.direction);
+            trace(SearchEvent::Explore(current, side.direction));
+
+            for neighbor in grid.get_neighbors(&current) {
+                counter.compare();
+
+                if closed.contains(&neighbor) {
+                    continue;
+                }
+
+                let candidate_g = current_g + side.edge_cost(grid, &current, &neighbor);
+                if candidate_g < side.g(&neighbor) {
+                    side.g_score.insert(neighbor, candidate_g);
+                    side.parent.insert(neighbor, current);
+                    side.open_set.push(Node { position: neighbor, f_score: candidate_g + heuristic(&neighbor, &side.target) });
+                    trace(SearchEvent::Frontier(neighbor, side.direction));
+                    counter.add_to_frontier();
+                    counter.allocate_memory(1);
+
+                    if let Some(&other_g) = other.g_score.get(&neighbor)
+                        && candidate_g + other_g < best_cost
+                    {
+                        best_cost = candidate_g + other_g;
+                        meeting = Some(neighbor);
+                    }
+                }
+            }
+        }
+
+        if let Some(node) = side.open_set.peek() {
+            totals[index] = node.f_score;
+        }
+    }
+
+    let path = meeting.map(|node| join_paths(&sides, node, node)).unwrap_or_default();
+    Ok((path, counter))
+}
+
+fn join_paths(sides: &[Side; 2], forward_node: Position, backward_node: Position) -> Vec<Position> {
+    let mut path = vec![forward_node];
+    let mut current = forward_node;
+    while let Some(&previous) = sides[0].parent.get(&current) {
+        current = previous;
+        path.push(current);
+    }
+    path.reverse();
+
+    current = backward_node;
+    if backward_node != forward_node {
+        path.push(current);
+    }
+    while let Some(&next) = sides[1].parent.get(&current) {
+        current = next;
+        path.push(current);
+    }
+
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pathfinder::{breadth_first as bfs, dijkstra as unidirectional_dijkstra, CellType, MovementModel};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn random_grid(seed: u64, movement: MovementModel) -> Grid {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut grid = Grid::new(20, 20, Position::new(0, 0), Position::new(19, 19)).with_movement(movement);
+        let terrain = [CellType::Blocked, CellType::Road, CellType::Mud, CellType::Water];
+
+        for row in 0..20 {
+            for col in 0..20 {
+                if rng.random_bool(0.35) {
+                    grid.set_terrain(Position::new(row, col), terrain[rng.random_range(0..terrain.len())]);
+                }
+            }
+        }
+        grid
+    }
+
+    fn assert_connected(grid: &Grid, path: &[Position]) {
+        assert_eq!(path[0], grid.start);
+        assert_eq!(path[path.len() - 1], grid.end);
+        for pair in path.windows(2) {
+            assert!(grid.get_neighbors(&pair[0]).contains(&pair[1]));
+        }
+    }
+
+    #[test]
+    fn test_bidirectional_costs_match_unidirectional_search() {
+        for seed in 0..20 {
+            for movement in [MovementModel::FourWay, MovementModel::EightWay, MovementModel::Hex] {
+                let grid = random_grid(seed, movement);
+                let (expected, _) = unidirectional_dijkstra::find_path(&grid).unwrap();
+                let (shortest, _) = bfs::find_path(&grid).unwrap();
+
+                let (dijkstra_path, _) = dijkstra(&grid).unwrap();
+                let (astar_path, _) = astar(&grid).unwrap();
+                let (bfs_path, _) = breadth_first(&grid).unwrap();
+
+                if expected.is_empty() {
+                    assert!(dijkstra_path.is_empty() && astar_path.is_empty() && bfs_path.is_empty());
+                    continue;
+                }
+
+                for path in [&dijkstra_path, &astar_path] {
+                    assert_connected(&grid, path);
+                    assert_eq!(grid.scaled_path_cost(path), grid.scaled_path_cost(&expected));
+                }
+                assert_connected(&grid, &bfs_path);
+                assert_eq!(bfs_path.len(), shortest.len());
+            }
+        }
+    }
+
+    #[test]
+    fn test_both_frontiers_explore() {
+        let grid = Grid::new(15, 15, Position::new(0, 0), Position::new(14, 14));
+
+        for search in [breadth_first, dijkstra, astar] {
+            let (path, counter) = search(&grid).unwrap();
+            assert_eq!(path.len(), 29);
+            assert!(counter.forward_explored > 0);
+            assert!(counter.backward_explored > 0);
+            assert_eq!(counter.forward_explored + counter.backward_explored, counter.nodes_explored);
+        }
+    }
+
+    #[test]
+    fn test_bidirectional_no_path() {
+        let mut grid = Grid::new(5, 5, Position::new(0, 0), Position::new(4, 4));
+        for col in 0..5 {
+            grid.add_obstacle(Position::new(2, col));
+        }
+
+        for search in [breadth_first, dijkstra, astar] {
+            let (path, _) = search(&grid).unwrap();
+            assert!(path.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_traced_events_cover_both_directions() {
+        let grid = Grid::new(10, 10, Position::new(0, 0), Position::new(9, 9));
+        let mut events = Vec::new();
+        astar_traced(&grid, &mut |event| events.push(event)).unwrap();
+
+        assert!(events.contains(&SearchEvent::Explore(grid.start, SearchDirection::Forward)));
+        assert!(events.contains(&SearchEvent::Explore(grid.end, SearchDirection::Backward)));
+        assert!(events.iter().any(|event| matches!(event, SearchEvent::Frontier(_, SearchDirection::Backward))));
+    }
+}
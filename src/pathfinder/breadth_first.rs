@@ -1,15 +1,20 @@
-use crate::pathfinder::{Grid, Position, PerformanceCounter};
+use crate::pathfinder::{Graph, Grid, Position, PerformanceCounter};
 use std::collections::{VecDeque, HashMap, HashSet};
+use std::hash::Hash;
 
 pub fn find_path(grid: &Grid) -> Result<(Vec<Position>, PerformanceCounter), String> {
+    search(grid, grid.start, grid.end)
+}
+
+pub fn search<G: Graph>(graph: &G, start: G::Node, goal: G::Node) -> Result<(Vec<G::Node>, PerformanceCounter), String> {
     let mut counter = PerformanceCounter::new();
     let mut queue = VecDeque::new();
     let mut visited = HashSet::new();
-    let mut came_from: HashMap<Position, Position> = HashMap::new();
+    let mut came_from: HashMap<G::Node, G::Node> = HashMap::new();
 
 
-    queue.push_back(grid.start);
-    visited.insert(grid.start);
+    queue.push_back(start);
+    visited.insert(start);
     counter.add_to_frontier();
     counter.allocate_memory(1);
 
@@ -17,13 +22,13 @@ pub fn find_path(grid: &Grid) -> Result<(Vec<Position>, PerformanceCounter), Str
         counter.explore_node();
 
 
-        if current == grid.end {
+        if current == goal {
             let path = reconstruct_path(&came_from, current);
             return Ok((path, counter));
         }
 
 
-        for neighbor in grid.get_neighbors(&current) {
+        for (neighbor, _) in graph.edges(&current) {
             counter.compare();
             
             if !visited.contains(&neighbor) {
@@ -41,7 +46,7 @@ pub fn find_path(grid: &Grid) -> Result<(Vec<Position>, PerformanceCounter), Str
     Ok((Vec::new(), counter))
 }
 
-fn reconstruct_path(came_from: &HashMap<Position, Position>, mut current: Position) -> Vec<Position> {
+fn reconstruct_path<N: Copy + Eq + Hash>(came_from: &HashMap<N, N>, mut current: N) -> Vec<N> {
     let mut path = vec![current];
     
     while let Some(&parent) = came_from.get(&current) {
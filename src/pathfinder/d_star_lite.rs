@@ -0,0 +1,240 @@
+use crate::pathfinder::incremental::{affected_cells, is_editable, IncrementalPlanner, Key, OpenList};
+use crate::pathfinder::{CellType, Grid, Position, PerformanceCounter};
+use std::collections::HashMap;
+
+pub struct DStarLite {
+    grid: Grid,
+    g_score: HashMap<Position, u64>,
+    rhs: HashMap<Position, u64>,
+    open_list: OpenList,
+    min_step_cost: u64,
+    key_modifier: u64,
+    last_start: Position,
+    counter: PerformanceCounter,
+}
+
+impl DStarLite {
+    pub fn new(grid: Grid) -> Self {
+        let last_start = grid.start;
+        let mut planner = Self {
+            grid,
+            g_score: HashMap::new(),
+            rhs: HashMap::new(),
+            open_list: OpenList::default(),
+            min_step_cost: 1,
+            key_modifier: 0,
+            last_start,
+            counter: PerformanceCounter::new(),
+        };
+        planner.reset();
+        planner
+    }
+
+    fn reset(&mut self) {
+        self.min_step_cost = self.grid.min_step_cost() as u64;
+        self.key_modifier = 0;
+        self.last_start = self.grid.start;
+        self.g_score.clear();
+        self.rhs.clear();
+        self.open_list.clear();
+
+        let goal = self.grid.end;
+        self.rhs.insert(goal, 0);
+        self.open_list.insert(goal, self.key(&goal));
+    }
+
+    fn heuristic(&self, pos: &Position) -> u64 {
+        self.grid.heuristic.scaled_distance(&self.grid.start, pos) * self.min_step_cost
+    }
+
+    fn g(&self, pos: &Position) -> u64 {
+        *self.g_score.get(pos).unwrap_or(&u64::MAX)
+    }
+
+    fn rhs(&self, pos: &Position) -> u64 {
+        *self.rhs.get(pos).unwrap_or(&u64::MAX)
+    }
+
+    fn key(&self, pos: &Position) -> Key {
+        let best = self.g(pos).min(self.rhs(pos));
+        Key(best.saturating_add(self.heuristic(pos)).saturating_add(self.key_modifier), best)
+    }
+
+    fn best_successor(&mut self, pos: &Position) -> Option<(Position, u64)> {
+        if !self.grid.is_valid_position(pos) {
+            return None;
+        }
+
+        let mut best = None;
+        for successor in self.grid.get_neighbors(pos) {
+            self.counter.compare();
+            let g = self.g(&successor);
+            if g == u64::MAX {
+                continue;
+            }
+
+            let candidate = g + self.grid.scaled_move_cost(pos, &successor);
+            if best.is_none_or(|(_, cost)| candidate < cost) {
+                best = Some((successor, candidate));
+            }
+        }
+        best
+    }
+
+    fn update_vertex(&mut self, pos: Position) {
+        if pos != self.grid.end {
+            let rhs = self.best_successor(&pos).map_or(u64::MAX, |(_, cost)| cost);
+            self.rhs.insert(pos, rhs);
+        }
+
+        self.open_list.remove(&pos);
+        if self.g(&pos) != self.rhs(&pos) {
+            self.open_list.insert(pos, self.key(&pos));
+            self.counter.add_to_frontier();
+        }
+    }
+
+    fn compute_shortest_path(&mut self) {
+        let start = self.grid.start;
+
+        while self.open_list.top_key() < self.key(&start) || self.rhs(&start) != self.g(&start) {
+            let Some((old_key, current)) = self.open_list.pop() else {
+                break;
+            };
+
+            let new_key = self.key(&current);
+            if old_key < new_key {
+                self.open_list.insert(current, new_key);
+                continue;
+            }
+            self.counter.explore_node();
+
+            if self.g(&current) > self.rhs(&current) {
+                self.g_score.insert(current, self.rhs(&current));
+            } else {
+                self.g_score.insert(current, u64::MAX);
+                self.update_vertex(current);
+            }
+
+            for predecessor in self.grid.get_neighbors(&current) {
+                self.update_vertex(predecessor);
+            }
+        }
+    }
+
+    fn extract_path(&mut self) -> Vec<Position> {
+        if self.g(&self.grid.start) == u64::MAX {
+            return Vec::new();
+        }
+
+        let mut path = vec![self.grid.start];
+        let mut current = self.grid.start;
+
+        while current != self.grid.end {
+            match self.best_successor(&current) {
+                Some((next, _)) if path.len() <= self.grid.width * self.grid.height => {
+                    current = next;
+                    path.push(current);
+                }
+                _ => return Vec::new(),
+            }
+        }
+
+        path
+    }
+}
+
+impl IncrementalPlanner for DStarLite {
+    fn name(&self) -> &'static str {
+        "D* Lite"
+    }
+
+    fn grid(&self) -> &Grid {
+        &self.grid
+    }
+
+    fn plan(&mut self) -> Result<(Vec<Position>, PerformanceCounter), String> {
+        self.counter = PerformanceCounter::new();
+        self.compute_shortest_path();
+        let path = self.extract_path();
+        Ok((path, std::mem::take(&mut self.counter)))
+    }
+
+    fn move_start(&mut self, pos: Position) -> bool {
+        if !self.grid.is_valid_position(&pos) {
+            return false;
+        }
+
+        if pos != self.grid.start {
+            self.grid.start = pos;
+            self.key_modifier += self.heuristic(&self.last_start);
+            self.last_start = pos;
+        }
+        true
+    }
+
+    fn set_cell(&mut self, pos: Position, cell: CellType) {
+        if !is_editable(&self.grid, &pos, cell) {
+            return;
+        }
+
+        let cheaper = self.grid.costs.cost(cell).is_some_and(|cost| (cost as u64) < self.min_step_cost);
+        self.grid.set_terrain(pos, cell);
+
+        if cheaper {
+            self.reset();
+            return;
+        }
+
+        for affected in affected_cells(&self.grid, &pos) {
+            self.update_vertex(affected);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pathfinder::{astar, MovementModel};
+
+    #[test]
+    fn test_d_star_lite_follows_moving_robot() {
+        for movement in [MovementModel::FourWay, MovementModel::EightWayNoCornerCutting, MovementModel::Hex] {
+            let mut grid = Grid::new(15, 15, Position::new(0, 0), Position::new(14, 14)).with_movement(movement);
+            let mut planner = DStarLite::new(grid.clone());
+            let (mut path, _) = planner.plan().unwrap();
+
+            for step in 0..6 {
+                let robot = path[2];
+                let obstacle = path[path.len() / 2];
+                planner.move_start(robot);
+                planner.add_obstacle(obstacle);
+                if step % 2 == 0 {
+                    planner.set_cell(path[path.len() - 3], CellType::Mud);
+                }
+
+                grid = planner.grid().clone();
+                path = planner.plan().unwrap().0;
+                let (expected, _) = astar::find_path(&grid).unwrap();
+
+                assert_eq!(path[0], robot);
+                assert_eq!(grid.scaled_path_cost(&path), grid.scaled_path_cost(&expected));
+            }
+        }
+    }
+
+    #[test]
+    fn test_d_star_lite_cheaper_terrain_resets_heuristic() {
+        let grid = Grid::new(10, 10, Position::new(0, 0), Position::new(9, 9));
+        let mut planner = DStarLite::new(grid);
+        planner.plan().unwrap();
+
+        for col in 0..9 {
+            planner.set_cell(Position::new(0, col + 1), CellType::Road);
+            planner.set_cell(Position::new(col + 1, 9), CellType::Road);
+        }
+
+        let (path, _) = planner.plan().unwrap();
+        assert_eq!(planner.grid().path_cost(&path), 19.0);
+    }
+}
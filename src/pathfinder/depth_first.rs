@@ -1,24 +1,29 @@
-use crate::pathfinder::{Grid, Position, PerformanceCounter};
+use crate::pathfinder::{Graph, Grid, Position, PerformanceCounter};
 use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
 
 pub fn find_path(grid: &Grid) -> Result<(Vec<Position>, PerformanceCounter), String> {
+    search(grid, grid.start, grid.end)
+}
+
+pub fn search<G: Graph>(graph: &G, start: G::Node, goal: G::Node) -> Result<(Vec<G::Node>, PerformanceCounter), String> {
     let mut counter = PerformanceCounter::new();
     let mut visited = HashSet::new();
     let mut path = Vec::new();
 
-    if dfs_recursive(grid, grid.start, grid.end, &mut visited, &mut path, &mut counter) {
+    if dfs_recursive(graph, start, goal, &mut visited, &mut path, &mut counter) {
         Ok((path, counter))
     } else {
         Ok((Vec::new(), counter))
     }
 }
 
-fn dfs_recursive(
-    grid: &Grid,
-    current: Position,
-    target: Position,
-    visited: &mut HashSet<Position>,
-    path: &mut Vec<Position>,
+fn dfs_recursive<G: Graph>(
+    graph: &G,
+    current: G::Node,
+    target: G::Node,
+    visited: &mut HashSet<G::Node>,
+    path: &mut Vec<G::Node>,
     counter: &mut PerformanceCounter,
 ) -> bool {
     counter.explore_node();
@@ -31,13 +36,13 @@ fn dfs_recursive(
     }
 
 
-    for neighbor in grid.get_neighbors(&current) {
+    for (neighbor, _) in graph.edges(&current) {
         counter.compare();
         
         if !visited.contains(&neighbor) {
             counter.allocate_memory(1);
             
-            if dfs_recursive(grid, neighbor, target, visited, path, counter) {
+            if dfs_recursive(graph, neighbor, target, visited, path, counter) {
                 return true;
             }
         }
@@ -50,13 +55,17 @@ fn dfs_recursive(
 
 
 pub fn find_path_iterative(grid: &Grid) -> Result<(Vec<Position>, PerformanceCounter), String> {
+    search_iterative(grid, grid.start, grid.end)
+}
+
+pub fn search_iterative<G: Graph>(graph: &G, start: G::Node, goal: G::Node) -> Result<(Vec<G::Node>, PerformanceCounter), String> {
     let mut counter = PerformanceCounter::new();
     let mut stack = Vec::new();
     let mut visited = HashSet::new();
-    let mut came_from: HashMap<Position, Position> = HashMap::new();
+    let mut came_from: HashMap<G::Node, G::Node> = HashMap::new();
 
 
-    stack.push(grid.start);
+    stack.push(start);
     counter.add_to_frontier();
     counter.allocate_memory(1);
 
@@ -69,16 +78,16 @@ pub fn find_path_iterative(grid: &Grid) -> Result<(Vec<Position>, PerformanceCou
         counter.explore_node();
 
 
-        if current == grid.end {
+        if current == goal {
             let path = reconstruct_path(&came_from, current);
             return Ok((path, counter));
         }
 
 
-        let mut neighbors = grid.get_neighbors(&current);
+        let mut neighbors = graph.edges(&current);
         neighbors.reverse();
         
-        for neighbor in neighbors {
+        for (neighbor, _) in neighbors {
             counter.compare();
             
             if !visited.contains(&neighbor) {
@@ -94,7 +103,7 @@ pub fn find_path_iterative(grid: &Grid) -> Result<(Vec<Position>, PerformanceCou
     Ok((Vec::new(), counter))
 }
 
-fn reconstruct_path(came_from: &HashMap<Position, Position>, mut current: Position) -> Vec<Position> {
+fn reconstruct_path<N: Copy + Eq + Hash>(came_from: &HashMap<N, N>, mut current: N) -> Vec<N> {
     let mut path = vec![current];
     
     while let Some(&parent) = came_from.get(&current) {
@@ -1,48 +1,53 @@
-use crate::pathfinder::{Grid, Position, PerformanceCounter};
+use crate::heap::{Handle, PriorityQueue};
+use crate::pathfinder::{Graph, Grid, Position, PerformanceCounter};
 use std::collections::{BinaryHeap, HashMap, HashSet};
 use std::cmp::Ordering;
+use std::hash::Hash;
 
 #[derive(Debug, Clone)]
-struct Node {
-    position: Position,
-    distance: f64,
+struct Node<N> {
+    position: N,
+    distance: u64,
 }
 
-impl PartialEq for Node {
+impl<N: Eq> PartialEq for Node<N> {
     fn eq(&self, other: &Self) -> bool {
         self.position == other.position
     }
 }
 
-impl Eq for Node {}
+impl<N: Eq> Eq for Node<N> {}
 
-impl PartialOrd for Node {
+impl<N: Eq> PartialOrd for Node<N> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-
-        other.distance.partial_cmp(&self.distance)
+        Some(self.cmp(other))
     }
 }
 
-impl Ord for Node {
+impl<N: Eq> Ord for Node<N> {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap_or(Ordering::Equal)
+        other.distance.cmp(&self.distance)
     }
 }
 
 pub fn find_path(grid: &Grid) -> Result<(Vec<Position>, PerformanceCounter), String> {
+    search(grid, grid.start, grid.end)
+}
+
+pub fn search<G: Graph>(graph: &G, start: G::Node, goal: G::Node) -> Result<(Vec<G::Node>, PerformanceCounter), String> {
     let mut counter = PerformanceCounter::new();
     let mut priority_queue = BinaryHeap::new();
-    let mut distances: HashMap<Position, f64> = HashMap::new();
-    let mut previous: HashMap<Position, Position> = HashMap::new();
-    let mut visited: HashSet<Position> = HashSet::new();
+    let mut distances: HashMap<G::Node, u64> = HashMap::new();
+    let mut previous: HashMap<G::Node, G::Node> = HashMap::new();
+    let mut visited: HashSet<G::Node> = HashSet::new();
 
 
-    distances.insert(grid.start, 0.0);
+    distances.insert(start, 0);
     
 
     priority_queue.push(Node {
-        position: grid.start,
-        distance: 0.0,
+        position: start,
+        distance: 0,
     });
     
     counter.add_to_frontier();
@@ -60,24 +65,23 @@ pub fn find_path(grid: &Grid) -> Result<(Vec<Position>, PerformanceCounter), Str
         counter.explore_node();
 
 
-        if current == grid.end {
+        if current == goal {
             let path = reconstruct_path(&previous, current);
             return Ok((path, counter));
         }
 
-        let current_distance = *distances.get(&current).unwrap_or(&f64::INFINITY);
+        let current_distance = distances[&current];
 
 
-        for neighbor in grid.get_neighbors(&current) {
+        for (neighbor, edge_weight) in graph.edges(&current) {
             counter.compare();
             
             if visited.contains(&neighbor) {
                 continue;
             }
 
-            let edge_weight = 1.0;
             let new_distance = current_distance + edge_weight;
-            let neighbor_distance = *distances.get(&neighbor).unwrap_or(&f64::INFINITY);
+            let neighbor_distance = *distances.get(&neighbor).unwrap_or(&u64::MAX);
 
             if new_distance < neighbor_distance {
                 distances.insert(neighbor, new_distance);
@@ -98,7 +102,72 @@ pub fn find_path(grid: &Grid) -> Result<(Vec<Position>, PerformanceCounter), Str
     Ok((Vec::new(), counter))
 }
 
-fn reconstruct_path(previous: &HashMap<Position, Position>, mut current: Position) -> Vec<Position> {
+pub fn find_path_with_queue<Q>(grid: &Grid, queue: Q) -> Result<(Vec<Position>, PerformanceCounter), String>
+where
+    Q: PriorityQueue<u64, Position>,
+{
+    search_with_queue(grid, grid.start, grid.end, queue)
+}
+
+pub fn search_with_queue<G, Q>(graph: &G, start: G::Node, goal: G::Node, mut queue: Q) -> Result<(Vec<G::Node>, PerformanceCounter), String>
+where
+    G: Graph,
+    Q: PriorityQueue<u64, G::Node>,
+{
+    let mut counter = PerformanceCounter::new();
+    let mut distances: HashMap<G::Node, u64> = HashMap::new();
+    let mut previous: HashMap<G::Node, G::Node> = HashMap::new();
+    let mut handles: HashMap<G::Node, Handle> = HashMap::new();
+    let mut settled: HashSet<G::Node> = HashSet::new();
+
+    distances.insert(start, 0);
+    handles.insert(start, queue.push(0, start));
+
+    counter.add_to_frontier();
+    counter.allocate_memory(1);
+
+    while let Some((distance, current)) = queue.pop() {
+        handles.remove(&current);
+        settled.insert(current);
+        counter.explore_node();
+
+        if current == goal {
+            let path = reconstruct_path(&previous, current);
+            return Ok((path, counter));
+        }
+
+        for (neighbor, weight) in graph.edges(&current) {
+            counter.compare();
+
+            if settled.contains(&neighbor) {
+                continue;
+            }
+
+            let new_distance = distance + weight;
+            let neighbor_distance = *distances.get(&neighbor).unwrap_or(&u64::MAX);
+
+            if new_distance < neighbor_distance {
+                distances.insert(neighbor, new_distance);
+                previous.insert(neighbor, current);
+
+                match handles.get(&neighbor) {
+                    Some(&handle) => {
+                        queue.decrease_key(handle, new_distance);
+                    }
+                    None => {
+                        handles.insert(neighbor, queue.push(new_distance, neighbor));
+                        counter.add_to_frontier();
+                        counter.allocate_memory(1);
+                    }
+                }
+            }
+        }
+    }
+
+    Ok((Vec::new(), counter))
+}
+
+fn reconstruct_path<N: Copy + Eq + Hash>(previous: &HashMap<N, N>, mut current: N) -> Vec<N> {
     let mut path = vec![current];
     
     while let Some(&parent) = previous.get(&current) {
@@ -171,6 +240,33 @@ mod tests {
         assert!(path.is_empty());
     }
 
+    #[test]
+    fn test_dijkstra_with_addressable_queues() {
+        use crate::heap::{binomial_heap, d_ary_heap, fibonacci_heap, pairing_heap, radix_heap};
+
+        let start = Position::new(0, 0);
+        let end = Position::new(5, 6);
+        let mut grid = Grid::new(7, 6, start, end);
+        for row in 0..5 {
+            grid.add_obstacle(Position::new(row, 3));
+        }
+
+        let (expected, _) = find_path(&grid).unwrap();
+        let paths = [
+            find_path_with_queue(&grid, d_ary_heap::DaryHeap::new(4)).unwrap().0,
+            find_path_with_queue(&grid, binomial_heap::BinomialHeap::new()).unwrap().0,
+            find_path_with_queue(&grid, fibonacci_heap::FibonacciHeap::new()).unwrap().0,
+            find_path_with_queue(&grid, pairing_heap::PairingHeap::new()).unwrap().0,
+            find_path_with_queue(&grid, radix_heap::RadixHeap::new()).unwrap().0,
+        ];
+
+        for path in paths {
+            assert_eq!(path.len(), expected.len());
+            assert_eq!(path[0], start);
+            assert_eq!(path[path.len() - 1], end);
+        }
+    }
+
     #[test]
     fn test_dijkstra_optimal_path() {
 
@@ -184,4 +280,30 @@ mod tests {
         let (path, _) = result.unwrap();
         assert_eq!(path.len(), 4);
     }
+
+    #[test]
+    fn test_dijkstra_prefers_road_detour_over_mud() {
+        use crate::pathfinder::breadth_first;
+
+        let start = Position::new(0, 0);
+        let end = Position::new(0, 4);
+        let mut grid = Grid::new(5, 2, start, end);
+        for col in 1..4 {
+            grid.set_terrain(Position::new(0, col), CellType::Mud);
+        }
+        for col in 0..5 {
+            grid.set_terrain(Position::new(1, col), CellType::Road);
+        }
+
+        let (path, _) = find_path(&grid).unwrap();
+        let (bfs_path, _) = breadth_first::find_path(&grid).unwrap();
+
+        assert_eq!(grid.path_cost(&path), 7.0);
+        assert_eq!(path.len(), 7);
+        assert_eq!(bfs_path.len(), 5);
+        assert!(grid.path_cost(&path) < grid.path_cost(&bfs_path));
+
+        let (queue_path, _) = find_path_with_queue(&grid, crate::heap::pairing_heap::PairingHeap::new()).unwrap();
+        assert_eq!(grid.path_cost(&queue_path), 7.0);
+    }
 }
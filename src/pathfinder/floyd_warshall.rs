@@ -0,0 +1,89 @@
+use crate::pathfinder::{bellman_ford, DistanceMatrix, NegativeCycle, PerformanceCounter, SignedGraph};
+
+pub fn all_pairs(graph: &SignedGraph) -> Result<(DistanceMatrix, PerformanceCounter), NegativeCycle> {
+    let nodes = graph.node_count();
+    let mut counter = PerformanceCounter::new();
+    let mut distances = vec![vec![None; nodes]; nodes];
+    let mut predecessors = vec![vec![None; nodes]; nodes];
+
+    for from in 0..nodes {
+        distances[from][from] = Some(0);
+        for &(to, weight) in graph.edges(from) {
+            if distances[from][to].is_none_or(|current| weight < current) {
+                distances[from][to] = Some(weight);
+                predecessors[from][to] = Some(from);
+            }
+        }
+    }
+    counter.allocate_memory(2 * nodes * nodes);
+
+    for via in 0..nodes {
+        counter.explore_node();
+        let through: Vec<Option<i64>> = distances[via].clone();
+        let through_predecessors: Vec<Option<usize>> = predecessors[via].clone();
+
+        for (row, row_predecessors) in distances.iter_mut().zip(predecessors.iter_mut()) {
+            let Some(to_via) = row[via] else {
+                continue;
+            };
+
+            for (target, (&onward, &predecessor)) in through.iter().zip(&through_predecessors).enumerate() {
+                counter.compare();
+                let Some(onward) = onward else {
+                    continue;
+                };
+
+                let candidate = to_via.saturating_add(onward);
+                if row[target].is_none_or(|current| candidate < current) {
+                    row[target] = Some(candidate);
+                    row_predecessors[target] = predecessor;
+                }
+            }
+        }
+    }
+
+    if let Some(node) = (0..nodes).find(|&node| distances[node][node].is_some_and(|distance| distance < 0))
+        && let Err(cycle) = bellman_ford::shortest_paths(graph, node)
+    {
+        return Err(cycle);
+    }
+
+    Ok((DistanceMatrix { distances, predecessors }, counter))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_floyd_warshall_matches_bellman_ford_rows() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let graph = SignedGraph::random_with_potentials(25, 120, &mut rng);
+        let (matrix, _) = all_pairs(&graph).unwrap();
+
+        for source in 0..25 {
+            let (tree, _) = bellman_ford::shortest_paths(&graph, source).unwrap();
+            assert_eq!(matrix.distances[source], tree.distances);
+
+            for target in 0..25 {
+                let path = matrix.path(source, target);
+                assert_eq!(path.and_then(|path| graph.path_weight(&path)), matrix.distance(source, target));
+            }
+        }
+    }
+
+    #[test]
+    fn test_floyd_warshall_detects_negative_cycle() {
+        let mut graph = SignedGraph::new(4);
+        graph.add_edge(0, 1, 3);
+        graph.add_edge(1, 2, -2);
+        graph.add_edge(2, 3, 2);
+        graph.add_edge(3, 1, -1);
+
+        let cycle = all_pairs(&graph).unwrap_err();
+        assert_eq!(cycle.weight, -1);
+        assert_eq!(cycle.cycle.len(), 4);
+    }
+}
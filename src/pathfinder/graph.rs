@@ -0,0 +1,332 @@
+use crate::pathfinder::{Grid, Position};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+pub trait Graph {
+    type Node: Copy + Eq + Hash + Debug;
+
+    fn node_count(&self) -> usize;
+
+    fn edge_count(&self) -> usize;
+
+    fn edges(&self, node: &Self::Node) -> Vec<(Self::Node, u64)>;
+
+    fn heuristic_to(&self, goal: Self::Node) -> impl Fn(&Self::Node) -> u64;
+
+    fn path_weight(&self, path: &[Self::Node]) -> u64 {
+        path.windows(2)
+            .map(|pair| {
+                self.edges(&pair[0]).into_iter()
+                    .filter(|(to, _)| *to == pair[1])
+                    .map(|(_, weight)| weight)
+                    .min()
+                    .unwrap_or(u64::MAX)
+            })
+            .fold(0, u64::saturating_add)
+    }
+}
+
+impl Graph for Grid {
+    type Node = Position;
+
+    fn node_count(&self) -> usize {
+        self.width * self.height
+    }
+
+    fn edge_count(&self) -> usize {
+        (0..self.height)
+            .flat_map(|row| (0..self.width).map(move |col| Position::new(row, col)))
+            .filter(|pos| self.is_valid_position(pos))
+            .map(|pos| self.get_neighbors(&pos).len())
+            .sum()
+    }
+
+    fn edges(&self, node: &Position) -> Vec<(Position, u64)> {
+        self.get_neighbors(node)
+            .into_iter()
+            .map(|neighbor| (neighbor, self.scaled_move_cost(node, &neighbor)))
+            .collect()
+    }
+
+    fn heuristic_to(&self, goal: Position) -> impl Fn(&Position) -> u64 {
+        let min_step_cost = self.min_step_cost() as u64;
+        move |node| self.heuristic.scaled_distance(node, &goal) * min_step_cost
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct AdjacencyList {
+    coordinates: Vec<(f64, f64)>,
+    adjacency: Vec<Vec<(usize, u64)>>,
+    cost_per_unit: Option<f64>,
+}
+
+impl AdjacencyList {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_node(&mut self, x: f64, y: f64) -> usize {
+        self.coordinates.push((x, y));
+        self.adjacency.push(Vec::new());
+        self.coordinates.len() - 1
+    }
+
+    pub fn add_edge(&mut self, from: usize, to: usize, weight: u64) {
+        if from >= self.coordinates.len() || to >= self.coordinates.len() {
+            return;
+        }
+
+        let length = distance(self.coordinates[from], self.coordinates[to]);
+        if length > 0.0 {
+            let ratio = weight as f64 / length;
+            self.cost_per_unit = Some(self.cost_per_unit.map_or(ratio, |current| current.min(ratio)));
+        }
+        self.adjacency[from].push((to, weight));
+    }
+
+    pub fn add_undirected_edge(&mut self, a: usize, b: usize, weight: u64) {
+        self.add_edge(a, b, weight);
+        self.add_edge(b, a, weight);
+    }
+
+    pub fn coordinates(&self, node: usize) -> (f64, f64) {
+        self.coordinates[node]
+    }
+}
+
+impl From<&Grid> for AdjacencyList {
+    fn from(grid: &Grid) -> Self {
+        let mut graph = Self::new();
+        for row in 0..grid.height {
+            for col in 0..grid.width {
+                graph.add_node(col as f64, row as f64);
+            }
+        }
+
+        for row in 0..grid.height {
+            for col in 0..grid.width {
+                let pos = Position::new(row, col);
+                if !grid.is_valid_position(&pos) {
+                    continue;
+                }
+                for (neighbor, weight) in grid.edges(&pos) {
+                    graph.add_edge(row * grid.width + col, neighbor.row * grid.width + neighbor.col, weight);
+                }
+            }
+        }
+
+        graph
+    }
+}
+
+impl Graph for AdjacencyList {
+    type Node = usize;
+
+    fn node_count(&self) -> usize {
+        self.coordinates.len()
+    }
+
+    fn edge_count(&self) -> usize {
+        self.adjacency.iter().map(Vec::len).sum()
+    }
+
+    fn edges(&self, node: &usize) -> Vec<(usize, u64)> {
+        self.adjacency[*node].clone()
+    }
+
+    fn heuristic_to(&self, goal: usize) -> impl Fn(&usize) -> u64 {
+        straight_line(&self.coordinates, self.cost_per_unit, goal)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AdjacencyMatrix {
+    coordinates: Vec<(f64, f64)>,
+    weights: Vec<Option<u64>>,
+    cost_per_unit: Option<f64>,
+}
+
+impl From<&AdjacencyList> for AdjacencyMatrix {
+    fn from(list: &AdjacencyList) -> Self {
+        let nodes = list.coordinates.len();
+        let mut weights = vec![None; nodes * nodes];
+
+        for (from, edges) in list.adjacency.iter().enumerate() {
+            for &(to, weight) in edges {
+                let slot: &mut Option<u64> = &mut weights[from * nodes + to];
+                *slot = Some(slot.map_or(weight, |current| current.min(weight)));
+            }
+        }
+
+        Self {
+            coordinates: list.coordinates.clone(),
+            weights,
+            cost_per_unit: list.cost_per_unit,
+        }
+    }
+}
+
+impl Graph for AdjacencyMatrix {
+    type Node = usize;
+
+    fn node_count(&self) -> usize {
+        self.coordinates.len()
+    }
+
+    fn edge_count(&self) -> usize {
+        self.weights.iter().flatten().count()
+    }
+
+    fn edges(&self, node: &usize) -> Vec<(usize, u64)> {
+        let nodes = self.coordinates.len();
+        self.weights[node * nodes..(node + 1) * nodes]
+            .iter()
+            .enumerate()
+            .filter_map(|(to, weight)| weight.map(|weight| (to, weight)))
+            .collect()
+    }
+
+    fn heuristic_to(&self, goal: usize) -> impl Fn(&usize) -> u64 {
+        straight_line(&self.coordinates, self.cost_per_unit, goal)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CompressedSparseRow {
+    coordinates: Vec<(f64, f64)>,
+    offsets: Vec<usize>,
+    targets: Vec<usize>,
+    weights: Vec<u64>,
+    cost_per_unit: Option<f64>,
+}
+
+impl From<&AdjacencyList> for CompressedSparseRow {
+    fn from(list: &AdjacencyList) -> Self {
+        let mut offsets = Vec::with_capacity(list.adjacency.len() + 1);
+        let mut targets = Vec::new();
+        let mut weights = Vec::new();
+
+        offsets.push(0);
+        for edges in &list.adjacency {
+            for &(to, weight) in edges {
+                targets.push(to);
+                weights.push(weight);
+            }
+            offsets.push(targets.len());
+        }
+
+        Self {
+            coordinates: list.coordinates.clone(),
+            offsets,
+            targets,
+            weights,
+            cost_per_unit: list.cost_per_unit,
+        }
+    }
+}
+
+impl Graph for CompressedSparseRow {
+    type Node = usize;
+
+    fn node_count(&self) -> usize {
+        self.coordinates.len()
+    }
+
+    fn edge_count(&self) -> usize {
+        self.targets.len()
+    }
+
+    fn edges(&self, node: &usize) -> Vec<(usize, u64)> {
+        let range = self.offsets[*node]..self.offsets[node + 1];
+        self.targets[range.clone()].iter().copied().zip(self.weights[range].iter().copied()).collect()
+    }
+
+    fn heuristic_to(&self, goal: usize) -> impl Fn(&usize) -> u64 {
+        straight_line(&self.coordinates, self.cost_per_unit, goal)
+    }
+}
+
+fn distance(a: (f64, f64), b: (f64, f64)) -> f64 {
+    ((a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)).sqrt()
+}
+
+fn straight_line(coordinates: &[(f64, f64)], cost_per_unit: Option<f64>, goal: usize) -> impl Fn(&usize) -> u64 {
+    let target = coordinates[goal];
+    let cost_per_unit = cost_per_unit.unwrap_or(0.0);
+    move |node| (distance(coordinates[*node], target) * cost_per_unit).floor() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pathfinder::{astar, dijkstra, CellType, MovementModel};
+
+    fn road_network() -> AdjacencyList {
+        let mut graph = AdjacencyList::new();
+        let points = [(0.0, 0.0), (4.0, 0.0), (8.0, 0.0), (4.0, 3.0), (8.0, 6.0), (0.0, 6.0)];
+        for (x, y) in points {
+            graph.add_node(x, y);
+        }
+
+        graph.add_undirected_edge(0, 1, 4_000);
+        graph.add_undirected_edge(1, 2, 4_000);
+        graph.add_undirected_edge(1, 3, 3_000);
+        graph.add_undirected_edge(3, 4, 5_000);
+        graph.add_undirected_edge(2, 4, 12_000);
+        graph.add_edge(0, 5, 6_000);
+        graph.add_edge(5, 4, 20_000);
+        graph
+    }
+
+    #[test]
+    fn test_representations_agree() {
+        let list = road_network();
+        let matrix = AdjacencyMatrix::from(&list);
+        let csr = CompressedSparseRow::from(&list);
+
+        assert_eq!(list.edge_count(), 12);
+        assert_eq!(matrix.edge_count(), list.edge_count());
+        assert_eq!(csr.edge_count(), list.edge_count());
+
+        for node in 0..list.node_count() {
+            let mut expected = list.edges(&node);
+            let mut from_csr = csr.edges(&node);
+            expected.sort_unstable();
+            from_csr.sort_unstable();
+            assert_eq!(matrix.edges(&node), expected);
+            assert_eq!(from_csr, expected);
+        }
+    }
+
+    #[test]
+    fn test_straight_line_heuristic_is_admissible() {
+        let list = road_network();
+        let (path, _) = dijkstra::search(&list, 0, 4).unwrap();
+        assert_eq!(path, vec![0, 1, 3, 4]);
+
+        let heuristic = list.heuristic_to(4);
+        assert!(heuristic(&0) <= 12_000);
+        assert!(heuristic(&0) > 0);
+        assert_eq!(list.edges(&4).len(), 2);
+    }
+
+    #[test]
+    fn test_grid_conversion_preserves_costs() {
+        let mut grid = Grid::new(8, 6, Position::new(0, 0), Position::new(5, 7)).with_movement(MovementModel::EightWay);
+        for row in 0..4 {
+            grid.add_obstacle(Position::new(row, 3));
+        }
+        grid.set_terrain(Position::new(4, 3), CellType::Mud);
+        grid.set_terrain(Position::new(5, 4), CellType::Road);
+
+        let list = AdjacencyList::from(&grid);
+        let (expected, _) = astar::find_path(&grid).unwrap();
+        let goal = grid.end.row * grid.width + grid.end.col;
+        let (path, _) = astar::search(&CompressedSparseRow::from(&list), 0, goal).unwrap();
+
+        assert_eq!(list.path_weight(&path), grid.scaled_path_cost(&expected));
+        assert_eq!(grid.path_weight(&expected), grid.scaled_path_cost(&expected));
+        assert_eq!(list.edge_count(), grid.edge_count());
+    }
+}
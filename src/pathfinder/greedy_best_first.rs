@@ -1,44 +1,53 @@
-use crate::pathfinder::{Grid, Position, PerformanceCounter};
+use crate::pathfinder::{Graph, Grid, Position, PerformanceCounter};
 use std::collections::{BinaryHeap, HashMap, HashSet};
 use std::cmp::Ordering;
+use std::hash::Hash;
 
 #[derive(Debug, Clone)]
-struct Node {
-    position: Position,
-    heuristic: f64,
+struct Node<N> {
+    position: N,
+    heuristic: u64,
+    step_cost: u64,
 }
 
-impl PartialEq for Node {
+impl<N: Eq> PartialEq for Node<N> {
     fn eq(&self, other: &Self) -> bool {
         self.position == other.position
     }
 }
 
-impl Eq for Node {}
+impl<N: Eq> Eq for Node<N> {}
 
-impl PartialOrd for Node {
+impl<N: Eq> PartialOrd for Node<N> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-
-        other.heuristic.partial_cmp(&self.heuristic)
+        Some(self.cmp(other))
     }
 }
 
-impl Ord for Node {
+// Greedy expands the lowest heuristic first; on weighted graphs equal
+// heuristics are broken by the cheaper edge into the node.
+impl<N: Eq> Ord for Node<N> {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap_or(Ordering::Equal)
+        other.heuristic.cmp(&self.heuristic)
+            .then_with(|| other.step_cost.cmp(&self.step_cost))
     }
 }
 
 pub fn find_path(grid: &Grid) -> Result<(Vec<Position>, PerformanceCounter), String> {
+    search(grid, grid.start, grid.end)
+}
+
+pub fn search<G: Graph>(graph: &G, start: G::Node, goal: G::Node) -> Result<(Vec<G::Node>, PerformanceCounter), String> {
     let mut counter = PerformanceCounter::new();
     let mut open_set = BinaryHeap::new();
-    let mut came_from: HashMap<Position, Position> = HashMap::new();
-    let mut visited: HashSet<Position> = HashSet::new();
-
+    let mut came_from: HashMap<G::Node, G::Node> = HashMap::new();
+    let mut visited: HashSet<G::Node> = HashSet::new();
+    let heuristic = graph.heuristic_to(goal);
 
     open_set.push(Node {
-        position: grid.start,
-        heuristic: heuristic(&grid.start, &grid.end),
+        position: start,
+        heuristic: heuristic(&start),
+        step_cost: 0,
     });
     
     counter.add_to_frontier();
@@ -56,13 +65,13 @@ pub fn find_path(grid: &Grid) -> Result<(Vec<Position>, PerformanceCounter), Str
         counter.explore_node();
 
 
-        if current == grid.end {
+        if current == goal {
             let path = reconstruct_path(&came_from, current);
             return Ok((path, counter));
         }
 
 
-        for neighbor in grid.get_neighbors(&current) {
+        for (neighbor, weight) in graph.edges(&current) {
             counter.compare();
             
             if !visited.contains(&neighbor) {
@@ -70,7 +79,8 @@ pub fn find_path(grid: &Grid) -> Result<(Vec<Position>, PerformanceCounter), Str
                 
                 open_set.push(Node {
                     position: neighbor,
-                    heuristic: heuristic(&neighbor, &grid.end),
+                    heuristic: heuristic(&neighbor),
+                    step_cost: weight,
                 });
                 
                 counter.add_to_frontier();
@@ -83,14 +93,7 @@ pub fn find_path(grid: &Grid) -> Result<(Vec<Position>, PerformanceCounter), Str
     Ok((Vec::new(), counter))
 }
 
-fn heuristic(from: &Position, to: &Position) -> f64 {
-
-    let dx = (from.col as i32 - to.col as i32).abs() as f64;
-    let dy = (from.row as i32 - to.row as i32).abs() as f64;
-    dx + dy
-}
-
-fn reconstruct_path(came_from: &HashMap<Position, Position>, mut current: Position) -> Vec<Position> {
+fn reconstruct_path<N: Copy + Eq + Hash>(came_from: &HashMap<N, N>, mut current: N) -> Vec<N> {
     let mut path = vec![current];
     
     while let Some(&parent) = came_from.get(&current) {
@@ -105,7 +108,7 @@ fn reconstruct_path(came_from: &HashMap<Position, Position>, mut current: Positi
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::pathfinder::CellType;
+    use crate::pathfinder::{CellType, Heuristic};
 
     #[test]
     fn test_greedy_best_first_empty_grid() {
@@ -163,13 +166,31 @@ mod tests {
         assert!(path.is_empty());
     }
 
+    #[test]
+    fn test_greedy_best_first_breaks_heuristic_ties_on_terrain() {
+        let start = Position::new(0, 0);
+        let end = Position::new(2, 2);
+
+        for (water, cheaper) in [(Position::new(0, 1), Position::new(1, 0)), (Position::new(1, 0), Position::new(0, 1))] {
+            let mut grid = Grid::new(3, 3, start, end);
+            grid.set_terrain(water, CellType::Water);
+
+            let (path, _) = find_path(&grid).unwrap();
+            assert_eq!(path[1], cheaper);
+            assert_eq!(path.last(), Some(&end));
+        }
+    }
+
     #[test]
     fn test_heuristic_calculation() {
         let pos1 = Position::new(0, 0);
         let pos2 = Position::new(3, 4);
         
-        let h = heuristic(&pos1, &pos2);
-        assert_eq!(h, 7.0);
+        assert_eq!(Heuristic::Manhattan.distance(&pos1, &pos2), 7.0);
+        assert_eq!(Heuristic::Octile.distance(&pos1, &pos2), 5.242);
+        assert_eq!(Heuristic::Chebyshev.distance(&pos1, &pos2), 4.0);
+        assert_eq!(Heuristic::Euclidean.distance(&pos1, &pos2), 4.999);
+        assert_eq!(Heuristic::Hex.distance(&pos1, &pos2), 6.0);
     }
 
     #[test]
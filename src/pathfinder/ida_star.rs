@@ -0,0 +1,125 @@
+use crate::pathfinder::{Grid, Position, PerformanceCounter};
+use std::collections::{HashMap, HashSet};
+
+pub const EXPANSION_LIMIT: usize = 1_000_000;
+
+enum Outcome {
+    Found,
+    Exceeded(u64),
+}
+
+struct Search<'a> {
+    grid: &'a Grid,
+    min_step_cost: u64,
+    path: Vec<Position>,
+    on_path: HashSet<Position>,
+    transpositions: HashMap<Position, u64>,
+    counter: PerformanceCounter,
+}
+
+impl Search<'_> {
+    fn heuristic(&self, pos: &Position) -> u64 {
+        self.grid.scaled_heuristic(pos, self.min_step_cost)
+    }
+
+    fn deepen(&mut self, current: Position, g: u64, bound: u64) -> Result<Outcome, String> {
+        let f = g + self.heuristic(&current);
+        if f > bound {
+            return Ok(Outcome::Exceeded(f));
+        }
+        if current == self.grid.end {
+            return Ok(Outcome::Found);
+        }
+        if self.transpositions.get(&current).is_some_and(|&seen| seen <= g) {
+            return Ok(Outcome::Exceeded(u64::MAX));
+        }
+        self.transpositions.insert(current, g);
+
+        self.counter.explore_node();
+        if self.counter.nodes_explored > EXPANSION_LIMIT {
+            return Err(format!("IDA* gave up after {} expansions", EXPANSION_LIMIT));
+        }
+
+        let mut successors: Vec<(u64, u64, Position)> = self.grid.get_neighbors(&current)
+            .into_iter()
+            .filter(|neighbor| !self.on_path.contains(neighbor))
+            .map(|neighbor| {
+                let next_g = g + self.grid.scaled_move_cost(&current, &neighbor);
+                (next_g + self.heuristic(&neighbor), next_g, neighbor)
+            })
+            .collect();
+        successors.sort_unstable_by_key(|&(f, _, _)| f);
+
+        let mut next_bound = u64::MAX;
+        for (_, next_g, neighbor) in successors {
+            self.counter.compare();
+            self.path.push(neighbor);
+            self.on_path.insert(neighbor);
+            self.counter.store_nodes(self.path.len() + self.transpositions.len());
+
+            match self.deepen(neighbor, next_g, bound)? {
+                Outcome::Found => return Ok(Outcome::Found),
+                Outcome::Exceeded(f) => next_bound = next_bound.min(f),
+            }
+
+            self.path.pop();
+            self.on_path.remove(&neighbor);
+        }
+
+        Ok(Outcome::Exceeded(next_bound))
+    }
+}
+
+pub fn find_path(grid: &Grid) -> Result<(Vec<Position>, PerformanceCounter), String> {
+    let mut search = Search {
+        grid,
+        min_step_cost: grid.min_step_cost() as u64,
+        path: vec![grid.start],
+        on_path: HashSet::from([grid.start]),
+        transpositions: HashMap::new(),
+        counter: PerformanceCounter::new(),
+    };
+    search.counter.store_nodes(1);
+
+    let mut bound = search.heuristic(&grid.start);
+    loop {
+        search.transpositions.clear();
+        match search.deepen(grid.start, 0, bound)? {
+            Outcome::Found => return Ok((search.path, search.counter)),
+            Outcome::Exceeded(u64::MAX) => return Ok((Vec::new(), search.counter)),
+            Outcome::Exceeded(next_bound) => bound = next_bound,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pathfinder::{astar, CellType};
+
+    #[test]
+    fn test_ida_star_matches_astar_cost() {
+        let mut grid = Grid::new(8, 8, Position::new(0, 0), Position::new(7, 7));
+        for row in 0..6 {
+            grid.add_obstacle(Position::new(row, 3));
+        }
+        grid.set_terrain(Position::new(6, 3), CellType::Mud);
+        grid.set_terrain(Position::new(7, 3), CellType::Water);
+
+        let (path, counter) = find_path(&grid).unwrap();
+        let (expected, _) = astar::find_path(&grid).unwrap();
+
+        assert_eq!(grid.scaled_path_cost(&path), grid.scaled_path_cost(&expected));
+        assert!(counter.peak_nodes_stored >= path.len());
+    }
+
+    #[test]
+    fn test_ida_star_no_path() {
+        let mut grid = Grid::new(4, 4, Position::new(0, 0), Position::new(3, 3));
+        grid.add_obstacle(Position::new(0, 1));
+        grid.add_obstacle(Position::new(1, 0));
+
+        let (path, _) = find_path(&grid).unwrap();
+        assert!(path.is_empty());
+    }
+}
@@ -0,0 +1,115 @@
+use crate::pathfinder::{CellType, Grid, Position, PerformanceCounter};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+
+pub trait IncrementalPlanner {
+    fn name(&self) -> &'static str;
+
+    fn grid(&self) -> &Grid;
+
+    fn plan(&mut self) -> Result<(Vec<Position>, PerformanceCounter), String>;
+
+    fn set_cell(&mut self, pos: Position, cell: CellType);
+
+    fn move_start(&mut self, _pos: Position) -> bool {
+        false
+    }
+
+    fn add_obstacle(&mut self, pos: Position) {
+        self.set_cell(pos, CellType::Blocked);
+    }
+
+    fn remove_obstacle(&mut self, pos: Position) {
+        self.set_cell(pos, CellType::Open);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReplanStep {
+    pub robot: Position,
+    pub cell: Position,
+    pub terrain: CellType,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct Key(pub u64, pub u64);
+
+impl Key {
+    pub const INFINITE: Key = Key(u64::MAX, u64::MAX);
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Entry {
+    key: Key,
+    position: Position,
+}
+
+impl PartialOrd for Entry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Entry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.key.cmp(&self.key)
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct OpenList {
+    heap: BinaryHeap<Entry>,
+    keys: HashMap<Position, Key>,
+}
+
+impl OpenList {
+    pub fn clear(&mut self) {
+        self.heap.clear();
+        self.keys.clear();
+    }
+
+    pub fn insert(&mut self, position: Position, key: Key) {
+        self.keys.insert(position, key);
+        self.heap.push(Entry { key, position });
+    }
+
+    pub fn remove(&mut self, position: &Position) {
+        self.keys.remove(position);
+    }
+
+    pub fn top_key(&mut self) -> Key {
+        self.discard_stale();
+        self.heap.peek().map_or(Key::INFINITE, |entry| entry.key)
+    }
+
+    pub fn pop(&mut self) -> Option<(Key, Position)> {
+        self.discard_stale();
+        let entry = self.heap.pop()?;
+        self.keys.remove(&entry.position);
+        Some((entry.key, entry.position))
+    }
+
+    fn discard_stale(&mut self) {
+        while let Some(entry) = self.heap.peek() {
+            if self.keys.get(&entry.position) == Some(&entry.key) {
+                break;
+            }
+            self.heap.pop();
+        }
+    }
+}
+
+pub(crate) fn affected_cells(grid: &Grid, pos: &Position) -> Vec<Position> {
+    let rows = pos.row.saturating_sub(1)..=(pos.row + 1).min(grid.height - 1);
+    rows.flat_map(|row| {
+        let cols = pos.col.saturating_sub(1)..=(pos.col + 1).min(grid.width - 1);
+        cols.map(move |col| Position::new(row, col))
+    })
+    .collect()
+}
+
+pub(crate) fn is_editable(grid: &Grid, pos: &Position, cell: CellType) -> bool {
+    pos.row < grid.height && pos.col < grid.width
+        && *pos != grid.start && *pos != grid.end
+        && grid.cells[pos.row][pos.col] != cell
+}
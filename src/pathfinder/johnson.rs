@@ -0,0 +1,91 @@
+use crate::pathfinder::{bellman_ford, DistanceMatrix, NegativeCycle, PerformanceCounter, ShortestPathTree, SignedGraph};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+pub fn all_pairs(graph: &SignedGraph) -> Result<(DistanceMatrix, PerformanceCounter), NegativeCycle> {
+    let nodes = graph.node_count();
+    let mut augmented = graph.clone();
+    let virtual_source = augmented.add_node();
+    for node in 0..nodes {
+        augmented.add_edge(virtual_source, node, 0);
+    }
+
+    let (potentials, mut counter) = bellman_ford::shortest_paths(&augmented, virtual_source)?;
+    let potentials: Vec<i64> = potentials.distances[..nodes].iter().map(|distance| distance.unwrap_or_default()).collect();
+
+    let matrix = (0..nodes)
+        .map(|source| reweighted_dijkstra(graph, &potentials, source, &mut counter))
+        .collect();
+
+    Ok((matrix, counter))
+}
+
+fn reweighted_dijkstra(graph: &SignedGraph, potentials: &[i64], source: usize, counter: &mut PerformanceCounter) -> ShortestPathTree {
+    let nodes = graph.node_count();
+    let mut tree = ShortestPathTree::new(source, nodes);
+    let mut reduced = vec![u64::MAX; nodes];
+    let mut settled = vec![false; nodes];
+    let mut queue = BinaryHeap::from([Reverse((0u64, source))]);
+
+    reduced[source] = 0;
+    counter.add_to_frontier();
+
+    while let Some(Reverse((distance, from))) = queue.pop() {
+        if settled[from] {
+            continue;
+        }
+        settled[from] = true;
+        counter.explore_node();
+        tree.distances[from] = Some(distance as i64 - potentials[source] + potentials[from]);
+
+        for &(to, weight) in graph.edges(from) {
+            counter.compare();
+            let candidate = distance + (weight + potentials[from] - potentials[to]) as u64;
+            if !settled[to] && candidate < reduced[to] {
+                reduced[to] = candidate;
+                tree.predecessors[to] = Some(from);
+                queue.push(Reverse((candidate, to)));
+                counter.add_to_frontier();
+            }
+        }
+    }
+
+    tree
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pathfinder::floyd_warshall;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_johnson_matches_floyd_warshall() {
+        for seed in 0..5 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let graph = SignedGraph::random_with_potentials(40, 100, &mut rng);
+
+            let (matrix, _) = all_pairs(&graph).unwrap();
+            let (expected, _) = floyd_warshall::all_pairs(&graph).unwrap();
+
+            assert_eq!(matrix.distances, expected.distances);
+            for (source, target) in [(0, 39), (12, 7), (25, 25)] {
+                let path = matrix.path(source, target);
+                assert_eq!(path.and_then(|path| graph.path_weight(&path)), expected.distance(source, target));
+            }
+        }
+    }
+
+    #[test]
+    fn test_johnson_reports_negative_cycle() {
+        let mut graph = SignedGraph::new(5);
+        graph.add_edge(0, 1, 4);
+        graph.add_edge(3, 4, -6);
+        graph.add_edge(4, 3, 5);
+
+        let cycle = all_pairs(&graph).unwrap_err();
+        assert_eq!(cycle.weight, -1);
+        assert!(!cycle.cycle.contains(&5));
+    }
+}
@@ -0,0 +1,426 @@
+use crate::pathfinder::{CellType, Grid, Heuristic, MovementModel, Position, PerformanceCounter};
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::cmp::Ordering;
+use std::time::{Duration, Instant};
+
+const DIRECTIONS: [(i64, i64); 8] = [(-1, 0), (1, 0), (0, -1), (0, 1), (-1, -1), (-1, 1), (1, -1), (1, 1)];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Node {
+    position: Position,
+    f_score: u64,
+}
+
+impl PartialOrd for Node {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Node {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.f_score.cmp(&self.f_score)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct JumpTable {
+    width: usize,
+    distances: Vec<[i32; 8]>,
+    pub build_time: Duration,
+    pub cells_scanned: usize,
+}
+
+impl JumpTable {
+    pub fn build(grid: &Grid) -> Self {
+        let start = Instant::now();
+        let mut cells_scanned = 0;
+        let mut distances = vec![[0; 8]; grid.width * grid.height];
+
+        for (index, &(dr, dc)) in DIRECTIONS.iter().enumerate() {
+            let rows: Vec<usize> = if dr > 0 { (0..grid.height).rev().collect() } else { (0..grid.height).collect() };
+            let cols: Vec<usize> = if dc > 0 { (0..grid.width).rev().collect() } else { (0..grid.width).collect() };
+
+            for &row in &rows {
+                for &col in &cols {
+                    let (r, c) = (row as i64, col as i64);
+                    if !walkable(grid, r, c) {
+                        continue;
+                    }
+                    cells_scanned += 1;
+
+                    if !can_step(grid, r, c, dr, dc) {
+                        continue;
+                    }
+
+                    let next = ((row as i64 + dr) as usize) * grid.width + (col as i64 + dc) as usize;
+                    let jump_point = if dr != 0 && dc != 0 {
+                        let (vertical, horizontal) = (index_of((dr, 0)), index_of((0, dc)));
+                        distances[next][vertical] > 0 || distances[next][horizontal] > 0
+                    } else {
+                        has_forced_neighbour(grid, r + dr, c + dc, dr, dc)
+                    };
+
+                    let onward = distances[next][index];
+                    distances[row * grid.width + col][index] = match (jump_point, onward > 0) {
+                        (true, _) => 1,
+                        (false, true) => onward + 1,
+                        (false, false) => onward - 1,
+                    };
+                }
+            }
+        }
+
+        Self {
+            width: grid.width,
+            distances,
+            build_time: start.elapsed(),
+            cells_scanned,
+        }
+    }
+
+    pub fn distance(&self, pos: &Position, direction: (i64, i64)) -> i32 {
+        self.distances[pos.row * self.width + pos.col][index_of(direction)]
+    }
+}
+
+pub fn find_path(grid: &Grid) -> Result<(Vec<Position>, PerformanceCounter), String> {
+    check_grid(grid)?;
+    search(grid, |pos, direction, counter| {
+        let (steps, jump_point) = scan(grid, pos, direction, Some(grid.end), counter);
+        jump_point.then(|| offset(pos, direction, steps as i64))
+    })
+}
+
+pub fn find_path_plus(grid: &Grid) -> Result<(Vec<Position>, PerformanceCounter), String> {
+    check_grid(grid)?;
+    let table = JumpTable::build(grid);
+    find_path_with_table(grid, &table)
+}
+
+pub fn find_path_with_table(grid: &Grid, table: &JumpTable) -> Result<(Vec<Position>, PerformanceCounter), String> {
+    check_grid(grid)?;
+    search(grid, |pos, (dr, dc), counter| {
+        counter.compare();
+        let distance = table.distance(&pos, (dr, dc)) as i64;
+        let goal_row = grid.end.row as i64 - pos.row as i64;
+        let goal_col = grid.end.col as i64 - pos.col as i64;
+
+        let goal_steps = if dr != 0 && dc != 0 {
+            (goal_row.signum() == dr && goal_col.signum() == dc).then(|| goal_row.abs().min(goal_col.abs()))
+        } else if dr == 0 {
+            (goal_row == 0 && goal_col.signum() == dc).then(|| goal_col.abs())
+        } else {
+            (goal_col == 0 && goal_row.signum() == dr).then(|| goal_row.abs())
+        };
+
+        match goal_steps {
+            Some(steps) if steps <= distance.abs() => Some(offset(pos, (dr, dc), steps)),
+            _ => (distance > 0).then(|| offset(pos, (dr, dc), distance)),
+        }
+    })
+}
+
+fn check_grid(grid: &Grid) -> Result<(), String> {
+    if grid.movement != MovementModel::EightWayNoCornerCutting {
+        return Err("Jump Point Search needs 8-way movement without corner cutting".to_string());
+    }
+    if grid.is_weighted() {
+        return Err("Jump Point Search needs a uniform-cost grid".to_string());
+    }
+    Ok(())
+}
+
+fn search<F>(grid: &Grid, mut jump: F) -> Result<(Vec<Position>, PerformanceCounter), String>
+where
+    F: FnMut(Position, (i64, i64), &mut PerformanceCounter) -> Option<Position>,
+{
+    let mut counter = PerformanceCounter::new();
+    let mut open_set = BinaryHeap::new();
+    let mut parent: HashMap<Position, Position> = HashMap::new();
+    let mut g_score: HashMap<Position, u64> = HashMap::new();
+    let mut closed: HashSet<Position> = HashSet::new();
+    let unit_cost = grid.costs.open as u64;
+    let octile = |from: &Position, to: &Position| Heuristic::Octile.scaled_distance(from, to) * unit_cost;
+
+    g_score.insert(grid.start, 0);
+    open_set.push(Node { position: grid.start, f_score: octile(&grid.start, &grid.end) });
+
+    counter.add_to_frontier();
+    counter.allocate_memory(1);
+
+    while let Some(Node { position: current, .. }) = open_set.pop() {
+        if !closed.insert(current) {
+            continue;
+        }
+        counter.explore_node();
+
+        if current == grid.end {
+            return Ok((expand_path(&reconstruct_path(&parent, current)), counter));
+        }
+
+        for direction in successor_directions(grid, current, parent.get(&current).copied()) {
+            let Some(next) = jump(current, direction, &mut counter) else {
+                continue;
+            };
+
+            if closed.contains(&next) {
+                continue;
+            }
+
+            let tentative_g_score = g_score[&current] + octile(&current, &next);
+            if tentative_g_score < *g_score.get(&next).unwrap_or(&u64::MAX) {
+                g_score.insert(next, tentative_g_score);
+                parent.insert(next, current);
+                open_set.push(Node { position: next, f_score: tentative_g_score + octile(&next, &grid.end) });
+                counter.add_to_frontier();
+                counter.allocate_memory(1);
+            }
+        }
+    }
+
+    Ok((Vec::new(), counter))
+}
+
+fn scan(grid: &Grid, from: Position, (dr, dc): (i64, i64), goal: Option<Position>, counter: &mut PerformanceCounter) -> (usize, bool) {
+    let (mut row, mut col) = (from.row as i64, from.col as i64);
+    let mut steps = 0;
+
+    loop {
+        if !can_step(grid, row, col, dr, dc) {
+            return (steps, false);
+        }
+        row += dr;
+        col += dc;
+        steps += 1;
+        counter.compare();
+
+        let pos = Position::new(row as usize, col as usize);
+        if goal == Some(pos) {
+            return (steps, true);
+        }
+
+        let jump_point = if dr != 0 && dc != 0 {
+            scan(grid, pos, (dr, 0), goal, counter).1 || scan(grid, pos, (0, dc), goal, counter).1
+        } else {
+            has_forced_neighbour(grid, row, col, dr, dc)
+        };
+
+        if jump_point {
+            return (steps, true);
+        }
+    }
+}
+
+fn successor_directions(grid: &Grid, pos: Position, parent: Option<Position>) -> Vec<(i64, i64)> {
+    let (row, col) = (pos.row as i64, pos.col as i64);
+    let Some(parent) = parent else {
+        return DIRECTIONS.into_iter().filter(|&(dr, dc)| can_step(grid, row, col, dr, dc)).collect();
+    };
+
+    let dr = (row - parent.row as i64).signum();
+    let dc = (col - parent.col as i64).signum();
+    let mut directions = Vec::new();
+
+    if dr != 0 && dc != 0 {
+        let (vertical, horizontal) = (walkable(grid, row + dr, col), walkable(grid, row, col + dc));
+        if vertical {
+            directions.push((dr, 0));
+        }
+        if horizontal {
+            directions.push((0, dc));
+        }
+        if vertical && horizontal {
+            directions.push((dr, dc));
+        }
+    } else if dr == 0 {
+        let (next, above, below) = (walkable(grid, row, col + dc), walkable(grid, row - 1, col), walkable(grid, row + 1, col));
+        if next {
+            directions.push((0, dc));
+            if above {
+                directions.push((-1, dc));
+            }
+            if below {
+                directions.push((1, dc));
+            }
+        }
+        if above {
+            directions.push((-1, 0));
+        }
+        if below {
+            directions.push((1, 0));
+        }
+    } else {
+        let (next, left, right) = (walkable(grid, row + dr, col), walkable(grid, row, col - 1), walkable(grid, row, col + 1));
+        if next {
+            directions.push((dr, 0));
+            if left {
+                directions.push((dr, -1));
+            }
+            if right {
+                directions.push((dr, 1));
+            }
+        }
+        if left {
+            directions.push((0, -1));
+        }
+        if right {
+            directions.push((0, 1));
+        }
+    }
+
+    directions
+}
+
+fn has_forced_neighbour(grid: &Grid, row: i64, col: i64, dr: i64, dc: i64) -> bool {
+    if dr == 0 {
+        (walkable(grid, row - 1, col) && !walkable(grid, row - 1, col - dc)) ||
+        (walkable(grid, row + 1, col) && !walkable(grid, row + 1, col - dc))
+    } else {
+        (walkable(grid, row, col - 1) && !walkable(grid, row - dr, col - 1)) ||
+        (walkable(grid, row, col + 1) && !walkable(grid, row - dr, col + 1))
+    }
+}
+
+fn can_step(grid: &Grid, row: i64, col: i64, dr: i64, dc: i64) -> bool {
+    walkable(grid, row + dr, col + dc) &&
+        (dr == 0 || dc == 0 || (walkable(grid, row + dr, col) && walkable(grid, row, col + dc)))
+}
+
+fn walkable(grid: &Grid, row: i64, col: i64) -> bool {
+    row >= 0 && col >= 0 && (row as usize) < grid.height && (col as usize) < grid.width &&
+        grid.cells[row as usize][col as usize] != CellType::Blocked
+}
+
+fn index_of(direction: (i64, i64)) -> usize {
+    DIRECTIONS.iter().position(|&d| d == direction).expect("valid direction")
+}
+
+fn offset(pos: Position, (dr, dc): (i64, i64), steps: i64) -> Position {
+    Position::new((pos.row as i64 + dr * steps) as usize, (pos.col as i64 + dc * steps) as usize)
+}
+
+fn expand_path(jump_points: &[Position]) -> Vec<Position> {
+    let mut path: Vec<Position> = jump_points.iter().take(1).copied().collect();
+
+    for pair in jump_points.windows(2) {
+        let (mut current, target) = (pair[0], pair[1]);
+        let direction = (
+            (target.row as i64 - current.row as i64).signum(),
+            (target.col as i64 - current.col as i64).signum(),
+        );
+
+        while current != target {
+            current = offset(current, direction, 1);
+            path.push(current);
+        }
+    }
+
+    path
+}
+
+fn reconstruct_path(parent: &HashMap<Position, Position>, mut current: Position) -> Vec<Position> {
+    let mut path = vec![current];
+
+    while let Some(&previous) = parent.get(&current) {
+        current = previous;
+        path.push(current);
+    }
+
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pathfinder::astar;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    fn strict_grid(width: usize, height: usize) -> Grid {
+        Grid::new(width, height, Position::new(0, 0), Position::new(height - 1, width - 1))
+            .with_movement(MovementModel::EightWayNoCornerCutting)
+    }
+
+    #[test]
+    fn test_jps_matches_astar_cost_on_random_grids() {
+        let mut rng = StdRng::seed_from_u64(43);
+
+        for _ in 0..25 {
+            let mut grid = strict_grid(24, 18);
+            for _ in 0..150 {
+                grid.add_obstacle(Position::new(rng.random_range(0..18), rng.random_range(0..24)));
+            }
+
+            let (expected, _) = astar::find_path(&grid).unwrap();
+            let (path, _) = find_path(&grid).unwrap();
+            let (plus_path, _) = find_path_plus(&grid).unwrap();
+
+            assert_eq!(path.is_empty(), expected.is_empty());
+            assert_eq!(grid.scaled_path_cost(&path), grid.scaled_path_cost(&expected));
+            assert_eq!(grid.scaled_path_cost(&plus_path), grid.scaled_path_cost(&expected));
+
+            for pair in path.windows(2).chain(plus_path.windows(2)) {
+                assert!(grid.get_neighbors(&pair[0]).contains(&pair[1]));
+            }
+        }
+    }
+
+    #[test]
+    fn test_jps_expands_fewer_nodes_on_open_grid() {
+        let grid = strict_grid(40, 30);
+
+        let (_, astar_counter) = astar::find_path(&grid).unwrap();
+        let (_, jps_counter) = find_path(&grid).unwrap();
+        let (_, plus_counter) = find_path_plus(&grid).unwrap();
+
+        assert!(jps_counter.nodes_explored * 10 < astar_counter.nodes_explored);
+        assert!(plus_counter.nodes_explored <= jps_counter.nodes_explored);
+    }
+
+    #[test]
+    fn test_jump_table_matches_direct_scans() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut grid = strict_grid(20, 15);
+        for _ in 0..80 {
+            grid.add_obstacle(Position::new(rng.random_range(0..15), rng.random_range(0..20)));
+        }
+
+        let table = JumpTable::build(&grid);
+        let mut counter = PerformanceCounter::new();
+        for row in 0..grid.height {
+            for col in 0..grid.width {
+                let pos = Position::new(row, col);
+                if !walkable(&grid, row as i64, col as i64) {
+                    continue;
+                }
+                for direction in DIRECTIONS {
+                    let (steps, jump_point) = scan(&grid, pos, direction, None, &mut counter);
+                    let expected = if jump_point { steps as i32 } else { -(steps as i32) };
+                    assert_eq!(table.distance(&pos, direction), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_jump_table_distances() {
+        let mut grid = strict_grid(6, 3);
+        grid.add_obstacle(Position::new(0, 3));
+        let table = JumpTable::build(&grid);
+
+        assert_eq!(table.distance(&Position::new(1, 0), (0, 1)), 4);
+        assert_eq!(table.distance(&Position::new(0, 0), (0, 1)), -2);
+        assert_eq!(table.distance(&Position::new(0, 0), (-1, 0)), 0);
+    }
+
+    #[test]
+    fn test_jps_rejects_unsupported_grids() {
+        let grid = Grid::new(4, 4, Position::new(0, 0), Position::new(3, 3));
+        assert!(find_path(&grid).is_err());
+
+        let mut weighted = strict_grid(4, 4);
+        weighted.set_terrain(Position::new(1, 1), CellType::Mud);
+        assert!(find_path_plus(&weighted).is_err());
+    }
+}
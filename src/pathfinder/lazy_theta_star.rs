@@ -0,0 +1,135 @@
+use crate::pathfinder::theta_star::{reconstruct_path, Node};
+use crate::pathfinder::{Grid, Heuristic, MovementModel, Position, PerformanceCounter};
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+pub fn find_path(grid: &Grid) -> Result<(Vec<Position>, PerformanceCounter), String> {
+    if grid.movement == MovementModel::Hex {
+        return Err("Lazy Theta* needs a square grid for line-of-sight checks".to_string());
+    }
+
+    let mut counter = PerformanceCounter::new();
+    let mut open_set = BinaryHeap::new();
+    let mut parent: HashMap<Position, Position> = HashMap::new();
+    let mut g_score: HashMap<Position, u64> = HashMap::new();
+    let mut verified: HashSet<Position> = HashSet::new();
+    let mut closed: HashSet<Position> = HashSet::new();
+    let min_step_cost = grid.min_step_cost() as u64;
+    let heuristic = |pos: &Position| Heuristic::Euclidean.scaled_distance(pos, &grid.end) * min_step_cost;
+    let optimistic_cost = |from: &Position, to: &Position| Heuristic::Euclidean.scaled_distance(from, to) * min_step_cost;
+
+    g_score.insert(grid.start, 0);
+    parent.insert(grid.start, grid.start);
+    verified.insert(grid.start);
+    open_set.push(Node { position: grid.start, f_score: heuristic(&grid.start) });
+
+    counter.add_to_frontier();
+    counter.allocate_memory(1);
+
+    while let Some(Node { position: current, f_score }) = open_set.pop() {
+        if closed.contains(&current) || f_score != g_score[&current] + heuristic(&current) {
+            continue;
+        }
+
+        if verified.insert(current) {
+            let assumed_g = g_score[&current];
+            let current_parent = parent[&current];
+
+            counter.compare();
+            let mut best = grid.scaled_segment_cost(&current_parent, &current)
+                .map(|cost| (current_parent, g_score[&current_parent] + cost));
+
+            for neighbor in grid.get_neighbors(&current) {
+                if closed.contains(&neighbor) {
+                    let candidate = g_score[&neighbor] + grid.scaled_move_cost(&neighbor, &current);
+                    if best.is_none_or(|(_, g)| candidate < g) {
+                        best = Some((neighbor, candidate));
+                    }
+                }
+            }
+
+            let Some((best_parent, best_g)) = best else {
+                continue;
+            };
+            parent.insert(current, best_parent);
+            g_score.insert(current, best_g);
+
+            if best_g > assumed_g {
+                open_set.push(Node { position: current, f_score: best_g + heuristic(&current) });
+                continue;
+            }
+        }
+
+        closed.insert(current);
+        counter.explore_node();
+
+        if current == grid.end {
+            return Ok((reconstruct_path(&parent, current), counter));
+        }
+
+        let current_parent = parent[&current];
+
+        for neighbor in grid.get_neighbors(&current) {
+            counter.compare();
+
+            if closed.contains(&neighbor) {
+                continue;
+            }
+
+            let candidate_g = g_score[&current_parent] + optimistic_cost(&current_parent, &neighbor);
+
+            if candidate_g < *g_score.get(&neighbor).unwrap_or(&u64::MAX) {
+                g_score.insert(neighbor, candidate_g);
+                parent.insert(neighbor, current_parent);
+                verified.remove(&neighbor);
+                open_set.push(Node { position: neighbor, f_score: candidate_g + heuristic(&neighbor) });
+                counter.add_to_frontier();
+                counter.allocate_memory(1);
+            }
+        }
+    }
+
+    Ok((Vec::new(), counter))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pathfinder::theta_star;
+
+    #[test]
+    fn test_lazy_theta_star_matches_theta_star_cost() {
+        let start = Position::new(0, 0);
+        let end = Position::new(9, 9);
+        let mut grid = Grid::new(10, 10, start, end).with_movement(MovementModel::EightWay);
+        for row in 0..8 {
+            grid.add_obstacle(Position::new(row, 4));
+        }
+        for row in 2..10 {
+            grid.add_obstacle(Position::new(row, 7));
+        }
+
+        let (path, lazy_counter) = find_path(&grid).unwrap();
+        let (expected, eager_counter) = theta_star::find_path(&grid).unwrap();
+
+        assert_eq!(path[0], start);
+        assert_eq!(path[path.len() - 1], end);
+        for pair in path.windows(2) {
+            assert!(grid.line_of_sight(&pair[0], &pair[1]));
+        }
+        assert!((grid.path_cost(&path) - grid.path_cost(&expected)).abs() < 1.0);
+        assert!(lazy_counter.comparisons <= eager_counter.comparisons);
+    }
+
+    #[test]
+    fn test_lazy_theta_star_no_path() {
+        let start = Position::new(0, 0);
+        let end = Position::new(2, 2);
+        let mut grid = Grid::new(3, 3, start, end).with_movement(MovementModel::EightWay);
+        grid.add_obstacle(Position::new(0, 1));
+        grid.add_obstacle(Position::new(1, 0));
+        grid.add_obstacle(Position::new(1, 1));
+
+        let (path, _) = find_path(&grid).unwrap();
+        assert!(path.is_empty());
+    }
+}
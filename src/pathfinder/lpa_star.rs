@@ -0,0 +1,222 @@
+use crate::pathfinder::incremental::{affected_cells, is_editable, IncrementalPlanner, Key, OpenList};
+use crate::pathfinder::{CellType, Grid, Position, PerformanceCounter};
+use std::collections::HashMap;
+
+pub struct LpaStar {
+    grid: Grid,
+    g_score: HashMap<Position, u64>,
+    rhs: HashMap<Position, u64>,
+    open_list: OpenList,
+    min_step_cost: u64,
+    counter: PerformanceCounter,
+}
+
+impl LpaStar {
+    pub fn new(grid: Grid) -> Self {
+        let mut planner = Self {
+            grid,
+            g_score: HashMap::new(),
+            rhs: HashMap::new(),
+            open_list: OpenList::default(),
+            min_step_cost: 1,
+            counter: PerformanceCounter::new(),
+        };
+        planner.reset();
+        planner
+    }
+
+    fn reset(&mut self) {
+        self.min_step_cost = self.grid.min_step_cost() as u64;
+        self.g_score.clear();
+        self.rhs.clear();
+        self.open_list.clear();
+
+        let start = self.grid.start;
+        self.rhs.insert(start, 0);
+        self.open_list.insert(start, self.key(&start));
+    }
+
+    fn g(&self, pos: &Position) -> u64 {
+        *self.g_score.get(pos).unwrap_or(&u64::MAX)
+    }
+
+    fn rhs(&self, pos: &Position) -> u64 {
+        *self.rhs.get(pos).unwrap_or(&u64::MAX)
+    }
+
+    fn key(&self, pos: &Position) -> Key {
+        let best = self.g(pos).min(self.rhs(pos));
+        let heuristic = self.grid.heuristic.scaled_distance(pos, &self.grid.end) * self.min_step_cost;
+        Key(best.saturating_add(heuristic), best)
+    }
+
+    fn best_predecessor(&mut self, pos: &Position) -> Option<(Position, u64)> {
+        if !self.grid.is_valid_position(pos) {
+            return None;
+        }
+
+        let mut best = None;
+        for predecessor in self.grid.get_neighbors(pos) {
+            self.counter.compare();
+            let g = self.g(&predecessor);
+            if g == u64::MAX {
+                continue;
+            }
+
+            let candidate = g + self.grid.scaled_move_cost(&predecessor, pos);
+            if best.is_none_or(|(_, cost)| candidate < cost) {
+                best = Some((predecessor, candidate));
+            }
+        }
+        best
+    }
+
+    fn update_vertex(&mut self, pos: Position) {
+        if pos != self.grid.start {
+            let rhs = self.best_predecessor(&pos).map_or(u64::MAX, |(_, cost)| cost);
+            self.rhs.insert(pos, rhs);
+        }
+
+        self.open_list.remove(&pos);
+        if self.g(&pos) != self.rhs(&pos) {
+            self.open_list.insert(pos, self.key(&pos));
+            self.counter.add_to_frontier();
+        }
+    }
+
+    fn compute_shortest_path(&mut self) {
+        let goal = self.grid.end;
+
+        while self.open_list.top_key() < self.key(&goal) || self.rhs(&goal) != self.g(&goal) {
+            let Some((_, current)) = self.open_list.pop() else {
+                break;
+            };
+            self.counter.explore_node();
+
+            if self.g(&current) > self.rhs(&current) {
+                self.g_score.insert(current, self.rhs(&current));
+            } else {
+                self.g_score.insert(current, u64::MAX);
+                self.update_vertex(current);
+            }
+
+            for successor in self.grid.get_neighbors(&current) {
+                self.update_vertex(successor);
+            }
+        }
+    }
+
+    fn extract_path(&mut self) -> Vec<Position> {
+        if self.g(&self.grid.end) == u64::MAX {
+            return Vec::new();
+        }
+
+        let mut path = vec![self.grid.end];
+        let mut current = self.grid.end;
+
+        while current != self.grid.start {
+            match self.best_predecessor(&current) {
+                Some((previous, _)) if path.len() <= self.grid.width * self.grid.height => {
+                    current = previous;
+                    path.push(current);
+                }
+                _ => return Vec::new(),
+            }
+        }
+
+        path.reverse();
+        path
+    }
+}
+
+impl IncrementalPlanner for LpaStar {
+    fn name(&self) -> &'static str {
+        "LPA*"
+    }
+
+    fn grid(&self) -> &Grid {
+        &self.grid
+    }
+
+    fn plan(&mut self) -> Result<(Vec<Position>, PerformanceCounter), String> {
+        self.counter = PerformanceCounter::new();
+        self.compute_shortest_path();
+        let path = self.extract_path();
+        Ok((path, std::mem::take(&mut self.counter)))
+    }
+
+    fn set_cell(&mut self, pos: Position, cell: CellType) {
+        if !is_editable(&self.grid, &pos, cell) {
+            return;
+        }
+
+        let cheaper = self.grid.costs.cost(cell).is_some_and(|cost| (cost as u64) < self.min_step_cost);
+        self.grid.set_terrain(pos, cell);
+
+        if cheaper {
+            self.reset();
+            return;
+        }
+
+        for affected in affected_cells(&self.grid, &pos) {
+            self.update_vertex(affected);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pathfinder::{astar, MovementModel};
+
+    #[test]
+    fn test_lpa_star_repairs_after_edits() {
+        for movement in [MovementModel::FourWay, MovementModel::EightWay, MovementModel::Hex] {
+            let mut grid = Grid::new(12, 12, Position::new(0, 0), Position::new(11, 11)).with_movement(movement);
+            grid.set_terrain(Position::new(5, 5), CellType::Mud);
+            let mut planner = LpaStar::new(grid.clone());
+
+            let edits = [
+                (Position::new(6, 6), CellType::Blocked),
+                (Position::new(3, 8), CellType::Water),
+                (Position::new(5, 5), CellType::Open),
+                (Position::new(6, 6), CellType::Open),
+                (Position::new(7, 7), CellType::Road),
+            ];
+
+            for (pos, cell) in edits {
+                planner.set_cell(pos, cell);
+                grid.set_terrain(pos, cell);
+
+                let (path, _) = planner.plan().unwrap();
+                let (expected, _) = astar::find_path(&grid).unwrap();
+                assert_eq!(grid.scaled_path_cost(&path), grid.scaled_path_cost(&expected));
+            }
+        }
+    }
+
+    #[test]
+    fn test_lpa_star_replans_with_fewer_expansions() {
+        let grid = Grid::new(20, 20, Position::new(0, 0), Position::new(19, 19));
+        let mut planner = LpaStar::new(grid);
+
+        let (_, initial) = planner.plan().unwrap();
+        planner.add_obstacle(Position::new(18, 19));
+        let (path, repair) = planner.plan().unwrap();
+
+        assert_eq!(path.len(), 39);
+        assert!(repair.nodes_explored < initial.nodes_explored);
+    }
+
+    #[test]
+    fn test_lpa_star_no_path_and_recovery() {
+        let mut planner = LpaStar::new(Grid::new(5, 5, Position::new(0, 0), Position::new(4, 4)));
+        for col in 0..5 {
+            planner.add_obstacle(Position::new(2, col));
+        }
+        assert!(planner.plan().unwrap().0.is_empty());
+
+        planner.remove_obstacle(Position::new(2, 3));
+        assert_eq!(planner.plan().unwrap().0.len(), 9);
+    }
+}
@@ -0,0 +1,246 @@
+use crate::prelude::*;
+use crate::pathfinder::{CellType, Grid, MovementModel, Position};
+use std::collections::{HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Scenario {
+    pub bucket: usize,
+    pub map: String,
+    pub width: usize,
+    pub height: usize,
+    pub start: Position,
+    pub goal: Position,
+    pub optimal_length: f64,
+}
+
+impl Scenario {
+    pub fn parse_all(contents: &str) -> Result<Vec<Self>> {
+        let mut scenarios = Vec::new();
+
+        for (number, line) in contents.lines().enumerate() {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            match fields.as_slice() {
+                [] | ["version", _] => continue,
+                [bucket, map, width, height, start_x, start_y, goal_x, goal_y, optimal_length] => {
+                    let field = |value: &str, name: &str| {
+                        value.parse::<usize>().map_err(|_| Error::validation(format!("Line {}: invalid {} '{}'", number + 1, name, value)))
+                    };
+                    scenarios.push(Self {
+                        bucket: field(bucket, "bucket")?,
+                        map: map.to_string(),
+                        width: field(width, "width")?,
+                        height: field(height, "height")?,
+                        start: Position::new(field(start_y, "start y")?, field(start_x, "start x")?),
+                        goal: Position::new(field(goal_y, "goal y")?, field(goal_x, "goal x")?),
+                        optimal_length: optimal_length.parse()
+                            .map_err(|_| Error::validation(format!("Line {}: invalid optimal length '{}'", number + 1, optimal_length)))?,
+                    });
+                }
+                _ => return Err(Error::validation(format!("Line {}: expected 9 scenario fields", number + 1))),
+            }
+        }
+
+        Ok(scenarios)
+    }
+
+    pub fn load_all(path: impl AsRef<Path>) -> Result<Vec<Self>> {
+        Self::parse_all(&std::fs::read_to_string(path)?)
+    }
+
+    pub fn map_path(&self, scenario_file: impl AsRef<Path>) -> PathBuf {
+        let directory = scenario_file.as_ref().parent().unwrap_or(Path::new(""));
+        let nested = directory.join(&self.map);
+        if nested.exists() {
+            return nested;
+        }
+        Path::new(&self.map).file_name().map_or(nested, |name| directory.join(name))
+    }
+
+    pub fn fits(&self, grid: &Grid) -> bool {
+        self.width == grid.width && self.height == grid.height
+            && grid.is_valid_position(&self.start) && grid.is_valid_position(&self.goal)
+    }
+}
+
+pub fn octile_length(path: &[Position]) -> f64 {
+    path.windows(2)
+        .map(|pair| {
+            let (dr, dc) = (pair[0].row.abs_diff(pair[1].row), pair[0].col.abs_diff(pair[1].col));
+            (dr.max(dc) - dr.min(dc)) as f64 + dr.min(dc) as f64 * std::f64::consts::SQRT_2
+        })
+        .sum()
+}
+
+impl Grid {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)?;
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("map") => Self::from_movingai_map(&contents),
+            _ => Self::from_ascii(&contents),
+        }
+    }
+
+    pub fn from_movingai_map(contents: &str) -> Result<Self> {
+        let mut lines = contents.lines();
+        let (mut width, mut height) = (None, None);
+
+        for line in lines.by_ref() {
+            let parse = |value: &str| value.parse::<usize>().map_err(|_| Error::validation(format!("Invalid map header '{}'", line)));
+            match line.split_whitespace().collect::<Vec<_>>().as_slice() {
+                [] => continue,
+                ["type", "octile"] => {}
+                ["type", other] => return Err(Error::validation(format!("Unsupported map type '{}'", other))),
+                ["height", value] => height = Some(parse(value)?),
+                ["width", value] => width = Some(parse(value)?),
+                ["map"] => break,
+                _ => return Err(Error::validation(format!("Invalid map header '{}'", line))),
+            }
+        }
+
+        let (Some(width), Some(height)) = (width, height) else {
+            return Err(Error::validation("Map header needs both width and height"));
+        };
+
+        let mut grid = Grid::new(width, height, Position::new(0, 0), Position::new(0, 0))
+            .with_movement(MovementModel::EightWayNoCornerCutting);
+
+        for row in 0..height {
+            let line = lines.next().ok_or_else(|| Error::validation(format!("Map has {} rows, expected {}", row, height)))?;
+            let cells: Vec<char> = line.trim_end().chars().collect();
+            if cells.len() != width {
+                return Err(Error::validation(format!("Map row {} has {} cells, expected {}", row, cells.len(), width)));
+            }
+
+            for (col, symbol) in cells.into_iter().enumerate() {
+                grid.cells[row][col] = match symbol {
+                    '.' | 'G' | 'S' => CellType::Open,
+                    '@' | 'O' | 'T' | 'W' => CellType::Blocked,
+                    other => return Err(Error::validation(format!("Unknown map symbol '{}' at row {}, column {}", other, row, col))),
+                };
+            }
+        }
+
+        let (start, end) = default_route(&grid).ok_or_else(|| Error::validation("Map has no passable cells"))?;
+        grid.set_route(start, end);
+        Ok(grid)
+    }
+
+    pub fn from_ascii(contents: &str) -> Result<Self> {
+        let rows: Vec<Vec<char>> = contents.lines()
+            .map(|line| line.trim_end().chars().collect::<Vec<_>>())
+            .filter(|row| !row.is_empty())
+            .collect();
+
+        let width = rows.first().map_or(0, Vec::len);
+        if width == 0 {
+            return Err(Error::validation("ASCII grid is empty"));
+        }
+
+        let mut grid = Grid::new(width, rows.len(), Position::new(0, 0), Position::new(0, 0));
+        let (mut start, mut end) = (Vec::new(), Vec::new());
+
+        for (row, cells) in rows.iter().enumerate() {
+            if cells.len() != width {
+                return Err(Error::validation(format!("ASCII row {} has {} cells, expected {}", row, cells.len(), width)));
+            }
+
+            for (col, &symbol) in cells.iter().enumerate() {
+                grid.cells[row][col] = match symbol {
+                    '.' => CellType::Open,
+                    '#' => CellType::Blocked,
+                    'S' => {
+                        start.push(Position::new(row, col));
+                        CellType::Open
+                    }
+                    'E' => {
+                        end.push(Position::new(row, col));
+                        CellType::Open
+                    }
+                    other => return Err(Error::validation(format!("Unknown ASCII symbol '{}' at row {}, column {}", other, row, col))),
+                };
+            }
+        }
+
+        match (start.as_slice(), end.as_slice()) {
+            ([start], [end]) => {
+                grid.set_route(*start, *end);
+                Ok(grid)
+            }
+            _ => Err(Error::validation(format!("ASCII grid needs exactly one S and one E (found {} and {})", start.len(), end.len()))),
+        }
+    }
+}
+
+fn default_route(grid: &Grid) -> Option<(Position, Position)> {
+    let start = (0..grid.height)
+        .flat_map(|row| (0..grid.width).map(move |col| Position::new(row, col)))
+        .find(|pos| grid.is_valid_position(pos))?;
+
+    let mut visited = HashSet::from([start]);
+    let mut queue = VecDeque::from([start]);
+    let mut farthest = start;
+
+    while let Some(current) = queue.pop_front() {
+        farthest = current;
+        for neighbor in grid.get_neighbors(&current) {
+            if visited.insert(neighbor) {
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    Some((start, farthest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pathfinder::{astar, jps};
+
+    const MAP: &str = "type octile\nheight 5\nwidth 6\nmap\n......\n.@@...\n......\n...T..\n......\n";
+
+    #[test]
+    fn test_movingai_map_and_scenarios() {
+        let grid = Grid::from_movingai_map(MAP).unwrap();
+        assert_eq!((grid.width, grid.height), (6, 5));
+        assert_eq!(grid.movement, MovementModel::EightWayNoCornerCutting);
+        assert_eq!(grid.cells[3][3], CellType::Blocked);
+
+        let scenarios = Scenario::parse_all(
+            "version 1\n0\ttest.map\t6\t5\t0\t2\t5\t4\t5.82842712\n0\ttest.map\t6\t5\t0\t0\t3\t0\t3.00000000\n1\ttest.map\t6\t5\t1\t0\t1\t2\t4.00000000\n",
+        ).unwrap();
+        assert_eq!(scenarios.len(), 3);
+        assert_eq!(scenarios[0].start, Position::new(2, 0));
+
+        for scenario in &scenarios {
+            assert!(scenario.fits(&grid));
+            let mut routed = grid.clone();
+            routed.set_route(scenario.start, scenario.goal);
+
+            for (path, _) in [astar::find_path(&routed).unwrap(), jps::find_path(&routed).unwrap()] {
+                assert!((octile_length(&path) - scenario.optimal_length).abs() < 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ascii_grid() {
+        let grid = Grid::from_ascii("S..#\n.#.#\n...E\n").unwrap();
+        assert_eq!((grid.start, grid.end), (Position::new(0, 0), Position::new(2, 3)));
+        assert_eq!(grid.cells[1][1], CellType::Blocked);
+        assert_eq!(astar::find_path(&grid).unwrap().0.len(), 6);
+
+        assert!(Grid::from_ascii("S..\n..\n..E").is_err());
+        assert!(Grid::from_ascii("S..\n...\n...").is_err());
+        assert!(Grid::from_ascii("S.x\n..E").is_err());
+    }
+
+    #[test]
+    fn test_invalid_movingai_input() {
+        assert!(Grid::from_movingai_map("type octile\nheight 2\nmap\n..\n..\n").is_err());
+        assert!(Grid::from_movingai_map("type octile\nheight 2\nwidth 2\nmap\n..\n.\n").is_err());
+        assert!(Scenario::parse_all("0\tx.map\t1\t1\t0\t0\t0\n").is_err());
+    }
+}
@@ -0,0 +1,618 @@
+use crate::prelude::*;
+use crate::pathfinder::{CellType, Grid, Position};
+use rand::rngs::StdRng;
+use rand::seq::{IndexedRandom, SliceRandom};
+use rand::{Rng, SeedableRng};
+use std::collections::{BTreeMap, VecDeque};
+use std::str::FromStr;
+
+const DIRECTIONS: [(isize, isize); 4] = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MazeAlgorithm {
+    #[default]
+    RecursiveBacktracker,
+    Prim,
+    Kruskal,
+    Wilson,
+    Eller,
+    RecursiveDivision,
+    CellularAutomaton,
+}
+
+impl MazeAlgorithm {
+    pub const ALL: [MazeAlgorithm; 7] = [
+        MazeAlgorithm::RecursiveBacktracker,
+        MazeAlgorithm::Prim,
+        MazeAlgorithm::Kruskal,
+        MazeAlgorithm::Wilson,
+        MazeAlgorithm::Eller,
+        MazeAlgorithm::RecursiveDivision,
+        MazeAlgorithm::CellularAutomaton,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MazeAlgorithm::RecursiveBacktracker => "Recursive Backtracker",
+            MazeAlgorithm::Prim => "Prim's",
+            MazeAlgorithm::Kruskal => "Kruskal's",
+            MazeAlgorithm::Wilson => "Wilson's",
+            MazeAlgorithm::Eller => "Eller's",
+            MazeAlgorithm::RecursiveDivision => "Recursive Division",
+            MazeAlgorithm::CellularAutomaton => "Cellular Automaton Caves",
+        }
+    }
+
+    pub fn is_perfect(&self) -> bool {
+        *self != MazeAlgorithm::CellularAutomaton
+    }
+}
+
+impl FromStr for MazeAlgorithm {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "backtracker" | "recursive-backtracker" | "dfs" => Ok(MazeAlgorithm::RecursiveBacktracker),
+            "prim" | "prims" => Ok(MazeAlgorithm::Prim),
+            "kruskal" | "kruskals" => Ok(MazeAlgorithm::Kruskal),
+            "wilson" | "wilsons" => Ok(MazeAlgorithm::Wilson),
+            "eller" | "ellers" => Ok(MazeAlgorithm::Eller),
+            "division" | "recursive-division" => Ok(MazeAlgorithm::RecursiveDivision),
+            "caves" | "cellular" | "cellular-automaton" => Ok(MazeAlgorithm::CellularAutomaton),
+            _ => Err(Error::validation(format!(
+                "Unknown maze generator '{}' (expected backtracker, prim, kruskal, wilson, eller, division or caves)",
+                s
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MazeGenerator {
+    pub algorithm: MazeAlgorithm,
+    pub braid: f64,
+    pub seed: Option<u64>,
+}
+
+impl Default for MazeGenerator {
+    fn default() -> Self {
+        Self::new(MazeAlgorithm::default())
+    }
+}
+
+impl MazeGenerator {
+    pub fn new(algorithm: MazeAlgorithm) -> Self {
+        Self {
+            algorithm,
+            braid: 0.0,
+            seed: None,
+        }
+    }
+
+    pub fn with_braid(mut self, braid: f64) -> Self {
+        self.braid = braid.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn with_seed(mut self, seed: Option<u64>) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn generate(&self, width: usize, height: usize) -> Grid {
+        self.generate_traced(width, height, &mut |_, _| {})
+    }
+
+    pub fn generate_traced(&self, width: usize, height: usize, on_change: &mut dyn FnMut(&Grid, Position)) -> Grid {
+        let start = Position::new(0, 0);
+        if width == 0 || height == 0 {
+            return Grid::new(width, height, start, start);
+        }
+
+        let mut rng = match self.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_rng(&mut rand::rng()),
+        };
+
+        let end = match self.algorithm {
+            MazeAlgorithm::CellularAutomaton => Position::new(height - 1, width - 1),
+            _ => Position::new((height - 1) / 2 * 2, (width - 1) / 2 * 2),
+        };
+
+        let fill = match self.algorithm {
+            MazeAlgorithm::RecursiveDivision | MazeAlgorithm::CellularAutomaton => CellType::Open,
+            _ => CellType::Blocked,
+        };
+
+        let mut grid = Grid::new(width, height, start, start);
+        for row in grid.cells.iter_mut() {
+            row.fill(fill);
+        }
+
+        let mut canvas = Canvas {
+            rows: height.div_ceil(2),
+            cols: width.div_ceil(2),
+            grid,
+            on_change,
+        };
+
+        match self.algorithm {
+            MazeAlgorithm::RecursiveBacktracker => recursive_backtracker(&mut canvas, &mut rng),
+            MazeAlgorithm::Prim => prim(&mut canvas, &mut rng),
+            MazeAlgorithm::Kruskal => kruskal(&mut canvas, &mut rng),
+            MazeAlgorithm::Wilson => wilson(&mut canvas, &mut rng),
+            MazeAlgorithm::Eller => eller(&mut canvas, &mut rng),
+            MazeAlgorithm::RecursiveDivision => recursive_division(&mut canvas, &mut rng),
+            MazeAlgorithm::CellularAutomaton => cellular_automaton(&mut canvas, end, &mut rng),
+        }
+
+        canvas.set(start, CellType::Open);
+        canvas.set(end, CellType::Open);
+        braid(&mut canvas, self.braid, &mut rng);
+
+        let mut grid = canvas.grid;
+        grid.set_route(start, end);
+        grid
+    }
+}
+
+pub fn count_dead_ends(grid: &Grid) -> usize {
+    (0..grid.height)
+        .flat_map(|row| (0..grid.width).map(move |col| Position::new(row, col)))
+        .filter(|pos| is_passable(grid, pos) && open_neighbours(grid, *pos) == 1)
+        .count()
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct DisjointSet {
+    parent: Vec<usize>,
+    rank: Vec<u8>,
+}
+
+impl DisjointSet {
+    pub fn new(size: usize) -> Self {
+        Self {
+            parent: (0..size).collect(),
+            rank: vec![0; size],
+        }
+    }
+
+    pub fn find(&mut self, mut node: usize) -> usize {
+        while self.parent[node] != node {
+            self.parent[node] = self.parent[self.parent[node]];
+            node = self.parent[node];
+        }
+        node
+    }
+
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        match self.rank[a].cmp(&self.rank[b]) {
+            std::cmp::Ordering::Less => self.parent[a] = b,
+            std::cmp::Ordering::Greater => self.parent[b] = a,
+            std::cmp::Ordering::Equal => {
+                self.parent[b] = a;
+                self.rank[a] += 1;
+            }
+        }
+        true
+    }
+}
+
+struct Canvas<'a> {
+    grid: Grid,
+    rows: usize,
+    cols: usize,
+    on_change: &'a mut dyn FnMut(&Grid, Position),
+}
+
+impl Canvas<'_> {
+    fn set(&mut self, pos: Position, cell: CellType) {
+        if self.grid.cells[pos.row][pos.col] != cell {
+            self.grid.cells[pos.row][pos.col] = cell;
+            (self.on_change)(&self.grid, pos);
+        }
+    }
+
+    fn rooms(&self) -> usize {
+        self.rows * self.cols
+    }
+
+    fn room_cell(&self, room: usize) -> Position {
+        Position::new(room / self.cols * 2, room % self.cols * 2)
+    }
+
+    fn neighbours(&self, room: usize) -> Vec<usize> {
+        let (row, col) = (room / self.cols, room % self.cols);
+        DIRECTIONS.iter()
+            .filter_map(|&(dr, dc)| {
+                let row = row.checked_add_signed(dr).filter(|&row| row < self.rows)?;
+                let col = col.checked_add_signed(dc).filter(|&col| col < self.cols)?;
+                Some(row * self.cols + col)
+            })
+            .collect()
+    }
+
+    fn open_room(&mut self, room: usize) {
+        self.set(self.room_cell(room), CellType::Open);
+    }
+
+    fn connect(&mut self, from: usize, to: usize) {
+        let (a, b) = (self.room_cell(from), self.room_cell(to));
+        self.set(a, CellType::Open);
+        self.set(Position::new((a.row + b.row) / 2, (a.col + b.col) / 2), CellType::Open);
+        self.set(b, CellType::Open);
+    }
+}
+
+fn recursive_backtracker(canvas: &mut Canvas, rng: &mut StdRng) {
+    let mut visited = vec![false; canvas.rooms()];
+    let first = rng.random_range(0..canvas.rooms());
+    visited[first] = true;
+    canvas.open_room(first);
+
+    let mut stack = vec![first];
+    while let Some(&current) = stack.last() {
+        let unvisited: Vec<usize> = canvas.neighbours(current).into_iter().filter(|&room| !visited[room]).collect();
+        match unvisited.choose(rng) {
+            Some(&next) => {
+                visited[next] = true;
+                canvas.connect(current, next);
+                stack.push(next);
+            }
+            None => {
+                stack.pop();
+            }
+        }
+    }
+}
+
+fn prim(canvas: &mut Canvas, rng: &mut StdRng) {
+    let mut in_maze = vec![false; canvas.rooms()];
+    let mut in_frontier = vec![false; canvas.rooms()];
+    let mut frontier = vec![rng.random_range(0..canvas.rooms())];
+
+    while !frontier.is_empty() {
+        let room = frontier.swap_remove(rng.random_range(0..frontier.len()));
+        let joined: Vec<usize> = canvas.neighbours(room).into_iter().filter(|&other| in_maze[other]).collect();
+        match joined.choose(rng) {
+            Some(&into) => canvas.connect(into, room),
+            None => canvas.open_room(room),
+        }
+        in_maze[room] = true;
+
+        for neighbour in canvas.neighbours(room) {
+            if !in_maze[neighbour] && !in_frontier[neighbour] {
+                in_frontier[neighbour] = true;
+                frontier.push(neighbour);
+            }
+        }
+    }
+}
+
+fn kruskal(canvas: &mut Canvas, rng: &mut StdRng) {
+    let mut walls: Vec<(usize, usize)> = (0..canvas.rooms())
+        .flat_map(|room| canvas.neighbours(room).into_iter().filter(move |&other| other > room).map(move |other| (room, other)))
+        .collect();
+    walls.shuffle(rng);
+
+    let mut sets = DisjointSet::new(canvas.rooms());
+    for (a, b) in walls {
+        if sets.union(a, b) {
+            canvas.connect(a, b);
+        }
+    }
+}
+
+fn wilson(canvas: &mut Canvas, rng: &mut StdRng) {
+    let mut in_maze = vec![false; canvas.rooms()];
+    let first = rng.random_range(0..canvas.rooms());
+    in_maze[first] = true;
+    canvas.open_room(first);
+
+    let mut order: Vec<usize> = (0..canvas.rooms()).collect();
+    order.shuffle(rng);
+    let mut next = vec![0; canvas.rooms()];
+
+    for start in order {
+        let mut current = start;
+        while !in_maze[current] {
+            let step = *canvas.neighbours(current).choose(rng).expect("a lattice with unvisited rooms has neighbours");
+            next[current] = step;
+            current = step;
+        }
+
+        let mut current = start;
+        while !in_maze[current] {
+            in_maze[current] = true;
+            canvas.connect(current, next[current]);
+            current = next[current];
+        }
+    }
+}
+
+fn eller(canvas: &mut Canvas, rng: &mut StdRng) {
+    let (rows, cols) = (canvas.rows, canvas.cols);
+    let mut sets: Vec<usize> = (0..cols).collect();
+    let mut next_set = cols;
+
+    for row in 0..rows {
+        let room = |col: usize| row * cols + col;
+        let last_row = row + 1 == rows;
+
+        for col in 0..cols {
+            canvas.open_room(room(col));
+        }
+
+        for col in 0..cols.saturating_sub(1) {
+            if sets[col] != sets[col + 1] && (last_row || rng.random_bool(0.5)) {
+                let (keep, merge) = (sets[col], sets[col + 1]);
+                for set in sets.iter_mut().filter(|set| **set == merge) {
+                    *set = keep;
+                }
+                canvas.connect(room(col), room(col + 1));
+            }
+        }
+
+        if last_row {
+            break;
+        }
+
+        let mut members: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+        for (col, &set) in sets.iter().enumerate() {
+            members.entry(set).or_default().push(col);
+        }
+
+        let mut below = vec![None; cols];
+        for (set, mut columns) in members {
+            columns.shuffle(rng);
+            let count = rng.random_range(1..=columns.len());
+            for &col in &columns[..count] {
+                below[col] = Some(set);
+                canvas.connect(room(col), room(col) + cols);
+            }
+        }
+
+        sets = below.into_iter()
+            .map(|set| set.unwrap_or_else(|| {
+                next_set += 1;
+                next_set - 1
+            }))
+            .collect();
+    }
+}
+
+fn recursive_division(canvas: &mut Canvas, rng: &mut StdRng) {
+    let (last_row, last_col) = (2 * (canvas.rows - 1), 2 * (canvas.cols - 1));
+    for row in 0..canvas.grid.height {
+        for col in 0..canvas.grid.width {
+            if row > last_row || col > last_col {
+                canvas.set(Position::new(row, col), CellType::Blocked);
+            }
+        }
+    }
+
+    let mut chambers = vec![(0, 0, canvas.rows, canvas.cols)];
+    while let Some((top, left, height, width)) = chambers.pop() {
+        let horizontal = match (height >= 2, width >= 2) {
+            (false, false) => continue,
+            (true, false) => true,
+            (false, true) => false,
+            (true, true) if height != width => height > width,
+            (true, true) => rng.random_bool(0.5),
+        };
+
+        if horizontal {
+            let split = rng.random_range(1..height);
+            let door = 2 * (left + rng.random_range(0..width));
+            let wall = 2 * (top + split) - 1;
+            for col in (2 * left..=2 * (left + width - 1)).filter(|&col| col != door) {
+                canvas.set(Position::new(wall, col), CellType::Blocked);
+            }
+            chambers.push((top, left, split, width));
+            chambers.push((top + split, left, height - split, width));
+        } else {
+            let split = rng.random_range(1..width);
+            let door = 2 * (top + rng.random_range(0..height));
+            let wall = 2 * (left + split) - 1;
+            for row in (2 * top..=2 * (top + height - 1)).filter(|&row| row != door) {
+                canvas.set(Position::new(row, wall), CellType::Blocked);
+            }
+            chambers.push((top, left, height, split));
+            chambers.push((top, left + split, height, width - split));
+        }
+    }
+}
+
+fn cellular_automaton(canvas: &mut Canvas, end: Position, rng: &mut StdRng) {
+    let (width, height) = (canvas.grid.width, canvas.grid.height);
+    let start = Position::new(0, 0);
+
+    for row in 0..height {
+        for col in 0..width {
+            if rng.random_bool(0.45) {
+                canvas.set(Position::new(row, col), CellType::Blocked);
+            }
+        }
+    }
+
+    for _ in 0..4 {
+        let mut changes = Vec::new();
+        for row in 0..height {
+            for col in 0..width {
+                let walls = (-1..=1isize)
+                    .flat_map(|dr| (-1..=1isize).map(move |dc| (dr, dc)))
+                    .filter(|&offset| offset != (0, 0))
+                    .filter(|&(dr, dc)| {
+                        let neighbour = row.checked_add_signed(dr).zip(col.checked_add_signed(dc));
+                        neighbour.is_none_or(|(row, col)| row >= height || col >= width || canvas.grid.cells[row][col] == CellType::Blocked)
+                    })
+                    .count();
+
+                let cell = match walls {
+                    5.. => CellType::Blocked,
+                    0..=3 => CellType::Open,
+                    _ => continue,
+                };
+                changes.push((Position::new(row, col), cell));
+            }
+        }
+
+        for (pos, cell) in changes {
+            canvas.set(pos, cell);
+        }
+    }
+
+    canvas.set(start, CellType::Open);
+    canvas.set(end, CellType::Open);
+
+    let reached = flood_fill(&canvas.grid, start);
+    let mut current = end;
+    while !reached[current.row * width + current.col] {
+        let step_up = current.row > start.row && (current.col == start.col || rng.random_bool(0.5));
+        current = if step_up {
+            Position::new(current.row - 1, current.col)
+        } else {
+            Position::new(current.row, current.col - 1)
+        };
+        canvas.set(current, CellType::Open);
+    }
+
+    let reached = flood_fill(&canvas.grid, start);
+    for row in 0..height {
+        for col in 0..width {
+            if !reached[row * width + col] {
+                canvas.set(Position::new(row, col), CellType::Blocked);
+            }
+        }
+    }
+}
+
+fn braid(canvas: &mut Canvas, braid: f64, rng: &mut StdRng) {
+    if braid <= 0.0 {
+        return;
+    }
+
+    let dead_ends: Vec<Position> = (0..canvas.grid.height)
+        .flat_map(|row| (0..canvas.grid.width).map(move |col| Position::new(row, col)))
+        .filter(|pos| is_passable(&canvas.grid, pos) && open_neighbours(&canvas.grid, *pos) == 1)
+        .collect();
+
+    for pos in dead_ends {
+        if open_neighbours(&canvas.grid, pos) != 1 || !rng.random_bool(braid) {
+            continue;
+        }
+
+        let candidates: Vec<(Position, Position)> = DIRECTIONS.iter()
+            .filter_map(|&(dr, dc)| {
+                let wall = step(&canvas.grid, pos, dr, dc)?;
+                let beyond = step(&canvas.grid, wall, dr, dc)?;
+                (!is_passable(&canvas.grid, &wall) && is_passable(&canvas.grid, &beyond)).then_some((wall, beyond))
+            })
+            .collect();
+
+        let dead_end_pairs: Vec<(Position, Position)> = candidates.iter()
+            .copied()
+            .filter(|&(_, beyond)| open_neighbours(&canvas.grid, beyond) == 1)
+            .collect();
+
+        let choices = if dead_end_pairs.is_empty() { &candidates } else { &dead_end_pairs };
+        if let Some(&(wall, _)) = choices.choose(rng) {
+            canvas.set(wall, CellType::Open);
+        }
+    }
+}
+
+fn step(grid: &Grid, pos: Position, dr: isize, dc: isize) -> Option<Position> {
+    let row = pos.row.checked_add_signed(dr).filter(|&row| row < grid.height)?;
+    let col = pos.col.checked_add_signed(dc).filter(|&col| col < grid.width)?;
+    Some(Position::new(row, col))
+}
+
+fn is_passable(grid: &Grid, pos: &Position) -> bool {
+    grid.cells[pos.row][pos.col] != CellType::Blocked
+}
+
+fn open_neighbours(grid: &Grid, pos: Position) -> usize {
+    DIRECTIONS.iter()
+        .filter_map(|&(dr, dc)| step(grid, pos, dr, dc))
+        .filter(|neighbour| is_passable(grid, neighbour))
+        .count()
+}
+
+fn flood_fill(grid: &Grid, start: Position) -> Vec<bool> {
+    let mut reached = vec![false; grid.width * grid.height];
+    let mut queue = VecDeque::from([start]);
+    reached[start.row * grid.width + start.col] = true;
+
+    while let Some(current) = queue.pop_front() {
+        for &(dr, dc) in &DIRECTIONS {
+            if let Some(next) = step(grid, current, dr, dc)
+                && is_passable(grid, &next)
+                && !reached[next.row * grid.width + next.col]
+            {
+                reached[next.row * grid.width + next.col] = true;
+                queue.push_back(next);
+            }
+        }
+    }
+
+    reached
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pathfinder::{astar, MovementModel};
+
+    #[test]
+    fn test_perfect_mazes_are_spanning_trees() {
+        for algorithm in MazeAlgorithm::ALL.into_iter().filter(MazeAlgorithm::is_perfect) {
+            for (width, height) in [(15, 11), (20, 16)] {
+                let grid = MazeGenerator::new(algorithm).with_seed(Some(3)).generate(width, height);
+                let rooms = width.div_ceil(2) * height.div_ceil(2);
+                let open = grid.cells.iter().flatten().filter(|cell| **cell != CellType::Blocked).count();
+
+                assert_eq!(open, 2 * rooms - 1, "{}", algorithm.as_str());
+                assert!(!astar::find_path(&grid).unwrap().0.is_empty(), "{}", algorithm.as_str());
+            }
+        }
+    }
+
+    #[test]
+    fn test_seed_and_braid() {
+        for algorithm in MazeAlgorithm::ALL {
+            let generator = MazeGenerator::new(algorithm).with_seed(Some(11));
+            assert_eq!(generator.generate(21, 21).cells, generator.generate(21, 21).cells, "{}", algorithm.as_str());
+
+            let mut changes = 0;
+            let braided = generator.with_braid(1.0).generate_traced(21, 21, &mut |_, _| changes += 1);
+            assert!(changes > 0);
+            if algorithm.is_perfect() {
+                assert_eq!(count_dead_ends(&braided), 0, "{}", algorithm.as_str());
+            }
+        }
+    }
+
+    #[test]
+    fn test_caves_are_connected() {
+        for seed in 0..5 {
+            let grid = MazeGenerator::new(MazeAlgorithm::CellularAutomaton).with_seed(Some(seed)).generate(30, 20);
+            let reached = flood_fill(&grid, grid.start);
+            assert_eq!(grid.end, Position::new(19, 29));
+            assert!(reached[grid.end.row * grid.width + grid.end.col]);
+
+            for (index, cell) in grid.cells.iter().flatten().enumerate() {
+                assert_eq!(*cell != CellType::Blocked, reached[index]);
+            }
+
+            let hex = grid.clone().with_movement(MovementModel::Hex);
+            assert!(!astar::find_path(&hex).unwrap().0.is_empty());
+        }
+    }
+}
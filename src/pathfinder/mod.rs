@@ -3,8 +3,36 @@ pub mod dijkstra;
 pub mod breadth_first;
 pub mod depth_first;
 pub mod greedy_best_first;
+pub mod movement;
+pub mod theta_star;
+pub mod lazy_theta_star;
+pub mod jps;
+pub mod bidirectional;
+pub mod ida_star;
+pub mod sma_star;
+pub mod incremental;
+pub mod lpa_star;
+pub mod d_star_lite;
+pub mod graph;
+pub mod shortest_paths;
+pub mod bellman_ford;
+pub mod spfa;
+pub mod floyd_warshall;
+pub mod johnson;
+pub mod map_file;
+pub mod maze;
+pub mod obstacles;
+
+pub use movement::{Heuristic, MovementModel, COST_SCALE};
+pub use incremental::{IncrementalPlanner, ReplanStep};
+pub use graph::{AdjacencyList, AdjacencyMatrix, CompressedSparseRow, Graph};
+pub use shortest_paths::{DistanceMatrix, NegativeCycle, ShortestPathTree, SignedGraph};
+pub use map_file::Scenario;
+pub use maze::{MazeAlgorithm, MazeGenerator};
+pub use obstacles::ObstacleGenerator;
 
 use crate::prelude::*;
+use crate::heap::{binomial_heap, d_ary_heap, fibonacci_heap, pairing_heap, radix_heap, PriorityQueue};
 use std::time::{Duration, Instant};
 use std::collections::{VecDeque, HashMap, HashSet};
 use rand::prelude::*;
@@ -16,6 +44,40 @@ pub enum CellType {
     Blocked,
     Start,
     End,
+    Road,
+    Mud,
+    Water,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TerrainCosts {
+    pub open: u32,
+    pub road: u32,
+    pub mud: u32,
+    pub water: u32,
+}
+
+impl TerrainCosts {
+    pub fn cost(&self, cell: CellType) -> Option<u32> {
+        match cell {
+            CellType::Open | CellType::Start | CellType::End => Some(self.open),
+            CellType::Road => Some(self.road),
+            CellType::Mud => Some(self.mud),
+            CellType::Water => Some(self.water),
+            CellType::Blocked => None,
+        }
+    }
+}
+
+impl Default for TerrainCosts {
+    fn default() -> Self {
+        Self {
+            open: 2,
+            road: 1,
+            mud: 5,
+            water: 10,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -49,6 +111,9 @@ pub struct Grid {
     pub cells: Vec<Vec<CellType>>,
     pub start: Position,
     pub end: Position,
+    pub costs: TerrainCosts,
+    pub movement: MovementModel,
+    pub heuristic: Heuristic,
 }
 
 impl Grid {
@@ -69,7 +134,151 @@ impl Grid {
             cells,
             start,
             end,
+            costs: TerrainCosts::default(),
+            movement: MovementModel::FourWay,
+            heuristic: MovementModel::FourWay.default_heuristic(),
+        }
+    }
+
+    pub fn with_costs(mut self, costs: TerrainCosts) -> Self {
+        self.costs = costs;
+        self
+    }
+
+    pub fn with_movement(mut self, movement: MovementModel) -> Self {
+        self.movement = movement;
+        self.heuristic = movement.default_heuristic();
+        self
+    }
+
+    pub fn with_heuristic(mut self, heuristic: Heuristic) -> Self {
+        self.heuristic = heuristic;
+        self
+    }
+
+    pub fn set_terrain(&mut self, pos: Position, terrain: CellType) {
+        if pos.row < self.height && pos.col < self.width && pos != self.start && pos != self.end {
+            self.cells[pos.row][pos.col] = terrain;
+        }
+    }
+
+    pub fn set_route(&mut self, start: Position, end: Position) {
+        for pos in [self.start, self.end] {
+            if pos.row < self.height && pos.col < self.width && matches!(self.cells[pos.row][pos.col], CellType::Start | CellType::End) {
+                self.cells[pos.row][pos.col] = CellType::Open;
+            }
+        }
+
+        self.start = start;
+        self.end = end;
+        if start.row < self.height && start.col < self.width {
+            self.cells[start.row][start.col] = CellType::Start;
+        }
+        if end.row < self.height && end.col < self.width {
+            self.cells[end.row][end.col] = CellType::End;
+        }
+    }
+
+    pub fn step_cost(&self, to: &Position) -> u32 {
+        self.costs.cost(self.cells[to.row][to.col]).unwrap_or(u32::MAX)
+    }
+
+    pub fn min_step_cost(&self) -> u32 {
+        self.cells.iter()
+            .flatten()
+            .filter_map(|&cell| self.costs.cost(cell))
+            .min()
+            .unwrap_or(1)
+            .max(1)
+    }
+
+    pub fn scaled_move_cost(&self, from: &Position, to: &Position) -> u64 {
+        let diagonal = self.movement != MovementModel::Hex && from.row != to.row && from.col != to.col;
+        let scale = if diagonal { movement::DIAGONAL_SCALE } else { COST_SCALE };
+        self.step_cost(to) as u64 * scale
+    }
+
+    pub fn move_cost(&self, from: &Position, to: &Position) -> f64 {
+        self.scaled_move_cost(from, to) as f64 / COST_SCALE as f64
+    }
+
+    pub fn scaled_heuristic(&self, from: &Position, min_step_cost: u64) -> u64 {
+        self.heuristic.scaled_distance(from, &self.end) * min_step_cost
+    }
+
+    pub fn line_of_sight(&self, from: &Position, to: &Position) -> bool {
+        self.trace_line(from, to).is_some()
+    }
+
+    pub fn scaled_segment_cost(&self, from: &Position, to: &Position) -> Option<u64> {
+        let (total_cost, cells) = self.trace_line(from, to)?;
+        if cells == 0 {
+            return Some(0);
+        }
+
+        let mean_cost = total_cost as f64 / cells as f64;
+        Some((from.distance_to(to) * mean_cost * COST_SCALE as f64).round() as u64)
+    }
+
+    fn trace_line(&self, from: &Position, to: &Position) -> Option<(u64, usize)> {
+        let blocked = |row: i64, col: i64| self.cells[row as usize][col as usize] == CellType::Blocked;
+        let (mut row, mut col) = (from.row as i64, from.col as i64);
+        let (target_row, target_col) = (to.row as i64, to.col as i64);
+        let (dr, dc) = ((target_row - row).abs(), (target_col - col).abs());
+        let (step_row, step_col) = ((target_row - row).signum(), (target_col - col).signum());
+        let mut error = dc - dr;
+        let (mut total_cost, mut cells) = (0u64, 0usize);
+
+        while (row, col) != (target_row, target_col) {
+            if error > 0 {
+                col += step_col;
+                error -= 2 * dr;
+            } else if error < 0 {
+                row += step_row;
+                error += 2 * dc;
+            } else {
+                let corners = (blocked(row + step_row, col), blocked(row, col + step_col));
+                let corner_blocked = match self.movement {
+                    MovementModel::EightWay => corners.0 && corners.1,
+                    _ => corners.0 || corners.1,
+                };
+                if corner_blocked {
+                    return None;
+                }
+                row += step_row;
+                col += step_col;
+                error += 2 * dc - 2 * dr;
+            }
+
+            if blocked(row, col) {
+                return None;
+            }
+            total_cost += self.step_cost(&Position::new(row as usize, col as usize)) as u64;
+            cells += 1;
         }
+
+        Some((total_cost, cells))
+    }
+
+    pub fn scaled_path_cost(&self, path: &[Position]) -> u64 {
+        path.windows(2)
+            .map(|pair| {
+                let adjacent = pair[0].row.abs_diff(pair[1].row) <= 1 && pair[0].col.abs_diff(pair[1].col) <= 1;
+                if adjacent {
+                    self.scaled_move_cost(&pair[0], &pair[1])
+                } else {
+                    self.scaled_segment_cost(&pair[0], &pair[1]).unwrap_or(u64::MAX)
+                }
+            })
+            .fold(0, u64::saturating_add)
+    }
+
+    pub fn path_cost(&self, path: &[Position]) -> f64 {
+        self.scaled_path_cost(path) as f64 / COST_SCALE as f64
+    }
+
+    pub fn is_weighted(&self) -> bool {
+        self.cells.iter().flatten().any(|&cell| matches!(cell, CellType::Road | CellType::Mud | CellType::Water))
     }
 
     pub fn add_obstacle(&mut self, pos: Position) {
@@ -85,23 +294,32 @@ impl Grid {
         let mut neighbors = Vec::new();
         let row = pos.row as i32;
         let col = pos.col as i32;
+        let open = |r: i32, c: i32| {
+            r >= 0 && r < self.height as i32 && c >= 0 && c < self.width as i32 &&
+            self.cells[r as usize][c as usize] != CellType::Blocked
+        };
 
-
-        let directions = [(0, 1), (0, -1), (1, 0), (-1, 0)];
-        
-        for (dr, dc) in directions {
+        for &(dr, dc) in self.movement.offsets(pos.row) {
             let new_row = row + dr;
             let new_col = col + dc;
-            
-            if new_row >= 0 && new_row < self.height as i32 &&
-               new_col >= 0 && new_col < self.width as i32 {
-                let new_pos = Position::new(new_row as usize, new_col as usize);
-                
 
-                if self.cells[new_pos.row][new_pos.col] != CellType::Blocked {
-                    neighbors.push(new_pos);
+            if !open(new_row, new_col) {
+                continue;
+            }
+
+            if dr != 0 && dc != 0 {
+                let corners_open = (open(row + dr, col), open(row, col + dc));
+                let allowed = match self.movement {
+                    MovementModel::EightWay => corners_open.0 || corners_open.1,
+                    MovementModel::EightWayNoCornerCutting => corners_open.0 && corners_open.1,
+                    MovementModel::FourWay | MovementModel::Hex => true,
+                };
+                if !allowed {
+                    continue;
                 }
             }
+
+            neighbors.push(Position::new(new_row as usize, new_col as usize));
         }
 
         neighbors
@@ -118,21 +336,170 @@ pub struct PathfindingMetrics {
     pub algorithm_name: String,
     pub path_found: bool,
     pub path_length: usize,
+    pub path_cost: f64,
     pub nodes_explored: usize,
     pub nodes_in_frontier: usize,
     pub duration: Duration,
     pub theoretical_complexity: String,
+    pub grid_name: String,
     pub grid_size: (usize, usize),
     pub obstacle_count: usize,
     pub path: Vec<Position>,
 }
 
+#[derive(Debug, Clone)]
+pub struct MovementMetrics {
+    pub movement: MovementModel,
+    pub heuristic: Heuristic,
+    pub algorithm_name: String,
+    pub path_length: usize,
+    pub path_cost: f64,
+    pub nodes_explored: usize,
+    pub duration: Duration,
+    pub optimal: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct JumpPointMetrics {
+    pub grid_name: String,
+    pub algorithm_name: String,
+    pub path_cost: f64,
+    pub nodes_expanded: usize,
+    pub cells_scanned: usize,
+    pub search_duration: Duration,
+    pub precompute_duration: Duration,
+    pub precompute_cells: usize,
+    pub optimal: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct BidirectionalMetrics {
+    pub grid_name: String,
+    pub algorithm_name: String,
+    pub path_length: usize,
+    pub path_cost: f64,
+    pub nodes_explored: usize,
+    pub forward_explored: usize,
+    pub backward_explored: usize,
+    pub duration: Duration,
+    pub optimal: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct MemoryBoundedMetrics {
+    pub grid_name: String,
+    pub algorithm_name: String,
+    pub path_cost: f64,
+    pub nodes_expanded: usize,
+    pub peak_nodes_stored: usize,
+    pub duration: Duration,
+    pub optimal: bool,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ReplanningMetrics {
+    pub grid_name: String,
+    pub scenario: &'static str,
+    pub algorithm_name: String,
+    pub replans: usize,
+    pub nodes_expanded: usize,
+    pub duration: Duration,
+    pub final_path_cost: f64,
+    pub matches_astar: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct GraphMetrics {
+    pub graph_name: String,
+    pub representation: String,
+    pub algorithm_name: String,
+    pub path_cost: f64,
+    pub nodes_explored: usize,
+    pub duration: Duration,
+    pub optimal: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct AllPairsMetrics {
+    pub graph_name: String,
+    pub algorithm_name: String,
+    pub nodes: usize,
+    pub edges: usize,
+    pub relaxations: usize,
+    pub duration: Duration,
+    pub matches_reference: bool,
+    pub negative_cycle: Option<NegativeCycle>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ScalingMetrics {
+    pub grid_size: (usize, usize),
+    pub obstacles: usize,
+    pub target_obstacles: usize,
+    pub generation_duration: Duration,
+    pub astar_duration: Duration,
+    pub dijkstra_duration: Duration,
+    pub bidirectional_duration: Duration,
+    pub astar_explored: usize,
+    pub path_found: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct MazeMetrics {
+    pub generator_name: String,
+    pub generation_duration: Duration,
+    pub open_cells: usize,
+    pub dead_ends: usize,
+    pub path_length: usize,
+    pub astar_explored: usize,
+    pub breadth_first_explored: usize,
+    pub greedy_path_length: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct MapMetrics {
+    pub algorithm_name: String,
+    pub scenarios: usize,
+    pub verified: usize,
+    pub nodes_explored: usize,
+    pub duration: Duration,
+    pub error: Option<String>,
+}
+
+type AllPairsSearch = fn(&SignedGraph) -> std::result::Result<(DistanceMatrix, PerformanceCounter), NegativeCycle>;
+
+type GraphSearch<G> = fn(&G, <G as Graph>::Node, <G as Graph>::Node) -> std::result::Result<(Vec<<G as Graph>::Node>, PerformanceCounter), String>;
+
+type PathSearch = fn(&Grid) -> std::result::Result<(Vec<Position>, PerformanceCounter), String>;
+
+type MapSearch<'a> = &'a dyn Fn(&Grid) -> std::result::Result<(Vec<Position>, PerformanceCounter), String>;
+
+#[derive(Debug, Clone)]
+pub struct QueueMetrics {
+    pub queue_name: String,
+    pub dijkstra_duration: Duration,
+    pub astar_duration: Duration,
+    pub dijkstra_explored: usize,
+    pub astar_explored: usize,
+    pub paths_optimal: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchDirection {
+    Forward,
+    Backward,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct PerformanceCounter {
     pub nodes_explored: usize,
     pub nodes_in_frontier: usize,
     pub comparisons: usize,
     pub memory_allocations: usize,
+    pub forward_explored: usize,
+    pub backward_explored: usize,
+    pub peak_nodes_stored: usize,
 }
 
 impl PerformanceCounter {
@@ -143,6 +510,14 @@ impl PerformanceCounter {
     pub fn explore_node(&mut self) {
         self.nodes_explored += 1;
     }
+
+    pub fn explore_node_from(&mut self, direction: SearchDirection) {
+        self.nodes_explored += 1;
+        match direction {
+            SearchDirection::Forward => self.forward_explored += 1,
+            SearchDirection::Backward => self.backward_explored += 1,
+        }
+    }
     
     pub fn add_to_frontier(&mut self) {
         self.nodes_in_frontier += 1;
@@ -155,19 +530,108 @@ impl PerformanceCounter {
     pub fn allocate_memory(&mut self, _size: usize) {
         self.memory_allocations += 1;
     }
+
+    pub fn store_nodes(&mut self, count: usize) {
+        self.peak_nodes_stored = self.peak_nodes_stored.max(count);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BenchmarkSuite {
+    Movement,
+    Maze,
+    JumpPoint,
+    Bidirectional,
+    MemoryBounded,
+    Replanning,
+    Graph,
+    Queue,
+}
+
+impl BenchmarkSuite {
+    pub const ALL: [BenchmarkSuite; 8] = [
+        BenchmarkSuite::Movement,
+        BenchmarkSuite::Maze,
+        BenchmarkSuite::JumpPoint,
+        BenchmarkSuite::Bidirectional,
+        BenchmarkSuite::MemoryBounded,
+        BenchmarkSuite::Replanning,
+        BenchmarkSuite::Graph,
+        BenchmarkSuite::Queue,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BenchmarkSuite::Movement => "movement",
+            BenchmarkSuite::Maze => "maze",
+            BenchmarkSuite::JumpPoint => "jump-point",
+            BenchmarkSuite::Bidirectional => "bidirectional",
+            BenchmarkSuite::MemoryBounded => "memory-bounded",
+            BenchmarkSuite::Replanning => "replanning",
+            BenchmarkSuite::Graph => "graph",
+            BenchmarkSuite::Queue => "queue",
+        }
+    }
+}
+
+impl std::str::FromStr for BenchmarkSuite {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "movement" | "heuristics" => Ok(BenchmarkSuite::Movement),
+            "maze" | "mazes" => Ok(BenchmarkSuite::Maze),
+            "jump-point" | "jps" => Ok(BenchmarkSuite::JumpPoint),
+            "bidirectional" => Ok(BenchmarkSuite::Bidirectional),
+            "memory-bounded" | "memory" => Ok(BenchmarkSuite::MemoryBounded),
+            "replanning" | "incremental" => Ok(BenchmarkSuite::Replanning),
+            "graph" | "graphs" => Ok(BenchmarkSuite::Graph),
+            "queue" | "queues" => Ok(BenchmarkSuite::Queue),
+            _ => Err(Error::validation(format!(
+                "Unknown benchmark suite '{}' (expected movement, maze, jump-point, bidirectional, memory-bounded, replanning, graph, queue or all)",
+                s
+            ))),
+        }
+    }
 }
 
 pub struct PathfinderCoordinator {
-    grids: Vec<Grid>,
+    grids: Vec<(&'static str, Grid)>,
+    movement: MovementModel,
+    maze: MazeGenerator,
+    seed: Option<u64>,
 }
 
 impl PathfinderCoordinator {
     pub fn new() -> Self {
         Self {
             grids: Vec::new(),
+            movement: MovementModel::FourWay,
+            maze: MazeGenerator::default(),
+            seed: None,
         }
     }
 
+    pub fn set_seed(&mut self, seed: Option<u64>) {
+        self.seed = seed;
+    }
+
+    pub fn set_maze(&mut self, maze: MazeGenerator) {
+        self.maze = maze;
+    }
+
+    pub fn maze(&self) -> MazeGenerator {
+        self.maze
+    }
+
+    pub fn set_movement(&mut self, movement: MovementModel) {
+        self.movement = movement;
+    }
+
+    pub fn movement(&self) -> MovementModel {
+        self.movement
+    }
+
     pub fn generate_test_grids(&mut self, grid_size: (usize, usize), obstacle_percentage: f64) -> Result<()> {
         let (width, height) = grid_size;
         
@@ -175,9 +639,15 @@ impl PathfinderCoordinator {
         self.grids.clear();
         
 
-        self.grids.push(self.create_empty_grid(width, height)?);
-        self.grids.push(self.create_random_obstacles_grid(width, height, obstacle_percentage)?);
-        self.grids.push(self.create_maze_like_grid(width, height)?);
+        self.grids.push(("Empty", self.create_empty_grid(width, height)?));
+        self.grids.push(("Random Obstacles", self.create_random_obstacles_grid(width, height, obstacle_percentage)?));
+        self.grids.push(("Maze", self.maze.generate(width, height)));
+        self.grids.push(("Weighted Terrain", self.create_terrain_grid(width, height)?));
+
+        for (_, grid) in self.grids.iter_mut() {
+            grid.movement = self.movement;
+            grid.heuristic = self.movement.default_heuristic();
+        }
         
         Ok(())
     }
@@ -189,74 +659,40 @@ impl PathfinderCoordinator {
     }
 
     fn create_random_obstacles_grid(&self, width: usize, height: usize, obstacle_percentage: f64) -> Result<Grid> {
+        ObstacleGenerator::new(obstacle_percentage).with_seed(self.seed).generate(width, height)
+    }
+
+    fn create_terrain_grid(&self, width: usize, height: usize) -> Result<Grid> {
         let start = Position::new(0, 0);
         let end = Position::new(height.saturating_sub(1), width.saturating_sub(1));
         let mut grid = Grid::new(width, height, start, end);
-        
 
         if width < 3 || height < 3 {
             return Ok(grid);
         }
-        
-        let mut rng = rand::rng();
-        let total_cells = width * height;
-        let obstacle_count = (total_cells as f64 * obstacle_percentage) as usize;
-        
 
-        let protected_positions = self.get_protected_positions(&grid);
-        
-        let mut obstacles_placed = 0;
-        let mut attempts = 0;
-        let max_attempts = total_cells * 3;
-        
-        while obstacles_placed < obstacle_count && attempts < max_attempts {
-            attempts += 1;
-            
-            let row = rng.random_range(0..height);
-            let col = rng.random_range(0..width);
-            let pos = Position::new(row, col);
-            
-
-            if protected_positions.contains(&pos) || grid.cells[row][col] != CellType::Open {
-                continue;
-            }
-            
-
-            grid.add_obstacle(pos);
-            
+        let mut rng = rand::rng();
 
-            if self.is_grid_connected(&grid) {
-                obstacles_placed += 1;
-            } else {
-                grid.cells[row][col] = CellType::Open;
+        for row in 0..height {
+            for col in 0..width {
+                let terrain = match rng.random_range(0..100) {
+                    0..35 => CellType::Mud,
+                    35..55 => CellType::Water,
+                    _ => CellType::Open,
+                };
+                grid.set_terrain(Position::new(row, col), terrain);
             }
         }
-        
 
-        if !self.is_grid_connected(&grid) {
-            return self.create_simple_connected_grid(width, height, obstacle_percentage);
+        let highway_row = height / 3;
+        let highway_col = width * 2 / 3;
+        for col in 0..width {
+            grid.set_terrain(Position::new(highway_row, col), CellType::Road);
         }
-        
-        Ok(grid)
-    }
-
-    fn create_maze_like_grid(&self, width: usize, height: usize) -> Result<Grid> {
-        let start = Position::new(0, 0);
-        let end = Position::new(height.saturating_sub(1), width.saturating_sub(1));
-        let mut grid = Grid::new(width, height, start, end);
-        
-
-        for row in 1..height-1 {
-            for col in 1..width-1 {
-                if row % 2 == 0 && col % 2 == 0 {
-                    let pos = Position::new(row, col);
-                    if pos != start && pos != end {
-                        grid.add_obstacle(pos);
-                    }
-                }
-            }
+        for row in 0..height {
+            grid.set_terrain(Position::new(row, highway_col), CellType::Road);
         }
-        
+
         Ok(grid)
     }
 
@@ -268,6 +704,7 @@ impl PathfinderCoordinator {
         println!("Running pathfinding benchmarks...");
         println!("Grid size: {}x{}", grid_size.0, grid_size.1);
         println!("Iterations per algorithm: {}", iterations);
+        println!("Movement model: {}", self.movement.as_str());
         println!();
 
 
@@ -276,7 +713,9 @@ impl PathfinderCoordinator {
             "Dijkstra",
             "Breadth-First Search",
             "Depth-First Search", 
-            "Greedy Best-First"
+            "Greedy Best-First",
+            "Theta*",
+            "Lazy Theta*",
         ];
 
         for algorithm in algorithms {
@@ -288,125 +727,1378 @@ impl PathfinderCoordinator {
         Ok(all_metrics)
     }
 
-    fn get_protected_positions(&self, grid: &Grid) -> HashSet<Position> {
-        let mut protected = HashSet::new();
-        
-
-        protected.insert(grid.start);
-        for neighbor in self.get_eight_directional_neighbors(grid.start, grid.width, grid.height) {
-            protected.insert(neighbor);
-        }
-        
-
-        protected.insert(grid.end);
-        for neighbor in self.get_eight_directional_neighbors(grid.end, grid.width, grid.height) {
-            protected.insert(neighbor);
+    pub fn run_suite(&self, suite: BenchmarkSuite, grid_size: (usize, usize), iterations: usize) -> Result<()> {
+        match suite {
+            BenchmarkSuite::Movement => self.run_movement_benchmarks(iterations).map(drop),
+            BenchmarkSuite::Maze => self.run_maze_benchmarks(grid_size, iterations).map(drop),
+            BenchmarkSuite::JumpPoint => self.run_jump_point_benchmarks(iterations).map(drop),
+            BenchmarkSuite::Bidirectional => self.run_bidirectional_benchmarks(iterations).map(drop),
+            BenchmarkSuite::MemoryBounded => self.run_memory_bounded_benchmarks(iterations).map(drop),
+            BenchmarkSuite::Replanning => self.run_replanning_benchmarks(iterations).map(drop),
+            BenchmarkSuite::Graph => self.run_graph_benchmarks(iterations).map(drop),
+            BenchmarkSuite::Queue => self.run_queue_benchmarks(iterations).map(drop),
         }
-        
-        protected
     }
 
-    fn get_eight_directional_neighbors(&self, pos: Position, width: usize, height: usize) -> Vec<Position> {
-        let mut neighbors = Vec::new();
-        let row = pos.row as i32;
-        let col = pos.col as i32;
-        
-
-        let directions = [
-            (-1, -1), (-1, 0), (-1, 1),
-            ( 0, -1),          ( 0, 1),
-            ( 1, -1), ( 1, 0), ( 1, 1)
-        ];
-        
-        for (dr, dc) in directions {
-            let new_row = row + dr;
-            let new_col = col + dc;
-            
-            if new_row >= 0 && new_row < height as i32 && 
-               new_col >= 0 && new_col < width as i32 {
-                neighbors.push(Position::new(new_row as usize, new_col as usize));
-            }
-        }
-        
-        neighbors
-    }
+    pub fn run_movement_benchmarks(&self, iterations: usize) -> Result<Vec<MovementMetrics>> {
+        let Some((_, base)) = self.grids.iter().find(|(name, _)| *name == "Random Obstacles") else {
+            return Err(Error::Generic("No grids generated. Generate test grids first.".to_string()));
+        };
 
-    fn is_grid_connected(&self, grid: &Grid) -> bool {
+        let iterations = iterations.max(1);
+        println!("Comparing movement models and heuristics on the random obstacles grid ({} iterations)", iterations);
 
-        let mut visited = HashSet::new();
-        let mut queue = VecDeque::new();
-        
-        queue.push_back(grid.start);
-        visited.insert(grid.start);
-        
-        while let Some(current) = queue.pop_front() {
-            if current == grid.end {
-                return true;
+        let mut results = Vec::new();
+        for movement in MovementModel::ALL {
+            let grid = base.clone().with_movement(movement);
+            let optimal = dijkstra::find_path(&grid).map(|(path, _)| grid.scaled_path_cost(&path)).unwrap_or(0);
+
+            let heuristics: &[Heuristic] = match movement {
+                MovementModel::FourWay => &[Heuristic::Manhattan, Heuristic::Euclidean],
+                MovementModel::Hex => &[Heuristic::Hex],
+                _ => &[Heuristic::Manhattan, Heuristic::Octile, Heuristic::Chebyshev, Heuristic::Euclidean],
+            };
+
+            for &heuristic in heuristics {
+                let grid = grid.clone().with_heuristic(heuristic);
+                results.push(self.benchmark_movement("A*", &grid, optimal, iterations, astar::find_path));
             }
-            
 
-            for neighbor in grid.get_neighbors(&current) {
-                if !visited.contains(&neighbor) {
-                    visited.insert(neighbor);
-                    queue.push_back(neighbor);
-                }
+            if movement != MovementModel::Hex {
+                results.push(self.benchmark_movement("Theta*", &grid, optimal, iterations, theta_star::find_path));
+                results.push(self.benchmark_movement("Lazy Theta*", &grid, optimal, iterations, lazy_theta_star::find_path));
             }
         }
-        
-        false
+
+        self.display_movement_results(&results);
+        Ok(results)
     }
 
-    fn create_simple_connected_grid(&self, width: usize, height: usize, obstacle_percentage: f64) -> Result<Grid> {
-        let start = Position::new(0, 0);
-        let end = Position::new(height.saturating_sub(1), width.saturating_sub(1));
-        let mut grid = Grid::new(width, height, start, end);
-        
+    fn benchmark_movement<F>(&self, name: &str, grid: &Grid, optimal: u64, iterations: usize, find_path: F) -> MovementMetrics
+    where
+        F: Fn(&Grid) -> std::result::Result<(Vec<Position>, PerformanceCounter), String>,
+    {
+        let mut duration = Duration::default();
+        let mut last_result = None;
+
+        for _ in 0..iterations {
+            let start = Instant::now();
+            let result = find_path(grid);
+            duration += start.elapsed();
+            last_result = result.ok();
+        }
 
-        let mut current = start;
-        let mut guaranteed_path = HashSet::new();
-        
+        let (path, counter) = last_result.unwrap_or_default();
+        MovementMetrics {
+            movement: grid.movement,
+            heuristic: grid.heuristic,
+            algorithm_name: name.to_string(),
+            path_length: path.len(),
+            path_cost: grid.path_cost(&path),
+            nodes_explored: counter.nodes_explored,
+            duration: duration / iterations as u32,
+            optimal: !path.is_empty() && grid.scaled_path_cost(&path) <= optimal,
+        }
+    }
 
-        while current != end {
-            guaranteed_path.insert(current);
-            
-            if current.col < end.col {
-                current = Position::new(current.row, current.col + 1);
-            } else if current.row < end.row {
-                current = Position::new(current.row + 1, current.col);
-            } else {
-                break;
-            }
+    fn display_movement_results(&self, results: &[MovementMetrics]) {
+        let mut table = Table::new();
+        table.add_row(Row::new(vec![
+            Cell::new("Movement"),
+            Cell::new("Algorithm"),
+            Cell::new("Heuristic"),
+            Cell::new("Path Length"),
+            Cell::new("Path Cost"),
+            Cell::new("Nodes Explored"),
+            Cell::new("Time (μs)"),
+            Cell::new("Grid Optimal"),
+        ]));
+
+        for metric in results {
+            let heuristic = if metric.algorithm_name == "A*" { metric.heuristic.as_str() } else { Heuristic::Euclidean.as_str() };
+            table.add_row(Row::new(vec![
+                Cell::new(metric.movement.as_str()),
+                Cell::new(&metric.algorithm_name),
+                Cell::new(heuristic),
+                Cell::new(&metric.path_length.to_string()),
+                Cell::new(&format!("{:.1}", metric.path_cost)),
+                Cell::new(&metric.nodes_explored.to_string()),
+                Cell::new(&format!("{:.2}", metric.duration.as_micros())),
+                Cell::new(&metric.optimal.to_string()),
+            ]));
         }
-        guaranteed_path.insert(end);
-        
 
-        let mut rng = rand::rng();
-        let total_cells = width * height;
-        let obstacle_count = ((total_cells - guaranteed_path.len()) as f64 * obstacle_percentage * 0.5) as usize;
-        
-        let mut obstacles_placed = 0;
-        while obstacles_placed < obstacle_count {
-            let row = rng.random_range(0..height);
-            let col = rng.random_range(0..width);
-            let pos = Position::new(row, col);
-            
+        table.printstd();
 
-            if !guaranteed_path.contains(&pos) && 
-               !self.get_eight_directional_neighbors(pos, width, height).iter().any(|&p| guaranteed_path.contains(&p)) &&
-               grid.cells[row][col] == CellType::Open {
-                grid.add_obstacle(pos);
-                obstacles_placed += 1;
-            }
+        if let Some(cheapest) = results.iter().filter(|m| m.path_length > 0).min_by(|a, b| a.path_cost.total_cmp(&b.path_cost)) {
+            println!("🏆 Cheapest Path: {} with {} ({:.1})", cheapest.algorithm_name, cheapest.movement.as_str(), cheapest.path_cost);
         }
-        
-        Ok(grid)
+
+        for metric in results.iter().filter(|m| m.algorithm_name == "A*" && !m.optimal) {
+            println!("⚠️  {} heuristic is inadmissible for {} movement", metric.heuristic.as_str(), metric.movement.as_str());
+        }
+        println!();
     }
 
-    fn benchmark_algorithm(&self, algorithm_name: &str, iterations: usize) -> Result<Vec<PathfindingMetrics>> {
-        let mut results = Vec::new();
+    pub fn run_scaling_benchmarks(&self, obstacle_percentage: f64) -> Result<Vec<ScalingMetrics>> {
+        const SIZES: [usize; 4] = [100, 250, 500, 1000];
 
-        for grid in &self.grids {
-            let mut total_duration = Duration::default();
+        let generator = ObstacleGenerator::new(obstacle_percentage).with_seed(self.seed);
+        println!("Scaling connected obstacle grids up to {}x{} ({:.0}% obstacles, {})", SIZES[3], SIZES[3], generator.density * 100.0, self.movement.as_str());
+
+        let mut results = Vec::new();
+        for size in SIZES {
+            let timer = Instant::now();
+            let mut grid = generator.generate(size, size)?;
+            let generation_duration = timer.elapsed();
+
+            grid.movement = self.movement;
+            grid.heuristic = self.movement.default_heuristic();
+
+            let timer = Instant::now();
+            let (path, astar_counter) = astar::find_path(&grid).unwrap_or_default();
+            let astar_duration = timer.elapsed();
+
+            let timer = Instant::now();
+            let _ = dijkstra::find_path(&grid);
+            let dijkstra_duration = timer.elapsed();
+
+            let timer = Instant::now();
+            let _ = bidirectional::dijkstra(&grid);
+            let bidirectional_duration = timer.elapsed();
+
+            results.push(ScalingMetrics {
+                grid_size: (size, size),
+                obstacles: grid.cells.iter().flatten().filter(|cell| **cell == CellType::Blocked).count(),
+                target_obstacles: generator.target_obstacles(size, size),
+                generation_duration,
+                astar_duration,
+                dijkstra_duration,
+                bidirectional_duration,
+                astar_explored: astar_counter.nodes_explored,
+                path_found: !path.is_empty(),
+            });
+        }
+
+        self.display_scaling_results(&results);
+        Ok(results)
+    }
+
+    fn display_scaling_results(&self, results: &[ScalingMetrics]) {
+        let mut table = Table::new();
+        table.add_row(Row::new(vec![
+            Cell::new("Grid"),
+            Cell::new("Cells"),
+            Cell::new("Obstacles"),
+            Cell::new("Generation (ms)"),
+            Cell::new("A* (ms)"),
+            Cell::new("Dijkstra (ms)"),
+            Cell::new("Bidirectional Dijkstra (ms)"),
+            Cell::new("A* Explored"),
+            Cell::new("Path Found"),
+        ]));
+
+        for metric in results {
+            let (width, height) = metric.grid_size;
+            table.add_row(Row::new(vec![
+                Cell::new(&format!("{}x{}", width, height)),
+                Cell::new(&(width * height).to_string()),
+                Cell::new(&format!("{}/{}", metric.obstacles, metric.target_obstacles)),
+                Cell::new(&format!("{:.2}", metric.generation_duration.as_secs_f64() * 1000.0)),
+                Cell::new(&format!("{:.2}", metric.astar_duration.as_secs_f64() * 1000.0)),
+                Cell::new(&format!("{:.2}", metric.dijkstra_duration.as_secs_f64() * 1000.0)),
+                Cell::new(&format!("{:.2}", metric.bidirectional_duration.as_secs_f64() * 1000.0)),
+                Cell::new(&metric.astar_explored.to_string()),
+                Cell::new(&metric.path_found.to_string()),
+            ]));
+        }
+
+        table.printstd();
+
+        if let [first, .., last] = results {
+            let cells = |metric: &ScalingMetrics| (metric.grid_size.0 * metric.grid_size.1) as f64;
+            println!(
+                "📏 {:.0}x more cells took {:.1}x longer to generate",
+                cells(last) / cells(first),
+                last.generation_duration.as_secs_f64() / first.generation_duration.as_secs_f64().max(1e-9)
+            );
+        }
+        for metric in results.iter().filter(|m| m.obstacles < m.target_obstacles) {
+            println!("⚠️  {}x{} placed {} of {} obstacles without disconnecting the grid", metric.grid_size.0, metric.grid_size.1, metric.obstacles, metric.target_obstacles);
+        }
+        println!();
+    }
+
+    pub fn run_maze_benchmarks(&self, grid_size: (usize, usize), iterations: usize) -> Result<Vec<MazeMetrics>> {
+        let (width, height) = grid_size;
+        if width < 3 || height < 3 {
+            return Err(Error::validation("Maze benchmarks need a grid of at least 3x3"));
+        }
+
+        let iterations = iterations.max(1);
+        println!("Comparing maze generators on {}x{} grids (braid {:.2}, {} iterations)", width, height, self.maze.braid, iterations);
+
+        let mut results = Vec::new();
+        for algorithm in MazeAlgorithm::ALL {
+            let generator = MazeGenerator { algorithm, ..self.maze };
+
+            let start = Instant::now();
+            let mut grid = generator.generate(width, height);
+            for _ in 1..iterations {
+                grid = generator.generate(width, height);
+            }
+            let generation_duration = start.elapsed() / iterations as u32;
+
+            grid.movement = self.movement;
+            grid.heuristic = self.movement.default_heuristic();
+
+            let (path, astar_counter) = astar::find_path(&grid).unwrap_or_default();
+            let (_, breadth_first_counter) = breadth_first::find_path(&grid).unwrap_or_default();
+            let (greedy_path, _) = greedy_best_first::find_path(&grid).unwrap_or_default();
+
+            results.push(MazeMetrics {
+                generator_name: algorithm.as_str().to_string(),
+                generation_duration,
+                open_cells: grid.cells.iter().flatten().filter(|cell| **cell != CellType::Blocked).count(),
+                dead_ends: maze::count_dead_ends(&grid),
+                path_length: path.len(),
+                astar_explored: astar_counter.nodes_explored,
+                breadth_first_explored: breadth_first_counter.nodes_explored,
+                greedy_path_length: greedy_path.len(),
+            });
+        }
+
+        self.display_maze_results(&results);
+        Ok(results)
+    }
+
+    fn display_maze_results(&self, results: &[MazeMetrics]) {
+        let mut table = Table::new();
+        table.add_row(Row::new(vec![
+            Cell::new("Generator"),
+            Cell::new("Generation (μs)"),
+            Cell::new("Open Cells"),
+            Cell::new("Dead Ends"),
+            Cell::new("Path Length"),
+            Cell::new("A* Explored"),
+            Cell::new("BFS Explored"),
+            Cell::new("Greedy Path Length"),
+        ]));
+
+        for metric in results {
+            table.add_row(Row::new(vec![
+                Cell::new(&metric.generator_name),
+                Cell::new(&format!("{:.2}", metric.generation_duration.as_micros())),
+                Cell::new(&metric.open_cells.to_string()),
+                Cell::new(&metric.dead_ends.to_string()),
+                Cell::new(&metric.path_length.to_string()),
+                Cell::new(&metric.astar_explored.to_string()),
+                Cell::new(&metric.breadth_first_explored.to_string()),
+                Cell::new(&metric.greedy_path_length.to_string()),
+            ]));
+        }
+
+        table.printstd();
+
+        if let Some(fastest) = results.iter().min_by_key(|m| m.generation_duration) {
+            println!("🏆 Fastest generator: {} ({}μs)", fastest.generator_name, fastest.generation_duration.as_micros());
+        }
+        if let Some(longest) = results.iter().max_by_key(|m| m.path_length) {
+            println!("📏 Longest solution: {} ({} cells)", longest.generator_name, longest.path_length);
+        }
+        if let Some(hardest) = results.iter().max_by_key(|m| m.astar_explored) {
+            println!("🎯 Most A* expansions: {} ({} nodes)", hardest.generator_name, hardest.astar_explored);
+        }
+        for metric in results.iter().filter(|m| m.greedy_path_length > m.path_length) {
+            println!("⚠️  Greedy best-first detours on {}: {} cells against {}", metric.generator_name, metric.greedy_path_length, metric.path_length);
+        }
+        println!();
+    }
+
+    pub fn run_jump_point_benchmarks(&self, iterations: usize) -> Result<Vec<JumpPointMetrics>> {
+        if self.grids.is_empty() {
+            return Err(Error::Generic("No grids generated. Generate test grids first.".to_string()));
+        }
+
+        let iterations = iterations.max(1);
+        println!("Comparing Jump Point Search with A* on uniform-cost 8-way grids ({} iterations)", iterations);
+
+        let mut results = Vec::new();
+        for (grid_name, grid) in self.grids.iter().filter(|(_, grid)| !grid.is_weighted()) {
+            let grid = grid.clone().with_movement(MovementModel::EightWayNoCornerCutting);
+            let optimal = dijkstra::find_path(&grid).map(|(path, _)| grid.scaled_path_cost(&path)).unwrap_or(0);
+
+            let start = Instant::now();
+            let mut table = jps::JumpTable::build(&grid);
+            for _ in 1..iterations {
+                table = jps::JumpTable::build(&grid);
+            }
+            let precompute_duration = start.elapsed() / iterations as u32;
+
+            let mut jps_plus = self.benchmark_jump_point(grid_name, "JPS+", &grid, optimal, iterations, |grid| {
+                jps::find_path_with_table(grid, &table)
+            });
+            jps_plus.precompute_duration = precompute_duration;
+            jps_plus.precompute_cells = table.cells_scanned;
+
+            results.push(self.benchmark_jump_point(grid_name, "A* (octile)", &grid, optimal, iterations, astar::find_path));
+            results.push(self.benchmark_jump_point(grid_name, "JPS", &grid, optimal, iterations, jps::find_path));
+            results.push(jps_plus);
+        }
+
+        self.display_jump_point_results(&results);
+        Ok(results)
+    }
+
+    fn benchmark_jump_point<F>(&self, grid_name: &str, algorithm_name: &str, grid: &Grid, optimal: u64, iterations: usize, find_path: F) -> JumpPointMetrics
+    where
+        F: Fn(&Grid) -> std::result::Result<(Vec<Position>, PerformanceCounter), String>,
+    {
+        let mut search_duration = Duration::default();
+        let mut last_result = None;
+
+        for _ in 0..iterations {
+            let start = Instant::now();
+            let result = find_path(grid);
+            search_duration += start.elapsed();
+            last_result = result.ok();
+        }
+
+        let (path, counter) = last_result.unwrap_or_default();
+        JumpPointMetrics {
+            grid_name: grid_name.to_string(),
+            algorithm_name: algorithm_name.to_string(),
+            path_cost: grid.path_cost(&path),
+            nodes_expanded: counter.nodes_explored,
+            cells_scanned: counter.comparisons,
+            search_duration: search_duration / iterations as u32,
+            precompute_duration: Duration::default(),
+            precompute_cells: 0,
+            optimal: !path.is_empty() && grid.scaled_path_cost(&path) == optimal,
+        }
+    }
+
+    fn display_jump_point_results(&self, results: &[JumpPointMetrics]) {
+        let mut table = Table::new();
+        table.add_row(Row::new(vec![
+            Cell::new("Grid"),
+            Cell::new("Algorithm"),
+            Cell::new("Path Cost"),
+            Cell::new("Nodes Expanded"),
+            Cell::new("Cells Scanned"),
+            Cell::new("Search (μs)"),
+            Cell::new("Precompute (μs)"),
+            Cell::new("Precompute Cells"),
+            Cell::new("Optimal"),
+        ]));
+
+        for metric in results {
+            table.add_row(Row::new(vec![
+                Cell::new(&metric.grid_name),
+                Cell::new(&metric.algorithm_name),
+                Cell::new(&format!("{:.1}", metric.path_cost)),
+                Cell::new(&metric.nodes_expanded.to_string()),
+                Cell::new(&metric.cells_scanned.to_string()),
+                Cell::new(&format!("{:.2}", metric.search_duration.as_micros())),
+                Cell::new(&format!("{:.2}", metric.precompute_duration.as_micros())),
+                Cell::new(&metric.precompute_cells.to_string()),
+                Cell::new(&metric.optimal.to_string()),
+            ]));
+        }
+
+        table.printstd();
+
+        for chunk in results.chunks(3) {
+            if let [astar, jps, jps_plus] = chunk {
+                println!(
+                    "🎯 {}: JPS expands {:.1}x fewer nodes than A*, JPS+ searches {:.1}x faster than JPS",
+                    astar.grid_name,
+                    astar.nodes_expanded as f64 / jps.nodes_expanded.max(1) as f64,
+                    jps.search_duration.as_secs_f64() / jps_plus.search_duration.as_secs_f64().max(1e-9)
+                );
+            }
+        }
+
+        for metric in results.iter().filter(|m| !m.optimal) {
+            println!("⚠️  {} returned a non-optimal path on {}", metric.algorithm_name, metric.grid_name);
+        }
+        println!();
+    }
+
+    pub fn run_bidirectional_benchmarks(&self, iterations: usize) -> Result<Vec<BidirectionalMetrics>> {
+        if self.grids.is_empty() {
+            return Err(Error::Generic("No grids generated. Generate test grids first.".to_string()));
+        }
+
+        let iterations = iterations.max(1);
+        println!("Comparing bidirectional searches with their one-sided counterparts ({} iterations)", iterations);
+
+        let searches: [(&str, PathSearch); 6] = [
+            ("Breadth-First Search", breadth_first::find_path),
+            ("Bidirectional BFS", bidirectional::breadth_first),
+            ("Dijkstra", dijkstra::find_path),
+            ("Bidirectional Dijkstra", bidirectional::dijkstra),
+            ("A*", astar::find_path),
+            ("NBA*", bidirectional::astar),
+        ];
+
+        let mut results = Vec::new();
+        for (grid_name, grid) in &self.grids {
+            let shortest_hops = breadth_first::find_path(grid).map(|(path, _)| path.len()).unwrap_or(0);
+            let optimal_cost = dijkstra::find_path(grid).map(|(path, _)| grid.scaled_path_cost(&path)).unwrap_or(0);
+
+            for (index, (algorithm_name, search)) in searches.into_iter().enumerate() {
+                results.push(self.benchmark_bidirectional(grid_name, algorithm_name, grid, iterations, search, |path| {
+                    if index < 2 { path.len() == shortest_hops } else { grid.scaled_path_cost(path) == optimal_cost }
+                }));
+            }
+        }
+
+        self.display_bidirectional_results(&results);
+        Ok(results)
+    }
+
+    fn benchmark_bidirectional<F, O>(&self, grid_name: &str, algorithm_name: &str, grid: &Grid, iterations: usize, find_path: F, is_optimal: O) -> BidirectionalMetrics
+    where
+        F: Fn(&Grid) -> std::result::Result<(Vec<Position>, PerformanceCounter), String>,
+        O: Fn(&[Position]) -> bool,
+    {
+        let mut duration = Duration::default();
+        let mut last_result = None;
+
+        for _ in 0..iterations {
+            let start = Instant::now();
+            let result = find_path(grid);
+            duration += start.elapsed();
+            last_result = result.ok();
+        }
+
+        let (path, counter) = last_result.unwrap_or_default();
+        BidirectionalMetrics {
+            grid_name: grid_name.to_string(),
+            algorithm_name: algorithm_name.to_string(),
+            path_length: path.len(),
+            path_cost: grid.path_cost(&path),
+            nodes_explored: counter.nodes_explored,
+            forward_explored: counter.forward_explored,
+            backward_explored: counter.backward_explored,
+            duration: duration / iterations as u32,
+            optimal: !path.is_empty() && is_optimal(&path),
+        }
+    }
+
+    fn display_bidirectional_results(&self, results: &[BidirectionalMetrics]) {
+        let mut table = Table::new();
+        table.add_row(Row::new(vec![
+            Cell::new("Grid"),
+            Cell::new("Algorithm"),
+            Cell::new("Path Length"),
+            Cell::new("Path Cost"),
+            Cell::new("Nodes Explored"),
+            Cell::new("Forward"),
+            Cell::new("Backward"),
+            Cell::new("Time (μs)"),
+            Cell::new("Optimal"),
+        ]));
+
+        for metric in results {
+            let side_count = |count: usize| {
+                if metric.forward_explored + metric.backward_explored == 0 { "-".to_string() } else { count.to_string() }
+            };
+            table.add_row(Row::new(vec![
+                Cell::new(&metric.grid_name),
+                Cell::new(&metric.algorithm_name),
+                Cell::new(&metric.path_length.to_string()),
+                Cell::new(&format!("{:.1}", metric.path_cost)),
+                Cell::new(&metric.nodes_explored.to_string()),
+                Cell::new(&side_count(metric.forward_explored)),
+                Cell::new(&side_count(metric.backward_explored)),
+                Cell::new(&format!("{:.2}", metric.duration.as_micros())),
+                Cell::new(&metric.optimal.to_string()),
+            ]));
+        }
+
+        table.printstd();
+
+        for pair in results.chunks(2) {
+            if let [one_sided, two_sided] = pair {
+                println!(
+                    "🎯 {}: {} explores {:.1}x fewer nodes than {}",
+                    one_sided.grid_name,
+                    two_sided.algorithm_name,
+                    one_sided.nodes_explored as f64 / two_sided.nodes_explored.max(1) as f64,
+                    one_sided.algorithm_name
+                );
+            }
+        }
+
+        for metric in results.iter().filter(|m| !m.optimal) {
+            println!("⚠️  {} returned a non-optimal path on {}", metric.algorithm_name, metric.grid_name);
+        }
+        println!();
+    }
+
+    pub fn run_memory_bounded_benchmarks(&self, iterations: usize) -> Result<Vec<MemoryBoundedMetrics>> {
+        if self.grids.is_empty() {
+            return Err(Error::Generic("No grids generated. Generate test grids first.".to_string()));
+        }
+
+        let iterations = iterations.max(1);
+        println!("Comparing memory-bounded searches with A* ({} iterations)", iterations);
+
+        println!("SMA* memory budget: {}% of A*'s peak nodes stored", sma_star::ASTAR_PEAK_PERCENT);
+
+        let mut results = Vec::new();
+        for (grid_name, grid) in &self.grids {
+            let optimal = dijkstra::find_path(grid).map(|(path, _)| grid.scaled_path_cost(&path)).unwrap_or(0);
+            let astar_peak = astar::find_path(grid).map(|(_, counter)| counter.memory_allocations).unwrap_or(0);
+            let sma_memory = sma_star::memory_budget(astar_peak);
+
+            results.push(self.benchmark_memory_bounded(grid_name, "A*", grid, optimal, iterations, astar::find_path));
+            results.push(self.benchmark_memory_bounded(grid_name, "IDA*", grid, optimal, iterations, ida_star::find_path));
+            results.push(self.benchmark_memory_bounded(grid_name, "SMA*", grid, optimal, iterations, |grid: &Grid| sma_star::find_path_with_memory(grid, sma_memory)));
+        }
+
+        self.display_memory_bounded_results(&results);
+        Ok(results)
+    }
+
+    fn benchmark_memory_bounded<F>(&self, grid_name: &str, algorithm_name: &str, grid: &Grid, optimal: u64, iterations: usize, find_path: F) -> MemoryBoundedMetrics
+    where
+        F: Fn(&Grid) -> std::result::Result<(Vec<Position>, PerformanceCounter), String>,
+    {
+        let mut duration = Duration::default();
+        let mut last_result = Err(String::new());
+
+        for _ in 0..iterations {
+            let start = Instant::now();
+            last_result = find_path(grid);
+            duration += start.elapsed();
+
+            if last_result.is_err() {
+                break;
+            }
+        }
+
+        let (path, counter, error) = match last_result {
+            Ok((path, counter)) => (path, counter, None),
+            Err(error) => (Vec::new(), PerformanceCounter::new(), Some(error)),
+        };
+
+        MemoryBoundedMetrics {
+            grid_name: grid_name.to_string(),
+            algorithm_name: algorithm_name.to_string(),
+            path_cost: grid.path_cost(&path),
+            nodes_expanded: counter.nodes_explored,
+            peak_nodes_stored: if counter.peak_nodes_stored > 0 { counter.peak_nodes_stored } else { counter.memory_allocations },
+            duration: duration / iterations as u32,
+            optimal: !path.is_empty() && grid.scaled_path_cost(&path) == optimal,
+            error,
+        }
+    }
+
+    fn display_memory_bounded_results(&self, results: &[MemoryBoundedMetrics]) {
+        let mut table = Table::new();
+        table.add_row(Row::new(vec![
+            Cell::new("Grid"),
+            Cell::new("Algorithm"),
+            Cell::new("Path Cost"),
+            Cell::new("Nodes Expanded"),
+            Cell::new("Peak Nodes Stored"),
+            Cell::new("Time (μs)"),
+            Cell::new("Optimal"),
+        ]));
+
+        for metric in results {
+            table.add_row(Row::new(vec![
+                Cell::new(&metric.grid_name),
+                Cell::new(&metric.algorithm_name),
+                Cell::new(&format!("{:.1}", metric.path_cost)),
+                Cell::new(&metric.nodes_expanded.to_string()),
+                Cell::new(&metric.peak_nodes_stored.to_string()),
+                Cell::new(&format!("{:.2}", metric.duration.as_micros())),
+                Cell::new(&metric.optimal.to_string()),
+            ]));
+        }
+
+        table.printstd();
+
+        if let Some(leanest) = results.iter().filter(|m| m.optimal).min_by_key(|m| m.peak_nodes_stored) {
+            println!("📏 Smallest Footprint: {} on {} ({} nodes stored)", leanest.algorithm_name, leanest.grid_name, leanest.peak_nodes_stored);
+        }
+
+        for metric in results {
+            if let Some(error) = &metric.error {
+                println!("⚠️  {} on {}: {}", metric.algorithm_name, metric.grid_name, error);
+            }
+        }
+        println!();
+    }
+
+    pub fn run_replanning_benchmarks(&self, iterations: usize) -> Result<Vec<ReplanningMetrics>> {
+        if self.grids.is_empty() {
+            return Err(Error::Generic("No grids generated. Generate test grids first.".to_string()));
+        }
+
+        const REPLAN_STEPS: usize = 12;
+        let iterations = iterations.max(1);
+        println!("Comparing incremental replanning with A* reruns over {} scripted grid changes ({} iterations)", REPLAN_STEPS, iterations);
+
+        let mut results = Vec::new();
+        for (grid_name, grid) in &self.grids {
+            for (scenario, moving) in [("Static start", false), ("Moving robot", true)] {
+                let script = self.create_replanning_script(grid, REPLAN_STEPS, moving);
+                let (rerun, reference) = self.replay_astar(grid_name, scenario, grid, &script, iterations);
+                results.push(rerun);
+
+                if !moving {
+                    results.push(self.replay_planner(grid_name, scenario, &script, &reference, iterations, || lpa_star::LpaStar::new(grid.clone())));
+                }
+                results.push(self.replay_planner(grid_name, scenario, &script, &reference, iterations, || d_star_lite::DStarLite::new(grid.clone())));
+            }
+        }
+
+        self.display_replanning_results(&results);
+        Ok(results)
+    }
+
+    fn create_replanning_script(&self, grid: &Grid, steps: usize, moving: bool) -> Vec<ReplanStep> {
+        let mut rng = rand::rng();
+        let mut working = grid.clone();
+        let mut added = Vec::new();
+        let mut script = Vec::new();
+
+        for step in 0..steps {
+            let Ok((path, _)) = astar::find_path(&working) else {
+                break;
+            };
+            if path.len() < 4 {
+                break;
+            }
+
+            let robot_index = if moving { (path.len() / (steps - step + 1)).max(1) } else { 0 };
+            let robot = path[robot_index];
+            working.start = robot;
+
+            let change = match added.pop() {
+                Some(cell) if step % 3 == 2 => Some((cell, CellType::Open)),
+                previous => {
+                    added.extend(previous);
+                    (0..5).map(|_| path[rng.random_range(robot_index + 1..path.len() - 1)]).find(|&cell| {
+                        let mut blocked = working.clone();
+                        blocked.add_obstacle(cell);
+                        astar::find_path(&blocked).is_ok_and(|(path, _)| !path.is_empty())
+                    })
+                    .map(|cell| {
+                        added.push(cell);
+                        (cell, CellType::Blocked)
+                    })
+                    .or_else(|| {
+                        let walls: Vec<Position> = path[robot_index..].iter()
+                            .flat_map(|cell| [(-1, 0), (1, 0), (0, -1), (0, 1)].map(|(dr, dc)| (cell, dr, dc)))
+                            .filter_map(|(cell, dr, dc)| {
+                                let wall = Position::new(cell.row.checked_add_signed(dr)?, cell.col.checked_add_signed(dc)?);
+                                let beyond = Position::new(wall.row.checked_add_signed(dr)?, wall.col.checked_add_signed(dc)?);
+                                (wall.row < working.height && wall.col < working.width
+                                    && working.cells[wall.row][wall.col] == CellType::Blocked
+                                    && working.is_valid_position(&beyond))
+                                    .then_some(wall)
+                            })
+                            .collect();
+                        walls.choose(&mut rng).map(|&wall| (wall, CellType::Open))
+                    })
+                }
+            };
+
+            let Some((cell, terrain)) = change else {
+                break;
+            };
+            working.set_terrain(cell, terrain);
+            script.push(ReplanStep { robot, cell, terrain });
+        }
+
+        script
+    }
+
+    fn replay_astar(&self, grid_name: &str, scenario: &'static str, grid: &Grid, script: &[ReplanStep], iterations: usize) -> (ReplanningMetrics, Vec<u64>) {
+        let mut duration = Duration::default();
+        let mut nodes_expanded = 0;
+        let mut costs = Vec::new();
+
+        for _ in 0..iterations {
+            let mut working = grid.clone();
+            nodes_expanded = 0;
+            costs.clear();
+
+            let start = Instant::now();
+            for step in std::iter::once(None).chain(script.iter().map(Some)) {
+                if let Some(step) = step {
+                    working.start = step.robot;
+                    working.set_terrain(step.cell, step.terrain);
+                }
+
+                let (path, counter) = astar::find_path(&working).unwrap_or_default();
+                nodes_expanded += counter.nodes_explored;
+                costs.push(working.scaled_path_cost(&path));
+            }
+            duration += start.elapsed();
+        }
+
+        let metrics = ReplanningMetrics {
+            grid_name: grid_name.to_string(),
+            scenario,
+            algorithm_name: "A* (rerun)".to_string(),
+            replans: script.len(),
+            nodes_expanded,
+            duration: duration / iterations as u32,
+            final_path_cost: costs.last().copied().unwrap_or(0) as f64 / COST_SCALE as f64,
+            matches_astar: true,
+        };
+        (metrics, costs)
+    }
+
+    fn replay_planner<P, F>(&self, grid_name: &str, scenario: &'static str, script: &[ReplanStep], reference: &[u64], iterations: usize, make: F) -> ReplanningMetrics
+    where
+        P: IncrementalPlanner,
+        F: Fn() -> P,
+    {
+        let mut duration = Duration::default();
+        let mut nodes_expanded = 0;
+        let mut costs = Vec::new();
+        let mut algorithm_name = "";
+
+        for _ in 0..iterations {
+            let mut planner = make();
+            algorithm_name = planner.name();
+            nodes_expanded = 0;
+            costs.clear();
+
+            let start = Instant::now();
+            for step in std::iter::once(None).chain(script.iter().map(Some)) {
+                if let Some(step) = step {
+                    planner.move_start(step.robot);
+                    planner.set_cell(step.cell, step.terrain);
+                }
+
+                let (path, counter) = planner.plan().unwrap_or_default();
+                nodes_expanded += counter.nodes_explored;
+                costs.push(planner.grid().scaled_path_cost(&path));
+            }
+            duration += start.elapsed();
+        }
+
+        ReplanningMetrics {
+            grid_name: grid_name.to_string(),
+            scenario,
+            algorithm_name: algorithm_name.to_string(),
+            replans: script.len(),
+            nodes_expanded,
+            duration: duration / iterations as u32,
+            final_path_cost: costs.last().copied().unwrap_or(0) as f64 / COST_SCALE as f64,
+            matches_astar: costs == reference,
+        }
+    }
+
+    fn display_replanning_results(&self, results: &[ReplanningMetrics]) {
+        let mut table = Table::new();
+        table.add_row(Row::new(vec![
+            Cell::new("Grid"),
+            Cell::new("Scenario"),
+            Cell::new("Algorithm"),
+            Cell::new("Replans"),
+            Cell::new("Nodes Expanded"),
+            Cell::new("Total Time (μs)"),
+            Cell::new("Final Path Cost"),
+            Cell::new("Matches A*"),
+        ]));
+
+        for metric in results {
+            table.add_row(Row::new(vec![
+                Cell::new(&metric.grid_name),
+                Cell::new(metric.scenario),
+                Cell::new(&metric.algorithm_name),
+                Cell::new(&metric.replans.to_string()),
+                Cell::new(&metric.nodes_expanded.to_string()),
+                Cell::new(&format!("{:.2}", metric.duration.as_micros())),
+                Cell::new(&format!("{:.1}", metric.final_path_cost)),
+                Cell::new(&metric.matches_astar.to_string()),
+            ]));
+        }
+
+        table.printstd();
+
+        let mut rerun: Option<&ReplanningMetrics> = None;
+        for metric in results {
+            match rerun {
+                Some(baseline) if baseline.grid_name == metric.grid_name && baseline.scenario == metric.scenario => {
+                    println!(
+                        "🎯 {} ({}): {} expands {:.1}x fewer nodes than A* reruns",
+                        metric.grid_name,
+                        metric.scenario,
+                        metric.algorithm_name,
+                        baseline.nodes_expanded as f64 / metric.nodes_expanded.max(1) as f64
+                    );
+                }
+                _ => rerun = Some(metric),
+            }
+        }
+
+        for metric in results.iter().filter(|m| !m.matches_astar) {
+            println!("⚠️  {} diverged from A* on {} ({})", metric.algorithm_name, metric.grid_name, metric.scenario);
+        }
+        println!();
+    }
+
+    pub fn run_graph_benchmarks(&self, iterations: usize) -> Result<Vec<GraphMetrics>> {
+        let Some((_, grid)) = self.grids.iter().find(|(name, _)| *name == "Random Obstacles") else {
+            return Err(Error::Generic("No grids generated. Generate test grids first.".to_string()));
+        };
+
+        let iterations = iterations.max(1);
+        println!("Running the grid searches on general graph representations ({} iterations)", iterations);
+
+        let index = |pos: Position| pos.row * grid.width + pos.col;
+        let grid_list = AdjacencyList::from(grid);
+        let mut results = Vec::new();
+        results.extend(self.benchmark_graph("Random Obstacles", "Grid", grid, (grid.start, grid.end), iterations));
+        results.extend(self.benchmark_graph("Random Obstacles", "Adjacency List", &grid_list, (index(grid.start), index(grid.end)), iterations));
+        results.extend(self.benchmark_graph("Random Obstacles", "CSR", &CompressedSparseRow::from(&grid_list), (index(grid.start), index(grid.end)), iterations));
+
+        let graphs = [
+            ("Road Network", self.create_road_network(400)),
+            ("Dependency Graph", self.create_dependency_graph(8, 30)),
+        ];
+        for (graph_name, (list, route)) in &graphs {
+            results.extend(self.benchmark_graph(graph_name, "Adjacency List", list, *route, iterations));
+            results.extend(self.benchmark_graph(graph_name, "Adjacency Matrix", &AdjacencyMatrix::from(list), *route, iterations));
+            results.extend(self.benchmark_graph(graph_name, "CSR", &CompressedSparseRow::from(list), *route, iterations));
+        }
+
+        println!("📏 Random Obstacles: {} nodes, {} edges", grid.node_count(), grid.edge_count());
+        for (graph_name, (list, _)) in &graphs {
+            println!("📏 {}: {} nodes, {} edges", graph_name, list.node_count(), list.edge_count());
+        }
+
+        self.display_graph_results(&results);
+        Ok(results)
+    }
+
+    fn create_road_network(&self, nodes: usize) -> (AdjacencyList, (usize, usize)) {
+        let mut rng = rand::rng();
+        let mut graph = AdjacencyList::new();
+        for _ in 0..nodes {
+            graph.add_node(rng.random_range(0.0..100.0), rng.random_range(0.0..100.0));
+        }
+
+        let length = |a: (f64, f64), b: (f64, f64)| ((a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)).sqrt();
+        for node in 0..nodes {
+            let origin = graph.coordinates(node);
+            let mut nearest: Vec<(f64, usize)> = (0..nodes)
+                .filter(|&other| other != node)
+                .map(|other| (length(origin, graph.coordinates(other)), other))
+                .collect();
+            nearest.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+            let linked_back = nearest.iter().find(|&&(_, other)| other < node).copied();
+            for (distance, other) in nearest.into_iter().take(3).chain(linked_back) {
+                let minutes_per_unit = if rng.random_bool(0.2) { 1.0 } else { 2.0 };
+                graph.add_undirected_edge(node, other, (distance * minutes_per_unit * COST_SCALE as f64).ceil() as u64);
+            }
+        }
+
+        let closest_to = |target: (f64, f64)| (0..nodes)
+            .min_by(|&a, &b| length(graph.coordinates(a), target).total_cmp(&length(graph.coordinates(b), target)))
+            .unwrap_or(0);
+        let route = (closest_to((0.0, 0.0)), closest_to((100.0, 100.0)));
+        (graph, route)
+    }
+
+    fn create_dependency_graph(&self, layers: usize, width: usize) -> (AdjacencyList, (usize, usize)) {
+        let mut rng = rand::rng();
+        let mut graph = AdjacencyList::new();
+        let application = graph.add_node(width as f64 / 2.0, -1.0);
+        let packages: Vec<Vec<usize>> = (0..layers)
+            .map(|layer| (0..width).map(|index| graph.add_node(index as f64, layer as f64)).collect())
+            .collect();
+        let core = graph.add_node(width as f64 / 2.0, layers as f64);
+
+        let build_time = |rng: &mut ThreadRng| rng.random_range(1..=20) * COST_SCALE;
+        for &package in &packages[0] {
+            graph.add_edge(application, package, build_time(&mut rng));
+        }
+        for pair in packages.windows(2) {
+            for &package in &pair[0] {
+                for _ in 0..3 {
+                    let dependency = pair[1][rng.random_range(0..width)];
+                    graph.add_edge(package, dependency, build_time(&mut rng));
+                }
+            }
+        }
+        for &package in &packages[layers - 1] {
+            graph.add_edge(package, core, build_time(&mut rng));
+        }
+
+        (graph, (application, core))
+    }
+
+    fn benchmark_graph<G: Graph>(&self, graph_name: &str, representation: &str, graph: &G, route: (G::Node, G::Node), iterations: usize) -> Vec<GraphMetrics> {
+        let searches: [(&str, GraphSearch<G>); 5] = [
+            ("A*", astar::search),
+            ("Dijkstra", dijkstra::search),
+            ("Breadth-First Search", breadth_first::search),
+            ("Depth-First Search", depth_first::search),
+            ("Greedy Best-First", greedy_best_first::search),
+        ];
+        let (start, goal) = route;
+        let optimal_cost = dijkstra::search(graph, start, goal).map(|(path, _)| graph.path_weight(&path)).unwrap_or(0);
+
+        searches.into_iter()
+            .map(|(algorithm_name, search)| {
+                let mut duration = Duration::default();
+                let mut last_result = None;
+
+                for _ in 0..iterations {
+                    let timer = Instant::now();
+                    let result = search(graph, start, goal);
+                    duration += timer.elapsed();
+                    last_result = result.ok();
+                }
+
+                let (path, counter) = last_result.unwrap_or_default();
+                let path_cost = graph.path_weight(&path);
+                GraphMetrics {
+                    graph_name: graph_name.to_string(),
+                    representation: representation.to_string(),
+                    algorithm_name: algorithm_name.to_string(),
+                    path_cost: path_cost as f64 / COST_SCALE as f64,
+                    nodes_explored: counter.nodes_explored,
+                    duration: duration / iterations as u32,
+                    optimal: !path.is_empty() && path_cost == optimal_cost,
+                }
+            })
+            .collect()
+    }
+
+    fn display_graph_results(&self, results: &[GraphMetrics]) {
+        let mut table = Table::new();
+        table.add_row(Row::new(vec![
+            Cell::new("Graph"),
+            Cell::new("Representation"),
+            Cell::new("Algorithm"),
+            Cell::new("Path Cost"),
+            Cell::new("Nodes Explored"),
+            Cell::new("Time (μs)"),
+            Cell::new("Optimal"),
+        ]));
+
+        for metric in results {
+            table.add_row(Row::new(vec![
+                Cell::new(&metric.graph_name),
+                Cell::new(&metric.representation),
+                Cell::new(&metric.algorithm_name),
+                Cell::new(&format!("{:.1}", metric.path_cost)),
+                Cell::new(&metric.nodes_explored.to_string()),
+                Cell::new(&format!("{:.2}", metric.duration.as_micros())),
+                Cell::new(&metric.optimal.to_string()),
+            ]));
+        }
+
+        table.printstd();
+
+        let mut graph_names: Vec<&str> = results.iter().map(|m| m.graph_name.as_str()).collect();
+        graph_names.dedup();
+        for graph_name in graph_names {
+            let fastest = results.iter()
+                .filter(|m| m.graph_name == graph_name && m.algorithm_name == "A*")
+                .min_by_key(|m| m.duration);
+            if let Some(fastest) = fastest {
+                println!("🏆 {}: A* runs fastest on the {} ({}μs)", graph_name, fastest.representation, fastest.duration.as_micros());
+            }
+        }
+
+        for metric in results.iter().filter(|m| !m.optimal && matches!(m.algorithm_name.as_str(), "A*" | "Dijkstra")) {
+            println!("⚠️  {} returned a non-optimal path on the {} {}", metric.algorithm_name, metric.graph_name, metric.representation);
+        }
+        println!();
+    }
+
+    pub fn run_all_pairs_benchmarks(&self, nodes: usize, iterations: usize) -> Result<Vec<AllPairsMetrics>> {
+        if nodes < 3 {
+            return Err(Error::validation("All-pairs benchmarks need at least 3 nodes"));
+        }
+
+        let iterations = iterations.max(1);
+        println!("Comparing all-pairs shortest paths with negative edge weights ({} nodes, {} iterations)", nodes, iterations);
+
+        let mut rng = rand::rng();
+        let mut negative_cycle = SignedGraph::random_with_potentials(nodes, nodes * 4, &mut rng);
+        let cycle = rand::seq::index::sample(&mut rng, nodes, 3).into_vec();
+        negative_cycle.add_edge(cycle[0], cycle[1], -200);
+        negative_cycle.add_edge(cycle[1], cycle[2], 50);
+        negative_cycle.add_edge(cycle[2], cycle[0], 50);
+
+        let graphs = [
+            ("Sparse", SignedGraph::random_with_potentials(nodes, nodes * 4, &mut rng)),
+            ("Dense", SignedGraph::random_with_potentials(nodes, nodes * (nodes - 1) / 2, &mut rng)),
+            ("Negative Cycle", negative_cycle),
+        ];
+
+        let searches: [(&str, AllPairsSearch); 4] = [
+            ("Floyd-Warshall", floyd_warshall::all_pairs),
+            ("Johnson", johnson::all_pairs),
+            ("Bellman-Ford ×V", |graph| shortest_paths::repeat_single_source(graph, bellman_ford::shortest_paths)),
+            ("SPFA ×V", |graph| shortest_paths::repeat_single_source(graph, spfa::shortest_paths)),
+        ];
+
+        let mut results = Vec::new();
+        for (graph_name, graph) in &graphs {
+            let reference = floyd_warshall::all_pairs(graph).map(|(matrix, _)| matrix.distances);
+
+            for (algorithm_name, search) in searches {
+                let mut duration = Duration::default();
+                let mut last_result = None;
+
+                for _ in 0..iterations {
+                    let start = Instant::now();
+                    let result = search(graph);
+                    duration += start.elapsed();
+                    last_result = Some(result);
+                }
+
+                let (relaxations, matches_reference, negative_cycle) = match last_result {
+                    Some(Ok((matrix, counter))) => (counter.comparisons, reference.as_ref().is_ok_and(|distances| *distances == matrix.distances), None),
+                    Some(Err(cycle)) => (0, reference.is_err(), Some(cycle)),
+                    None => (0, false, None),
+                };
+
+                results.push(AllPairsMetrics {
+                    graph_name: graph_name.to_string(),
+                    algorithm_name: algorithm_name.to_string(),
+                    nodes: graph.node_count(),
+                    edges: graph.edge_count(),
+                    relaxations,
+                    duration: duration / iterations as u32,
+                    matches_reference,
+                    negative_cycle,
+                });
+            }
+        }
+
+        self.display_all_pairs_results(&results);
+        Ok(results)
+    }
+
+    fn display_all_pairs_results(&self, results: &[AllPairsMetrics]) {
+        let mut table = Table::new();
+        table.add_row(Row::new(vec![
+            Cell::new("Graph"),
+            Cell::new("Algorithm"),
+            Cell::new("Nodes"),
+            Cell::new("Edges"),
+            Cell::new("Relaxations"),
+            Cell::new("Time (μs)"),
+            Cell::new("Result"),
+        ]));
+
+        for metric in results {
+            let outcome = match (&metric.negative_cycle, metric.matches_reference) {
+                (Some(cycle), _) => format!("negative cycle ({} edges)", cycle.cycle.len() - 1),
+                (None, true) => "matches".to_string(),
+                (None, false) => "differs".to_string(),
+            };
+            table.add_row(Row::new(vec![
+                Cell::new(&metric.graph_name),
+                Cell::new(&metric.algorithm_name),
+                Cell::new(&metric.nodes.to_string()),
+                Cell::new(&metric.edges.to_string()),
+                Cell::new(&if metric.negative_cycle.is_some() { "-".to_string() } else { metric.relaxations.to_string() }),
+                Cell::new(&format!("{:.2}", metric.duration.as_micros())),
+                Cell::new(&outcome),
+            ]));
+        }
+
+        table.printstd();
+
+        for graph in results.chunks(4) {
+            if let Some(fastest) = graph.iter().min_by_key(|m| m.duration) {
+                println!("🏆 {}: {} is fastest ({}μs)", fastest.graph_name, fastest.algorithm_name, fastest.duration.as_micros());
+            }
+            if let Some(cycle) = graph.iter().find_map(|m| m.negative_cycle.as_ref()) {
+                println!("🔁 {}: {}", graph[0].graph_name, cycle);
+            }
+        }
+
+        for metric in results.iter().filter(|m| !m.matches_reference) {
+            println!("⚠️  {} disagreed with Floyd-Warshall on the {} graph", metric.algorithm_name, metric.graph_name);
+        }
+        println!();
+    }
+
+    pub fn run_map_benchmarks(&self, map_name: &str, grid: &Grid, scenarios: &[Scenario], iterations: usize) -> Result<Vec<MapMetrics>> {
+        const SCENARIO_LIMIT: usize = 100;
+
+        let routes: Vec<(Position, Position, Option<f64>)> = if scenarios.is_empty() {
+            let iterations = iterations.max(1);
+            println!("Benchmarking {} ({}x{}) from ({}, {}) to ({}, {}) ({} iterations)", map_name, grid.width, grid.height, grid.start.row, grid.start.col, grid.end.row, grid.end.col, iterations);
+            vec![(grid.start, grid.end, None); iterations]
+        } else {
+            let fitting: Vec<&Scenario> = scenarios.iter().filter(|scenario| scenario.fits(grid)).collect();
+            if fitting.is_empty() {
+                return Err(Error::validation(format!("No scenario matches the {}x{} map {}", grid.width, grid.height, map_name)));
+            }
+
+            let step = fitting.len().div_ceil(SCENARIO_LIMIT);
+            println!("Verifying {} of {} scenarios on {} ({}x{})", fitting.len().div_ceil(step), scenarios.len(), map_name, grid.width, grid.height);
+            if fitting.len() < scenarios.len() {
+                println!("⚠️  Skipped {} scenarios that do not fit the map", scenarios.len() - fitting.len());
+            }
+            fitting.into_iter().step_by(step).map(|scenario| (scenario.start, scenario.goal, Some(scenario.optimal_length))).collect()
+        };
+
+        let table = jps::JumpTable::build(grid);
+        let jump_point_plus = |grid: &Grid| jps::find_path_with_table(grid, &table);
+        let searches: [(&str, MapSearch); 6] = [
+            ("A*", &astar::find_path),
+            ("Dijkstra", &dijkstra::find_path),
+            ("Bidirectional Dijkstra", &bidirectional::dijkstra),
+            ("NBA*", &bidirectional::astar),
+            ("JPS", &jps::find_path),
+            ("JPS+", &jump_point_plus),
+        ];
+
+        let mut results: Vec<MapMetrics> = searches.iter()
+            .map(|(algorithm_name, _)| MapMetrics {
+                algorithm_name: algorithm_name.to_string(),
+                scenarios: 0,
+                verified: 0,
+                nodes_explored: 0,
+                duration: Duration::default(),
+                error: None,
+            })
+            .collect();
+
+        for &(start, goal, optimal_length) in &routes {
+            let mut routed = grid.clone();
+            routed.set_route(start, goal);
+            let reference_cost = dijkstra::find_path(&routed).map(|(path, _)| routed.scaled_path_cost(&path)).unwrap_or(0);
+
+            for (metric, (_, search)) in results.iter_mut().zip(&searches) {
+                if metric.error.is_some() {
+                    continue;
+                }
+
+                let timer = Instant::now();
+                let result = search(&routed);
+                let elapsed = timer.elapsed();
+
+                match result {
+                    Ok((path, counter)) => {
+                        metric.scenarios += 1;
+                        metric.duration += elapsed;
+                        metric.nodes_explored += counter.nodes_explored;
+                        let optimal = match optimal_length {
+                            Some(expected) => !path.is_empty() && (map_file::octile_length(&path) - expected).abs() <= 1e-3 * expected.max(1.0),
+                            None => !path.is_empty() && routed.scaled_path_cost(&path) == reference_cost,
+                        };
+                        metric.verified += optimal as usize;
+                    }
+                    Err(error) => metric.error = Some(error),
+                }
+            }
+        }
+
+        for metric in &mut results {
+            if metric.scenarios > 0 {
+                metric.duration /= metric.scenarios as u32;
+                metric.nodes_explored /= metric.scenarios;
+            }
+        }
+
+        self.display_map_results(&results, scenarios.is_empty());
+        Ok(results)
+    }
+
+    fn display_map_results(&self, results: &[MapMetrics], single_route: bool) {
+        let mut table = Table::new();
+        table.add_row(Row::new(vec![
+            Cell::new("Algorithm"),
+            Cell::new(if single_route { "Runs" } else { "Scenarios" }),
+            Cell::new("Optimal"),
+            Cell::new("Avg Nodes Explored"),
+            Cell::new("Avg Time (μs)"),
+        ]));
+
+        for metric in results {
+            if metric.error.is_some() {
+                table.add_row(Row::new(vec![
+                    Cell::new(&metric.algorithm_name),
+                    Cell::new("-"),
+                    Cell::new("n/a"),
+                    Cell::new("-"),
+                    Cell::new("-"),
+                ]));
+                continue;
+            }
+
+            table.add_row(Row::new(vec![
+                Cell::new(&metric.algorithm_name),
+                Cell::new(&metric.scenarios.to_string()),
+                Cell::new(&format!("{}/{}", metric.verified, metric.scenarios)),
+                Cell::new(&metric.nodes_explored.to_string()),
+                Cell::new(&format!("{:.2}", metric.duration.as_micros())),
+            ]));
+        }
+
+        table.printstd();
+
+        let verified = results.iter().filter(|m| m.error.is_none() && m.scenarios > 0 && m.verified == m.scenarios);
+        if let Some(fastest) = verified.min_by_key(|m| m.duration) {
+            println!("🏆 Fastest optimal search: {} ({}μs per {})", fastest.algorithm_name, fastest.duration.as_micros(), if single_route { "run" } else { "scenario" });
+        }
+        if let Some(fewest) = results.iter().filter(|m| m.error.is_none() && m.scenarios > 0).min_by_key(|m| m.nodes_explored) {
+            println!("🎯 Fewest nodes explored: {} ({} on average)", fewest.algorithm_name, fewest.nodes_explored);
+        }
+
+        for metric in results {
+            if let Some(error) = &metric.error {
+                println!("⚠️  {} skipped: {}", metric.algorithm_name, error);
+            } else if metric.verified < metric.scenarios {
+                let reference = if single_route { "Dijkstra" } else { "the scenario file" };
+                println!("⚠️  {} missed the optimal length from {} {} times", metric.algorithm_name, reference, metric.scenarios - metric.verified);
+            }
+        }
+        println!();
+    }
+
+    pub fn run_queue_benchmarks(&self, iterations: usize) -> Result<Vec<QueueMetrics>> {
+        if self.grids.is_empty() {
+            return Err(Error::Generic("No grids generated. Generate test grids first.".to_string()));
+        }
+
+        let iterations = iterations.max(1);
+        let optimal_costs: Vec<u64> = self.grids.iter()
+            .map(|(_, grid)| dijkstra::find_path(grid).map(|(path, _)| grid.scaled_path_cost(&path)).unwrap_or(0))
+            .collect();
+
+        println!("Comparing priority queues inside Dijkstra and A* ({} grids, {} iterations)", self.grids.len(), iterations);
+
+        let results = vec![
+            self.benchmark_std_queue(&optimal_costs, iterations),
+            self.benchmark_queue(&optimal_costs, iterations, || d_ary_heap::DaryHeap::new(2)),
+            self.benchmark_queue(&optimal_costs, iterations, || d_ary_heap::DaryHeap::new(4)),
+            self.benchmark_queue(&optimal_costs, iterations, || d_ary_heap::DaryHeap::new(8)),
+            self.benchmark_queue(&optimal_costs, iterations, binomial_heap::BinomialHeap::new),
+            self.benchmark_queue(&optimal_costs, iterations, fibonacci_heap::FibonacciHeap::new),
+            self.benchmark_queue(&optimal_costs, iterations, pairing_heap::PairingHeap::new),
+            self.benchmark_queue(&optimal_costs, iterations, radix_heap::RadixHeap::new),
+        ];
+
+        self.display_queue_results(&results);
+        Ok(results)
+    }
+
+    fn benchmark_std_queue(&self, optimal_costs: &[u64], iterations: usize) -> QueueMetrics {
+        self.benchmark_queue_runs("std BinaryHeap (lazy)", optimal_costs, iterations, dijkstra::find_path, astar::find_path)
+    }
+
+    fn benchmark_queue<Q, F>(&self, optimal_costs: &[u64], iterations: usize, make: F) -> QueueMetrics
+    where
+        Q: PriorityQueue<u64, Position>,
+        F: Fn() -> Q,
+    {
+        let name = make().name();
+        self.benchmark_queue_runs(
+            name,
+            optimal_costs,
+            iterations,
+            |grid| dijkstra::find_path_with_queue(grid, make()),
+            |grid| astar::find_path_with_queue(grid, make()),
+        )
+    }
+
+    fn benchmark_queue_runs<D, A>(&self, name: &str, optimal_costs: &[u64], iterations: usize, dijkstra_fn: D, astar_fn: A) -> QueueMetrics
+    where
+        D: Fn(&Grid) -> std::result::Result<(Vec<Position>, PerformanceCounter), String>,
+        A: Fn(&Grid) -> std::result::Result<(Vec<Position>, PerformanceCounter), String>,
+    {
+        let mut metrics = QueueMetrics {
+            queue_name: name.to_string(),
+            dijkstra_duration: Duration::default(),
+            astar_duration: Duration::default(),
+            dijkstra_explored: 0,
+            astar_explored: 0,
+            paths_optimal: true,
+        };
+
+        for ((_, grid), &optimal) in self.grids.iter().zip(optimal_costs) {
+            for _ in 0..iterations {
+                let start = Instant::now();
+                let dijkstra_result = dijkstra_fn(grid);
+                metrics.dijkstra_duration += start.elapsed();
+
+                let start = Instant::now();
+                let astar_result = astar_fn(grid);
+                metrics.astar_duration += start.elapsed();
+
+                for (result, explored) in [(dijkstra_result, &mut metrics.dijkstra_explored), (astar_result, &mut metrics.astar_explored)] {
+                    match result {
+                        Ok((path, counter)) => {
+                            *explored += counter.nodes_explored;
+                            metrics.paths_optimal &= grid.scaled_path_cost(&path) == optimal;
+                        }
+                        Err(_) => metrics.paths_optimal = false,
+                    }
+                }
+            }
+        }
+
+        metrics.dijkstra_duration /= iterations as u32;
+        metrics.astar_duration /= iterations as u32;
+        metrics.dijkstra_explored /= iterations;
+        metrics.astar_explored /= iterations;
+        metrics
+    }
+
+    fn display_queue_results(&self, results: &[QueueMetrics]) {
+        let mut table = Table::new();
+        table.add_row(Row::new(vec![
+            Cell::new("Priority Queue"),
+            Cell::new("Dijkstra (μs)"),
+            Cell::new("A* (μs)"),
+            Cell::new("Dijkstra Explored"),
+            Cell::new("A* Explored"),
+            Cell::new("Optimal Paths"),
+        ]));
+
+        for metric in results {
+            table.add_row(Row::new(vec![
+                Cell::new(&metric.queue_name),
+                Cell::new(&format!("{:.2}", metric.dijkstra_duration.as_micros())),
+                Cell::new(&format!("{:.2}", metric.astar_duration.as_micros())),
+                Cell::new(&metric.dijkstra_explored.to_string()),
+                Cell::new(&metric.astar_explored.to_string()),
+                Cell::new(&metric.paths_optimal.to_string()),
+            ]));
+        }
+
+        table.printstd();
+
+        if let Some(fastest) = results.iter().min_by_key(|m| m.dijkstra_duration + m.astar_duration) {
+            println!("🏆 Fastest Queue: {} ({}μs Dijkstra, {}μs A*)",
+                fastest.queue_name, fastest.dijkstra_duration.as_micros(), fastest.astar_duration.as_micros());
+        }
+
+        for metric in results.iter().filter(|m| !m.paths_optimal) {
+            println!("⚠️  {} produced a non-optimal path", metric.queue_name);
+        }
+        println!();
+    }
+
+    fn benchmark_algorithm(&self, algorithm_name: &str, iterations: usize) -> Result<Vec<PathfindingMetrics>> {
+        let mut results = Vec::new();
+
+        for (grid_name, grid) in &self.grids {
+            let mut total_duration = Duration::default();
             let mut successful_runs = 0;
             let mut last_result = None;
 
@@ -419,6 +2111,8 @@ impl PathfinderCoordinator {
                     "Breadth-First Search" => breadth_first::find_path(grid),
                     "Depth-First Search" => depth_first::find_path(grid),
                     "Greedy Best-First" => greedy_best_first::find_path(grid),
+                    "Theta*" => theta_star::find_path(grid),
+                    "Lazy Theta*" => lazy_theta_star::find_path(grid),
                     _ => return Err(Error::NotFound(format!("Unknown algorithm: {}", algorithm_name))),
                 };
                 
@@ -446,10 +2140,12 @@ impl PathfinderCoordinator {
                         algorithm_name: algorithm_name.to_string(),
                         path_found: !path.is_empty(),
                         path_length: path.len(),
+                        path_cost: grid.path_cost(&path),
                         nodes_explored: counter.nodes_explored,
                         nodes_in_frontier: counter.nodes_in_frontier,
                         duration: avg_duration,
                         theoretical_complexity: self.get_theoretical_complexity(algorithm_name),
+                        grid_name: grid_name.to_string(),
                         grid_size: (grid.width, grid.height),
                         obstacle_count,
                         path,
@@ -469,6 +2165,7 @@ impl PathfinderCoordinator {
             "Breadth-First Search" => "O(V + E)".to_string(),
             "Depth-First Search" => "O(V + E)".to_string(),
             "Greedy Best-First" => "O(b^m)".to_string(),
+            "Theta*" | "Lazy Theta*" => "O(E·L + V log V)".to_string(),
             _ => "Unknown".to_string(),
         }
     }
@@ -482,9 +2179,11 @@ impl PathfinderCoordinator {
         let mut table = Table::new();
         table.add_row(Row::new(vec![
             Cell::new("Algorithm"),
+            Cell::new("Grid"),
             Cell::new("Grid Size"),
             Cell::new("Path Found"),
             Cell::new("Path Length"),
+            Cell::new("Path Cost"),
             Cell::new("Nodes Explored"),
             Cell::new("Time (μs)"),
             Cell::new("Big O"),
@@ -494,9 +2193,11 @@ impl PathfinderCoordinator {
         for metric in metrics {
             table.add_row(Row::new(vec![
                 Cell::new(&metric.algorithm_name),
+                Cell::new(&metric.grid_name),
                 Cell::new(&format!("{}x{}", metric.grid_size.0, metric.grid_size.1)),
                 Cell::new(&metric.path_found.to_string()),
                 Cell::new(&metric.path_length.to_string()),
+                Cell::new(&format!("{:.1}", metric.path_cost)),
                 Cell::new(&metric.nodes_explored.to_string()),
                 Cell::new(&format!("{:.2}", metric.duration.as_micros())),
                 Cell::new(&metric.theoretical_complexity),
@@ -506,6 +2207,20 @@ impl PathfinderCoordinator {
 
         table.printstd();
         println!();
+
+        for (grid_name, _) in self.grids.iter().filter(|(_, grid)| grid.is_weighted()) {
+            let cost_of = |algorithm: &str| metrics.iter()
+                .find(|m| m.algorithm_name == algorithm && m.grid_name == *grid_name)
+                .map(|m| (m.path_cost, m.path_length));
+
+            if let (Some((dijkstra_cost, dijkstra_length)), Some((bfs_cost, bfs_length))) = (cost_of("Dijkstra"), cost_of("Breadth-First Search")) {
+                println!(
+                    "💰 {}: Dijkstra path costs {:.1} over {} steps vs BFS {:.1} over {} steps",
+                    grid_name, dijkstra_cost, dijkstra_length, bfs_cost, bfs_length
+                );
+            }
+        }
+        println!();
         Ok(())
     }
 }
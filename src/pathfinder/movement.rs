@@ -0,0 +1,213 @@
+use super::Position;
+use crate::prelude::*;
+use std::str::FromStr;
+
+pub const COST_SCALE: u64 = 1000;
+pub const DIAGONAL_SCALE: u64 = 1414;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MovementModel {
+    #[default]
+    FourWay,
+    EightWay,
+    EightWayNoCornerCutting,
+    Hex,
+}
+
+impl MovementModel {
+    pub const ALL: [MovementModel; 4] = [
+        MovementModel::FourWay,
+        MovementModel::EightWay,
+        MovementModel::EightWayNoCornerCutting,
+        MovementModel::Hex,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MovementModel::FourWay => "4-way",
+            MovementModel::EightWay => "8-way",
+            MovementModel::EightWayNoCornerCutting => "8-way (no corner cutting)",
+            MovementModel::Hex => "hex",
+        }
+    }
+
+    pub fn default_heuristic(&self) -> Heuristic {
+        match self {
+            MovementModel::FourWay => Heuristic::Manhattan,
+            MovementModel::EightWay | MovementModel::EightWayNoCornerCutting => Heuristic::Octile,
+            MovementModel::Hex => Heuristic::Hex,
+        }
+    }
+
+    pub fn offsets(&self, row: usize) -> &'static [(i32, i32)] {
+        const FOUR_WAY: [(i32, i32); 4] = [(0, 1), (0, -1), (1, 0), (-1, 0)];
+        const EIGHT_WAY: [(i32, i32); 8] = [(0, 1), (0, -1), (1, 0), (-1, 0), (1, 1), (1, -1), (-1, 1), (-1, -1)];
+        const HEX_EVEN_ROW: [(i32, i32); 6] = [(0, 1), (0, -1), (-1, -1), (-1, 0), (1, -1), (1, 0)];
+        const HEX_ODD_ROW: [(i32, i32); 6] = [(0, 1), (0, -1), (-1, 0), (-1, 1), (1, 0), (1, 1)];
+
+        match self {
+            MovementModel::FourWay => &FOUR_WAY,
+            MovementModel::EightWay | MovementModel::EightWayNoCornerCutting => &EIGHT_WAY,
+            MovementModel::Hex if row.is_multiple_of(2) => &HEX_EVEN_ROW,
+            MovementModel::Hex => &HEX_ODD_ROW,
+        }
+    }
+}
+
+impl FromStr for MovementModel {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "4" | "four" | "4-way" => Ok(MovementModel::FourWay),
+            "8" | "eight" | "8-way" => Ok(MovementModel::EightWay),
+            "8-strict" | "no-corner-cutting" => Ok(MovementModel::EightWayNoCornerCutting),
+            "hex" | "6" => Ok(MovementModel::Hex),
+            _ => Err(Error::validation(format!(
+                "Unknown movement model '{}' (expected 4, 8, 8-strict or hex)",
+                s
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Heuristic {
+    Manhattan,
+    Octile,
+    Chebyshev,
+    Euclidean,
+    Hex,
+}
+
+impl Heuristic {
+    pub const ALL: [Heuristic; 5] = [
+        Heuristic::Manhattan,
+        Heuristic::Octile,
+        Heuristic::Chebyshev,
+        Heuristic::Euclidean,
+        Heuristic::Hex,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Heuristic::Manhattan => "Manhattan",
+            Heuristic::Octile => "Octile",
+            Heuristic::Chebyshev => "Chebyshev",
+            Heuristic::Euclidean => "Euclidean",
+            Heuristic::Hex => "Hex",
+        }
+    }
+
+    pub fn scaled_distance(&self, from: &Position, to: &Position) -> u64 {
+        let dr = from.row.abs_diff(to.row) as u64;
+        let dc = from.col.abs_diff(to.col) as u64;
+        let (long, short) = (dr.max(dc), dr.min(dc));
+
+        match self {
+            Heuristic::Manhattan => (dr + dc) * COST_SCALE,
+            Heuristic::Octile => (long - short) * COST_SCALE + short * DIAGONAL_SCALE,
+            Heuristic::Chebyshev => long * COST_SCALE,
+            // Scaled by the rounded-down diagonal cost so it never exceeds the octile distance.
+            Heuristic::Euclidean => (from.distance_to(to) * DIAGONAL_SCALE as f64 / std::f64::consts::SQRT_2).floor() as u64,
+            Heuristic::Hex => hex_distance(from, to) * COST_SCALE,
+        }
+    }
+
+    pub fn distance(&self, from: &Position, to: &Position) -> f64 {
+        self.scaled_distance(from, to) as f64 / COST_SCALE as f64
+    }
+}
+
+impl FromStr for Heuristic {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Heuristic::ALL
+            .into_iter()
+            .find(|heuristic| heuristic.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| Error::validation(format!("Unknown heuristic '{}'", s)))
+    }
+}
+
+fn hex_distance(from: &Position, to: &Position) -> u64 {
+    let cube = |pos: &Position| {
+        let x = pos.col as i64 - (pos.row as i64 - (pos.row as i64 & 1)) / 2;
+        let z = pos.row as i64;
+        (x, -x - z, z)
+    };
+
+    let (a, b) = (cube(from), cube(to));
+    (a.0 - b.0).unsigned_abs().max((a.1 - b.1).unsigned_abs()).max((a.2 - b.2).unsigned_abs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pathfinder::Grid;
+
+    #[test]
+    fn test_neighbours_per_movement_model() {
+        let centre = Position::new(2, 2);
+        let mut grid = Grid::new(5, 5, Position::new(0, 0), Position::new(4, 4));
+        grid.add_obstacle(Position::new(1, 2));
+
+        let count = |movement: MovementModel| grid.clone().with_movement(movement).get_neighbors(&centre).len();
+        assert_eq!(count(MovementModel::FourWay), 3);
+        assert_eq!(count(MovementModel::EightWay), 7);
+        assert_eq!(count(MovementModel::EightWayNoCornerCutting), 5);
+        assert_eq!(count(MovementModel::Hex), 5);
+    }
+
+    #[test]
+    fn test_diagonal_squeeze_needs_one_open_corner() {
+        let mut grid = Grid::new(2, 2, Position::new(0, 0), Position::new(1, 1)).with_movement(MovementModel::EightWay);
+        grid.add_obstacle(Position::new(0, 1));
+        assert!(grid.get_neighbors(&grid.start).contains(&grid.end));
+
+        grid.add_obstacle(Position::new(1, 0));
+        assert!(grid.get_neighbors(&grid.start).is_empty());
+    }
+
+    #[test]
+    fn test_hex_heuristic_matches_hex_steps() {
+        let grid = Grid::new(7, 7, Position::new(0, 0), Position::new(6, 6)).with_movement(MovementModel::Hex);
+        let from = Position::new(3, 3);
+
+        for neighbour in grid.get_neighbors(&from) {
+            assert_eq!(hex_distance(&from, &neighbour), 1);
+        }
+        assert_eq!(hex_distance(&Position::new(0, 0), &Position::new(6, 6)), 9);
+    }
+
+    #[test]
+    fn test_euclidean_is_admissible_for_eight_way_costs() {
+        let origin = Position::new(0, 0);
+        for row in 0..40 {
+            for col in 0..40 {
+                let target = Position::new(row, col);
+                assert!(Heuristic::Euclidean.scaled_distance(&origin, &target) <= Heuristic::Octile.scaled_distance(&origin, &target));
+            }
+        }
+
+        let grid = Grid::new(12, 12, Position::new(0, 0), Position::new(11, 11))
+            .with_movement(MovementModel::EightWay)
+            .with_heuristic(Heuristic::Euclidean);
+        for row in 0..12 {
+            for col in 0..12 {
+                let goal = Position::new(row, col);
+                let (astar_path, _) = crate::pathfinder::astar::search(&grid, grid.start, goal).unwrap();
+                let (dijkstra_path, _) = crate::pathfinder::dijkstra::search(&grid, grid.start, goal).unwrap();
+                assert_eq!(grid.scaled_path_cost(&astar_path), grid.scaled_path_cost(&dijkstra_path));
+            }
+        }
+    }
+
+    #[test]
+    fn test_movement_model_parsing() {
+        assert_eq!("8".parse::<MovementModel>().unwrap(), MovementModel::EightWay);
+        assert_eq!("8-strict".parse::<MovementModel>().unwrap(), MovementModel::EightWayNoCornerCutting);
+        assert_eq!("octile".parse::<Heuristic>().unwrap(), Heuristic::Octile);
+        assert!("diagonal".parse::<MovementModel>().is_err());
+    }
+}
@@ -0,0 +1,197 @@
+use crate::pathfinder::maze::DisjointSet;
+use crate::pathfinder::{CellType, Grid, Position};
+use crate::prelude::*;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+const RIGHT: u8 = 1;
+const DOWN: u8 = 2;
+const LEFT: u8 = 4;
+const UP: u8 = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ObstacleGenerator {
+    pub density: f64,
+    pub seed: Option<u64>,
+}
+
+impl ObstacleGenerator {
+    pub fn new(density: f64) -> Self {
+        Self {
+            density: density.clamp(0.0, 1.0),
+            seed: None,
+        }
+    }
+
+    pub fn with_seed(mut self, seed: Option<u64>) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn target_obstacles(&self, width: usize, height: usize) -> usize {
+        ((width * height) as f64 * self.density) as usize
+    }
+
+    pub fn generate(&self, width: usize, height: usize) -> Result<Grid> {
+        let start = Position::new(0, 0);
+        let end = Position::new(height.saturating_sub(1), width.saturating_sub(1));
+        let mut grid = Grid::new(width, height, start, end);
+        if width == 0 || height == 0 {
+            return Ok(grid);
+        }
+
+        let mut rng = match self.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_rng(&mut rand::rng()),
+        };
+
+        let protected = protected_cells(&grid);
+        let mut links = random_spanning_tree(width, height, &mut rng);
+        let mut leaves: Vec<usize> = (0..width * height)
+            .filter(|&cell| links[cell].count_ones() == 1 && !protected[cell])
+            .collect();
+
+        let target = self.target_obstacles(width, height);
+        let mut placed = 0;
+
+        while placed < target && !leaves.is_empty() {
+            let cell = leaves.swap_remove(rng.random_range(0..leaves.len()));
+            if links[cell].count_ones() != 1 {
+                continue;
+            }
+
+            let (direction, neighbour) = linked_neighbour(cell, links[cell], width);
+            links[cell] = 0;
+            links[neighbour] &= !opposite(direction);
+            grid.cells[cell / width][cell % width] = CellType::Blocked;
+            placed += 1;
+
+            if links[neighbour].count_ones() == 1 && !protected[neighbour] {
+                leaves.push(neighbour);
+            }
+        }
+
+        // The tree path between the protected start and end cells can never be pruned.
+        if placed < target {
+            let cells = (width * height) as f64;
+            return Err(Error::validation(format!(
+                "Only {} of {} obstacles fit on a connected {}x{} grid ({:.1}% density, {:.1}% requested)",
+                placed, target, width, height, placed as f64 / cells * 100.0, self.density * 100.0
+            )));
+        }
+
+        Ok(grid)
+    }
+}
+
+fn random_spanning_tree(width: usize, height: usize, rng: &mut StdRng) -> Vec<u8> {
+    let mut edges = Vec::with_capacity(2 * width * height);
+    for cell in 0..width * height {
+        if cell % width + 1 < width {
+            edges.push((cell, RIGHT));
+        }
+        if cell / width + 1 < height {
+            edges.push((cell, DOWN));
+        }
+    }
+    edges.shuffle(rng);
+
+    let mut links = vec![0u8; width * height];
+    let mut sets = DisjointSet::new(width * height);
+    for (cell, direction) in edges {
+        let neighbour = if direction == RIGHT { cell + 1 } else { cell + width };
+        if sets.union(cell, neighbour) {
+            links[cell] |= direction;
+            links[neighbour] |= opposite(direction);
+        }
+    }
+    links
+}
+
+fn linked_neighbour(cell: usize, links: u8, width: usize) -> (u8, usize) {
+    match links {
+        RIGHT => (RIGHT, cell + 1),
+        DOWN => (DOWN, cell + width),
+        LEFT => (LEFT, cell - 1),
+        _ => (UP, cell - width),
+    }
+}
+
+fn opposite(direction: u8) -> u8 {
+    match direction {
+        RIGHT => LEFT,
+        DOWN => UP,
+        LEFT => RIGHT,
+        _ => DOWN,
+    }
+}
+
+fn protected_cells(grid: &Grid) -> Vec<bool> {
+    let mut protected = vec![false; grid.width * grid.height];
+    for pos in [grid.start, grid.end] {
+        for row in pos.row.saturating_sub(1)..=(pos.row + 1).min(grid.height - 1) {
+            for col in pos.col.saturating_sub(1)..=(pos.col + 1).min(grid.width - 1) {
+                protected[row * grid.width + col] = true;
+            }
+        }
+    }
+    protected
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pathfinder::{breadth_first, MovementModel};
+    use std::collections::{HashSet, VecDeque};
+
+    fn obstacles(grid: &Grid) -> usize {
+        grid.cells.iter().flatten().filter(|cell| **cell == CellType::Blocked).count()
+    }
+
+    #[test]
+    fn test_exact_density_and_connectivity() {
+        for (density, seed) in [(0.0, 1), (0.3, 2), (0.55, 3), (0.8, 4)] {
+            let generator = ObstacleGenerator::new(density).with_seed(Some(seed));
+            let grid = generator.generate(40, 25).unwrap();
+            assert_eq!(obstacles(&grid), generator.target_obstacles(40, 25));
+
+            let open = grid.cells.iter().flatten().filter(|cell| **cell != CellType::Blocked).count();
+            let mut reached = HashSet::from([grid.start]);
+            let mut queue = VecDeque::from([grid.start]);
+            while let Some(current) = queue.pop_front() {
+                for neighbour in grid.get_neighbors(&current) {
+                    if reached.insert(neighbour) {
+                        queue.push_back(neighbour);
+                    }
+                }
+            }
+            assert_eq!(reached.len(), open);
+
+            for movement in MovementModel::ALL {
+                let grid = grid.clone().with_movement(movement);
+                assert!(!breadth_first::find_path(&grid).unwrap().0.is_empty(), "{}", movement.as_str());
+            }
+        }
+    }
+
+    #[test]
+    fn test_seeded_generation_scales() {
+        let generator = ObstacleGenerator::new(0.35).with_seed(Some(9));
+        assert_eq!(generator.generate(30, 30).unwrap().cells, generator.generate(30, 30).unwrap().cells);
+
+        let grid = generator.generate(1000, 1000).unwrap();
+        assert_eq!(obstacles(&grid), 350_000);
+        assert!(!breadth_first::find_path(&grid).unwrap().0.is_empty());
+    }
+
+    #[test]
+    fn test_unreachable_density_is_an_error() {
+        let error = ObstacleGenerator::new(1.0).with_seed(Some(5)).generate(3, 3).unwrap_err();
+        assert!(error.to_string().contains("of 9 obstacles"), "{}", error);
+
+        for seed in 0..20 {
+            assert!(ObstacleGenerator::new(0.95).with_seed(Some(seed)).generate(12, 12).is_err());
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use crate::pathfinder::{AdjacencyList, Graph, PerformanceCounter};
+use rand::Rng;
+use std::collections::HashSet;
+use std::fmt;
+
+#[derive(Debug, Clone, Default)]
+pub struct SignedGraph {
+    adjacency: Vec<Vec<(usize, i64)>>,
+}
+
+impl SignedGraph {
+    pub fn new(nodes: usize) -> Self {
+        Self { adjacency: vec![Vec::new(); nodes] }
+    }
+
+    pub fn random_with_potentials<R: Rng>(nodes: usize, edges: usize, rng: &mut R) -> Self {
+        let mut graph = Self::new(nodes);
+        if nodes < 2 {
+            return graph;
+        }
+
+        let potentials: Vec<i64> = (0..nodes).map(|_| rng.random_range(0..=50)).collect();
+        for _ in 0..edges {
+            let from = rng.random_range(0..nodes);
+            let to = (from + rng.random_range(1..nodes)) % nodes;
+            graph.add_edge(from, to, rng.random_range(1..=100) + potentials[from] - potentials[to]);
+        }
+        graph
+    }
+
+    pub fn add_node(&mut self) -> usize {
+        self.adjacency.push(Vec::new());
+        self.adjacency.len() - 1
+    }
+
+    pub fn add_edge(&mut self, from: usize, to: usize, weight: i64) {
+        if from < self.adjacency.len() && to < self.adjacency.len() {
+            self.adjacency[from].push((to, weight));
+        }
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.adjacency.iter().map(Vec::len).sum()
+    }
+
+    pub fn edges(&self, node: usize) -> &[(usize, i64)] {
+        &self.adjacency[node]
+    }
+
+    pub fn edge_weight(&self, from: usize, to: usize) -> Option<i64> {
+        self.adjacency[from].iter()
+            .filter(|&&(target, _)| target == to)
+            .map(|&(_, weight)| weight)
+            .min()
+    }
+
+    pub fn path_weight(&self, path: &[usize]) -> Option<i64> {
+        path.windows(2).try_fold(0i64, |total, pair| total.checked_add(self.edge_weight(pair[0], pair[1])?))
+    }
+}
+
+impl From<&AdjacencyList> for SignedGraph {
+    fn from(list: &AdjacencyList) -> Self {
+        let mut graph = Self::new(list.node_count());
+        for from in 0..list.node_count() {
+            for (to, weight) in list.edges(&from) {
+                graph.add_edge(from, to, weight as i64);
+            }
+        }
+        graph
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NegativeCycle {
+    pub cycle: Vec<usize>,
+    pub weight: i64,
+}
+
+impl NegativeCycle {
+    pub(crate) fn from_predecessors(graph: &SignedGraph, predecessors: &[Option<usize>], from: usize) -> Option<Self> {
+        let mut seen = HashSet::new();
+        let mut current = from;
+        while seen.insert(current) {
+            current = predecessors[current]?;
+        }
+
+        let mut cycle = vec![current];
+        let mut node = predecessors[current]?;
+        while node != current {
+            cycle.push(node);
+            node = predecessors[node]?;
+        }
+        cycle.push(current);
+        cycle.reverse();
+
+        let weight = graph.path_weight(&cycle)?;
+        (weight < 0).then_some(Self { cycle, weight })
+    }
+}
+
+impl fmt::Display for NegativeCycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nodes: Vec<String> = self.cycle.iter().map(usize::to_string).collect();
+        write!(f, "negative cycle {} (weight {})", nodes.join(" → "), self.weight)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShortestPathTree {
+    pub source: usize,
+    pub distances: Vec<Option<i64>>,
+    pub predecessors: Vec<Option<usize>>,
+}
+
+impl ShortestPathTree {
+    pub(crate) fn new(source: usize, nodes: usize) -> Self {
+        let mut distances = vec![None; nodes];
+        distances[source] = Some(0);
+        Self {
+            source,
+            distances,
+            predecessors: vec![None; nodes],
+        }
+    }
+
+    pub fn path_to(&self, target: usize) -> Option<Vec<usize>> {
+        self.distances[target]?;
+        reconstruct(&self.predecessors, self.source, target)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DistanceMatrix {
+    pub distances: Vec<Vec<Option<i64>>>,
+    pub predecessors: Vec<Vec<Option<usize>>>,
+}
+
+impl DistanceMatrix {
+    pub fn distance(&self, from: usize, to: usize) -> Option<i64> {
+        self.distances[from][to]
+    }
+
+    pub fn path(&self, from: usize, to: usize) -> Option<Vec<usize>> {
+        self.distances[from][to]?;
+        reconstruct(&self.predecessors[from], from, to)
+    }
+}
+
+impl FromIterator<ShortestPathTree> for DistanceMatrix {
+    fn from_iter<I: IntoIterator<Item = ShortestPathTree>>(trees: I) -> Self {
+        let (distances, predecessors) = trees.into_iter().map(|tree| (tree.distances, tree.predecessors)).unzip();
+        Self { distances, predecessors }
+    }
+}
+
+pub fn repeat_single_source<F>(graph: &SignedGraph, shortest_paths: F) -> Result<(DistanceMatrix, PerformanceCounter), NegativeCycle>
+where
+    F: Fn(&SignedGraph, usize) -> Result<(ShortestPathTree, PerformanceCounter), NegativeCycle>,
+{
+    let mut total = PerformanceCounter::new();
+    let mut trees = Vec::with_capacity(graph.node_count());
+
+    for source in 0..graph.node_count() {
+        let (tree, counter) = shortest_paths(graph, source)?;
+        total.nodes_explored += counter.nodes_explored;
+        total.comparisons += counter.comparisons;
+        trees.push(tree);
+    }
+
+    Ok((trees.into_iter().collect(), total))
+}
+
+fn reconstruct(predecessors: &[Option<usize>], source: usize, target: usize) -> Option<Vec<usize>> {
+    let mut path = vec![target];
+    let mut current = target;
+
+    while current != source {
+        current = predecessors[current]?;
+        path.push(current);
+        if path.len() > predecessors.len() {
+            return None;
+        }
+    }
+
+    path.reverse();
+    Some(path)
+}
@@ -0,0 +1,338 @@
+use crate::pathfinder::{Grid, Position, PerformanceCounter};
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+pub const EXPANSION_LIMIT: usize = 1_000_000;
+pub const ASTAR_PEAK_PERCENT: usize = 125;
+
+struct Node {
+    position: Position,
+    g: u64,
+    f: u64,
+    depth: usize,
+    parent: Option<usize>,
+    children: Vec<usize>,
+    tried: HashSet<Position>,
+    forgotten_f: u64,
+    complete: bool,
+    queued: bool,
+}
+
+struct Memory<'a> {
+    grid: &'a Grid,
+    min_step_cost: u64,
+    max_nodes: usize,
+    nodes: HashMap<usize, Node>,
+    next_id: usize,
+    queue: BTreeSet<(u64, Reverse<usize>, usize)>,
+    best_g: HashMap<Position, (u64, usize)>,
+    counter: PerformanceCounter,
+}
+
+impl Memory<'_> {
+    fn heuristic(&self, pos: &Position) -> u64 {
+        self.grid.scaled_heuristic(pos, self.min_step_cost)
+    }
+
+    fn enqueue(&mut self, id: usize) {
+        let node = self.nodes.get_mut(&id).expect("node is stored");
+        if !node.queued {
+            node.queued = true;
+            self.queue.insert((node.f, Reverse(node.depth), id));
+        }
+    }
+
+    fn dequeue(&mut self, id: usize) {
+        let node = self.nodes.get_mut(&id).expect("node is stored");
+        if node.queued {
+            node.queued = false;
+            self.queue.remove(&(node.f, Reverse(node.depth), id));
+        }
+    }
+
+    fn set_f(&mut self, id: usize, f: u64) {
+        let queued = self.nodes[&id].queued;
+        self.dequeue(id);
+        self.nodes.get_mut(&id).expect("node is stored").f = f;
+        if queued {
+            self.enqueue(id);
+        }
+    }
+
+    fn store(&mut self, position: Position, g: u64, f: u64, parent: Option<usize>) -> usize {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        let depth = parent.map_or(0, |parent| self.nodes[&parent].depth + 1);
+        self.nodes.insert(id, Node {
+            position,
+            g,
+            f,
+            depth,
+            parent,
+            children: Vec::new(),
+            tried: HashSet::new(),
+            forgotten_f: u64::MAX,
+            complete: false,
+            queued: false,
+        });
+
+        if let Some(parent) = parent {
+            let parent = self.nodes.get_mut(&parent).expect("parent is stored");
+            parent.children.push(id);
+            parent.tried.insert(position);
+        }
+        self.best_g.insert(position, (g, id));
+        self.enqueue(id);
+
+        self.counter.add_to_frontier();
+        self.counter.allocate_memory(1);
+        self.counter.store_nodes(self.nodes.len());
+        id
+    }
+
+    fn candidates(&mut self, id: usize) -> Vec<(Position, u64)> {
+        let node = &self.nodes[&id];
+        let mut excluded: HashSet<Position> = node.children.iter().map(|child| self.nodes[child].position).collect();
+
+        let mut ancestor = Some(id);
+        while let Some(current) = ancestor {
+            excluded.insert(self.nodes[&current].position);
+            ancestor = self.nodes[&current].parent;
+        }
+
+        let mut candidates = Vec::new();
+        for neighbor in self.grid.get_neighbors(&node.position) {
+            self.counter.compare();
+            if excluded.contains(&neighbor) || node.tried.contains(&neighbor) {
+                continue;
+            }
+
+            let g = node.g + self.grid.scaled_move_cost(&node.position, &neighbor);
+            if self.best_g.get(&neighbor).is_some_and(|&(best, _)| best <= g) {
+                continue;
+            }
+            candidates.push((neighbor, g));
+        }
+
+        candidates.sort_unstable_by_key(|&(neighbor, g)| g + self.heuristic(&neighbor));
+        candidates
+    }
+
+    fn forget_worst_leaf(&mut self, protected: usize) -> bool {
+        let victim = self.queue.iter().rev()
+            .map(|&(_, _, id)| id)
+            .find(|&id| id != protected && self.nodes[&id].parent.is_some() && self.nodes[&id].children.is_empty());
+
+        let Some(victim) = victim else {
+            return false;
+        };
+
+        self.dequeue(victim);
+        let node = self.nodes.remove(&victim).expect("victim is stored");
+        if self.best_g.get(&node.position).is_some_and(|&(_, owner)| owner == victim) {
+            self.best_g.remove(&node.position);
+        }
+
+        if let Some(parent_id) = node.parent {
+            let parent = self.nodes.get_mut(&parent_id).expect("parent is stored");
+            parent.children.retain(|&child| child != victim);
+            parent.forgotten_f = parent.forgotten_f.min(node.f);
+            parent.complete = false;
+            self.enqueue(parent_id);
+        }
+        true
+    }
+
+    fn finish_round(&mut self, id: usize) {
+        let children_f = self.children_f(id);
+        let node = self.nodes.get_mut(&id).expect("node is stored");
+
+        if node.forgotten_f == u64::MAX {
+            node.complete = true;
+            if !node.children.is_empty() {
+                self.dequeue(id);
+            }
+            self.back_up(id);
+            return;
+        }
+
+        let raised = children_f.min(node.forgotten_f).max(node.f);
+        let parent = node.parent;
+        node.forgotten_f = u64::MAX;
+        node.tried.clear();
+        self.set_f(id, raised);
+        if let Some(parent) = parent {
+            self.back_up(parent);
+        }
+    }
+
+    fn children_f(&self, id: usize) -> u64 {
+        self.nodes[&id].children.iter().map(|child| self.nodes[child].f).min().unwrap_or(u64::MAX)
+    }
+
+    fn back_up(&mut self, id: usize) {
+        let mut current = Some(id);
+
+        while let Some(id) = current {
+            let node = &self.nodes[&id];
+            if !node.complete {
+                break;
+            }
+
+            let backed_up = self.children_f(id).max(node.f);
+            if backed_up == node.f && !node.children.is_empty() {
+                break;
+            }
+
+            current = node.parent;
+            self.set_f(id, backed_up);
+        }
+    }
+
+    fn path_to(&self, id: usize) -> Vec<Position> {
+        let mut path = Vec::new();
+        let mut current = Some(id);
+
+        while let Some(id) = current {
+            path.push(self.nodes[&id].position);
+            current = self.nodes[&id].parent;
+        }
+
+        path.reverse();
+        path
+    }
+}
+
+pub fn memory_budget(astar_peak: usize) -> usize {
+    (astar_peak * ASTAR_PEAK_PERCENT / 100).max(2)
+}
+
+pub fn find_path(grid: &Grid) -> Result<(Vec<Position>, PerformanceCounter), String> {
+    find_path_with_memory(grid, (grid.width * grid.height).max(2))
+}
+
+pub fn find_path_with_memory(grid: &Grid, max_nodes: usize) -> Result<(Vec<Position>, PerformanceCounter), String> {
+    if max_nodes < 2 {
+        return Err("SMA* needs room for at least two nodes".to_string());
+    }
+
+    let mut memory = Memory {
+        grid,
+        min_step_cost: grid.min_step_cost() as u64,
+        max_nodes,
+        nodes: HashMap::new(),
+        next_id: 0,
+        queue: BTreeSet::new(),
+        best_g: HashMap::new(),
+        counter: PerformanceCounter::new(),
+    };
+    let root_f = memory.heuristic(&grid.start);
+    memory.store(grid.start, 0, root_f, None);
+
+    while let Some(&(f, _, best)) = memory.queue.first() {
+        if f == u64::MAX {
+            break;
+        }
+        if memory.nodes[&best].position == grid.end {
+            return Ok((memory.path_to(best), memory.counter));
+        }
+        if memory.counter.nodes_explored >= EXPANSION_LIMIT {
+            return Err(format!("SMA* gave up after {} expansions", EXPANSION_LIMIT));
+        }
+
+        let candidates = memory.candidates(best);
+        let Some(&(position, g)) = candidates.first() else {
+            memory.finish_round(best);
+            continue;
+        };
+
+        memory.counter.explore_node();
+        if memory.nodes.len() >= memory.max_nodes && !memory.forget_worst_leaf(best) {
+            memory.set_f(best, u64::MAX);
+            if let Some(parent) = memory.nodes[&best].parent {
+                memory.back_up(parent);
+            }
+            continue;
+        }
+
+        let (depth, parent_f) = (memory.nodes[&best].depth + 1, memory.nodes[&best].f);
+        let f = if position != grid.end && depth + 1 >= memory.max_nodes {
+            u64::MAX
+        } else {
+            parent_f.max(g + memory.heuristic(&position))
+        };
+        memory.store(position, g, f, Some(best));
+
+        if candidates.len() == 1 {
+            memory.finish_round(best);
+        }
+    }
+
+    Ok((Vec::new(), memory.counter))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pathfinder::{astar, ida_star, CellType, MovementModel};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn test_sma_star_optimal_within_memory_limit() {
+        for seed in 0..15 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let mut grid = Grid::new(10, 10, Position::new(0, 0), Position::new(9, 9)).with_movement(MovementModel::EightWay);
+            for _ in 0..30 {
+                let pos = Position::new(rng.random_range(0..10), rng.random_range(0..10));
+                let cell = if rng.random_bool(0.6) { CellType::Blocked } else { CellType::Mud };
+                grid.set_terrain(pos, cell);
+            }
+
+            let (expected, _) = astar::find_path(&grid).unwrap();
+            if expected.is_empty() {
+                continue;
+            }
+            let (path, counter) = find_path_with_memory(&grid, 40).unwrap();
+
+            assert_eq!(grid.scaled_path_cost(&path), grid.scaled_path_cost(&expected));
+            assert!(counter.peak_nodes_stored <= 40);
+        }
+    }
+
+    #[test]
+    fn test_memory_bounded_searches_match_astar_on_weighted_grid() {
+        for (seed, movement) in [(1, MovementModel::FourWay), (2, MovementModel::EightWay), (3, MovementModel::EightWay)] {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let mut grid = Grid::new(20, 20, Position::new(0, 0), Position::new(19, 19)).with_movement(movement);
+            for row in 0..20 {
+                for col in 0..20 {
+                    let terrain = match rng.random_range(0..100) {
+                        0..35 => CellType::Mud,
+                        35..55 => CellType::Water,
+                        _ => CellType::Open,
+                    };
+                    grid.set_terrain(Position::new(row, col), terrain);
+                }
+            }
+
+            let (expected, astar_counter) = astar::find_path(&grid).unwrap();
+            let (ida_path, _) = ida_star::find_path(&grid).unwrap();
+            let (sma_path, sma_counter) = find_path_with_memory(&grid, memory_budget(astar_counter.memory_allocations)).unwrap();
+
+            assert_eq!(grid.scaled_path_cost(&ida_path), grid.scaled_path_cost(&expected));
+            assert_eq!(grid.scaled_path_cost(&sma_path), grid.scaled_path_cost(&expected));
+            assert!(sma_counter.peak_nodes_stored <= memory_budget(astar_counter.memory_allocations));
+        }
+    }
+
+    #[test]
+    fn test_sma_star_too_little_memory_for_path() {
+        let grid = Grid::new(10, 1, Position::new(0, 0), Position::new(0, 9));
+
+        assert!(find_path_with_memory(&grid, 5).unwrap().0.is_empty());
+        assert_eq!(find_path_with_memory(&grid, 10).unwrap().0.len(), 10);
+        assert!(find_path_with_memory(&grid, 1).is_err());
+    }
+}
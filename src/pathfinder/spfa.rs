@@ -0,0 +1,87 @@
+use crate::pathfinder::{NegativeCycle, PerformanceCounter, ShortestPathTree, SignedGraph};
+use std::collections::VecDeque;
+
+pub fn shortest_paths(graph: &SignedGraph, source: usize) -> Result<(ShortestPathTree, PerformanceCounter), NegativeCycle> {
+    let nodes = graph.node_count();
+    let mut counter = PerformanceCounter::new();
+    let mut tree = ShortestPathTree::new(source, nodes);
+    let mut queue = VecDeque::from([source]);
+    let mut queued = vec![false; nodes];
+    let mut hops = vec![0usize; nodes];
+
+    queued[source] = true;
+    counter.add_to_frontier();
+
+    while let Some(from) = queue.pop_front() {
+        queued[from] = false;
+        counter.explore_node();
+        let distance = tree.distances[from].unwrap_or_default();
+
+        for &(to, weight) in graph.edges(from) {
+            counter.compare();
+            let candidate = distance.saturating_add(weight);
+            if tree.distances[to].is_some_and(|current| candidate >= current) {
+                continue;
+            }
+
+            tree.distances[to] = Some(candidate);
+            tree.predecessors[to] = Some(from);
+            hops[to] = hops[from] + 1;
+
+            if hops[to] >= nodes
+                && let Some(cycle) = NegativeCycle::from_predecessors(graph, &tree.predecessors, to)
+            {
+                return Err(cycle);
+            }
+
+            if !queued[to] {
+                queued[to] = true;
+                queue.push_back(to);
+                counter.add_to_frontier();
+            }
+        }
+    }
+
+    Ok((tree, counter))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pathfinder::bellman_ford;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_spfa_matches_bellman_ford() {
+        for seed in 0..10 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let graph = SignedGraph::random_with_potentials(30, 90, &mut rng);
+
+            let (tree, counter) = shortest_paths(&graph, 0).unwrap();
+            let (expected, reference) = bellman_ford::shortest_paths(&graph, 0).unwrap();
+
+            assert_eq!(tree.distances, expected.distances);
+            assert!(counter.comparisons <= reference.comparisons);
+            for target in 0..30 {
+                let path = tree.path_to(target);
+                assert_eq!(path.and_then(|path| graph.path_weight(&path)), expected.distances[target]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_spfa_negative_cycle_witness() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut graph = SignedGraph::random_with_potentials(20, 60, &mut rng);
+        graph.add_edge(0, 5, 1);
+        graph.add_edge(5, 9, -300);
+        graph.add_edge(9, 5, 100);
+
+        let cycle = shortest_paths(&graph, 0).unwrap_err();
+
+        assert!(cycle.weight < 0);
+        assert_eq!(graph.path_weight(&cycle.cycle), Some(cycle.weight));
+        assert_eq!(cycle.cycle.first(), cycle.cycle.last());
+    }
+}
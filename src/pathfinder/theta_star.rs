@@ -0,0 +1,147 @@
+use crate::pathfinder::{Grid, Heuristic, MovementModel, Position, PerformanceCounter};
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::cmp::Ordering;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Node {
+    pub position: Position,
+    pub f_score: u64,
+}
+
+impl PartialOrd for Node {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Node {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.f_score.cmp(&self.f_score)
+    }
+}
+
+pub fn find_path(grid: &Grid) -> Result<(Vec<Position>, PerformanceCounter), String> {
+    if grid.movement == MovementModel::Hex {
+        return Err("Theta* needs a square grid for line-of-sight checks".to_string());
+    }
+
+    let mut counter = PerformanceCounter::new();
+    let mut open_set = BinaryHeap::new();
+    let mut parent: HashMap<Position, Position> = HashMap::new();
+    let mut g_score: HashMap<Position, u64> = HashMap::new();
+    let mut closed: HashSet<Position> = HashSet::new();
+    let min_step_cost = grid.min_step_cost() as u64;
+    let heuristic = |pos: &Position| Heuristic::Euclidean.scaled_distance(pos, &grid.end) * min_step_cost;
+
+    g_score.insert(grid.start, 0);
+    parent.insert(grid.start, grid.start);
+    open_set.push(Node { position: grid.start, f_score: heuristic(&grid.start) });
+
+    counter.add_to_frontier();
+    counter.allocate_memory(1);
+
+    while let Some(Node { position: current, .. }) = open_set.pop() {
+        if !closed.insert(current) {
+            continue;
+        }
+        counter.explore_node();
+
+        if current == grid.end {
+            return Ok((reconstruct_path(&parent, current), counter));
+        }
+
+        let current_parent = parent[&current];
+
+        for neighbor in grid.get_neighbors(&current) {
+            counter.compare();
+
+            if closed.contains(&neighbor) {
+                continue;
+            }
+
+            let through_parent = if current_parent != current {
+                counter.compare();
+                grid.scaled_segment_cost(&current_parent, &neighbor)
+            } else {
+                None
+            };
+
+            let (candidate_parent, candidate_g) = match through_parent {
+                Some(cost) => (current_parent, g_score[&current_parent] + cost),
+                None => (current, g_score[&current] + grid.scaled_move_cost(&current, &neighbor)),
+            };
+
+            if candidate_g < *g_score.get(&neighbor).unwrap_or(&u64::MAX) {
+                g_score.insert(neighbor, candidate_g);
+                parent.insert(neighbor, candidate_parent);
+                open_set.push(Node { position: neighbor, f_score: candidate_g + heuristic(&neighbor) });
+                counter.add_to_frontier();
+                counter.allocate_memory(1);
+            }
+        }
+    }
+
+    Ok((Vec::new(), counter))
+}
+
+pub(crate) fn reconstruct_path(parent: &HashMap<Position, Position>, mut current: Position) -> Vec<Position> {
+    let mut path = vec![current];
+
+    while let Some(&previous) = parent.get(&current) {
+        if previous == current {
+            break;
+        }
+        current = previous;
+        path.push(current);
+    }
+
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pathfinder::{astar, CellType};
+
+    #[test]
+    fn test_theta_star_straight_line_on_open_grid() {
+        let start = Position::new(0, 0);
+        let end = Position::new(3, 6);
+        let grid = Grid::new(7, 4, start, end).with_movement(MovementModel::EightWay);
+
+        let (path, _) = find_path(&grid).unwrap();
+        assert_eq!(path, vec![start, end]);
+
+        let (grid_path, _) = astar::find_path(&grid).unwrap();
+        assert!(grid.path_cost(&path) < grid.path_cost(&grid_path));
+    }
+
+    #[test]
+    fn test_theta_star_waypoints_keep_line_of_sight() {
+        let start = Position::new(0, 0);
+        let end = Position::new(6, 6);
+        let mut grid = Grid::new(7, 7, start, end).with_movement(MovementModel::EightWay);
+        for row in 0..5 {
+            grid.add_obstacle(Position::new(row, 3));
+        }
+
+        let (path, _) = find_path(&grid).unwrap();
+        assert_eq!(path[0], start);
+        assert_eq!(path[path.len() - 1], end);
+        assert!(path.len() >= 3);
+
+        for pair in path.windows(2) {
+            assert!(grid.line_of_sight(&pair[0], &pair[1]));
+        }
+        for pos in &path {
+            assert_ne!(grid.cells[pos.row][pos.col], CellType::Blocked);
+        }
+    }
+
+    #[test]
+    fn test_theta_star_rejects_hex_grid() {
+        let grid = Grid::new(3, 3, Position::new(0, 0), Position::new(2, 2)).with_movement(MovementModel::Hex);
+        assert!(find_path(&grid).is_err());
+    }
+}
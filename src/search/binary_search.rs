@@ -1,4 +1,10 @@
-pub fn search(data: &[String], target: &str) -> (bool, usize) {
+use std::borrow::Borrow;
+
+pub fn search<T, Q>(data: &[T], target: &Q) -> (bool, usize)
+where
+    T: Borrow<Q>,
+    Q: Ord + ?Sized,
+{
     let mut left = 0;
     let mut right = data.len();
     let mut comparisons = 0;
@@ -7,7 +13,7 @@ pub fn search(data: &[String], target: &str) -> (bool, usize) {
         let mid = left + (right - left) / 2;
         comparisons += 1;
         
-        match data[mid].as_str().cmp(target) {
+        match data[mid].borrow().cmp(target) {
             std::cmp::Ordering::Equal => return (true, comparisons),
             std::cmp::Ordering::Less => left = mid + 1,
             std::cmp::Ordering::Greater => right = mid,
@@ -0,0 +1,254 @@
+use super::membership_filter::{hash_with_seed, MembershipFilter};
+
+const BLOCK_WORDS: usize = 8;
+const BLOCK_BITS: usize = BLOCK_WORDS * 64;
+const COUNTER_MAX: u8 = 15;
+
+pub fn optimal_parameters(expected_items: usize, false_positive_rate: f64) -> (usize, usize) {
+    let n = expected_items.max(1) as f64;
+    let p = false_positive_rate.clamp(f64::MIN_POSITIVE, 0.5);
+    let ln2 = std::f64::consts::LN_2;
+
+    let bits = (-n * p.ln() / (ln2 * ln2)).ceil().max(64.0) as usize;
+    let hashes = ((bits as f64 / n) * ln2).round().max(1.0) as usize;
+    (bits, hashes)
+}
+
+fn blocked_false_positive_rate(blocks: usize, expected_items: usize, hashes: usize) -> f64 {
+    let load = expected_items.max(1) as f64 / blocks as f64;
+    let limit = (load + 10.0 * load.sqrt() + 20.0) as usize;
+    let mut probability = (-load).exp();
+    let mut rate = 0.0;
+
+    for items in 0..=limit {
+        if items > 0 {
+            probability *= load / items as f64;
+        }
+        let filled = 1.0 - (1.0 - 1.0 / BLOCK_BITS as f64).powf((hashes * items) as f64);
+        rate += probability * filled.powi(hashes as i32);
+    }
+    rate
+}
+
+fn probes(item: &str, hashes: usize, modulus: usize) -> impl Iterator<Item = usize> {
+    let h1 = hash_with_seed(item, 0);
+    let h2 = hash_with_seed(item, 1) | 1;
+    (0..hashes as u64).map(move |i| (h1.wrapping_add(i.wrapping_mul(h2)) % modulus as u64) as usize)
+}
+
+#[derive(Debug, Clone)]
+pub struct BloomFilter {
+    bits: Vec<u64>,
+    bit_count: usize,
+    hashes: usize,
+}
+
+impl BloomFilter {
+    pub fn new(expected_items: usize, false_positive_rate: f64) -> Self {
+        let (bit_count, hashes) = optimal_parameters(expected_items, false_positive_rate);
+        Self {
+            bits: vec![0; bit_count.div_ceil(64)],
+            bit_count,
+            hashes,
+        }
+    }
+
+    pub fn hashes(&self) -> usize {
+        self.hashes
+    }
+}
+
+impl MembershipFilter for BloomFilter {
+    fn name(&self) -> &'static str {
+        "Bloom Filter"
+    }
+
+    fn insert(&mut self, item: &str) -> bool {
+        for bit in probes(item, self.hashes, self.bit_count) {
+            self.bits[bit / 64] |= 1 << (bit % 64);
+        }
+        true
+    }
+
+    fn contains(&self, item: &str) -> bool {
+        probes(item, self.hashes, self.bit_count).all(|bit| self.bits[bit / 64] & (1 << (bit % 64)) != 0)
+    }
+
+    fn memory_bits(&self) -> usize {
+        self.bits.len() * 64
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CountingBloomFilter {
+    counters: Vec<u8>,
+    counter_count: usize,
+    hashes: usize,
+}
+
+impl CountingBloomFilter {
+    pub fn new(expected_items: usize, false_positive_rate: f64) -> Self {
+        let (counter_count, hashes) = optimal_parameters(expected_items, false_positive_rate);
+        Self {
+            counters: vec![0; counter_count.div_ceil(2)],
+            counter_count,
+            hashes,
+        }
+    }
+
+    fn counter(&self, index: usize) -> u8 {
+        (self.counters[index / 2] >> ((index % 2) * 4)) & 0x0F
+    }
+
+    fn set_counter(&mut self, index: usize, value: u8) {
+        let shift = (index % 2) * 4;
+        let byte = &mut self.counters[index / 2];
+        *byte = (*byte & !(0x0F << shift)) | (value << shift);
+    }
+
+    pub fn remove(&mut self, item: &str) -> bool {
+        if !self.contains(item) {
+            return false;
+        }
+
+        for index in probes(item, self.hashes, self.counter_count) {
+            let value = self.counter(index);
+            if value < COUNTER_MAX {
+                self.set_counter(index, value - 1);
+            }
+        }
+        true
+    }
+}
+
+impl MembershipFilter for CountingBloomFilter {
+    fn name(&self) -> &'static str {
+        "Counting Bloom Filter"
+    }
+
+    fn insert(&mut self, item: &str) -> bool {
+        for index in probes(item, self.hashes, self.counter_count) {
+            let value = self.counter(index);
+            if value < COUNTER_MAX {
+                self.set_counter(index, value + 1);
+            }
+        }
+        true
+    }
+
+    fn contains(&self, item: &str) -> bool {
+        probes(item, self.hashes, self.counter_count).all(|index| self.counter(index) > 0)
+    }
+
+    fn memory_bits(&self) -> usize {
+        self.counters.len() * 8
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BlockedBloomFilter {
+    blocks: Vec<[u64; BLOCK_WORDS]>,
+    hashes: usize,
+}
+
+impl BlockedBloomFilter {
+    pub fn new(expected_items: usize, false_positive_rate: f64) -> Self {
+        let (bit_count, hashes) = optimal_parameters(expected_items, false_positive_rate);
+        let mut blocks = bit_count.div_ceil(BLOCK_BITS);
+        while blocked_false_positive_rate(blocks, expected_items, hashes) > false_positive_rate {
+            blocks += blocks.div_ceil(20);
+        }
+
+        Self {
+            blocks: vec![[0; BLOCK_WORDS]; blocks],
+            hashes,
+        }
+    }
+
+    fn block_and_bits(&self, item: &str) -> (usize, impl Iterator<Item = usize> + use<>) {
+        let block = (hash_with_seed(item, 0) % self.blocks.len() as u64) as usize;
+        let mut state = hash_with_seed(item, 1);
+        let bits = (0..self.hashes).map(move |_| {
+            state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut mixed = (state ^ (state >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            mixed = (mixed ^ (mixed >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            ((mixed ^ (mixed >> 31)) % BLOCK_BITS as u64) as usize
+        });
+        (block, bits)
+    }
+}
+
+impl MembershipFilter for BlockedBloomFilter {
+    fn name(&self) -> &'static str {
+        "Blocked Bloom Filter"
+    }
+
+    fn insert(&mut self, item: &str) -> bool {
+        let (block, bits) = self.block_and_bits(item);
+        let words = &mut self.blocks[block];
+        for bit in bits {
+            words[bit / 64] |= 1 << (bit % 64);
+        }
+        true
+    }
+
+    fn contains(&self, item: &str) -> bool {
+        let (block, mut bits) = self.block_and_bits(item);
+        let words = &self.blocks[block];
+        bits.all(|bit| words[bit / 64] & (1 << (bit % 64)) != 0)
+    }
+
+    fn memory_bits(&self) -> usize {
+        self.blocks.len() * BLOCK_BITS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(prefix: &str, count: usize) -> Vec<String> {
+        (0..count).map(|i| format!("{}{}", prefix, i)).collect()
+    }
+
+    fn measured_fpr(filter: &dyn MembershipFilter) -> f64 {
+        keys("absent", 100_000).iter().filter(|key| filter.contains(key)).count() as f64 / 100_000.0
+    }
+
+    #[test]
+    fn test_no_false_negatives_and_fpr_near_target() {
+        let members = keys("member", 10_000);
+        for target in [0.05, 0.01, 0.001] {
+            let mut filters: Vec<(Box<dyn MembershipFilter>, f64)> = vec![
+                (Box::new(BloomFilter::new(members.len(), target)), 1.5),
+                (Box::new(CountingBloomFilter::new(members.len(), target)), 1.5),
+                (Box::new(BlockedBloomFilter::new(members.len(), target)), 1.5),
+            ];
+
+            for (filter, tolerance) in &mut filters {
+                for member in &members {
+                    assert!(filter.insert(member));
+                }
+                assert!(members.iter().all(|member| filter.contains(member)), "{}", filter.name());
+
+                let measured = measured_fpr(filter.as_ref());
+                assert!(measured <= target * *tolerance, "{} target {} measured {}", filter.name(), target, measured);
+            }
+        }
+    }
+
+    #[test]
+    fn test_counting_remove_keeps_other_keys() {
+        let members = keys("member", 2_000);
+        let mut filter = CountingBloomFilter::new(members.len(), 0.01);
+        for member in &members {
+            filter.insert(member);
+        }
+
+        for member in members.iter().step_by(2) {
+            assert!(filter.remove(member));
+        }
+        assert!(members.iter().skip(1).step_by(2).all(|member| filter.contains(member)));
+        assert!(measured_fpr(&filter) <= 0.01);
+    }
+}
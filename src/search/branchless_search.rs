@@ -0,0 +1,42 @@
+pub fn search(data: &[u32], target: u32) -> (bool, usize) {
+    let (index, comparisons) = lower_bound(data, target);
+    let found = index < data.len() && data[index] == target;
+    (found, comparisons + 1)
+}
+
+pub fn lower_bound(data: &[u32], target: u32) -> (usize, usize) {
+    if data.is_empty() {
+        return (0, 0);
+    }
+
+    let mut base = 0;
+    let mut len = data.len();
+    let mut comparisons = 0;
+
+    while len > 1 {
+        let half = len / 2;
+        comparisons += 1;
+        base = std::hint::select_unpredictable(data[base + half - 1] < target, base + half, base);
+        len -= half;
+    }
+
+    comparisons += 1;
+    (base + usize::from(data[base] < target), comparisons)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search::binary_search;
+    use crate::search::layout_fixtures::{datasets, targets};
+
+    #[test]
+    fn test_matches_binary_search() {
+        for data in datasets() {
+            for target in targets(&data) {
+                assert_eq!(search(&data, target).0, binary_search::search(&data, &target).0, "n={} target={}", data.len(), target);
+                assert_eq!(lower_bound(&data, target).0, data.partition_point(|&key| key < target), "n={} target={}", data.len(), target);
+            }
+        }
+    }
+}
@@ -0,0 +1,235 @@
+use super::membership_filter::{hash_with_seed, MembershipFilter};
+
+const BUCKET_SIZE: usize = 4;
+const MAX_KICKS: usize = 500;
+const LOAD_FACTOR: f64 = 0.95;
+
+#[derive(Debug, Clone)]
+pub struct CuckooFilter {
+    slots: Vec<u16>,
+    bucket_mask: usize,
+    fingerprint_bits: u32,
+    len: usize,
+    kick_seed: u64,
+    victim: Option<(usize, u16)>,
+}
+
+impl CuckooFilter {
+    pub fn new(expected_items: usize, false_positive_rate: f64) -> Self {
+        let p = false_positive_rate.clamp(f64::MIN_POSITIVE, 0.5);
+        let fingerprint_bits = (2.0 * BUCKET_SIZE as f64 / p).log2().ceil().clamp(4.0, 16.0) as u32;
+        let buckets = ((expected_items.max(1) as f64 / (BUCKET_SIZE as f64 * LOAD_FACTOR)).ceil() as usize)
+            .next_power_of_two();
+
+        Self {
+            slots: vec![0; buckets * BUCKET_SIZE],
+            bucket_mask: buckets - 1,
+            fingerprint_bits,
+            len: 0,
+            kick_seed: 0x9E37_79B9_7F4A_7C15,
+            victim: None,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn fingerprint_bits(&self) -> u32 {
+        self.fingerprint_bits
+    }
+
+    pub fn load_factor(&self) -> f64 {
+        self.len as f64 / self.slots.len() as f64
+    }
+
+    fn fingerprint_and_index(&self, item: &str) -> (u16, usize) {
+        let hash = hash_with_seed(item, 0);
+        let mask = (1u64 << self.fingerprint_bits) - 1;
+        let fingerprint = ((hash >> 32) & mask).max(1) as u16;
+        (fingerprint, hash as usize & self.bucket_mask)
+    }
+
+    fn alternate_index(&self, index: usize, fingerprint: u16) -> usize {
+        let fingerprint_hash = (fingerprint as u64).wrapping_mul(0x5BD1_E995_5BD1_E995) >> 17;
+        (index ^ fingerprint_hash as usize) & self.bucket_mask
+    }
+
+    fn bucket(&self, index: usize) -> &[u16] {
+        &self.slots[index * BUCKET_SIZE..(index + 1) * BUCKET_SIZE]
+    }
+
+    fn try_place(&mut self, index: usize, fingerprint: u16) -> bool {
+        let bucket = &mut self.slots[index * BUCKET_SIZE..(index + 1) * BUCKET_SIZE];
+        match bucket.iter_mut().find(|slot| **slot == 0) {
+            Some(slot) => {
+                *slot = fingerprint;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn remove(&mut self, item: &str) -> bool {
+        let (fingerprint, first) = self.fingerprint_and_index(item);
+        let second = self.alternate_index(first, fingerprint);
+
+        if let Some((index, victim)) = self.victim
+            && victim == fingerprint
+            && (index == first || index == second)
+        {
+            self.victim = None;
+            self.len -= 1;
+            return true;
+        }
+
+        for index in [first, second] {
+            let bucket = &mut self.slots[index * BUCKET_SIZE..(index + 1) * BUCKET_SIZE];
+            if let Some(slot) = bucket.iter_mut().find(|slot| **slot == fingerprint) {
+                *slot = 0;
+                self.len -= 1;
+                if let Some((index, victim)) = self.victim.take() {
+                    self.place(index, victim);
+                }
+                return true;
+            }
+        }
+        false
+    }
+
+    fn place(&mut self, first: usize, mut fingerprint: u16) -> bool {
+        let second = self.alternate_index(first, fingerprint);
+        if self.try_place(first, fingerprint) || self.try_place(second, fingerprint) {
+            return true;
+        }
+
+        let mut index = if self.kick_seed & 1 == 0 { first } else { second };
+        for _ in 0..MAX_KICKS {
+            self.kick_seed ^= self.kick_seed << 13;
+            self.kick_seed ^= self.kick_seed >> 7;
+            self.kick_seed ^= self.kick_seed << 17;
+
+            let victim = index * BUCKET_SIZE + (self.kick_seed as usize % BUCKET_SIZE);
+            std::mem::swap(&mut fingerprint, &mut self.slots[victim]);
+
+            index = self.alternate_index(index, fingerprint);
+            if self.try_place(index, fingerprint) {
+                return true;
+            }
+        }
+
+        self.victim = Some((index, fingerprint));
+        false
+    }
+}
+
+impl MembershipFilter for CuckooFilter {
+    fn name(&self) -> &'static str {
+        "Cuckoo Filter"
+    }
+
+    fn insert(&mut self, item: &str) -> bool {
+        if self.victim.is_some() {
+            return false;
+        }
+
+        let (fingerprint, index) = self.fingerprint_and_index(item);
+        self.len += 1;
+        self.place(index, fingerprint)
+    }
+
+    fn contains(&self, item: &str) -> bool {
+        let (fingerprint, first) = self.fingerprint_and_index(item);
+        let second = self.alternate_index(first, fingerprint);
+
+        if let Some((index, victim)) = self.victim
+            && victim == fingerprint
+            && (index == first || index == second)
+        {
+            return true;
+        }
+
+        self.bucket(first).contains(&fingerprint) || self.bucket(second).contains(&fingerprint)
+    }
+
+    fn memory_bits(&self) -> usize {
+        self.slots.len() * u16::BITS as usize
+    }
+
+    fn packed_bits(&self) -> usize {
+        self.slots.len() * self.fingerprint_bits as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(prefix: &str, count: usize) -> Vec<String> {
+        (0..count).map(|i| format!("{}{}", prefix, i)).collect()
+    }
+
+    #[test]
+    fn test_no_false_negatives_and_fpr_near_target() {
+        for target in [0.05, 0.01, 0.001] {
+            let members = keys("member", 10_000);
+            let mut filter = CuckooFilter::new(members.len(), target);
+            for member in &members {
+                assert!(filter.insert(member));
+            }
+            assert!(members.iter().all(|member| filter.contains(member)));
+
+            let false_positives = keys("absent", 100_000).iter().filter(|key| filter.contains(key)).count();
+            let measured = false_positives as f64 / 100_000.0;
+            assert!(measured <= target * 1.5, "target {} measured {}", target, measured);
+        }
+    }
+
+    #[test]
+    fn test_memory_reports_allocated_slots() {
+        let filter = CuckooFilter::new(1_000, 0.01);
+        assert_eq!(filter.memory_bits(), filter.slots.len() * 16);
+        assert!(filter.packed_bits() < filter.memory_bits());
+    }
+
+    #[test]
+    fn test_remove_keeps_other_keys() {
+        let members = keys("member", 2_000);
+        let mut filter = CuckooFilter::new(members.len(), 0.05);
+        for member in members.iter().chain(&members[..100]) {
+            assert!(filter.insert(member));
+        }
+
+        for member in members.iter().step_by(2) {
+            assert!(filter.remove(member));
+        }
+        assert!(members.iter().skip(1).step_by(2).all(|member| filter.contains(member)));
+        assert!(members[..100].iter().all(|member| filter.contains(member)));
+        assert_eq!(filter.len(), 1_100);
+    }
+
+    #[test]
+    fn test_overflow_victim_is_placed_after_remove() {
+        let mut filter = CuckooFilter::new(64, 0.01);
+        let members = keys("member", 200);
+        let inserted: Vec<&String> = members.iter().take_while(|member| filter.insert(member)).collect();
+        let victim = &members[inserted.len()];
+
+        assert!(inserted.len() < members.len());
+        assert!(inserted.iter().all(|member| filter.contains(member)));
+        assert!(filter.contains(victim));
+        assert!(!filter.insert("rejected"));
+
+        for member in &inserted[..8] {
+            assert!(filter.remove(member));
+        }
+        assert!(inserted[8..].iter().all(|member| filter.contains(member)));
+        assert!(filter.contains(victim));
+        assert!(filter.insert("accepted"));
+        assert_eq!(filter.len(), inserted.len() - 6);
+    }
+}
@@ -0,0 +1,485 @@
+use crate::prelude::*;
+use rand::prelude::*;
+use rand::rngs::StdRng;
+use std::io::Read;
+use std::str::FromStr;
+
+const ENGLISH_LETTERS: &str = "etaoinshrdlcumwfgypbvkjxqz";
+const ENGLISH_FREQUENCIES: [f64; 26] = [
+    12.7, 9.1, 8.2, 7.5, 7.0, 6.7, 6.3, 6.1, 6.0, 4.3, 4.0, 2.8, 2.8,
+    2.4, 2.4, 2.2, 2.0, 2.0, 1.9, 1.5, 1.0, 0.8, 0.15, 0.15, 0.1, 0.07,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DatasetFormat {
+    Lines,
+    Csv,
+    Tsv,
+    Json,
+}
+
+impl DatasetFormat {
+    pub fn detect(path: &str) -> Self {
+        let path = path.strip_suffix(".gz").unwrap_or(path);
+        let extension = path.rsplit_once('.').map(|(_, ext)| ext).unwrap_or("");
+        extension.parse().unwrap_or(Self::Lines)
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Lines => "lines",
+            Self::Csv => "csv",
+            Self::Tsv => "tsv",
+            Self::Json => "json",
+        }
+    }
+}
+
+impl FromStr for DatasetFormat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "lines" | "txt" | "text" => Ok(Self::Lines),
+            "csv" => Ok(Self::Csv),
+            "tsv" => Ok(Self::Tsv),
+            "json" => Ok(Self::Json),
+            _ => Err(Error::validation(format!(
+                "Unknown dataset format '{}' (expected lines, csv, tsv or json)",
+                s
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LengthDistribution {
+    Uniform,
+    Normal { mean: f64, std_dev: f64 },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AlphabetDistribution {
+    Uniform(String),
+    English,
+}
+
+impl FromStr for AlphabetDistribution {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "english" => Ok(Self::English),
+            "uniform" => Ok(Self::Uniform("abcdefghijklmnopqrstuvwxyz".to_string())),
+            "binary" => Ok(Self::Uniform("ab".to_string())),
+            "dna" => Ok(Self::Uniform("acgt".to_string())),
+            _ => Err(Error::validation(format!(
+                "Unknown alphabet '{}' (expected english, uniform, binary or dna)",
+                s
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SyntheticConfig {
+    pub count: usize,
+    pub min_length: usize,
+    pub max_length: usize,
+    pub length_distribution: LengthDistribution,
+    pub alphabet: AlphabetDistribution,
+    pub seed: Option<u64>,
+}
+
+impl Default for SyntheticConfig {
+    fn default() -> Self {
+        Self {
+            count: 50_000,
+            min_length: 2,
+            max_length: 12,
+            length_distribution: LengthDistribution::Normal { mean: 7.0, std_dev: 2.5 },
+            alphabet: AlphabetDistribution::English,
+            seed: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum DatasetSource {
+    File {
+        path: String,
+        format: Option<DatasetFormat>,
+        column: Option<String>,
+    },
+    Synthetic(SyntheticConfig),
+}
+
+impl DatasetSource {
+    pub fn file(path: &str) -> Self {
+        Self::File {
+            path: path.to_string(),
+            format: None,
+            column: None,
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            Self::File { path, format, column } => {
+                let format = format.unwrap_or_else(|| DatasetFormat::detect(path));
+                match column {
+                    Some(column) => format!("{} ({}, column '{}')", path, format.as_str(), column),
+                    None => format!("{} ({})", path, format.as_str()),
+                }
+            }
+            Self::Synthetic(config) => format!(
+                "synthetic ({} words, length {}-{})",
+                config.count, config.min_length, config.max_length
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LoadOptions {
+    pub lowercase: bool,
+    pub min_length: usize,
+}
+
+impl Default for LoadOptions {
+    fn default() -> Self {
+        Self {
+            lowercase: false,
+            min_length: 1,
+        }
+    }
+}
+
+pub async fn load(source: &DatasetSource, options: &LoadOptions) -> Result<Vec<String>> {
+    let words = match source {
+        DatasetSource::File { path, format, column } => {
+            let content = read_file(path).await?;
+            let format = format.unwrap_or_else(|| DatasetFormat::detect(path));
+            parse(&content, format, column.as_deref())?
+        }
+        DatasetSource::Synthetic(config) => generate_synthetic(config)?,
+    };
+
+    Ok(apply_options(words, options))
+}
+
+async fn read_file(path: &str) -> Result<String> {
+    let bytes = tokio::fs::read(path).await
+        .map_err(|e| Error::Generic(format!("Failed to read file {}: {}", path, e)))?;
+
+    if path.ends_with(".gz") {
+        decompress(&bytes).map_err(|e| Error::Generic(format!("Failed to decompress {}: {}", path, e)))
+    } else {
+        String::from_utf8(bytes)
+            .map_err(|_| Error::validation(format!("{} is not valid UTF-8", path)))
+    }
+}
+
+fn decompress(bytes: &[u8]) -> std::io::Result<String> {
+    let mut content = String::new();
+    flate2::read::GzDecoder::new(bytes).read_to_string(&mut content)?;
+    Ok(content)
+}
+
+pub fn parse(content: &str, format: DatasetFormat, column: Option<&str>) -> Result<Vec<String>> {
+    match format {
+        DatasetFormat::Lines => Ok(content.lines().map(|line| line.to_string()).collect()),
+        DatasetFormat::Csv => parse_delimited(content, ',', column),
+        DatasetFormat::Tsv => parse_delimited(content, '\t', column),
+        DatasetFormat::Json => parse_json(content, column),
+    }
+}
+
+fn parse_delimited(content: &str, delimiter: char, column: Option<&str>) -> Result<Vec<String>> {
+    let mut rows = content.lines().filter(|line| !line.trim().is_empty()).peekable();
+
+    // Only a named column implies a header row; by index the first line is data.
+    let index = match column.map(|column| (column, column.parse::<usize>())) {
+        None => 0,
+        Some((_, Ok(index))) => index,
+        Some((column, Err(_))) => {
+            let Some(header) = rows.next() else {
+                return Ok(Vec::new());
+            };
+            let header = split_record(header, delimiter);
+            header.iter()
+                .position(|name| name.trim() == column)
+                .ok_or_else(|| Error::not_found(format!("Column '{}' not found in header {:?}", column, header)))?
+        }
+    };
+
+    if let Some(first) = rows.peek() {
+        let width = split_record(first, delimiter).len();
+        if index >= width {
+            return Err(Error::validation(format!("Column index {} out of range ({} columns)", index, width)));
+        }
+    }
+
+    Ok(rows
+        .filter_map(|row| split_record(row, delimiter).into_iter().nth(index))
+        .collect())
+}
+
+fn split_record(line: &str, delimiter: char) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            c if c == delimiter && !in_quotes => fields.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+
+    fields.push(field);
+    fields
+}
+
+fn parse_json(content: &str, column: Option<&str>) -> Result<Vec<String>> {
+    let value: serde_json::Value = serde_json::from_str(content)
+        .map_err(|e| Error::validation(format!("Invalid JSON: {}", e)))?;
+
+    let serde_json::Value::Array(items) = value else {
+        return Err(Error::validation("JSON dataset must be a top-level array"));
+    };
+
+    let mut words = Vec::with_capacity(items.len());
+    for item in items {
+        let field = match (&item, column) {
+            (serde_json::Value::Object(object), Some(column)) => object.get(column).cloned(),
+            (serde_json::Value::Object(_), None) => {
+                return Err(Error::input("JSON objects need a --column naming the field to search"));
+            }
+            _ => Some(item),
+        };
+
+        match field {
+            Some(serde_json::Value::String(word)) => words.push(word),
+            Some(serde_json::Value::Number(number)) => words.push(number.to_string()),
+            _ => {}
+        }
+    }
+
+    Ok(words)
+}
+
+fn apply_options(words: Vec<String>, options: &LoadOptions) -> Vec<String> {
+    words
+        .into_iter()
+        .map(|word| {
+            let word = word.trim();
+            if options.lowercase { word.to_lowercase() } else { word.to_string() }
+        })
+        .filter(|word| !word.is_empty() && word.chars().count() >= options.min_length)
+        .collect()
+}
+
+pub fn generate_synthetic(config: &SyntheticConfig) -> Result<Vec<String>> {
+    if config.min_length == 0 || config.min_length > config.max_length {
+        return Err(Error::validation("Synthetic word lengths must satisfy 1 <= min <= max"));
+    }
+
+    let (letters, cumulative) = match &config.alphabet {
+        AlphabetDistribution::English => {
+            let total: f64 = ENGLISH_FREQUENCIES.iter().sum();
+            let mut running = 0.0;
+            let cumulative: Vec<f64> = ENGLISH_FREQUENCIES.iter()
+                .map(|f| {
+                    running += f / total;
+                    running
+                })
+                .collect();
+            (ENGLISH_LETTERS.chars().collect::<Vec<_>>(), Some(cumulative))
+        }
+        AlphabetDistribution::Uniform(alphabet) => (alphabet.chars().collect::<Vec<_>>(), None),
+    };
+
+    if letters.is_empty() {
+        return Err(Error::validation("Synthetic alphabet must not be empty"));
+    }
+
+    let mut rng = match config.seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_rng(&mut rand::rng()),
+    };
+
+    let words = (0..config.count)
+        .map(|_| {
+            let length = match config.length_distribution {
+                LengthDistribution::Uniform => rng.random_range(config.min_length..=config.max_length),
+                LengthDistribution::Normal { mean, std_dev } => {
+                    let u1: f64 = rng.random_range(f64::EPSILON..1.0);
+                    let u2: f64 = rng.random();
+                    let z = (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos();
+                    ((mean + z * std_dev).round().max(0.0) as usize).clamp(config.min_length, config.max_length)
+                }
+            };
+
+            (0..length)
+                .map(|_| match &cumulative {
+                    Some(cumulative) => {
+                        let point: f64 = rng.random();
+                        letters[cumulative.partition_point(|&c| c < point).min(letters.len() - 1)]
+                    }
+                    None => letters[rng.random_range(0..letters.len())],
+                })
+                .collect()
+        })
+        .collect();
+
+    Ok(words)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn words(list: &[&str]) -> Vec<String> {
+        list.iter().map(|word| word.to_string()).collect()
+    }
+
+    #[test]
+    fn test_delimited_column_selection() {
+        let csv = "id,name,price\n1,apple,3\n2,banana,1\n";
+        assert_eq!(parse(csv, DatasetFormat::Csv, Some("name")).unwrap(), words(&["apple", "banana"]));
+        assert_eq!(parse(csv, DatasetFormat::Csv, Some("price")).unwrap(), words(&["3", "1"]));
+        assert!(parse(csv, DatasetFormat::Csv, Some("missing")).is_err());
+
+        let tsv = "apple\t3\nbanana\t1\n";
+        assert_eq!(parse(tsv, DatasetFormat::Tsv, Some("1")).unwrap(), words(&["3", "1"]));
+        assert!(parse(tsv, DatasetFormat::Tsv, Some("2")).is_err());
+    }
+
+    #[test]
+    fn test_delimited_without_header_keeps_first_row() {
+        let csv = "apple,3\nbanana,1\n\ncherry,2\n";
+        assert_eq!(parse(csv, DatasetFormat::Csv, None).unwrap(), words(&["apple", "banana", "cherry"]));
+        assert_eq!(parse(csv, DatasetFormat::Csv, Some("0")).unwrap(), words(&["apple", "banana", "cherry"]));
+    }
+
+    #[test]
+    fn test_split_record_handles_quotes() {
+        assert_eq!(split_record(r#"a,"b,c",d"#, ','), words(&["a", "b,c", "d"]));
+        assert_eq!(split_record(r#""say ""hi""",x"#, ','), words(&[r#"say "hi""#, "x"]));
+        assert_eq!(split_record("a,,b", ','), words(&["a", "", "b"]));
+        assert_eq!(split_record("a\t\"b\tc\"", '\t'), words(&["a", "b\tc"]));
+    }
+
+    #[test]
+    fn test_json_arrays() {
+        assert_eq!(parse(r#"["apple", "banana", 42, null]"#, DatasetFormat::Json, None).unwrap(), words(&["apple", "banana", "42"]));
+
+        let objects = r#"[{"name": "apple"}, {"name": "banana"}, {"other": "x"}]"#;
+        assert_eq!(parse(objects, DatasetFormat::Json, Some("name")).unwrap(), words(&["apple", "banana"]));
+        assert!(parse(objects, DatasetFormat::Json, None).is_err());
+        assert!(parse(r#"{"name": "apple"}"#, DatasetFormat::Json, None).is_err());
+        assert!(parse("not json", DatasetFormat::Json, None).is_err());
+    }
+
+    #[test]
+    fn test_format_detection() {
+        assert_eq!(DatasetFormat::detect("words.csv"), DatasetFormat::Csv);
+        assert_eq!(DatasetFormat::detect("words.tsv.gz"), DatasetFormat::Tsv);
+        assert_eq!(DatasetFormat::detect("words.json"), DatasetFormat::Json);
+        assert_eq!(DatasetFormat::detect("words.txt.gz"), DatasetFormat::Lines);
+        assert_eq!(DatasetFormat::detect("words"), DatasetFormat::Lines);
+        assert_eq!("JSON".parse::<DatasetFormat>().unwrap(), DatasetFormat::Json);
+        assert!("xml".parse::<DatasetFormat>().is_err());
+        assert!("klingon".parse::<AlphabetDistribution>().is_err());
+    }
+
+    #[test]
+    fn test_gzip_round_trip() {
+        let content = "apple\nbanana\ncherry\n".repeat(100);
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(content.as_bytes()).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        assert!(compressed.len() < content.len());
+        assert_eq!(decompress(&compressed).unwrap(), content);
+        assert!(decompress(content.as_bytes()).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_load_gzipped_csv_file() {
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(b"word,count\nApple,3\nBanana,1\n").unwrap();
+        let path = std::env::temp_dir().join(format!("{}_dataset_loader_roundtrip.csv.gz", std::process::id()));
+        std::fs::write(&path, encoder.finish().unwrap()).unwrap();
+
+        let source = DatasetSource::File {
+            path: path.to_str().unwrap().to_string(),
+            format: None,
+            column: Some("word".to_string()),
+        };
+        let options = LoadOptions { lowercase: true, min_length: 1 };
+        let loaded = load(&source, &options).await;
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(loaded.unwrap(), words(&["apple", "banana"]));
+    }
+
+    #[test]
+    fn test_lowercase_and_min_length_are_opt_in() {
+        let raw = words(&["  Apple ", "Go", "", "BANANA", "a"]);
+        assert_eq!(apply_options(raw.clone(), &LoadOptions::default()), words(&["Apple", "Go", "BANANA", "a"]));
+
+        let options = LoadOptions { lowercase: true, min_length: 3 };
+        assert_eq!(apply_options(raw, &options), words(&["apple", "banana"]));
+    }
+
+    #[test]
+    fn test_synthetic_generator_is_seeded_and_bounded() {
+        let config = SyntheticConfig {
+            count: 5_000,
+            min_length: 3,
+            max_length: 9,
+            length_distribution: LengthDistribution::Uniform,
+            alphabet: "dna".parse().unwrap(),
+            seed: Some(7),
+        };
+
+        let generated = generate_synthetic(&config).unwrap();
+        assert_eq!(generated, generate_synthetic(&config).unwrap());
+        assert_eq!(generated.len(), 5_000);
+        assert!(generated.iter().all(|word| (3..=9).contains(&word.len())));
+        assert!(generated.iter().all(|word| word.chars().all(|c| "acgt".contains(c))));
+        for length in 3..=9 {
+            assert!(generated.iter().any(|word| word.len() == length));
+        }
+    }
+
+    #[test]
+    fn test_synthetic_english_distribution() {
+        let config = SyntheticConfig {
+            count: 5_000,
+            length_distribution: LengthDistribution::Normal { mean: 7.0, std_dev: 1.0 },
+            seed: Some(11),
+            ..SyntheticConfig::default()
+        };
+
+        let generated = generate_synthetic(&config).unwrap();
+        let mean = generated.iter().map(|word| word.len()).sum::<usize>() as f64 / generated.len() as f64;
+        assert!((mean - 7.0).abs() < 0.2, "mean length {}", mean);
+
+        let letters: String = generated.concat();
+        let count = |c: char| letters.chars().filter(|&l| l == c).count();
+        assert!(count('e') > count('t') && count('t') > count('z') * 20);
+
+        let invalid = SyntheticConfig { min_length: 5, max_length: 4, ..config };
+        assert!(generate_synthetic(&invalid).is_err());
+    }
+}
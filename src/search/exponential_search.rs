@@ -1,4 +1,10 @@
-pub fn search(data: &[String], target: &str) -> (bool, usize) {
+use std::borrow::Borrow;
+
+pub fn search<T, Q>(data: &[T], target: &Q) -> (bool, usize)
+where
+    T: Borrow<Q>,
+    Q: Ord + ?Sized,
+{
     if data.is_empty() {
         return (false, 0);
     }
@@ -7,12 +13,12 @@ pub fn search(data: &[String], target: &str) -> (bool, usize) {
     let mut comparisons = 0;
     
     comparisons += 1;
-    if data[0] == target {
+    if data[0].borrow() == target {
         return (true, comparisons);
     }
     
     let mut bound = 1;
-    while bound < n && data[bound].as_str() < target {
+    while bound < n && data[bound].borrow() < target {
         comparisons += 1;
         bound *= 2;
     }
@@ -24,7 +30,11 @@ pub fn search(data: &[String], target: &str) -> (bool, usize) {
     (found, comparisons + binary_comparisons)
 }
 
-fn binary_search_range(data: &[String], target: &str, left: usize, right: usize) -> (bool, usize) {
+fn binary_search_range<T, Q>(data: &[T], target: &Q, left: usize, right: usize) -> (bool, usize)
+where
+    T: Borrow<Q>,
+    Q: Ord + ?Sized,
+{
     let mut left = left;
     let mut right = right;
     let mut comparisons = 0;
@@ -33,7 +43,7 @@ fn binary_search_range(data: &[String], target: &str, left: usize, right: usize)
         let mid = left + (right - left) / 2;
         comparisons += 1;
         
-        match data[mid].as_str().cmp(target) {
+        match data[mid].borrow().cmp(target) {
             std::cmp::Ordering::Equal => return (true, comparisons),
             std::cmp::Ordering::Less => left = mid + 1,
             std::cmp::Ordering::Greater => right = mid,
@@ -0,0 +1,98 @@
+const PREFETCH_DISTANCE: usize = 16;
+
+#[derive(Debug, Clone)]
+pub struct EytzingerLayout {
+    layout: Vec<u32>,
+}
+
+impl EytzingerLayout {
+    pub fn build(sorted: &[u32]) -> Self {
+        let mut layout = vec![0; sorted.len() + 1];
+        let mut next = 0;
+        fill(sorted, &mut layout, &mut next, 1);
+        Self { layout }
+    }
+
+    pub fn len(&self) -> usize {
+        self.layout.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn as_slice(&self) -> &[u32] {
+        &self.layout[1..]
+    }
+
+    pub fn memory_bytes(&self) -> usize {
+        self.layout.len() * std::mem::size_of::<u32>()
+    }
+
+    pub fn search(&self, target: u32) -> (bool, usize) {
+        self.descend(target, false)
+    }
+
+    pub fn search_with_prefetch(&self, target: u32) -> (bool, usize) {
+        self.descend(target, true)
+    }
+
+    fn descend(&self, target: u32, prefetch_enabled: bool) -> (bool, usize) {
+        let n = self.len();
+        let mut k = 1;
+        let mut comparisons = 0;
+
+        while k <= n {
+            if prefetch_enabled {
+                prefetch(&self.layout, k * PREFETCH_DISTANCE);
+            }
+            comparisons += 1;
+            k = 2 * k + usize::from(self.layout[k] < target);
+        }
+
+        k >>= k.trailing_ones() + 1;
+        comparisons += 1;
+        (k != 0 && self.layout[k] == target, comparisons)
+    }
+}
+
+fn fill(sorted: &[u32], layout: &mut [u32], next: &mut usize, k: usize) {
+    if k < layout.len() {
+        fill(sorted, layout, next, 2 * k);
+        layout[k] = sorted[*next];
+        *next += 1;
+        fill(sorted, layout, next, 2 * k + 1);
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+fn prefetch(layout: &[u32], index: usize) {
+    use std::arch::x86_64::{_mm_prefetch, _MM_HINT_T0};
+
+    let address = layout.as_ptr().wrapping_add(index) as *const i8;
+    unsafe { _mm_prefetch(address, _MM_HINT_T0) };
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+fn prefetch(_layout: &[u32], _index: usize) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search::binary_search;
+    use crate::search::layout_fixtures::{datasets, targets};
+
+    #[test]
+    fn test_matches_binary_search() {
+        for data in datasets() {
+            let layout = EytzingerLayout::build(&data);
+            assert_eq!(layout.len(), data.len());
+
+            for target in targets(&data) {
+                let expected = binary_search::search(&data, &target).0;
+                assert_eq!(layout.search(target).0, expected, "n={} target={}", data.len(), target);
+                assert_eq!(layout.search_with_prefetch(target).0, expected, "n={} target={}", data.len(), target);
+            }
+        }
+    }
+}
@@ -0,0 +1,444 @@
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    pub word: String,
+    pub distance: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditDistance {
+    Levenshtein,
+    DamerauLevenshtein,
+}
+
+impl EditDistance {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Levenshtein => "Levenshtein",
+            Self::DamerauLevenshtein => "Damerau-Levenshtein (OSA)",
+        }
+    }
+
+    pub fn bounded(&self, a: &str, b: &str, max_distance: usize) -> (Option<usize>, usize) {
+        match self {
+            Self::Levenshtein => levenshtein(a, b, max_distance),
+            Self::DamerauLevenshtein => damerau_levenshtein(a, b, max_distance),
+        }
+    }
+}
+
+pub fn levenshtein_distance(a: &str, b: &str) -> (usize, usize) {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut comparisons = 0;
+
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for i in 1..=a.len() {
+        current[0] = i;
+        for j in 1..=b.len() {
+            comparisons += 1;
+            let substitution = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            current[j] = (previous[j] + 1)
+                .min(current[j - 1] + 1)
+                .min(previous[j - 1] + substitution);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    (previous[b.len()], comparisons)
+}
+
+pub fn levenshtein(a: &str, b: &str, max_distance: usize) -> (Option<usize>, usize) {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    if a.len().abs_diff(b.len()) > max_distance {
+        return (None, 0);
+    }
+
+    let mut comparisons = 0;
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for i in 1..=a.len() {
+        current[0] = i;
+        let mut row_min = current[0];
+
+        for j in 1..=b.len() {
+            comparisons += 1;
+            let substitution = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            current[j] = (previous[j] + 1)
+                .min(current[j - 1] + 1)
+                .min(previous[j - 1] + substitution);
+            row_min = row_min.min(current[j]);
+        }
+
+        if row_min > max_distance {
+            return (None, comparisons);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    let distance = previous[b.len()];
+    if distance <= max_distance {
+        (Some(distance), comparisons)
+    } else {
+        (None, comparisons)
+    }
+}
+
+pub fn damerau_levenshtein(a: &str, b: &str, max_distance: usize) -> (Option<usize>, usize) {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    if a.len().abs_diff(b.len()) > max_distance {
+        return (None, 0);
+    }
+
+    let mut comparisons = 0;
+    let mut before_previous = vec![0; b.len() + 1];
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for i in 1..=a.len() {
+        current[0] = i;
+        let mut row_min = current[0];
+
+        for j in 1..=b.len() {
+            comparisons += 1;
+            let substitution = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            let mut cost = (previous[j] + 1)
+                .min(current[j - 1] + 1)
+                .min(previous[j - 1] + substitution);
+
+            if i > 1 && j > 1 {
+                comparisons += 1;
+                if a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                    cost = cost.min(before_previous[j - 2] + 1);
+                }
+            }
+
+            current[j] = cost;
+            row_min = row_min.min(cost);
+        }
+
+        if row_min > max_distance {
+            return (None, comparisons);
+        }
+
+        std::mem::swap(&mut before_previous, &mut previous);
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    let distance = previous[b.len()];
+    if distance <= max_distance {
+        (Some(distance), comparisons)
+    } else {
+        (None, comparisons)
+    }
+}
+
+pub fn search(data: &[String], target: &str, max_distance: usize, metric: EditDistance) -> (Vec<FuzzyMatch>, usize) {
+    let mut comparisons = 0;
+    let mut matches = Vec::new();
+
+    for word in data {
+        let (distance, word_comparisons) = metric.bounded(word, target, max_distance);
+        comparisons += word_comparisons;
+
+        if let Some(distance) = distance {
+            matches.push(FuzzyMatch {
+                word: word.clone(),
+                distance,
+            });
+        }
+    }
+
+    rank_matches(&mut matches);
+    (matches, comparisons)
+}
+
+pub fn rank_matches(matches: &mut Vec<FuzzyMatch>) {
+    matches.sort_by(|a, b| a.distance.cmp(&b.distance).then_with(|| a.word.cmp(&b.word)));
+    matches.dedup_by(|a, b| a.word == b.word);
+}
+
+#[derive(Debug, Clone)]
+struct BkNode {
+    word: String,
+    children: HashMap<usize, usize>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct BkTree {
+    nodes: Vec<BkNode>,
+}
+
+impl BkTree {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn build(data: &[String]) -> Self {
+        let mut tree = Self::new();
+        for word in data {
+            tree.insert(word);
+        }
+        tree
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn insert(&mut self, word: &str) -> usize {
+        let mut comparisons = 0;
+
+        if self.nodes.is_empty() {
+            self.nodes.push(BkNode {
+                word: word.to_string(),
+                children: HashMap::new(),
+            });
+            return comparisons;
+        }
+
+        let mut current = 0;
+        loop {
+            let (distance, word_comparisons) = levenshtein_distance(&self.nodes[current].word, word);
+            comparisons += word_comparisons;
+
+            if distance == 0 {
+                return comparisons;
+            }
+
+            match self.nodes[current].children.get(&distance) {
+                Some(&child) => current = child,
+                None => {
+                    let index = self.nodes.len();
+                    self.nodes.push(BkNode {
+                        word: word.to_string(),
+                        children: HashMap::new(),
+                    });
+                    self.nodes[current].children.insert(distance, index);
+                    return comparisons;
+                }
+            }
+        }
+    }
+
+    pub fn search(&self, target: &str, max_distance: usize) -> (Vec<FuzzyMatch>, usize) {
+        let mut comparisons = 0;
+        let mut matches = Vec::new();
+
+        if self.nodes.is_empty() {
+            return (matches, comparisons);
+        }
+
+        let mut stack = vec![0];
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            let (distance, word_comparisons) = levenshtein_distance(&node.word, target);
+            comparisons += word_comparisons;
+
+            if distance <= max_distance {
+                matches.push(FuzzyMatch {
+                    word: node.word.clone(),
+                    distance,
+                });
+            }
+
+            let low = distance.saturating_sub(max_distance);
+            let high = distance + max_distance;
+            for (&edge, &child) in &node.children {
+                if edge >= low && edge <= high {
+                    stack.push(child);
+                }
+            }
+        }
+
+        rank_matches(&mut matches);
+        (matches, comparisons)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SymSpellIndex {
+    max_distance: usize,
+    metric: EditDistance,
+    words: Vec<String>,
+    deletes: HashMap<String, Vec<usize>>,
+}
+
+impl SymSpellIndex {
+    pub fn build(data: &[String], max_distance: usize, metric: EditDistance) -> Self {
+        let mut index = Self {
+            max_distance,
+            metric,
+            words: Vec::with_capacity(data.len()),
+            deletes: HashMap::new(),
+        };
+
+        let mut seen = HashSet::new();
+        for word in data {
+            if !seen.insert(word.as_str()) {
+                continue;
+            }
+
+            let word_index = index.words.len();
+            index.words.push(word.clone());
+
+            for variant in generate_deletes(word, max_distance) {
+                index.deletes.entry(variant).or_default().push(word_index);
+            }
+        }
+
+        index
+    }
+
+    pub fn max_distance(&self) -> usize {
+        self.max_distance
+    }
+
+    pub fn metric(&self) -> EditDistance {
+        self.metric
+    }
+
+    pub fn delete_entries(&self) -> usize {
+        self.deletes.len()
+    }
+
+    pub fn search(&self, target: &str, max_distance: usize) -> (Vec<FuzzyMatch>, usize) {
+        let max_distance = max_distance.min(self.max_distance);
+        let mut comparisons = 0;
+        let mut matches = Vec::new();
+        let mut checked = HashSet::new();
+
+        for variant in generate_deletes(target, max_distance) {
+            comparisons += 1;
+            let Some(candidates) = self.deletes.get(&variant) else {
+                continue;
+            };
+
+            for &candidate in candidates {
+                if !checked.insert(candidate) {
+                    continue;
+                }
+
+                let word = &self.words[candidate];
+                let (distance, word_comparisons) = self.metric.bounded(word, target, max_distance);
+                comparisons += word_comparisons;
+
+                if let Some(distance) = distance {
+                    matches.push(FuzzyMatch {
+                        word: word.clone(),
+                        distance,
+                    });
+                }
+            }
+        }
+
+        rank_matches(&mut matches);
+        (matches, comparisons)
+    }
+}
+
+fn generate_deletes(word: &str, max_distance: usize) -> HashSet<String> {
+    let mut variants = HashSet::new();
+    variants.insert(word.to_string());
+
+    let mut frontier = vec![word.to_string()];
+    for _ in 0..max_distance {
+        let mut next = Vec::new();
+
+        for current in &frontier {
+            let chars: Vec<char> = current.chars().collect();
+            for skip in 0..chars.len() {
+                let variant: String = chars
+                    .iter()
+                    .enumerate()
+                    .filter(|&(i, _)| i != skip)
+                    .map(|(_, &c)| c)
+                    .collect();
+
+                if variants.insert(variant.clone()) {
+                    next.push(variant);
+                }
+            }
+        }
+
+        frontier = next;
+    }
+
+    variants
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::*;
+
+    #[test]
+    fn test_edit_distances_on_known_pairs() {
+        let pairs = [
+            ("kitten", "sitting", 3, 3),
+            ("flaw", "lawn", 2, 2),
+            ("", "abc", 3, 3),
+            ("same", "same", 0, 0),
+            ("abcd", "acbd", 2, 1),
+            ("ca", "abc", 3, 3),
+            ("résumé", "resume", 2, 2),
+        ];
+
+        for (a, b, lev, osa) in pairs {
+            assert_eq!(levenshtein_distance(a, b).0, lev, "{} / {}", a, b);
+            assert_eq!(levenshtein(a, b, 10).0, Some(lev), "{} / {}", a, b);
+            assert_eq!(damerau_levenshtein(a, b, 10).0, Some(osa), "{} / {}", a, b);
+            assert_eq!(levenshtein(b, a, 10).0, Some(lev), "{} / {}", b, a);
+            assert_eq!(damerau_levenshtein(b, a, 10).0, Some(osa), "{} / {}", b, a);
+        }
+
+        assert_eq!(levenshtein("kitten", "sitting", 2).0, None);
+        assert_eq!(damerau_levenshtein("abcd", "acbd", 0).0, None);
+        assert_eq!(levenshtein("a", "abcd", 2), (None, 0));
+    }
+
+    #[test]
+    fn test_indexes_match_brute_force_scan() {
+        let mut rng = StdRng::seed_from_u64(26);
+        let alphabet = ['a', 'b', 'c', 'd'];
+        let random_word = |rng: &mut StdRng, len: usize| -> String {
+            (0..len).map(|_| *alphabet.choose(rng).unwrap()).collect()
+        };
+
+        let words: Vec<String> = (0..400).map(|_| {
+            let len = rng.random_range(1..7);
+            random_word(&mut rng, len)
+        }).collect();
+        let tree = BkTree::build(&words);
+
+        for max_distance in 0..=2 {
+            let symspell = [EditDistance::Levenshtein, EditDistance::DamerauLevenshtein]
+                .map(|metric| SymSpellIndex::build(&words, max_distance, metric));
+
+            for _ in 0..50 {
+                let len = rng.random_range(0..8);
+                let target = random_word(&mut rng, len);
+
+                let (expected, _) = search(&words, &target, max_distance, EditDistance::Levenshtein);
+                assert_eq!(tree.search(&target, max_distance).0, expected, "BK-tree '{}' d={}", target, max_distance);
+
+                for index in &symspell {
+                    let (expected, _) = search(&words, &target, max_distance, index.metric());
+                    assert_eq!(index.search(&target, max_distance).0, expected, "SymSpell {} '{}' d={}", index.metric().name(), target, max_distance);
+                }
+            }
+        }
+    }
+}
@@ -1,4 +1,64 @@
+use std::borrow::Borrow;
+use std::cell::Cell;
 use std::collections::HashMap;
+use std::hash::{BuildHasher, Hash, Hasher};
+
+// Lets a plain `HashMap<String, _>` be probed with keys that count how many
+// stored keys the lookup compares against.
+pub trait CountedWord {
+    fn word(&self) -> &str;
+
+    fn record_comparison(&self) {}
+}
+
+impl CountedWord for String {
+    fn word(&self) -> &str {
+        self
+    }
+}
+
+impl<'a> Borrow<dyn CountedWord + 'a> for String {
+    fn borrow(&self) -> &(dyn CountedWord + 'a) {
+        self
+    }
+}
+
+impl PartialEq for dyn CountedWord + '_ {
+    fn eq(&self, other: &Self) -> bool {
+        self.record_comparison();
+        self.word() == other.word()
+    }
+}
+
+impl Eq for dyn CountedWord + '_ {}
+
+impl Hash for dyn CountedWord + '_ {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.word().hash(state);
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct CountedKey<'a> {
+    word: &'a str,
+    comparisons: &'a Cell<usize>,
+}
+
+impl<'a> CountedKey<'a> {
+    pub fn new(word: &'a str, comparisons: &'a Cell<usize>) -> Self {
+        Self { word, comparisons }
+    }
+}
+
+impl CountedWord for CountedKey<'_> {
+    fn word(&self) -> &str {
+        self.word
+    }
+
+    fn record_comparison(&self) {
+        self.comparisons.set(self.comparisons.get() + 1);
+    }
+}
 
 pub fn search(word_map: &HashMap<String, usize>, target: &str) -> bool {
     word_map.contains_key(target)
@@ -21,9 +81,72 @@ pub fn search_with_collision_analysis(word_map: &HashMap<String, usize>, target:
     (found, estimated_probes)
 }
 
+pub fn counted_keys<'a>(targets: &[&'a str], comparisons: &'a Cell<usize>) -> Vec<CountedKey<'a>> {
+    targets.iter().map(|&target| CountedKey::new(target, comparisons)).collect()
+}
+
+pub fn search_multiple_counted<S: BuildHasher>(word_map: &HashMap<String, usize, S>, keys: &[CountedKey], comparisons: &Cell<usize>) -> Vec<(bool, usize)> {
+    keys
+        .iter()
+        .map(|key| {
+            let before = comparisons.get();
+            let found = word_map.contains_key(key as &dyn CountedWord);
+            (found, comparisons.get() - before)
+        })
+        .collect()
+}
+
 pub fn search_multiple(word_map: &HashMap<String, usize>, targets: &[&str]) -> Vec<(String, bool)> {
     targets
         .iter()
         .map(|&target| (target.to_string(), word_map.contains_key(target)))
         .collect()
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::hash::BuildHasherDefault;
+
+    #[derive(Default)]
+    struct CollidingHasher;
+
+    impl Hasher for CollidingHasher {
+        fn finish(&self) -> u64 {
+            0
+        }
+
+        fn write(&mut self, _bytes: &[u8]) {}
+    }
+
+    #[test]
+    fn test_counted_batch_search() {
+        let words: Vec<String> = (0..1_000).map(|i| format!("word{}", i)).collect();
+        let word_map = build_hash_map(&words);
+        let comparisons = Cell::new(0);
+
+        let keys = counted_keys(&["word0", "word999", "absent"], &comparisons);
+        let results = search_multiple_counted(&word_map, &keys, &comparisons);
+        assert_eq!(results.iter().map(|&(found, _)| found).collect::<Vec<_>>(), [true, true, false]);
+        assert!(results[..2].iter().all(|&(_, comparisons)| comparisons >= 1));
+    }
+
+    #[test]
+    fn test_counted_comparisons_under_full_collision() {
+        let words: Vec<String> = (0..8).map(|i| format!("word{}", i)).collect();
+        let mut word_map: HashMap<String, usize, BuildHasherDefault<CollidingHasher>> = HashMap::with_capacity_and_hasher(words.len(), Default::default());
+        for (index, word) in words.iter().enumerate() {
+            word_map.insert(word.clone(), index);
+        }
+        let comparisons = Cell::new(0);
+
+        let targets: Vec<&str> = words.iter().map(|word| word.as_str()).chain(["absent"]).collect();
+        let keys = counted_keys(&targets, &comparisons);
+        let results = search_multiple_counted(&word_map, &keys, &comparisons);
+
+        // Every key shares one probe sequence, so the k-th inserted word takes k
+        // comparisons and a miss compares against all of them.
+        let expected: Vec<(bool, usize)> = (1..=words.len()).map(|k| (true, k)).chain([(false, words.len())]).collect();
+        assert_eq!(results, expected);
+    }
+}
@@ -1,4 +1,10 @@
-pub fn search(data: &[String], target: &str) -> (bool, usize) {
+use std::borrow::Borrow;
+
+pub fn search<T, Q>(data: &[T], target: &Q) -> (bool, usize)
+where
+    T: Borrow<Q>,
+    Q: Ord + ?Sized,
+{
     if data.is_empty() {
         return (false, 0);
     }
@@ -8,7 +14,7 @@ pub fn search(data: &[String], target: &str) -> (bool, usize) {
     let mut comparisons = 0;
     let mut prev = 0;
     
-    while prev < n && data[(jump_size.min(n - 1)).min(prev + jump_size - 1)].as_str() < target {
+    while prev < n && data[(prev + jump_size - 1).min(n - 1)].borrow() < target {
         comparisons += 1;
         prev += jump_size;
         if prev >= n {
@@ -19,10 +25,10 @@ pub fn search(data: &[String], target: &str) -> (bool, usize) {
     let end = (prev + jump_size).min(n);
     for i in prev..end {
         comparisons += 1;
-        if data[i] == target {
+        if data[i].borrow() == target {
             return (true, comparisons);
         }
-        if data[i].as_str() > target {
+        if data[i].borrow() > target {
             break;
         }
     }
@@ -109,4 +115,24 @@ pub fn calculate_optimal_jump_size(array_size: usize) -> usize {
         return 1;
     }
     (array_size as f64).sqrt() as usize
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_finds_every_element_beyond_first_block() {
+        for n in [1, 2, 3, 10, 17, 100] {
+            let data: Vec<u32> = (0..n).map(|i| i * 2).collect();
+            let jump_size = (n as f64).sqrt() as usize;
+
+            for (index, value) in data.iter().enumerate() {
+                let (found, comparisons) = search(&data, value);
+                assert!(found, "n={} value={}", n, value);
+                assert!(comparisons <= index / jump_size + 1 + jump_size, "n={} value={}", n, value);
+                assert!(!search(&data, &(value + 1)).0);
+            }
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+pub trait MembershipFilter {
+    fn name(&self) -> &'static str;
+    fn insert(&mut self, item: &str) -> bool;
+    fn contains(&self, item: &str) -> bool;
+    fn memory_bits(&self) -> usize;
+
+    fn packed_bits(&self) -> usize {
+        self.memory_bits()
+    }
+}
+
+pub fn hash_with_seed(item: &str, seed: u64) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    seed.hash(&mut hasher);
+    item.hash(&mut hasher);
+    hasher.finish()
+}
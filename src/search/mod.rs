@@ -4,8 +4,19 @@ pub mod hash_search;
 pub mod interpolation_search;
 pub mod exponential_search;
 pub mod jump_search;
+pub mod fuzzy_search;
+pub mod branchless_search;
+pub mod eytzinger_search;
+pub mod s_tree_search;
+pub mod workload;
+pub mod dataset;
+pub mod membership_filter;
+pub mod bloom_filter;
+pub mod cuckoo_filter;
+pub mod quotient_filter;
 
 use crate::prelude::*;
+use crate::search_tree;
 use std::collections::HashMap;
 use std::time::{Duration, Instant};
 use rand::prelude::*;
@@ -22,6 +33,79 @@ pub struct SearchMetrics {
     pub actual_complexity: f64,
 }
 
+#[derive(Debug, Clone)]
+pub struct FuzzySearchMetrics {
+    pub algorithm_name: String,
+    pub max_distance: usize,
+    pub suggestions: Vec<fuzzy_search::FuzzyMatch>,
+    pub comparisons: usize,
+    pub build_duration: Duration,
+    pub duration: Duration,
+}
+
+#[derive(Debug, Clone)]
+pub struct LayoutMetrics {
+    pub algorithm_name: String,
+    pub size: usize,
+    pub hits: usize,
+    pub comparisons: f64,
+    pub nanos_per_query: f64,
+    pub build_duration: Duration,
+    pub memory_bytes: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct WorkloadMetrics {
+    pub algorithm_name: String,
+    pub lookups: usize,
+    pub hits: usize,
+    pub misses: usize,
+    pub avg_hit_comparisons: f64,
+    pub avg_miss_comparisons: f64,
+    pub duration: Duration,
+    pub throughput: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct FilterMetrics {
+    pub filter_name: String,
+    pub target_fpr: f64,
+    pub actual_fpr: f64,
+    pub false_negatives: usize,
+    pub bits_per_key: f64,
+    pub packed_bits_per_key: f64,
+    pub build_duration: Duration,
+    pub lookup_nanos: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct TreeMetrics {
+    pub structure_name: String,
+    pub height: usize,
+    pub build_comparisons: usize,
+    pub rotations: usize,
+    pub node_splits: usize,
+    pub node_merges: usize,
+    pub avg_hit_comparisons: f64,
+    pub avg_miss_comparisons: f64,
+    pub range_results: usize,
+    pub range_comparisons: usize,
+    pub avg_remove_comparisons: f64,
+    pub build_duration: Duration,
+    pub lookup_nanos: f64,
+}
+
+struct TreeQuery<'a> {
+    keys: &'a [&'a str],
+    probes: &'a [String],
+    low: &'a str,
+    high: &'a str,
+}
+
+pub const LAYOUT_SIZES: [usize; 6] = [1_000, 10_000, 100_000, 1_000_000, 10_000_000, 100_000_000];
+pub const LARGE_LAYOUT_SIZE: usize = 10_000_000;
+const TREE_RANGE_WIDTH: usize = 1_000;
+
 pub struct SearchCoordinator {
     words: Vec<String>,
     shuffled_words: Vec<String>,
@@ -40,20 +124,22 @@ impl SearchCoordinator {
     }
 
     pub async fn load_words(&mut self, file_path: &str) -> Result<()> {
-        println!("Loading words from: {}", file_path);
-        let content = tokio::fs::read_to_string(file_path).await
-            .map_err(|e| Error::Generic(format!("Failed to read file {}: {}", file_path, e)))?;
-
-        self.words = content
-            .lines()
-            .map(|line| line.trim().to_lowercase())
-            .filter(|line| !line.is_empty() && line.len() >= 2)
-            .collect();
+        self.load_dataset(&dataset::DatasetSource::file(file_path), &dataset::LoadOptions::default()).await
+    }
 
-        if self.words.is_empty() {
-            return Err(Error::Generic("No valid words found in file".to_string()));
+    pub async fn load_dataset(&mut self, source: &dataset::DatasetSource, options: &dataset::LoadOptions) -> Result<()> {
+        println!("Loading words from: {}", source.describe());
+        let words = dataset::load(source, options).await?;
+        self.set_words(words)
+    }
+
+    pub fn set_words(&mut self, words: Vec<String>) -> Result<()> {
+        if words.is_empty() {
+            return Err(Error::Generic("No valid words found in dataset".to_string()));
         }
 
+        self.words = words;
+
         self.shuffled_words = self.words.clone();
         let mut rng = rng();
         self.shuffled_words.shuffle(&mut rng);
@@ -302,6 +388,743 @@ impl SearchCoordinator {
         }
     }
 
+    pub fn run_fuzzy_search(&self, target_word: &str, max_distance: usize, iterations: usize) -> Result<Vec<FuzzySearchMetrics>> {
+        if self.words.is_empty() {
+            return Err(Error::Generic("No words loaded. Load words first.".to_string()));
+        }
+
+        if iterations == 0 {
+            return Err(Error::validation("Iterations must be greater than 0"));
+        }
+
+        println!("\nRunning fuzzy search for target: '{}' (max distance: {})", target_word, max_distance);
+        println!("Iterations per algorithm: {}", iterations);
+        println!("{}", "=".repeat(60));
+
+        let results = vec![
+            self.benchmark_fuzzy_scan(target_word, max_distance, iterations, fuzzy_search::EditDistance::Levenshtein),
+            self.benchmark_fuzzy_scan(target_word, max_distance, iterations, fuzzy_search::EditDistance::DamerauLevenshtein),
+            self.benchmark_bk_tree(target_word, max_distance, iterations),
+            self.benchmark_symspell(target_word, max_distance, iterations),
+        ];
+
+        self.display_fuzzy_results(target_word, &results);
+        Ok(results)
+    }
+
+    fn benchmark_fuzzy_scan(&self, target: &str, max_distance: usize, iterations: usize, metric: fuzzy_search::EditDistance) -> FuzzySearchMetrics {
+        let mut total_comparisons = 0;
+        let mut suggestions = Vec::new();
+
+        let start = Instant::now();
+
+        for _ in 0..iterations {
+            let (matches, comparisons) = fuzzy_search::search(&self.words, target, max_distance, metric);
+            total_comparisons += comparisons;
+            suggestions = matches;
+        }
+
+        FuzzySearchMetrics {
+            algorithm_name: format!("{} Scan", metric.name()),
+            max_distance,
+            suggestions,
+            comparisons: total_comparisons / iterations,
+            build_duration: Duration::ZERO,
+            duration: start.elapsed() / iterations as u32,
+        }
+    }
+
+    fn benchmark_bk_tree(&self, target: &str, max_distance: usize, iterations: usize) -> FuzzySearchMetrics {
+        let build_start = Instant::now();
+        let tree = fuzzy_search::BkTree::build(&self.words);
+        let build_duration = build_start.elapsed();
+
+        let mut total_comparisons = 0;
+        let mut suggestions = Vec::new();
+
+        let start = Instant::now();
+
+        for _ in 0..iterations {
+            let (matches, comparisons) = tree.search(target, max_distance);
+            total_comparisons += comparisons;
+            suggestions = matches;
+        }
+
+        FuzzySearchMetrics {
+            algorithm_name: format!("BK-Tree ({})", fuzzy_search::EditDistance::Levenshtein.name()),
+            max_distance,
+            suggestions,
+            comparisons: total_comparisons / iterations,
+            build_duration,
+            duration: start.elapsed() / iterations as u32,
+        }
+    }
+
+    fn benchmark_symspell(&self, target: &str, max_distance: usize, iterations: usize) -> FuzzySearchMetrics {
+        let build_start = Instant::now();
+        let index = fuzzy_search::SymSpellIndex::build(&self.words, max_distance, fuzzy_search::EditDistance::Levenshtein);
+        let build_duration = build_start.elapsed();
+
+        let mut total_comparisons = 0;
+        let mut suggestions = Vec::new();
+
+        let start = Instant::now();
+
+        for _ in 0..iterations {
+            let (matches, comparisons) = index.search(target, max_distance);
+            total_comparisons += comparisons;
+            suggestions = matches;
+        }
+
+        FuzzySearchMetrics {
+            algorithm_name: format!("SymSpell ({})", index.metric().name()),
+            max_distance,
+            suggestions,
+            comparisons: total_comparisons / iterations,
+            build_duration,
+            duration: start.elapsed() / iterations as u32,
+        }
+    }
+
+    fn display_fuzzy_results(&self, target: &str, results: &[FuzzySearchMetrics]) {
+        let mut table = Table::new();
+
+        table.add_row(Row::new(vec![
+            Cell::new("Algorithm"),
+            Cell::new("Suggestions"),
+            Cell::new("Comparisons"),
+            Cell::new("Build (ms)"),
+            Cell::new("Duration (μs)"),
+            Cell::new("Best Match"),
+        ]));
+
+        for metric in results {
+            let best_match = metric.suggestions.first()
+                .map(|m| format!("{} (d={})", m.word, m.distance))
+                .unwrap_or_else(|| "-".to_string());
+
+            table.add_row(Row::new(vec![
+                Cell::new(&metric.algorithm_name),
+                Cell::new(&format!("{}", metric.suggestions.len())),
+                Cell::new(&format!("{}", metric.comparisons)),
+                Cell::new(&format!("{}", metric.build_duration.as_millis())),
+                Cell::new(&format!("{}", metric.duration.as_micros())),
+                Cell::new(&best_match),
+            ]));
+        }
+
+        println!("\n{}", table);
+
+        if let Some(most_efficient) = results.iter().min_by_key(|m| m.comparisons) {
+            println!("🎯 Fewest Comparisons: {} ({} comparisons)",
+                most_efficient.algorithm_name, most_efficient.comparisons);
+        }
+
+        let suggestions = results.iter()
+            .max_by_key(|m| m.suggestions.len())
+            .map(|m| &m.suggestions[..])
+            .unwrap_or(&[]);
+
+        if suggestions.iter().any(|m| m.distance == 0) {
+            println!("✅ '{}' is spelled correctly", target);
+        } else if suggestions.is_empty() {
+            println!("❓ No suggestions found for '{}'", target);
+        } else {
+            let top: Vec<String> = suggestions.iter()
+                .take(5)
+                .map(|m| format!("{} ({})", m.word, m.distance))
+                .collect();
+            println!("💡 Did you mean: {}?", top.join(", "));
+        }
+    }
+
+    pub fn run_workload_benchmarks(&self, config: &workload::WorkloadConfig) -> Result<Vec<WorkloadMetrics>> {
+        if self.words.is_empty() {
+            return Err(Error::Generic("No words loaded. Load words first.".to_string()));
+        }
+
+        let workload = workload::generate(&self.words, config)?;
+
+        println!("\nRunning batch workload benchmarks");
+        println!("Targets: {} ({} distinct)", workload.targets.len(), workload.distinct_targets);
+        println!("Expected hits: {} | Expected misses: {}", workload.expected_hits, workload.expected_misses());
+        println!("Popularity: {}", config.popularity.name());
+        if let Some(seed) = config.seed {
+            println!("Seed: {}", seed);
+        }
+        println!("{}", "=".repeat(60));
+
+        let results = vec![
+            self.benchmark_workload("Linear Search", &workload, |target| linear_search::search(&self.shuffled_words, target)),
+            self.benchmark_workload("Binary Search", &workload, |target| binary_search::search(&self.sorted_words, target)),
+            self.benchmark_hash_workload(&workload),
+            self.benchmark_workload("Interpolation Search", &workload, |target| interpolation_search::search(&self.sorted_words, target)),
+            self.benchmark_workload("Jump Search", &workload, |target| jump_search::search(&self.sorted_words, target)),
+            self.benchmark_workload("Exponential Search", &workload, |target| exponential_search::search(&self.sorted_words, target)),
+        ];
+
+        self.display_workload_results(&workload, &results);
+        Ok(results)
+    }
+
+    fn benchmark_workload<F>(&self, name: &str, workload: &workload::Workload, search_fn: F) -> WorkloadMetrics
+    where
+        F: Fn(&str) -> (bool, usize),
+    {
+        let mut hits = 0;
+        let mut hit_comparisons = 0;
+        let mut miss_comparisons = 0;
+
+        let start = Instant::now();
+
+        for target in &workload.targets {
+            let (found, comparisons) = search_fn(target);
+            if found {
+                hits += 1;
+                hit_comparisons += comparisons;
+            } else {
+                miss_comparisons += comparisons;
+            }
+        }
+
+        let duration = start.elapsed();
+        let lookups = workload.targets.len();
+
+        WorkloadMetrics {
+            algorithm_name: name.to_string(),
+            lookups,
+            hits,
+            misses: lookups - hits,
+            avg_hit_comparisons: hit_comparisons as f64 / hits.max(1) as f64,
+            avg_miss_comparisons: miss_comparisons as f64 / (lookups - hits).max(1) as f64,
+            duration,
+            throughput: lookups as f64 / duration.as_secs_f64().max(f64::EPSILON),
+        }
+    }
+
+    fn benchmark_hash_workload(&self, workload: &workload::Workload) -> WorkloadMetrics {
+        let targets: Vec<&str> = workload.targets.iter().map(|t| t.as_str()).collect();
+        let comparisons = std::cell::Cell::new(0);
+        let keys = hash_search::counted_keys(&targets, &comparisons);
+
+        let start = Instant::now();
+        let results = hash_search::search_multiple_counted(&self.word_map, &keys, &comparisons);
+        let duration = start.elapsed();
+
+        let hits = results.iter().filter(|(found, _)| *found).count();
+        let misses = targets.len() - hits;
+        let (hit_comparisons, miss_comparisons) = results.iter().fold((0, 0), |(hit, miss), &(found, comparisons)| {
+            if found { (hit + comparisons, miss) } else { (hit, miss + comparisons) }
+        });
+
+        WorkloadMetrics {
+            algorithm_name: "Hash Search (Batch)".to_string(),
+            lookups: targets.len(),
+            hits,
+            misses,
+            avg_hit_comparisons: hit_comparisons as f64 / hits.max(1) as f64,
+            avg_miss_comparisons: miss_comparisons as f64 / misses.max(1) as f64,
+            duration,
+            throughput: targets.len() as f64 / duration.as_secs_f64().max(f64::EPSILON),
+        }
+    }
+
+    fn display_workload_results(&self, workload: &workload::Workload, results: &[WorkloadMetrics]) {
+        let mut table = Table::new();
+
+        table.add_row(Row::new(vec![
+            Cell::new("Algorithm"),
+            Cell::new("Hits"),
+            Cell::new("Misses"),
+            Cell::new("Avg Hit Comparisons"),
+            Cell::new("Avg Miss Comparisons"),
+            Cell::new("Duration (ms)"),
+            Cell::new("Lookups/sec"),
+        ]));
+
+        for metric in results {
+            table.add_row(Row::new(vec![
+                Cell::new(&metric.algorithm_name),
+                Cell::new(&format!("{}", metric.hits)),
+                Cell::new(&format!("{}", metric.misses)),
+                Cell::new(&format!("{:.1}", metric.avg_hit_comparisons)),
+                Cell::new(&format!("{:.1}", metric.avg_miss_comparisons)),
+                Cell::new(&format!("{:.2}", metric.duration.as_secs_f64() * 1000.0)),
+                Cell::new(&format!("{:.0}", metric.throughput)),
+            ]));
+        }
+
+        println!("\n{}", table);
+
+        for metric in results.iter().filter(|m| m.hits != workload.expected_hits) {
+            println!("⚠️  {} reported {} hits, expected {}",
+                metric.algorithm_name, metric.hits, workload.expected_hits);
+        }
+
+        if let Some(fastest) = results.iter().max_by(|a, b| a.throughput.total_cmp(&b.throughput)) {
+            println!("🏆 Highest Throughput: {} ({:.0} lookups/sec)",
+                fastest.algorithm_name, fastest.throughput);
+        }
+
+        if let Some(cheapest_miss) = results.iter().min_by(|a, b| a.avg_miss_comparisons.total_cmp(&b.avg_miss_comparisons)) {
+            println!("🎯 Cheapest Miss: {} ({:.1} comparisons)",
+                cheapest_miss.algorithm_name, cheapest_miss.avg_miss_comparisons);
+        }
+    }
+
+    pub fn run_filter_benchmarks(&self, false_positive_rate: f64) -> Result<Vec<FilterMetrics>> {
+        if self.words.is_empty() {
+            return Err(Error::Generic("No words loaded. Load words first.".to_string()));
+        }
+
+        if !(false_positive_rate > 0.0 && false_positive_rate < 1.0) {
+            return Err(Error::validation("False-positive rate must be between 0 and 1"));
+        }
+
+        let mut keys: Vec<&str> = self.word_map.keys().map(|k| k.as_str()).collect();
+        keys.sort_unstable();
+        let present: std::collections::HashSet<&str> = keys.iter().copied().collect();
+        let probes = workload::absent_words(&keys, &present, keys.len().min(100_000));
+
+        println!("\nRunning membership filter benchmarks");
+        println!("Keys: {} | Absent probes: {} | Target FPR: {}", keys.len(), probes.len(), false_positive_rate);
+        println!("{}", "=".repeat(60));
+
+        let n = keys.len();
+        let results = vec![
+            self.benchmark_hash_membership(&keys, &probes),
+            self.benchmark_filter(bloom_filter::BloomFilter::new(n, false_positive_rate), false_positive_rate, &keys, &probes),
+            self.benchmark_filter(bloom_filter::CountingBloomFilter::new(n, false_positive_rate), false_positive_rate, &keys, &probes),
+            self.benchmark_filter(bloom_filter::BlockedBloomFilter::new(n, false_positive_rate), false_positive_rate, &keys, &probes),
+            self.benchmark_filter(cuckoo_filter::CuckooFilter::new(n, false_positive_rate), false_positive_rate, &keys, &probes),
+            self.benchmark_filter(quotient_filter::QuotientFilter::new(n, false_positive_rate), false_positive_rate, &keys, &probes),
+        ];
+
+        self.display_filter_results(&results);
+        Ok(results)
+    }
+
+    fn benchmark_filter<F>(&self, mut filter: F, target_fpr: f64, keys: &[&str], probes: &[String]) -> FilterMetrics
+    where
+        F: membership_filter::MembershipFilter,
+    {
+        let start = Instant::now();
+        let rejected = keys.iter().filter(|key| !filter.insert(key)).count();
+        let build_duration = start.elapsed();
+
+        if rejected > 0 {
+            println!("⚠️  {} rejected {} keys (filter full)", filter.name(), rejected);
+        }
+
+        let false_negatives = keys.iter().filter(|key| !filter.contains(key)).count();
+
+        let start = Instant::now();
+        let false_positives = probes.iter().filter(|probe| filter.contains(probe)).count();
+        let lookup_duration = start.elapsed();
+
+        FilterMetrics {
+            filter_name: filter.name().to_string(),
+            target_fpr,
+            actual_fpr: false_positives as f64 / probes.len().max(1) as f64,
+            false_negatives,
+            bits_per_key: filter.memory_bits() as f64 / keys.len().max(1) as f64,
+            packed_bits_per_key: filter.packed_bits() as f64 / keys.len().max(1) as f64,
+            build_duration,
+            lookup_nanos: lookup_duration.as_nanos() as f64 / probes.len().max(1) as f64,
+        }
+    }
+
+    fn benchmark_hash_membership(&self, keys: &[&str], probes: &[String]) -> FilterMetrics {
+        let start = Instant::now();
+        let word_map = hash_search::build_hash_map(&self.words);
+        let build_duration = start.elapsed();
+
+        let start = Instant::now();
+        let false_positives = probes.iter().filter(|probe| hash_search::search(&word_map, probe)).count();
+        let lookup_duration = start.elapsed();
+
+        let entry_bytes = std::mem::size_of::<String>() + std::mem::size_of::<usize>() + 1;
+        let memory_bytes = word_map.capacity() * entry_bytes + word_map.keys().map(|k| k.capacity()).sum::<usize>();
+
+        let bits_per_key = (memory_bytes * 8) as f64 / keys.len().max(1) as f64;
+
+        FilterMetrics {
+            filter_name: "Hash Search (exact)".to_string(),
+            target_fpr: 0.0,
+            actual_fpr: false_positives as f64 / probes.len().max(1) as f64,
+            false_negatives: 0,
+            bits_per_key,
+            packed_bits_per_key: bits_per_key,
+            build_duration,
+            lookup_nanos: lookup_duration.as_nanos() as f64 / probes.len().max(1) as f64,
+        }
+    }
+
+    fn display_filter_results(&self, results: &[FilterMetrics]) {
+        let mut table = Table::new();
+
+        table.add_row(Row::new(vec![
+            Cell::new("Structure"),
+            Cell::new("Target FPR"),
+            Cell::new("Actual FPR"),
+            Cell::new("False Negatives"),
+            Cell::new("Bits/Key"),
+            Cell::new("Packed Bits/Key"),
+            Cell::new("Build (ms)"),
+            Cell::new("Lookup (ns)"),
+        ]));
+
+        for metric in results {
+            table.add_row(Row::new(vec![
+                Cell::new(&metric.filter_name),
+                Cell::new(&format!("{:.4}", metric.target_fpr)),
+                Cell::new(&format!("{:.4}", metric.actual_fpr)),
+                Cell::new(&format!("{}", metric.false_negatives)),
+                Cell::new(&format!("{:.2}", metric.bits_per_key)),
+                Cell::new(&format!("{:.2}", metric.packed_bits_per_key)),
+                Cell::new(&format!("{:.2}", metric.build_duration.as_secs_f64() * 1000.0)),
+                Cell::new(&format!("{:.1}", metric.lookup_nanos)),
+            ]));
+        }
+
+        println!("\n{}", table);
+
+        for metric in results.iter().filter(|m| m.false_negatives > 0) {
+            println!("⚠️  {} missed {} inserted keys", metric.filter_name, metric.false_negatives);
+        }
+
+        if let Some(smallest) = results.iter().min_by(|a, b| a.bits_per_key.total_cmp(&b.bits_per_key)) {
+            println!("💾 Most Compact: {} ({:.2} bits/key)", smallest.filter_name, smallest.bits_per_key);
+        }
+
+        if let Some(fastest) = results.iter().min_by(|a, b| a.lookup_nanos.total_cmp(&b.lookup_nanos)) {
+            println!("🏆 Fastest Lookup: {} ({:.1}ns)", fastest.filter_name, fastest.lookup_nanos);
+        }
+    }
+
+    pub fn run_tree_benchmarks(&self, order: usize) -> Result<Vec<TreeMetrics>> {
+        if self.words.is_empty() {
+            return Err(Error::Generic("No words loaded. Load words first.".to_string()));
+        }
+
+        if order < 3 {
+            return Err(Error::validation("B-tree order must be at least 3"));
+        }
+
+        let mut seen = std::collections::HashSet::new();
+        let keys: Vec<&str> = self.shuffled_words.iter()
+            .map(|word| word.as_str())
+            .filter(|word| seen.insert(*word))
+            .collect();
+        let probes = workload::absent_words(&keys, &seen, keys.len().min(100_000));
+
+        let mut sorted_keys = keys.clone();
+        sorted_keys.sort_unstable();
+        let low = sorted_keys[sorted_keys.len() / 4];
+        let high = sorted_keys[(sorted_keys.len() / 4 + TREE_RANGE_WIDTH).min(sorted_keys.len() - 1)];
+
+        println!("\nRunning balanced search tree benchmarks");
+        println!("Keys: {} | Absent probes: {} | B-tree order: {} | Range: {}..={}", keys.len(), probes.len(), order, low, high);
+        println!("{}", "=".repeat(60));
+
+        let query = TreeQuery { keys: &keys, probes: &probes, low, high };
+        let results = vec![
+            self.benchmark_sorted_array(&query),
+            self.benchmark_tree(search_tree::avl_tree::AvlTree::new(), &query),
+            self.benchmark_tree(search_tree::red_black_tree::RedBlackTree::new(), &query),
+            self.benchmark_tree(search_tree::b_tree::BTree::new(order), &query),
+            self.benchmark_tree(search_tree::treap::Treap::new(), &query),
+            self.benchmark_tree(search_tree::skip_list::SkipList::new(), &query),
+        ];
+
+        self.display_tree_results(&results);
+        Ok(results)
+    }
+
+    fn benchmark_tree<'a, M>(&self, mut map: M, query: &TreeQuery<'a>) -> TreeMetrics
+    where
+        M: search_tree::OrderedMap<&'a str, usize>,
+    {
+        let mut counter = search_tree::PerformanceCounter::new();
+
+        let start = Instant::now();
+        for (i, key) in query.keys.iter().enumerate() {
+            map.insert(key, i, &mut counter);
+        }
+        let build_duration = start.elapsed();
+        let build = counter.clone();
+
+        counter.reset();
+        let start = Instant::now();
+        let hits = query.keys.iter().filter(|key| map.get(key, &mut counter).is_some()).count();
+        let hit_duration = start.elapsed();
+        let hit_comparisons = counter.comparisons;
+
+        counter.reset();
+        let start = Instant::now();
+        let false_hits = query.probes.iter().filter(|probe| map.get(&probe.as_str(), &mut counter).is_some()).count();
+        let miss_duration = start.elapsed();
+        let miss_comparisons = counter.comparisons;
+
+        counter.reset();
+        let range_results = map.range(&query.low, &query.high, &mut counter).len();
+        let range_comparisons = counter.comparisons;
+        let height = map.height();
+
+        counter.reset();
+        let removed = query.keys.iter().step_by(2).filter(|key| map.remove(key, &mut counter).is_some()).count();
+
+        if hits != query.keys.len() || false_hits > 0 || removed != query.keys.len().div_ceil(2) {
+            println!("⚠️  {} returned inconsistent results ({} hits, {} false hits, {} removed)",
+                map.name(), hits, false_hits, removed);
+        }
+
+        TreeMetrics {
+            structure_name: map.name().to_string(),
+            height,
+            build_comparisons: build.comparisons,
+            rotations: build.rotations + counter.rotations,
+            node_splits: build.node_splits,
+            node_merges: counter.node_merges,
+            avg_hit_comparisons: hit_comparisons as f64 / query.keys.len().max(1) as f64,
+            avg_miss_comparisons: miss_comparisons as f64 / query.probes.len().max(1) as f64,
+            range_results,
+            range_comparisons,
+            avg_remove_comparisons: counter.comparisons as f64 / removed.max(1) as f64,
+            build_duration,
+            lookup_nanos: (hit_duration + miss_duration).as_nanos() as f64 / (query.keys.len() + query.probes.len()).max(1) as f64,
+        }
+    }
+
+    fn benchmark_sorted_array(&self, query: &TreeQuery) -> TreeMetrics {
+        let mut build_comparisons = 0;
+        let start = Instant::now();
+        let mut sorted: Vec<String> = query.keys.iter().map(|key| key.to_string()).collect();
+        sorted.sort_unstable_by(|a, b| {
+            build_comparisons += 1;
+            a.cmp(b)
+        });
+        let build_duration = start.elapsed();
+
+        let start = Instant::now();
+        let hit_comparisons: usize = query.keys.iter().map(|key| binary_search::search(&sorted, *key).1).sum();
+        let miss_comparisons: usize = query.probes.iter().map(|probe| binary_search::search(&sorted, probe.as_str()).1).sum();
+        let lookup_duration = start.elapsed();
+
+        let (_, low_comparisons, first) = binary_search::search_with_insertion_point(&sorted, query.low);
+        let (found, high_comparisons, last) = binary_search::search_with_insertion_point(&sorted, query.high);
+        let last = found.map_or(last, |index| index + 1);
+
+        let mut remove_comparisons = 0;
+        for key in query.keys.iter().step_by(2) {
+            let (found, comparisons, _) = binary_search::search_with_insertion_point(&sorted, key);
+            remove_comparisons += comparisons;
+            if let Some(index) = found {
+                sorted.remove(index);
+            }
+        }
+
+        TreeMetrics {
+            structure_name: "Binary Search (sorted array)".to_string(),
+            height: (query.keys.len() as f64).log2().ceil() as usize,
+            build_comparisons,
+            rotations: 0,
+            node_splits: 0,
+            node_merges: 0,
+            avg_hit_comparisons: hit_comparisons as f64 / query.keys.len().max(1) as f64,
+            avg_miss_comparisons: miss_comparisons as f64 / query.probes.len().max(1) as f64,
+            range_results: last.saturating_sub(first),
+            range_comparisons: low_comparisons + high_comparisons,
+            avg_remove_comparisons: remove_comparisons as f64 / query.keys.len().div_ceil(2).max(1) as f64,
+            build_duration,
+            lookup_nanos: lookup_duration.as_nanos() as f64 / (query.keys.len() + query.probes.len()).max(1) as f64,
+        }
+    }
+
+    fn display_tree_results(&self, results: &[TreeMetrics]) {
+        let mut table = Table::new();
+
+        table.add_row(Row::new(vec![
+            Cell::new("Structure"),
+            Cell::new("Height"),
+            Cell::new("Build Cmp"),
+            Cell::new("Rotations"),
+            Cell::new("Splits/Merges"),
+            Cell::new("Hit Cmp"),
+            Cell::new("Miss Cmp"),
+            Cell::new("Range (Cmp)"),
+            Cell::new("Remove Cmp"),
+            Cell::new("Build (ms)"),
+            Cell::new("Lookup (ns)"),
+        ]));
+
+        for metric in results {
+            table.add_row(Row::new(vec![
+                Cell::new(&metric.structure_name),
+                Cell::new(&format!("{}", metric.height)),
+                Cell::new(&format!("{}", metric.build_comparisons)),
+                Cell::new(&format!("{}", metric.rotations)),
+                Cell::new(&format!("{}/{}", metric.node_splits, metric.node_merges)),
+                Cell::new(&format!("{:.1}", metric.avg_hit_comparisons)),
+                Cell::new(&format!("{:.1}", metric.avg_miss_comparisons)),
+                Cell::new(&format!("{} ({})", metric.range_results, metric.range_comparisons)),
+                Cell::new(&format!("{:.1}", metric.avg_remove_comparisons)),
+                Cell::new(&format!("{:.2}", metric.build_duration.as_secs_f64() * 1000.0)),
+                Cell::new(&format!("{:.1}", metric.lookup_nanos)),
+            ]));
+        }
+
+        println!("\n{}", table);
+
+        if let Some(fastest) = results.iter().min_by(|a, b| a.lookup_nanos.total_cmp(&b.lookup_nanos)) {
+            println!("🏆 Fastest Lookup: {} ({:.1}ns)", fastest.structure_name, fastest.lookup_nanos);
+        }
+
+        if let Some(fewest) = results.iter().min_by(|a, b| a.avg_hit_comparisons.total_cmp(&b.avg_hit_comparisons)) {
+            println!("🎯 Fewest Comparisons: {} ({:.1} per hit)", fewest.structure_name, fewest.avg_hit_comparisons);
+        }
+
+        if let Some(shallowest) = results.iter().skip(1).min_by_key(|m| m.height) {
+            println!("🌳 Shallowest Tree: {} (height {})", shallowest.structure_name, shallowest.height);
+        }
+    }
+
+    pub fn run_layout_benchmarks(&self, max_size: usize, queries: usize, allow_large: bool) -> Result<Vec<LayoutMetrics>> {
+        if queries == 0 {
+            return Err(Error::validation("Query count must be greater than 0"));
+        }
+
+        // Each size holds the sorted keys plus Eytzinger and S-tree copies, over 1 GB at 100M.
+        if max_size > LARGE_LAYOUT_SIZE && !allow_large {
+            return Err(Error::validation(format!(
+                "Layout sizes above {} need several GB of memory; pass --large-layouts to run them",
+                LARGE_LAYOUT_SIZE
+            )));
+        }
+
+        let sizes: Vec<usize> = LAYOUT_SIZES.iter().copied().filter(|&size| size <= max_size).collect();
+        if sizes.is_empty() {
+            return Err(Error::validation(format!("Maximum size must be at least {}", LAYOUT_SIZES[0])));
+        }
+
+        println!("\nRunning memory layout benchmarks on u32 keys");
+        println!("Sizes: {:?}", sizes);
+        println!("Queries per layout: {} (about half are misses)", queries);
+        println!("{}", "=".repeat(60));
+
+        let mut rng = rng();
+        let mut results = Vec::new();
+
+        for &size in &sizes {
+            let sorted: Vec<u32> = (0..size).map(|i| (2 * i + 1) as u32).collect();
+            let targets: Vec<u32> = (0..queries).map(|_| rng.random_range(0..2 * size) as u32).collect();
+            let sorted_bytes = sorted.len() * std::mem::size_of::<u32>();
+
+            let start = Instant::now();
+            let eytzinger = eytzinger_search::EytzingerLayout::build(&sorted);
+            let eytzinger_build = start.elapsed();
+
+            let start = Instant::now();
+            let s_tree = s_tree_search::STree::build(&sorted);
+            let s_tree_build = start.elapsed();
+
+            results.push(self.benchmark_layout_query("Binary Search", size, Duration::ZERO, sorted_bytes, &targets, |target| {
+                binary_search::search(&sorted, &target)
+            }));
+            results.push(self.benchmark_layout_query("Jump Search", size, Duration::ZERO, sorted_bytes, &targets, |target| {
+                jump_search::search(&sorted, &target)
+            }));
+            results.push(self.benchmark_layout_query("Exponential Search", size, Duration::ZERO, sorted_bytes, &targets, |target| {
+                exponential_search::search(&sorted, &target)
+            }));
+            results.push(self.benchmark_layout_query("Branchless Binary", size, Duration::ZERO, sorted_bytes, &targets, |target| {
+                branchless_search::search(&sorted, target)
+            }));
+            results.push(self.benchmark_layout_query("Eytzinger", size, eytzinger_build, eytzinger.memory_bytes(), &targets, |target| {
+                eytzinger.search(target)
+            }));
+            results.push(self.benchmark_layout_query("Eytzinger + Prefetch", size, eytzinger_build, eytzinger.memory_bytes(), &targets, |target| {
+                eytzinger.search_with_prefetch(target)
+            }));
+            results.push(self.benchmark_layout_query("S-Tree (B=16)", size, s_tree_build, s_tree.memory_bytes(), &targets, |target| {
+                s_tree.search(target)
+            }));
+        }
+
+        self.display_layout_results(&sizes, &results);
+        Ok(results)
+    }
+
+    fn benchmark_layout_query<F>(&self, name: &str, size: usize, build_duration: Duration, memory_bytes: usize, targets: &[u32], search_fn: F) -> LayoutMetrics
+    where
+        F: Fn(u32) -> (bool, usize),
+    {
+        let mut hits = 0;
+        let mut total_comparisons = 0;
+
+        let start = Instant::now();
+
+        for &target in targets {
+            let (found, comparisons) = search_fn(target);
+            total_comparisons += comparisons;
+            if found {
+                hits += 1;
+            }
+        }
+
+        let duration = start.elapsed();
+
+        LayoutMetrics {
+            algorithm_name: name.to_string(),
+            size,
+            hits,
+            comparisons: total_comparisons as f64 / targets.len() as f64,
+            nanos_per_query: duration.as_nanos() as f64 / targets.len() as f64,
+            build_duration,
+            memory_bytes,
+        }
+    }
+
+    fn display_layout_results(&self, sizes: &[usize], results: &[LayoutMetrics]) {
+        let mut table = Table::new();
+
+        table.add_row(Row::new(vec![
+            Cell::new("Size"),
+            Cell::new("Algorithm"),
+            Cell::new("Hits"),
+            Cell::new("Comparisons"),
+            Cell::new("ns/query"),
+            Cell::new("Build (ms)"),
+            Cell::new("Memory (MB)"),
+        ]));
+
+        for metric in results {
+            table.add_row(Row::new(vec![
+                Cell::new(&format!("{}", metric.size)),
+                Cell::new(&metric.algorithm_name),
+                Cell::new(&format!("{}", metric.hits)),
+                Cell::new(&format!("{:.1}", metric.comparisons)),
+                Cell::new(&format!("{:.1}", metric.nanos_per_query)),
+                Cell::new(&format!("{:.2}", metric.build_duration.as_secs_f64() * 1000.0)),
+                Cell::new(&format!("{:.2}", metric.memory_bytes as f64 / (1024.0 * 1024.0))),
+            ]));
+        }
+
+        println!("\n{}", table);
+
+        for &size in sizes {
+            let fastest = results.iter()
+                .filter(|m| m.size == size)
+                .min_by(|a, b| a.nanos_per_query.total_cmp(&b.nanos_per_query));
+
+            if let Some(fastest) = fastest {
+                println!("🏆 Fastest at {} keys ({:.1} KB): {} ({:.1}ns/query)",
+                    size, (size * std::mem::size_of::<u32>()) as f64 / 1024.0,
+                    fastest.algorithm_name, fastest.nanos_per_query);
+            }
+        }
+    }
+
     pub fn analyse_array_type(&self, pattern_type: &str, size: usize) -> Result<()> {
         println!("\nAnalysing search performance on {} pattern (size: {})", pattern_type, size);
         
@@ -355,4 +1178,30 @@ impl Default for SearchCoordinator {
     fn default() -> Self {
         Self::new()
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+pub(crate) mod layout_fixtures {
+    use rand::prelude::*;
+
+    pub fn datasets() -> Vec<Vec<u32>> {
+        let mut rng = StdRng::seed_from_u64(29);
+        let mut duplicates: Vec<u32> = (0..300).map(|_| rng.random_range(0..60) * 3).collect();
+        duplicates.sort_unstable();
+
+        vec![
+            Vec::new(),
+            vec![7],
+            (0..37).map(|i| i * 2 + 1).collect(),
+            duplicates,
+            vec![0, 0, 5, u32::MAX - 1, u32::MAX, u32::MAX],
+        ]
+    }
+
+    pub fn targets(data: &[u32]) -> Vec<u32> {
+        let mut targets: Vec<u32> = (0..200).collect();
+        targets.extend([u32::MAX - 2, u32::MAX - 1, u32::MAX]);
+        targets.extend_from_slice(data);
+        targets
+    }
+}
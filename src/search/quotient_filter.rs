@@ -0,0 +1,383 @@
+use super::membership_filter::{hash_with_seed, MembershipFilter};
+
+const MAX_LOAD_FACTOR: f64 = 0.75;
+const METADATA_BITS: usize = 3;
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Slot {
+    remainder: u64,
+    occupied: bool,
+    continuation: bool,
+    shifted: bool,
+}
+
+impl Slot {
+    fn is_empty(&self) -> bool {
+        !self.occupied && !self.continuation && !self.shifted
+    }
+
+    fn is_run_start(&self) -> bool {
+        !self.continuation && !self.is_empty()
+    }
+
+    fn is_cluster_start(&self) -> bool {
+        self.occupied && !self.continuation && !self.shifted
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct QuotientFilter {
+    slots: Vec<Slot>,
+    quotient_bits: u32,
+    remainder_bits: u32,
+    len: usize,
+}
+
+impl QuotientFilter {
+    pub fn new(expected_items: usize, false_positive_rate: f64) -> Self {
+        let p = false_positive_rate.clamp(f64::MIN_POSITIVE, 0.5);
+        let remainder_bits = (1.0 / p).log2().ceil().clamp(1.0, 32.0) as u32;
+        let quotient_bits = ((expected_items.max(1) as f64 / MAX_LOAD_FACTOR).log2().ceil() as u32).clamp(1, 31);
+
+        Self {
+            slots: vec![Slot::default(); 1 << quotient_bits],
+            quotient_bits,
+            remainder_bits,
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn remainder_bits(&self) -> u32 {
+        self.remainder_bits
+    }
+
+    pub fn load_factor(&self) -> f64 {
+        self.len as f64 / self.slots.len() as f64
+    }
+
+    fn split(&self, item: &str) -> (usize, u64) {
+        let hash = hash_with_seed(item, 0);
+        let remainder = hash & ((1u64 << self.remainder_bits) - 1);
+        let quotient = (hash >> self.remainder_bits) & ((1u64 << self.quotient_bits) - 1);
+        (quotient as usize, remainder)
+    }
+
+    fn next(&self, index: usize) -> usize {
+        (index + 1) & (self.slots.len() - 1)
+    }
+
+    fn previous(&self, index: usize) -> usize {
+        index.wrapping_sub(1) & (self.slots.len() - 1)
+    }
+
+    fn run_start(&self, quotient: usize) -> usize {
+        let mut bucket = quotient;
+        while self.slots[bucket].shifted {
+            bucket = self.previous(bucket);
+        }
+
+        let mut run = bucket;
+        while bucket != quotient {
+            loop {
+                run = self.next(run);
+                if !self.slots[run].continuation {
+                    break;
+                }
+            }
+            loop {
+                bucket = self.next(bucket);
+                if self.slots[bucket].occupied {
+                    break;
+                }
+            }
+        }
+        run
+    }
+
+    fn shift_in(&mut self, mut index: usize, remainder: u64, continuation: bool, shifted: bool) {
+        let mut carried = Slot {
+            remainder,
+            occupied: false,
+            continuation,
+            shifted,
+        };
+
+        loop {
+            let slot = &mut self.slots[index];
+            let was_empty = slot.is_empty();
+
+            let displaced = Slot {
+                occupied: false,
+                ..*slot
+            };
+            slot.remainder = carried.remainder;
+            slot.continuation = carried.continuation;
+            slot.shifted = carried.shifted;
+
+            if was_empty {
+                return;
+            }
+
+            carried = Slot {
+                shifted: true,
+                ..displaced
+            };
+            index = self.next(index);
+        }
+    }
+
+    fn find(&self, quotient: usize, remainder: u64) -> Option<usize> {
+        if !self.slots[quotient].occupied {
+            return None;
+        }
+
+        let mut index = self.run_start(quotient);
+        loop {
+            let existing = self.slots[index].remainder;
+            if existing == remainder {
+                return Some(index);
+            }
+            if existing > remainder {
+                return None;
+            }
+            index = self.next(index);
+            if !self.slots[index].continuation {
+                return None;
+            }
+        }
+    }
+
+    pub fn remove(&mut self, item: &str) -> bool {
+        let (quotient, remainder) = self.split(item);
+        let Some(index) = self.find(quotient, remainder) else {
+            return false;
+        };
+
+        let removed_run_start = self.slots[index].is_run_start();
+        if removed_run_start && !self.slots[self.next(index)].continuation {
+            self.slots[quotient].occupied = false;
+        }
+
+        self.shift_out(index, quotient);
+
+        if removed_run_start && self.slots[index].continuation {
+            self.slots[index].continuation = false;
+            if index == quotient {
+                self.slots[index].shifted = false;
+            }
+        }
+
+        self.len -= 1;
+        true
+    }
+
+    fn shift_out(&mut self, mut index: usize, mut quotient: usize) {
+        let origin = index;
+        let mut following = self.next(index);
+
+        loop {
+            let next = self.slots[following];
+            if next.is_empty() || next.is_cluster_start() || following == origin {
+                self.slots[index] = Slot {
+                    occupied: self.slots[index].occupied,
+                    ..Slot::default()
+                };
+                return;
+            }
+
+            let mut shifted = next.shifted;
+            if next.is_run_start() {
+                loop {
+                    quotient = self.next(quotient);
+                    if self.slots[quotient].occupied {
+                        break;
+                    }
+                }
+                if quotient == index {
+                    shifted = false;
+                }
+            }
+
+            let slot = &mut self.slots[index];
+            slot.remainder = next.remainder;
+            slot.continuation = next.continuation;
+            slot.shifted = shifted;
+
+            index = following;
+            following = self.next(following);
+        }
+    }
+}
+
+impl MembershipFilter for QuotientFilter {
+    fn name(&self) -> &'static str {
+        "Quotient Filter"
+    }
+
+    fn insert(&mut self, item: &str) -> bool {
+        if self.len >= self.slots.len() {
+            return false;
+        }
+
+        let (quotient, remainder) = self.split(item);
+
+        if self.slots[quotient].is_empty() {
+            self.slots[quotient] = Slot {
+                remainder,
+                occupied: true,
+                continuation: false,
+                shifted: false,
+            };
+            self.len += 1;
+            return true;
+        }
+
+        let had_run = self.slots[quotient].occupied;
+        self.slots[quotient].occupied = true;
+        let start = self.run_start(quotient);
+
+        if !had_run {
+            self.shift_in(start, remainder, false, start != quotient);
+            self.len += 1;
+            return true;
+        }
+
+        let mut index = start;
+        loop {
+            if self.slots[index].remainder > remainder {
+                break;
+            }
+            index = self.next(index);
+            if !self.slots[index].continuation {
+                break;
+            }
+        }
+
+        if index == start {
+            self.slots[start].continuation = true;
+            self.shift_in(start, remainder, false, start != quotient);
+        } else {
+            self.shift_in(index, remainder, true, true);
+        }
+
+        self.len += 1;
+        true
+    }
+
+    fn contains(&self, item: &str) -> bool {
+        let (quotient, remainder) = self.split(item);
+        self.find(quotient, remainder).is_some()
+    }
+
+    fn memory_bits(&self) -> usize {
+        self.slots.len() * std::mem::size_of::<Slot>() * 8
+    }
+
+    fn packed_bits(&self) -> usize {
+        self.slots.len() * (self.remainder_bits as usize + METADATA_BITS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::*;
+    use std::collections::HashMap;
+
+    fn assert_matches_model(filter: &QuotientFilter, model: &HashMap<(usize, u64), usize>, probes: &[String]) {
+        for probe in probes {
+            let expected = model.get(&filter.split(probe)).is_some_and(|&count| count > 0);
+            assert_eq!(filter.contains(probe), expected, "{}", probe);
+        }
+        assert_eq!(filter.len(), model.values().sum::<usize>());
+    }
+
+    #[test]
+    fn test_inserts_and_removes_match_fingerprint_model() {
+        let mut rng = StdRng::seed_from_u64(32);
+        let keys: Vec<String> = (0..400).map(|i| format!("key{}", i)).collect();
+
+        for _ in 0..20 {
+            let mut filter = QuotientFilter::new(48, 0.05);
+            let mut model: HashMap<(usize, u64), usize> = HashMap::new();
+
+            for _ in 0..300 {
+                let key = keys.choose(&mut rng).unwrap();
+                let fingerprint = filter.split(key);
+                if rng.random_bool(0.6) {
+                    if filter.insert(key) {
+                        *model.entry(fingerprint).or_default() += 1;
+                    } else {
+                        assert_eq!(filter.len(), filter.slots.len());
+                    }
+                } else {
+                    let present = model.get(&fingerprint).is_some_and(|&count| count > 0);
+                    assert_eq!(filter.remove(key), present);
+                    if present {
+                        *model.get_mut(&fingerprint).unwrap() -= 1;
+                    }
+                }
+                assert_matches_model(&filter, &model, &keys);
+            }
+        }
+    }
+
+    #[test]
+    fn test_memory_reports_allocated_slots() {
+        let filter = QuotientFilter::new(1_000, 0.01);
+        assert_eq!(filter.memory_bits(), filter.slots.len() * std::mem::size_of::<Slot>() * 8);
+        assert_eq!(filter.packed_bits(), filter.slots.len() * (filter.remainder_bits as usize + METADATA_BITS));
+    }
+
+    #[test]
+    fn test_full_and_wrapped_table() {
+        let mut filter = QuotientFilter::new(6, 0.01);
+        let capacity = filter.slots.len();
+        let last = capacity - 1;
+        let keys: Vec<String> = (0..)
+            .map(|i| format!("wrap{}", i))
+            .filter(|key| filter.split(key).0 == last)
+            .take(capacity)
+            .collect();
+
+        for key in &keys {
+            assert!(filter.insert(key));
+        }
+        assert_eq!(filter.len(), capacity);
+        assert!(filter.slots.iter().all(|slot| !slot.is_empty()));
+        assert!(filter.slots[0].shifted);
+        assert!(!filter.insert("one more"));
+        assert!(keys.iter().all(|key| filter.contains(key)));
+
+        for key in keys.iter().step_by(2) {
+            assert!(filter.remove(key));
+        }
+        assert!(keys.iter().skip(1).step_by(2).all(|key| filter.contains(key)));
+        assert_eq!(filter.len(), capacity / 2);
+        assert!(filter.insert("one more"));
+        assert!(filter.contains("one more"));
+    }
+
+    #[test]
+    fn test_no_false_negatives_and_fpr_near_target() {
+        for target in [0.05, 0.01] {
+            let mut filter = QuotientFilter::new(5_000, target);
+            for i in 0..5_000 {
+                assert!(filter.insert(&format!("member{}", i)));
+            }
+            assert!((0..5_000).all(|i| filter.contains(&format!("member{}", i))));
+
+            let false_positives = (0..50_000).filter(|i| filter.contains(&format!("absent{}", i))).count();
+            let measured = false_positives as f64 / 50_000.0;
+            assert!(measured <= target * 1.5, "target {} measured {}", target, measured);
+        }
+    }
+}
@@ -0,0 +1,114 @@
+pub const NODE_KEYS: usize = 16;
+
+#[derive(Debug, Clone)]
+pub struct STree {
+    nodes: Vec<u32>,
+    blocks: usize,
+    len: usize,
+    contains_max: bool,
+}
+
+impl STree {
+    pub fn build(sorted: &[u32]) -> Self {
+        let blocks = sorted.len().div_ceil(NODE_KEYS);
+        let mut tree = Self {
+            nodes: vec![u32::MAX; blocks * NODE_KEYS],
+            blocks,
+            len: sorted.len(),
+            contains_max: sorted.last() == Some(&u32::MAX),
+        };
+
+        let mut next = 0;
+        tree.fill(sorted, &mut next, 0);
+        tree
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn memory_bytes(&self) -> usize {
+        self.nodes.len() * std::mem::size_of::<u32>()
+    }
+
+    pub fn height(&self) -> usize {
+        let mut height = 0;
+        let mut k = 0;
+        while k < self.blocks {
+            height += 1;
+            k = child(k, 0);
+        }
+        height
+    }
+
+    fn fill(&mut self, sorted: &[u32], next: &mut usize, k: usize) {
+        if k >= self.blocks {
+            return;
+        }
+
+        for i in 0..NODE_KEYS {
+            self.fill(sorted, next, child(k, i));
+            if *next < sorted.len() {
+                self.nodes[k * NODE_KEYS + i] = sorted[*next];
+                *next += 1;
+            }
+        }
+        self.fill(sorted, next, child(k, NODE_KEYS));
+    }
+
+    pub fn search(&self, target: u32) -> (bool, usize) {
+        let mut k = 0;
+        let mut candidate = None;
+        let mut comparisons = 0;
+
+        while k < self.blocks {
+            let node = &self.nodes[k * NODE_KEYS..(k + 1) * NODE_KEYS];
+            let rank = node.iter().map(|&key| usize::from(key < target)).sum::<usize>();
+            comparisons += NODE_KEYS;
+
+            if rank < NODE_KEYS {
+                candidate = Some(node[rank]);
+            }
+            k = child(k, rank);
+        }
+
+        comparisons += 1;
+        let found = match candidate {
+            Some(u32::MAX) => target == u32::MAX && self.contains_max,
+            Some(key) => key == target,
+            None => false,
+        };
+        (found, comparisons)
+    }
+}
+
+fn child(k: usize, i: usize) -> usize {
+    k * (NODE_KEYS + 1) + i + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search::binary_search;
+    use crate::search::layout_fixtures::{datasets, targets};
+
+    #[test]
+    fn test_matches_binary_search() {
+        for data in datasets() {
+            let tree = STree::build(&data);
+            assert_eq!(tree.len(), data.len());
+
+            for target in targets(&data) {
+                let expected = binary_search::search(&data, &target).0;
+                assert_eq!(tree.search(target).0, expected, "n={} target={}", data.len(), target);
+            }
+        }
+
+        let without_max = STree::build(&[1, 2, 3]);
+        assert!(!without_max.search(u32::MAX).0);
+    }
+}
@@ -0,0 +1,200 @@
+use crate::prelude::*;
+use rand::prelude::*;
+use rand::rngs::StdRng;
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Popularity {
+    Uniform,
+    Zipfian(f64),
+}
+
+impl Popularity {
+    pub fn name(&self) -> String {
+        match self {
+            Self::Uniform => "Uniform".to_string(),
+            Self::Zipfian(exponent) => format!("Zipfian (s={})", exponent),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct WorkloadConfig {
+    pub targets: usize,
+    pub hit_ratio: f64,
+    pub popularity: Popularity,
+    pub seed: Option<u64>,
+}
+
+impl Default for WorkloadConfig {
+    fn default() -> Self {
+        Self {
+            targets: 1_000,
+            hit_ratio: 0.8,
+            popularity: Popularity::Uniform,
+            seed: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Workload {
+    pub targets: Vec<String>,
+    pub expected_hits: usize,
+    pub distinct_targets: usize,
+}
+
+impl Workload {
+    pub fn expected_misses(&self) -> usize {
+        self.targets.len() - self.expected_hits
+    }
+}
+
+pub fn generate(words: &[String], config: &WorkloadConfig) -> Result<Workload> {
+    if config.targets == 0 {
+        return Err(Error::validation("Workload must contain at least one target"));
+    }
+
+    if !(0.0..=1.0).contains(&config.hit_ratio) {
+        return Err(Error::validation("Hit ratio must be between 0.0 and 1.0"));
+    }
+
+    if let Popularity::Zipfian(exponent) = config.popularity
+        && (!exponent.is_finite() || exponent <= 0.0)
+    {
+        return Err(Error::validation("Zipfian exponent must be a positive number"));
+    }
+
+    let mut rng = match config.seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_rng(&mut rand::rng()),
+    };
+
+    let present: HashSet<&str> = words.iter().map(|w| w.as_str()).collect();
+    let mut hit_pool: Vec<&str> = present.iter().copied().collect();
+    hit_pool.sort_unstable();
+    hit_pool.shuffle(&mut rng);
+
+    if hit_pool.is_empty() && config.hit_ratio > 0.0 {
+        return Err(Error::validation("Cannot generate hits from an empty word list"));
+    }
+
+    let miss_pool = absent_words(&hit_pool, &present, hit_pool.len().clamp(1, config.targets));
+
+    let hit_sampler = RankSampler::new(hit_pool.len(), config.popularity);
+    let miss_sampler = RankSampler::new(miss_pool.len(), config.popularity);
+
+    let expected_hits = (config.targets as f64 * config.hit_ratio).round() as usize;
+    let mut targets = Vec::with_capacity(config.targets);
+
+    for i in 0..config.targets {
+        if i < expected_hits {
+            targets.push(hit_pool[hit_sampler.sample(&mut rng)].to_string());
+        } else {
+            targets.push(miss_pool[miss_sampler.sample(&mut rng)].clone());
+        }
+    }
+
+    targets.shuffle(&mut rng);
+    let distinct_targets = targets.iter().collect::<HashSet<_>>().len();
+
+    Ok(Workload {
+        targets,
+        expected_hits,
+        distinct_targets,
+    })
+}
+
+pub fn absent_words(source: &[&str], present: &HashSet<&str>, count: usize) -> Vec<String> {
+    let mut absent = Vec::with_capacity(count);
+    let mut seen = HashSet::new();
+    let suffixes = ['q', 'x', 'z', 'j'];
+
+    for (i, word) in source.iter().cycle().enumerate() {
+        if absent.len() >= count {
+            break;
+        }
+
+        let mut candidate = format!("{}{}", word, suffixes[i % suffixes.len()]);
+        while present.contains(candidate.as_str()) || seen.contains(&candidate) {
+            candidate.push(suffixes[i % suffixes.len()]);
+        }
+
+        seen.insert(candidate.clone());
+        absent.push(candidate);
+    }
+
+    if absent.is_empty() {
+        absent.push("q".to_string());
+    }
+
+    absent
+}
+
+struct RankSampler {
+    cumulative: Option<Vec<f64>>,
+    len: usize,
+}
+
+impl RankSampler {
+    fn new(len: usize, popularity: Popularity) -> Self {
+        let cumulative = match popularity {
+            Popularity::Uniform => None,
+            Popularity::Zipfian(exponent) => {
+                let mut total = 0.0;
+                let weights: Vec<f64> = (1..=len)
+                    .map(|rank| {
+                        total += 1.0 / (rank as f64).powf(exponent);
+                        total
+                    })
+                    .collect();
+                Some(weights.into_iter().map(|w| w / total).collect())
+            }
+        };
+
+        Self { cumulative, len }
+    }
+
+    fn sample(&self, rng: &mut StdRng) -> usize {
+        match &self.cumulative {
+            None => rng.random_range(0..self.len),
+            Some(cumulative) => {
+                let point: f64 = rng.random();
+                cumulative.partition_point(|&c| c < point).min(self.len - 1)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zipfian_sampler_bounds_and_skew() {
+        let len = 1_000;
+        let mut rng = StdRng::seed_from_u64(30);
+
+        for exponent in [0.5, 1.0, 1.5] {
+            let sampler = RankSampler::new(len, Popularity::Zipfian(exponent));
+            let mut counts = vec![0usize; len];
+            for _ in 0..50_000 {
+                let rank = sampler.sample(&mut rng);
+                assert!(rank < len);
+                counts[rank] += 1;
+            }
+
+            let first_decile: usize = counts[..len / 10].iter().sum();
+            let last_decile: usize = counts[len - len / 10..].iter().sum();
+            assert!(counts[0] > counts[len - 1], "exponent {}", exponent);
+            assert!(first_decile > 2 * last_decile, "exponent {}", exponent);
+        }
+
+        let sampler = RankSampler::new(len, Popularity::Zipfian(1.0));
+        let top: usize = (0..50_000).filter(|_| sampler.sample(&mut rng) < len / 10).count();
+        assert!(top > 25_000, "top decile share {}", top);
+
+        let single = RankSampler::new(1, Popularity::Zipfian(1.0));
+        assert!((0..100).all(|_| single.sample(&mut rng) == 0));
+    }
+}
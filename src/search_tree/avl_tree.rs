@@ -0,0 +1,261 @@
+use super::{OrderedMap, PerformanceCounter};
+use std::cmp::Ordering;
+
+type Link<K, V> = Option<Box<AvlNode<K, V>>>;
+
+#[derive(Debug, Clone)]
+struct AvlNode<K, V> {
+    key: K,
+    value: V,
+    height: usize,
+    left: Link<K, V>,
+    right: Link<K, V>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AvlTree<K, V> {
+    root: Link<K, V>,
+    len: usize,
+}
+
+impl<K: Ord, V> AvlTree<K, V> {
+    pub fn new() -> Self {
+        Self { root: None, len: 0 }
+    }
+
+    pub fn insert_or_merge<F>(&mut self, key: K, value: V, merge: F, counter: &mut PerformanceCounter) -> bool
+    where
+        F: FnOnce(&mut V, V),
+    {
+        let mut merged = false;
+        let root = insert(self.root.take(), key, value, counter, |existing, value| {
+            merge(existing, value);
+            merged = true;
+        });
+        self.root = Some(root);
+        if !merged {
+            self.len += 1;
+        }
+        !merged
+    }
+
+    pub fn entries(&self) -> Vec<(&K, &V)> {
+        let mut entries = Vec::with_capacity(self.len);
+        let mut stack = Vec::new();
+        let mut current = self.root.as_deref();
+
+        while current.is_some() || !stack.is_empty() {
+            while let Some(node) = current {
+                stack.push(node);
+                current = node.left.as_deref();
+            }
+            let Some(node) = stack.pop() else {
+                break;
+            };
+            entries.push((&node.key, &node.value));
+            current = node.right.as_deref();
+        }
+        entries
+    }
+}
+
+impl<K: Ord, V> Default for AvlTree<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord, V> OrderedMap<K, V> for AvlTree<K, V> {
+    fn name(&self) -> &'static str {
+        "AVL Tree"
+    }
+
+    fn insert(&mut self, key: K, value: V, counter: &mut PerformanceCounter) -> Option<V> {
+        let mut replaced = None;
+        let root = insert(self.root.take(), key, value, counter, |existing, value| {
+            replaced = Some(std::mem::replace(existing, value));
+        });
+        self.root = Some(root);
+        if replaced.is_none() {
+            self.len += 1;
+        }
+        replaced
+    }
+
+    fn remove(&mut self, key: &K, counter: &mut PerformanceCounter) -> Option<V> {
+        let (root, removed) = remove(self.root.take(), key, counter);
+        self.root = root;
+        if removed.is_some() {
+            self.len -= 1;
+        }
+        removed
+    }
+
+    fn get(&self, key: &K, counter: &mut PerformanceCounter) -> Option<&V> {
+        let mut current = self.root.as_deref();
+        while let Some(node) = current {
+            current = match counter.compare(key, &node.key) {
+                Ordering::Equal => return Some(&node.value),
+                Ordering::Less => node.left.as_deref(),
+                Ordering::Greater => node.right.as_deref(),
+            };
+        }
+        None
+    }
+
+    fn range(&self, low: &K, high: &K, counter: &mut PerformanceCounter) -> Vec<(&K, &V)> {
+        let mut entries = Vec::new();
+        collect_range(self.root.as_deref(), low, high, counter, &mut entries);
+        entries
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn height(&self) -> usize {
+        height(&self.root)
+    }
+}
+
+fn height<K, V>(link: &Link<K, V>) -> usize {
+    link.as_ref().map_or(0, |node| node.height)
+}
+
+fn update<K, V>(node: &mut AvlNode<K, V>) {
+    node.height = 1 + height(&node.left).max(height(&node.right));
+}
+
+fn balance_factor<K, V>(node: &AvlNode<K, V>) -> isize {
+    height(&node.left) as isize - height(&node.right) as isize
+}
+
+fn rotate_right<K, V>(mut node: Box<AvlNode<K, V>>, counter: &mut PerformanceCounter) -> Box<AvlNode<K, V>> {
+    counter.rotate();
+    let mut pivot = node.left.take().expect("rotate_right requires a left child");
+    node.left = pivot.right.take();
+    update(&mut node);
+    pivot.right = Some(node);
+    update(&mut pivot);
+    pivot
+}
+
+fn rotate_left<K, V>(mut node: Box<AvlNode<K, V>>, counter: &mut PerformanceCounter) -> Box<AvlNode<K, V>> {
+    counter.rotate();
+    let mut pivot = node.right.take().expect("rotate_left requires a right child");
+    node.right = pivot.left.take();
+    update(&mut node);
+    pivot.left = Some(node);
+    update(&mut pivot);
+    pivot
+}
+
+fn rebalance<K, V>(mut node: Box<AvlNode<K, V>>, counter: &mut PerformanceCounter) -> Box<AvlNode<K, V>> {
+    update(&mut node);
+    let balance = balance_factor(&node);
+
+    if balance > 1 {
+        let left = node.left.take().expect("left-heavy node has a left child");
+        node.left = Some(if balance_factor(&left) < 0 { rotate_left(left, counter) } else { left });
+        return rotate_right(node, counter);
+    }
+
+    if balance < -1 {
+        let right = node.right.take().expect("right-heavy node has a right child");
+        node.right = Some(if balance_factor(&right) > 0 { rotate_right(right, counter) } else { right });
+        return rotate_left(node, counter);
+    }
+
+    node
+}
+
+fn insert<K: Ord, V, F>(link: Link<K, V>, key: K, value: V, counter: &mut PerformanceCounter, merge: F) -> Box<AvlNode<K, V>>
+where
+    F: FnOnce(&mut V, V),
+{
+    let Some(mut node) = link else {
+        counter.allocate_memory(std::mem::size_of::<AvlNode<K, V>>());
+        let node = AvlNode { key, value, height: 1, left: None, right: None };
+        return Box::new(node);
+    };
+
+    match counter.compare(&key, &node.key) {
+        Ordering::Equal => {
+            merge(&mut node.value, value);
+            return node;
+        }
+        Ordering::Less => node.left = Some(insert(node.left.take(), key, value, counter, merge)),
+        Ordering::Greater => node.right = Some(insert(node.right.take(), key, value, counter, merge)),
+    }
+
+    rebalance(node, counter)
+}
+
+fn remove<K: Ord, V>(link: Link<K, V>, key: &K, counter: &mut PerformanceCounter) -> (Link<K, V>, Option<V>) {
+    let Some(mut node) = link else {
+        return (None, None);
+    };
+
+    match counter.compare(key, &node.key) {
+        Ordering::Less => {
+            let (child, removed) = remove(node.left.take(), key, counter);
+            node.left = child;
+            (Some(rebalance(node, counter)), removed)
+        }
+        Ordering::Greater => {
+            let (child, removed) = remove(node.right.take(), key, counter);
+            node.right = child;
+            (Some(rebalance(node, counter)), removed)
+        }
+        Ordering::Equal => {
+            let AvlNode { value, left, right, .. } = *node;
+            let replacement = match (left, right) {
+                (None, None) => None,
+                (Some(child), None) | (None, Some(child)) => Some(child),
+                (Some(left), Some(right)) => {
+                    let (rest, mut successor) = remove_min(right, counter);
+                    successor.left = Some(left);
+                    successor.right = rest;
+                    Some(rebalance(successor, counter))
+                }
+            };
+            (replacement, Some(value))
+        }
+    }
+}
+
+fn remove_min<K, V>(mut node: Box<AvlNode<K, V>>, counter: &mut PerformanceCounter) -> (Link<K, V>, Box<AvlNode<K, V>>) {
+    match node.left.take() {
+        None => (node.right.take(), node),
+        Some(left) => {
+            let (rest, min) = remove_min(left, counter);
+            node.left = rest;
+            (Some(rebalance(node, counter)), min)
+        }
+    }
+}
+
+fn collect_range<'a, K: Ord, V>(
+    link: Option<&'a AvlNode<K, V>>,
+    low: &K,
+    high: &K,
+    counter: &mut PerformanceCounter,
+    entries: &mut Vec<(&'a K, &'a V)>,
+) {
+    let Some(node) = link else {
+        return;
+    };
+
+    let above_low = counter.compare(&node.key, low) != Ordering::Less;
+    let below_high = counter.compare(&node.key, high) != Ordering::Greater;
+
+    if above_low {
+        collect_range(node.left.as_deref(), low, high, counter, entries);
+    }
+    if above_low && below_high {
+        entries.push((&node.key, &node.value));
+    }
+    if below_high {
+        collect_range(node.right.as_deref(), low, high, counter, entries);
+    }
+}
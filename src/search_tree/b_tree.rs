@@ -0,0 +1,340 @@
+use super::{OrderedMap, PerformanceCounter};
+use std::cmp::Ordering;
+
+pub const DEFAULT_ORDER: usize = 32;
+
+#[derive(Debug, Clone)]
+struct BNode<K, V> {
+    keys: Vec<K>,
+    values: Vec<V>,
+    children: Vec<BNode<K, V>>,
+}
+
+impl<K: Ord, V> BNode<K, V> {
+    fn new() -> Self {
+        Self {
+            keys: Vec::new(),
+            values: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    fn find(&self, key: &K, counter: &mut PerformanceCounter) -> std::result::Result<usize, usize> {
+        let mut left = 0;
+        let mut right = self.keys.len();
+
+        while left < right {
+            let mid = left + (right - left) / 2;
+            match counter.compare(key, &self.keys[mid]) {
+                Ordering::Equal => return Ok(mid),
+                Ordering::Less => right = mid,
+                Ordering::Greater => left = mid + 1,
+            }
+        }
+
+        Err(left)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BTree<K, V> {
+    root: BNode<K, V>,
+    min_degree: usize,
+    len: usize,
+}
+
+impl<K: Ord, V> BTree<K, V> {
+    pub fn new(order: usize) -> Self {
+        Self {
+            root: BNode::new(),
+            min_degree: (order / 2).max(2),
+            len: 0,
+        }
+    }
+
+    pub fn order(&self) -> usize {
+        self.min_degree * 2
+    }
+
+    fn max_keys(&self) -> usize {
+        2 * self.min_degree - 1
+    }
+
+    fn split_child(&self, parent: &mut BNode<K, V>, index: usize, counter: &mut PerformanceCounter) {
+        counter.split_node();
+        counter.allocate_memory(std::mem::size_of::<BNode<K, V>>());
+
+        let t = self.min_degree;
+        let child = &mut parent.children[index];
+
+        let mut sibling = BNode::new();
+        sibling.keys = child.keys.split_off(t);
+        sibling.values = child.values.split_off(t);
+        if !child.is_leaf() {
+            sibling.children = child.children.split_off(t);
+        }
+
+        let median_key = child.keys.pop().expect("full node has a median key");
+        let median_value = child.values.pop().expect("full node has a median value");
+
+        parent.keys.insert(index, median_key);
+        parent.values.insert(index, median_value);
+        parent.children.insert(index + 1, sibling);
+    }
+
+    fn insert_non_full(&self, node: &mut BNode<K, V>, key: K, value: V, counter: &mut PerformanceCounter) -> Option<V> {
+        let mut index = match node.find(&key, counter) {
+            Ok(index) => return Some(std::mem::replace(&mut node.values[index], value)),
+            Err(index) => index,
+        };
+
+        if node.is_leaf() {
+            node.keys.insert(index, key);
+            node.values.insert(index, value);
+            return None;
+        }
+
+        if node.children[index].keys.len() == self.max_keys() {
+            self.split_child(node, index, counter);
+            match counter.compare(&key, &node.keys[index]) {
+                Ordering::Equal => return Some(std::mem::replace(&mut node.values[index], value)),
+                Ordering::Greater => index += 1,
+                Ordering::Less => {}
+            }
+        }
+
+        self.insert_non_full(&mut node.children[index], key, value, counter)
+    }
+
+    fn borrow_from_previous(node: &mut BNode<K, V>, index: usize, counter: &mut PerformanceCounter) {
+        counter.rotate();
+        let (left, right) = node.children.split_at_mut(index);
+        let sibling = &mut left[index - 1];
+        let child = &mut right[0];
+
+        let sibling_key = sibling.keys.pop().expect("sibling has spare keys");
+        let sibling_value = sibling.values.pop().expect("sibling has spare values");
+        let parent_key = std::mem::replace(&mut node.keys[index - 1], sibling_key);
+        let parent_value = std::mem::replace(&mut node.values[index - 1], sibling_value);
+
+        child.keys.insert(0, parent_key);
+        child.values.insert(0, parent_value);
+        if let Some(grandchild) = sibling.children.pop() {
+            child.children.insert(0, grandchild);
+        }
+    }
+
+    fn borrow_from_next(node: &mut BNode<K, V>, index: usize, counter: &mut PerformanceCounter) {
+        counter.rotate();
+        let (left, right) = node.children.split_at_mut(index + 1);
+        let child = &mut left[index];
+        let sibling = &mut right[0];
+
+        let sibling_key = sibling.keys.remove(0);
+        let sibling_value = sibling.values.remove(0);
+        let parent_key = std::mem::replace(&mut node.keys[index], sibling_key);
+        let parent_value = std::mem::replace(&mut node.values[index], sibling_value);
+
+        child.keys.push(parent_key);
+        child.values.push(parent_value);
+        if !sibling.is_leaf() {
+            child.children.push(sibling.children.remove(0));
+        }
+    }
+
+    fn merge_children(node: &mut BNode<K, V>, index: usize, counter: &mut PerformanceCounter) {
+        counter.merge_nodes();
+        let sibling = node.children.remove(index + 1);
+        let separator_key = node.keys.remove(index);
+        let separator_value = node.values.remove(index);
+
+        let child = &mut node.children[index];
+        child.keys.push(separator_key);
+        child.values.push(separator_value);
+        child.keys.extend(sibling.keys);
+        child.values.extend(sibling.values);
+        child.children.extend(sibling.children);
+    }
+
+    fn ensure_child_has_spare(&self, node: &mut BNode<K, V>, index: usize, counter: &mut PerformanceCounter) -> usize {
+        let t = self.min_degree;
+        if node.children[index].keys.len() >= t {
+            return index;
+        }
+
+        if index > 0 && node.children[index - 1].keys.len() >= t {
+            Self::borrow_from_previous(node, index, counter);
+            index
+        } else if index + 1 < node.children.len() && node.children[index + 1].keys.len() >= t {
+            Self::borrow_from_next(node, index, counter);
+            index
+        } else if index + 1 < node.children.len() {
+            Self::merge_children(node, index, counter);
+            index
+        } else {
+            Self::merge_children(node, index - 1, counter);
+            index - 1
+        }
+    }
+
+    fn remove_from(&self, node: &mut BNode<K, V>, key: &K, counter: &mut PerformanceCounter) -> Option<V> {
+        match node.find(key, counter) {
+            Ok(index) if node.is_leaf() => {
+                node.keys.remove(index);
+                Some(node.values.remove(index))
+            }
+            Ok(index) => {
+                let t = self.min_degree;
+                if node.children[index].keys.len() >= t {
+                    let (key, value) = self.remove_max(&mut node.children[index], counter);
+                    node.keys[index] = key;
+                    Some(std::mem::replace(&mut node.values[index], value))
+                } else if node.children[index + 1].keys.len() >= t {
+                    let (key, value) = self.remove_min(&mut node.children[index + 1], counter);
+                    node.keys[index] = key;
+                    Some(std::mem::replace(&mut node.values[index], value))
+                } else {
+                    Self::merge_children(node, index, counter);
+                    self.remove_from(&mut node.children[index], key, counter)
+                }
+            }
+            Err(_) if node.is_leaf() => None,
+            Err(index) => {
+                let index = self.ensure_child_has_spare(node, index, counter);
+                self.remove_from(&mut node.children[index], key, counter)
+            }
+        }
+    }
+
+    fn remove_max(&self, node: &mut BNode<K, V>, counter: &mut PerformanceCounter) -> (K, V) {
+        if node.is_leaf() {
+            let key = node.keys.pop().expect("non-empty node");
+            let value = node.values.pop().expect("non-empty node");
+            return (key, value);
+        }
+
+        let index = self.ensure_child_has_spare(node, node.children.len() - 1, counter);
+        self.remove_max(&mut node.children[index], counter)
+    }
+
+    fn remove_min(&self, node: &mut BNode<K, V>, counter: &mut PerformanceCounter) -> (K, V) {
+        if node.is_leaf() {
+            return (node.keys.remove(0), node.values.remove(0));
+        }
+
+        let index = self.ensure_child_has_spare(node, 0, counter);
+        self.remove_min(&mut node.children[index], counter)
+    }
+}
+
+impl<K: Ord, V> Default for BTree<K, V> {
+    fn default() -> Self {
+        Self::new(DEFAULT_ORDER)
+    }
+}
+
+impl<K: Ord, V> OrderedMap<K, V> for BTree<K, V> {
+    fn name(&self) -> &'static str {
+        "B-Tree"
+    }
+
+    fn insert(&mut self, key: K, value: V, counter: &mut PerformanceCounter) -> Option<V> {
+        if self.root.keys.len() == self.max_keys() {
+            let old_root = std::mem::replace(&mut self.root, BNode::new());
+            self.root.children.push(old_root);
+            let mut root = std::mem::replace(&mut self.root, BNode::new());
+            self.split_child(&mut root, 0, counter);
+            self.root = root;
+        }
+
+        let mut root = std::mem::replace(&mut self.root, BNode::new());
+        let replaced = self.insert_non_full(&mut root, key, value, counter);
+        self.root = root;
+
+        if replaced.is_none() {
+            self.len += 1;
+        }
+        replaced
+    }
+
+    fn remove(&mut self, key: &K, counter: &mut PerformanceCounter) -> Option<V> {
+        let mut root = std::mem::replace(&mut self.root, BNode::new());
+        let removed = self.remove_from(&mut root, key, counter);
+
+        if root.keys.is_empty() && !root.is_leaf() {
+            root = root.children.remove(0);
+        }
+        self.root = root;
+
+        if removed.is_some() {
+            self.len -= 1;
+        }
+        removed
+    }
+
+    fn get(&self, key: &K, counter: &mut PerformanceCounter) -> Option<&V> {
+        let mut node = &self.root;
+        loop {
+            match node.find(key, counter) {
+                Ok(index) => return Some(&node.values[index]),
+                Err(_) if node.is_leaf() => return None,
+                Err(index) => node = &node.children[index],
+            }
+        }
+    }
+
+    fn range(&self, low: &K, high: &K, counter: &mut PerformanceCounter) -> Vec<(&K, &V)> {
+        let mut entries = Vec::new();
+        collect_range(&self.root, low, high, counter, &mut entries);
+        entries
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn height(&self) -> usize {
+        if self.len == 0 {
+            return 0;
+        }
+
+        let mut height = 1;
+        let mut node = &self.root;
+        while let Some(child) = node.children.first() {
+            height += 1;
+            node = child;
+        }
+        height
+    }
+}
+
+fn collect_range<'a, K: Ord, V>(
+    node: &'a BNode<K, V>,
+    low: &K,
+    high: &K,
+    counter: &mut PerformanceCounter,
+    entries: &mut Vec<(&'a K, &'a V)>,
+) -> bool {
+    let start = match node.find(low, counter) {
+        Ok(index) | Err(index) => index,
+    };
+
+    for index in start..=node.keys.len() {
+        if !node.is_leaf() && !collect_range(&node.children[index], low, high, counter, entries) {
+            return false;
+        }
+
+        if index < node.keys.len() {
+            if counter.compare(&node.keys[index], high) == Ordering::Greater {
+                return false;
+            }
+            entries.push((&node.keys[index], &node.values[index]));
+        }
+    }
+
+    true
+}
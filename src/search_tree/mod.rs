@@ -0,0 +1,138 @@
+pub mod avl_tree;
+pub mod red_black_tree;
+pub mod b_tree;
+pub mod treap;
+pub mod skip_list;
+
+use std::cmp::Ordering;
+
+#[derive(Debug, Clone, Default)]
+pub struct PerformanceCounter {
+    pub comparisons: usize,
+    pub rotations: usize,
+    pub node_splits: usize,
+    pub node_merges: usize,
+    pub memory_allocations: usize,
+}
+
+impl PerformanceCounter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn compare<K: Ord + ?Sized>(&mut self, a: &K, b: &K) -> Ordering {
+        self.comparisons += 1;
+        a.cmp(b)
+    }
+
+    pub fn rotate(&mut self) {
+        self.rotations += 1;
+    }
+
+    pub fn split_node(&mut self) {
+        self.node_splits += 1;
+    }
+
+    pub fn merge_nodes(&mut self) {
+        self.node_merges += 1;
+    }
+
+    pub fn allocate_memory(&mut self, _size: usize) {
+        self.memory_allocations += 1;
+    }
+}
+
+pub trait OrderedMap<K: Ord, V> {
+    fn name(&self) -> &'static str;
+    fn insert(&mut self, key: K, value: V, counter: &mut PerformanceCounter) -> Option<V>;
+    fn remove(&mut self, key: &K, counter: &mut PerformanceCounter) -> Option<V>;
+    fn get(&self, key: &K, counter: &mut PerformanceCounter) -> Option<&V>;
+    fn range(&self, low: &K, high: &K, counter: &mut PerformanceCounter) -> Vec<(&K, &V)>;
+    fn len(&self) -> usize;
+    fn height(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn contains_key(&self, key: &K, counter: &mut PerformanceCounter) -> bool {
+        self.get(key, counter).is_some()
+    }
+}
+
+fn next_random(state: &mut u64) -> u64 {
+    *state ^= *state << 13;
+    *state ^= *state >> 7;
+    *state ^= *state << 17;
+    *state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    fn check_against_btreemap(map: &mut dyn OrderedMap<u32, u32>) {
+        let mut reference = BTreeMap::new();
+        let mut counter = PerformanceCounter::new();
+        let mut state = 0x1234_5678_u64;
+
+        for step in 0..5_000u32 {
+            let key = (next_random(&mut state) % 1_000) as u32;
+            if next_random(&mut state).is_multiple_of(3) {
+                assert_eq!(map.remove(&key, &mut counter), reference.remove(&key), "{} remove {}", map.name(), key);
+            } else {
+                assert_eq!(map.insert(key, step, &mut counter), reference.insert(key, step), "{} insert {}", map.name(), key);
+            }
+            assert_eq!(map.len(), reference.len(), "{} len", map.name());
+        }
+
+        for key in 0..1_000 {
+            assert_eq!(map.get(&key, &mut counter), reference.get(&key), "{} get {}", map.name(), key);
+        }
+
+        let expected: Vec<_> = reference.range(250..=500).collect();
+        assert_eq!(map.range(&250, &500, &mut counter), expected, "{} range", map.name());
+
+        let keys: Vec<u32> = reference.keys().copied().collect();
+        for key in keys {
+            assert_eq!(map.remove(&key, &mut counter), reference.remove(&key), "{} drain {}", map.name(), key);
+        }
+        assert!(map.is_empty(), "{} empty", map.name());
+        assert_eq!(map.height(), 0, "{} height", map.name());
+    }
+
+    #[test]
+    fn test_ordered_maps_match_btreemap() {
+        check_against_btreemap(&mut avl_tree::AvlTree::new());
+        check_against_btreemap(&mut red_black_tree::RedBlackTree::new());
+        check_against_btreemap(&mut b_tree::BTree::new(4));
+        check_against_btreemap(&mut b_tree::BTree::new(32));
+        check_against_btreemap(&mut treap::Treap::new());
+        check_against_btreemap(&mut skip_list::SkipList::new());
+    }
+
+    #[test]
+    fn test_balanced_heights_on_sorted_input() {
+        let mut counter = PerformanceCounter::new();
+        let mut avl = avl_tree::AvlTree::new();
+        let mut red_black = red_black_tree::RedBlackTree::new();
+        let mut b_tree = b_tree::BTree::new(16);
+
+        for key in 0..4_096u32 {
+            avl.insert(key, (), &mut counter);
+            red_black.insert(key, (), &mut counter);
+            b_tree.insert(key, (), &mut counter);
+        }
+
+        assert!(avl.height() <= 18, "AVL height {}", avl.height());
+        assert!(red_black.height() <= 24, "red-black height {}", red_black.height());
+        assert!(b_tree.height() <= 4, "B-tree height {}", b_tree.height());
+        assert!(counter.rotations > 0);
+        assert!(counter.node_splits > 0);
+    }
+}
@@ -0,0 +1,293 @@
+use super::{OrderedMap, PerformanceCounter};
+use std::cmp::Ordering;
+
+type Link<K, V> = Option<Box<RbNode<K, V>>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Colour {
+    Red,
+    Black,
+}
+
+impl Colour {
+    fn flip(self) -> Self {
+        match self {
+            Self::Red => Self::Black,
+            Self::Black => Self::Red,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct RbNode<K, V> {
+    key: K,
+    value: V,
+    colour: Colour,
+    left: Link<K, V>,
+    right: Link<K, V>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RedBlackTree<K, V> {
+    root: Link<K, V>,
+    len: usize,
+}
+
+impl<K: Ord, V> RedBlackTree<K, V> {
+    pub fn new() -> Self {
+        Self { root: None, len: 0 }
+    }
+
+    pub fn black_height(&self) -> usize {
+        let mut height = 0;
+        let mut current = self.root.as_deref();
+        while let Some(node) = current {
+            if node.colour == Colour::Black {
+                height += 1;
+            }
+            current = node.left.as_deref();
+        }
+        height
+    }
+}
+
+impl<K: Ord, V> Default for RedBlackTree<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord, V> OrderedMap<K, V> for RedBlackTree<K, V> {
+    fn name(&self) -> &'static str {
+        "Red-Black Tree"
+    }
+
+    fn insert(&mut self, key: K, value: V, counter: &mut PerformanceCounter) -> Option<V> {
+        let (mut root, replaced) = insert(self.root.take(), key, value, counter);
+        root.colour = Colour::Black;
+        self.root = Some(root);
+        if replaced.is_none() {
+            self.len += 1;
+        }
+        replaced
+    }
+
+    fn remove(&mut self, key: &K, counter: &mut PerformanceCounter) -> Option<V> {
+        self.get(key, counter)?;
+
+        let mut root = self.root.take()?;
+        if !is_red(&root.left) && !is_red(&root.right) {
+            root.colour = Colour::Red;
+        }
+
+        let (mut root, removed) = remove(root, key, counter);
+        if let Some(root) = root.as_mut() {
+            root.colour = Colour::Black;
+        }
+        self.root = root;
+        self.len -= 1;
+        Some(removed)
+    }
+
+    fn get(&self, key: &K, counter: &mut PerformanceCounter) -> Option<&V> {
+        let mut current = self.root.as_deref();
+        while let Some(node) = current {
+            current = match counter.compare(key, &node.key) {
+                Ordering::Equal => return Some(&node.value),
+                Ordering::Less => node.left.as_deref(),
+                Ordering::Greater => node.right.as_deref(),
+            };
+        }
+        None
+    }
+
+    fn range(&self, low: &K, high: &K, counter: &mut PerformanceCounter) -> Vec<(&K, &V)> {
+        let mut entries = Vec::new();
+        collect_range(self.root.as_deref(), low, high, counter, &mut entries);
+        entries
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn height(&self) -> usize {
+        fn depth<K, V>(link: &Link<K, V>) -> usize {
+            link.as_ref().map_or(0, |node| 1 + depth(&node.left).max(depth(&node.right)))
+        }
+        depth(&self.root)
+    }
+}
+
+fn is_red<K, V>(link: &Link<K, V>) -> bool {
+    link.as_ref().is_some_and(|node| node.colour == Colour::Red)
+}
+
+fn left_left_is_red<K, V>(node: &RbNode<K, V>) -> bool {
+    node.left.as_ref().is_some_and(|left| is_red(&left.left))
+}
+
+fn rotate_left<K, V>(mut node: Box<RbNode<K, V>>, counter: &mut PerformanceCounter) -> Box<RbNode<K, V>> {
+    counter.rotate();
+    let mut pivot = node.right.take().expect("rotate_left requires a right child");
+    node.right = pivot.left.take();
+    pivot.colour = node.colour;
+    node.colour = Colour::Red;
+    pivot.left = Some(node);
+    pivot
+}
+
+fn rotate_right<K, V>(mut node: Box<RbNode<K, V>>, counter: &mut PerformanceCounter) -> Box<RbNode<K, V>> {
+    counter.rotate();
+    let mut pivot = node.left.take().expect("rotate_right requires a left child");
+    node.left = pivot.right.take();
+    pivot.colour = node.colour;
+    node.colour = Colour::Red;
+    pivot.right = Some(node);
+    pivot
+}
+
+fn flip_colours<K, V>(node: &mut RbNode<K, V>) {
+    node.colour = node.colour.flip();
+    if let Some(left) = node.left.as_mut() {
+        left.colour = left.colour.flip();
+    }
+    if let Some(right) = node.right.as_mut() {
+        right.colour = right.colour.flip();
+    }
+}
+
+fn balance<K, V>(mut node: Box<RbNode<K, V>>, counter: &mut PerformanceCounter) -> Box<RbNode<K, V>> {
+    if is_red(&node.right) && !is_red(&node.left) {
+        node = rotate_left(node, counter);
+    }
+    if is_red(&node.left) && left_left_is_red(&node) {
+        node = rotate_right(node, counter);
+    }
+    if is_red(&node.left) && is_red(&node.right) {
+        flip_colours(&mut node);
+    }
+    node
+}
+
+fn insert<K: Ord, V>(link: Link<K, V>, key: K, value: V, counter: &mut PerformanceCounter) -> (Box<RbNode<K, V>>, Option<V>) {
+    let Some(mut node) = link else {
+        counter.allocate_memory(std::mem::size_of::<RbNode<K, V>>());
+        let node = RbNode { key, value, colour: Colour::Red, left: None, right: None };
+        return (Box::new(node), None);
+    };
+
+    let replaced = match counter.compare(&key, &node.key) {
+        Ordering::Equal => Some(std::mem::replace(&mut node.value, value)),
+        Ordering::Less => {
+            let (child, replaced) = insert(node.left.take(), key, value, counter);
+            node.left = Some(child);
+            replaced
+        }
+        Ordering::Greater => {
+            let (child, replaced) = insert(node.right.take(), key, value, counter);
+            node.right = Some(child);
+            replaced
+        }
+    };
+
+    (balance(node, counter), replaced)
+}
+
+fn move_red_left<K, V>(mut node: Box<RbNode<K, V>>, counter: &mut PerformanceCounter) -> Box<RbNode<K, V>> {
+    flip_colours(&mut node);
+    if node.right.as_ref().is_some_and(|right| is_red(&right.left)) {
+        let right = node.right.take().expect("checked above");
+        node.right = Some(rotate_right(right, counter));
+        node = rotate_left(node, counter);
+        flip_colours(&mut node);
+    }
+    node
+}
+
+fn move_red_right<K, V>(mut node: Box<RbNode<K, V>>, counter: &mut PerformanceCounter) -> Box<RbNode<K, V>> {
+    flip_colours(&mut node);
+    if left_left_is_red(&node) {
+        node = rotate_right(node, counter);
+        flip_colours(&mut node);
+    }
+    node
+}
+
+fn remove_min<K, V>(mut node: Box<RbNode<K, V>>, counter: &mut PerformanceCounter) -> (Link<K, V>, Box<RbNode<K, V>>) {
+    if node.left.is_none() {
+        return (None, node);
+    }
+
+    if !is_red(&node.left) && !left_left_is_red(&node) {
+        node = move_red_left(node, counter);
+    }
+
+    let (rest, min) = remove_min(node.left.take().expect("left child exists"), counter);
+    node.left = rest;
+    (Some(balance(node, counter)), min)
+}
+
+fn remove<K: Ord, V>(mut node: Box<RbNode<K, V>>, key: &K, counter: &mut PerformanceCounter) -> (Link<K, V>, V) {
+    let removed;
+
+    if counter.compare(key, &node.key) == Ordering::Less {
+        if !is_red(&node.left) && !left_left_is_red(&node) {
+            node = move_red_left(node, counter);
+        }
+        let (rest, value) = remove(node.left.take().expect("key lies in left subtree"), key, counter);
+        node.left = rest;
+        removed = value;
+    } else {
+        if is_red(&node.left) {
+            node = rotate_right(node, counter);
+        }
+
+        if counter.compare(key, &node.key) == Ordering::Equal && node.right.is_none() {
+            return (None, node.value);
+        }
+
+        if !is_red(&node.right) && !node.right.as_ref().is_some_and(|right| is_red(&right.left)) {
+            node = move_red_right(node, counter);
+        }
+
+        if counter.compare(key, &node.key) == Ordering::Equal {
+            let (rest, successor) = remove_min(node.right.take().expect("right child exists"), counter);
+            let successor = *successor;
+            node.key = successor.key;
+            removed = std::mem::replace(&mut node.value, successor.value);
+            node.right = rest;
+        } else {
+            let (rest, value) = remove(node.right.take().expect("key lies in right subtree"), key, counter);
+            node.right = rest;
+            removed = value;
+        }
+    }
+
+    (Some(balance(node, counter)), removed)
+}
+
+fn collect_range<'a, K: Ord, V>(
+    link: Option<&'a RbNode<K, V>>,
+    low: &K,
+    high: &K,
+    counter: &mut PerformanceCounter,
+    entries: &mut Vec<(&'a K, &'a V)>,
+) {
+    let Some(node) = link else {
+        return;
+    };
+
+    let above_low = counter.compare(&node.key, low) != Ordering::Less;
+    let below_high = counter.compare(&node.key, high) != Ordering::Greater;
+
+    if above_low {
+        collect_range(node.left.as_deref(), low, high, counter, entries);
+    }
+    if above_low && below_high {
+        entries.push((&node.key, &node.value));
+    }
+    if below_high {
+        collect_range(node.right.as_deref(), low, high, counter, entries);
+    }
+}
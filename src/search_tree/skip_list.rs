@@ -0,0 +1,174 @@
+use super::{next_random, OrderedMap, PerformanceCounter};
+use std::cmp::Ordering;
+
+const NIL: usize = usize::MAX;
+const HEAD: usize = 0;
+const MAX_LEVEL: usize = 32;
+const DEFAULT_SEED: u64 = 0x2545_F491_4F6C_DD1D;
+
+#[derive(Debug, Clone)]
+struct SkipNode<K, V> {
+    entry: Option<(K, V)>,
+    forward: Vec<usize>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SkipList<K, V> {
+    nodes: Vec<SkipNode<K, V>>,
+    free: Vec<usize>,
+    level: usize,
+    len: usize,
+    rng_state: u64,
+}
+
+impl<K: Ord, V> SkipList<K, V> {
+    pub fn new() -> Self {
+        Self::with_seed(DEFAULT_SEED)
+    }
+
+    pub fn with_seed(seed: u64) -> Self {
+        Self {
+            nodes: vec![SkipNode { entry: None, forward: vec![NIL; MAX_LEVEL] }],
+            free: Vec::new(),
+            level: 1,
+            len: 0,
+            rng_state: seed.max(1),
+        }
+    }
+
+    fn random_level(&mut self) -> usize {
+        let bits = next_random(&mut self.rng_state);
+        (bits.trailing_ones() as usize + 1).min(MAX_LEVEL)
+    }
+
+    fn key(&self, index: usize) -> &K {
+        &self.nodes[index].entry.as_ref().expect("non-head node has an entry").0
+    }
+
+    fn predecessors(&self, key: &K, counter: &mut PerformanceCounter) -> [usize; MAX_LEVEL] {
+        let mut update = [HEAD; MAX_LEVEL];
+        let mut current = HEAD;
+
+        for level in (0..self.level).rev() {
+            loop {
+                let next = self.nodes[current].forward[level];
+                if next == NIL || counter.compare(self.key(next), key) != Ordering::Less {
+                    break;
+                }
+                current = next;
+            }
+            update[level] = current;
+        }
+
+        update
+    }
+
+    fn lower_bound(&self, key: &K, counter: &mut PerformanceCounter) -> usize {
+        let update = self.predecessors(key, counter);
+        self.nodes[update[0]].forward[0]
+    }
+
+    fn allocate(&mut self, key: K, value: V, height: usize, counter: &mut PerformanceCounter) -> usize {
+        counter.allocate_memory(std::mem::size_of::<SkipNode<K, V>>() + height * std::mem::size_of::<usize>());
+        let node = SkipNode { entry: Some((key, value)), forward: vec![NIL; height] };
+
+        match self.free.pop() {
+            Some(index) => {
+                self.nodes[index] = node;
+                index
+            }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        }
+    }
+}
+
+impl<K: Ord, V> Default for SkipList<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord, V> OrderedMap<K, V> for SkipList<K, V> {
+    fn name(&self) -> &'static str {
+        "Skip List"
+    }
+
+    fn insert(&mut self, key: K, value: V, counter: &mut PerformanceCounter) -> Option<V> {
+        let update = self.predecessors(&key, counter);
+        let next = self.nodes[update[0]].forward[0];
+
+        if next != NIL && counter.compare(self.key(next), &key) == Ordering::Equal {
+            let entry = self.nodes[next].entry.as_mut().expect("non-head node has an entry");
+            return Some(std::mem::replace(&mut entry.1, value));
+        }
+
+        let height = self.random_level();
+        self.level = self.level.max(height);
+
+        let index = self.allocate(key, value, height, counter);
+        for (level, &predecessor) in update.iter().enumerate().take(height) {
+            self.nodes[index].forward[level] = self.nodes[predecessor].forward[level];
+            self.nodes[predecessor].forward[level] = index;
+        }
+
+        self.len += 1;
+        None
+    }
+
+    fn remove(&mut self, key: &K, counter: &mut PerformanceCounter) -> Option<V> {
+        let update = self.predecessors(key, counter);
+        let target = self.nodes[update[0]].forward[0];
+
+        if target == NIL || counter.compare(self.key(target), key) != Ordering::Equal {
+            return None;
+        }
+
+        for (level, &predecessor) in update.iter().enumerate().take(self.nodes[target].forward.len()) {
+            self.nodes[predecessor].forward[level] = self.nodes[target].forward[level];
+        }
+
+        while self.level > 1 && self.nodes[HEAD].forward[self.level - 1] == NIL {
+            self.level -= 1;
+        }
+
+        let node = std::mem::replace(&mut self.nodes[target], SkipNode { entry: None, forward: Vec::new() });
+        self.free.push(target);
+        self.len -= 1;
+        node.entry.map(|(_, value)| value)
+    }
+
+    fn get(&self, key: &K, counter: &mut PerformanceCounter) -> Option<&V> {
+        let candidate = self.lower_bound(key, counter);
+        if candidate == NIL || counter.compare(self.key(candidate), key) != Ordering::Equal {
+            return None;
+        }
+        self.nodes[candidate].entry.as_ref().map(|(_, value)| value)
+    }
+
+    fn range(&self, low: &K, high: &K, counter: &mut PerformanceCounter) -> Vec<(&K, &V)> {
+        let mut entries = Vec::new();
+        let mut current = self.lower_bound(low, counter);
+
+        while current != NIL {
+            let (key, value) = self.nodes[current].entry.as_ref().expect("non-head node has an entry");
+            if counter.compare(key, high) == Ordering::Greater {
+                break;
+            }
+            entries.push((key, value));
+            current = self.nodes[current].forward[0];
+        }
+
+        entries
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn height(&self) -> usize {
+        if self.len == 0 { 0 } else { self.level }
+    }
+}
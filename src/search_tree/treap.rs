@@ -0,0 +1,210 @@
+use super::{next_random, OrderedMap, PerformanceCounter};
+use std::cmp::Ordering;
+
+type Link<K, V> = Option<Box<TreapNode<K, V>>>;
+
+const DEFAULT_SEED: u64 = 0x9E37_79B9_7F4A_7C15;
+
+#[derive(Debug, Clone)]
+struct TreapNode<K, V> {
+    key: K,
+    value: V,
+    priority: u64,
+    left: Link<K, V>,
+    right: Link<K, V>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Treap<K, V> {
+    root: Link<K, V>,
+    len: usize,
+    rng_state: u64,
+}
+
+impl<K: Ord, V> Treap<K, V> {
+    pub fn new() -> Self {
+        Self::with_seed(DEFAULT_SEED)
+    }
+
+    pub fn with_seed(seed: u64) -> Self {
+        Self {
+            root: None,
+            len: 0,
+            rng_state: seed.max(1),
+        }
+    }
+}
+
+impl<K: Ord, V> Default for Treap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord, V> OrderedMap<K, V> for Treap<K, V> {
+    fn name(&self) -> &'static str {
+        "Treap"
+    }
+
+    fn insert(&mut self, key: K, value: V, counter: &mut PerformanceCounter) -> Option<V> {
+        let priority = next_random(&mut self.rng_state);
+        let (root, replaced) = insert(self.root.take(), key, value, priority, counter);
+        self.root = Some(root);
+        if replaced.is_none() {
+            self.len += 1;
+        }
+        replaced
+    }
+
+    fn remove(&mut self, key: &K, counter: &mut PerformanceCounter) -> Option<V> {
+        let (root, removed) = remove(self.root.take(), key, counter);
+        self.root = root;
+        if removed.is_some() {
+            self.len -= 1;
+        }
+        removed
+    }
+
+    fn get(&self, key: &K, counter: &mut PerformanceCounter) -> Option<&V> {
+        let mut current = self.root.as_deref();
+        while let Some(node) = current {
+            current = match counter.compare(key, &node.key) {
+                Ordering::Equal => return Some(&node.value),
+                Ordering::Less => node.left.as_deref(),
+                Ordering::Greater => node.right.as_deref(),
+            };
+        }
+        None
+    }
+
+    fn range(&self, low: &K, high: &K, counter: &mut PerformanceCounter) -> Vec<(&K, &V)> {
+        let mut entries = Vec::new();
+        collect_range(self.root.as_deref(), low, high, counter, &mut entries);
+        entries
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn height(&self) -> usize {
+        fn depth<K, V>(link: &Link<K, V>) -> usize {
+            link.as_ref().map_or(0, |node| 1 + depth(&node.left).max(depth(&node.right)))
+        }
+        depth(&self.root)
+    }
+}
+
+fn priority<K, V>(link: &Link<K, V>) -> Option<u64> {
+    link.as_ref().map(|node| node.priority)
+}
+
+fn rotate_right<K, V>(mut node: Box<TreapNode<K, V>>, counter: &mut PerformanceCounter) -> Box<TreapNode<K, V>> {
+    counter.rotate();
+    let mut pivot = node.left.take().expect("rotate_right requires a left child");
+    node.left = pivot.right.take();
+    pivot.right = Some(node);
+    pivot
+}
+
+fn rotate_left<K, V>(mut node: Box<TreapNode<K, V>>, counter: &mut PerformanceCounter) -> Box<TreapNode<K, V>> {
+    counter.rotate();
+    let mut pivot = node.right.take().expect("rotate_left requires a right child");
+    node.right = pivot.left.take();
+    pivot.left = Some(node);
+    pivot
+}
+
+fn insert<K: Ord, V>(
+    link: Link<K, V>,
+    key: K,
+    value: V,
+    priority: u64,
+    counter: &mut PerformanceCounter,
+) -> (Box<TreapNode<K, V>>, Option<V>) {
+    let Some(mut node) = link else {
+        counter.allocate_memory(std::mem::size_of::<TreapNode<K, V>>());
+        let node = TreapNode { key, value, priority, left: None, right: None };
+        return (Box::new(node), None);
+    };
+
+    match counter.compare(&key, &node.key) {
+        Ordering::Equal => {
+            let old = std::mem::replace(&mut node.value, value);
+            (node, Some(old))
+        }
+        Ordering::Less => {
+            let (child, replaced) = insert(node.left.take(), key, value, priority, counter);
+            let rotate = child.priority > node.priority;
+            node.left = Some(child);
+            (if rotate { rotate_right(node, counter) } else { node }, replaced)
+        }
+        Ordering::Greater => {
+            let (child, replaced) = insert(node.right.take(), key, value, priority, counter);
+            let rotate = child.priority > node.priority;
+            node.right = Some(child);
+            (if rotate { rotate_left(node, counter) } else { node }, replaced)
+        }
+    }
+}
+
+fn remove<K: Ord, V>(link: Link<K, V>, key: &K, counter: &mut PerformanceCounter) -> (Link<K, V>, Option<V>) {
+    let Some(mut node) = link else {
+        return (None, None);
+    };
+
+    match counter.compare(key, &node.key) {
+        Ordering::Less => {
+            let (child, removed) = remove(node.left.take(), key, counter);
+            node.left = child;
+            (Some(node), removed)
+        }
+        Ordering::Greater => {
+            let (child, removed) = remove(node.right.take(), key, counter);
+            node.right = child;
+            (Some(node), removed)
+        }
+        Ordering::Equal => match (priority(&node.left), priority(&node.right)) {
+            (None, None) => (None, Some(node.value)),
+            (Some(_), None) => (node.left.take(), Some(node.value)),
+            (None, Some(_)) => (node.right.take(), Some(node.value)),
+            (Some(left), Some(right)) if left > right => {
+                let mut pivot = rotate_right(node, counter);
+                let (child, removed) = remove(pivot.right.take(), key, counter);
+                pivot.right = child;
+                (Some(pivot), removed)
+            }
+            (Some(_), Some(_)) => {
+                let mut pivot = rotate_left(node, counter);
+                let (child, removed) = remove(pivot.left.take(), key, counter);
+                pivot.left = child;
+                (Some(pivot), removed)
+            }
+        },
+    }
+}
+
+fn collect_range<'a, K: Ord, V>(
+    link: Option<&'a TreapNode<K, V>>,
+    low: &K,
+    high: &K,
+    counter: &mut PerformanceCounter,
+    entries: &mut Vec<(&'a K, &'a V)>,
+) {
+    let Some(node) = link else {
+        return;
+    };
+
+    let above_low = counter.compare(&node.key, low) != Ordering::Less;
+    let below_high = counter.compare(&node.key, high) != Ordering::Greater;
+
+    if above_low {
+        collect_range(node.left.as_deref(), low, high, counter, entries);
+    }
+    if above_low && below_high {
+        entries.push((&node.key, &node.value));
+    }
+    if below_high {
+        collect_range(node.right.as_deref(), low, high, counter, entries);
+    }
+}
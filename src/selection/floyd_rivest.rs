@@ -0,0 +1,66 @@
+use super::PerformanceCounter;
+use std::cmp::Ordering;
+
+const SAMPLE_THRESHOLD: usize = 600;
+
+pub fn select(arr: &mut [i32], k: usize, counter: &mut PerformanceCounter) -> i32 {
+    assert!(k < arr.len(), "k must be smaller than the array length");
+
+    floyd_rivest(arr, 0, arr.len() - 1, k, counter);
+    arr[k]
+}
+
+fn floyd_rivest(arr: &mut [i32], mut left: usize, mut right: usize, k: usize, counter: &mut PerformanceCounter) {
+    while right > left {
+        if right - left > SAMPLE_THRESHOLD {
+            let n = (right - left + 1) as f64;
+            let i = (k - left + 1) as f64;
+            let z = n.ln();
+            let s = 0.5 * (2.0 * z / 3.0).exp();
+            let sign = if i < n / 2.0 { -1.0 } else { 1.0 };
+            let sd = 0.5 * (z * s * (n - s) / n).sqrt() * sign;
+            let k_f = k as f64;
+            let new_left = (k_f - i * s / n + sd).floor().max(left as f64) as usize;
+            let new_right = (k_f + (n - i) * s / n + sd).floor().min(right as f64) as usize;
+            floyd_rivest(arr, new_left, new_right, k, counter);
+        }
+
+        let pivot = arr[k];
+        let mut i = left;
+        let mut j = right;
+
+        counter.swap(arr, left, k);
+        if counter.compare(&arr[right], &pivot) == Ordering::Greater {
+            counter.swap(arr, right, left);
+        }
+
+        while i < j {
+            counter.swap(arr, i, j);
+            i += 1;
+            j -= 1;
+            while counter.compare(&arr[i], &pivot) == Ordering::Less {
+                i += 1;
+            }
+            while counter.compare(&arr[j], &pivot) == Ordering::Greater {
+                j -= 1;
+            }
+        }
+
+        if counter.compare(&arr[left], &pivot) == Ordering::Equal {
+            counter.swap(arr, left, j);
+        } else {
+            j += 1;
+            counter.swap(arr, j, right);
+        }
+
+        if j <= k {
+            left = j + 1;
+        }
+        if k <= j {
+            if j == 0 {
+                return;
+            }
+            right = j - 1;
+        }
+    }
+}
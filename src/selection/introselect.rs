@@ -0,0 +1,55 @@
+use super::{median_of_medians, partition_around, PerformanceCounter};
+use std::cmp::Ordering;
+
+pub fn select(arr: &mut [i32], k: usize, counter: &mut PerformanceCounter) -> i32 {
+    assert!(k < arr.len(), "k must be smaller than the array length");
+
+    let mut low = 0;
+    let mut high = arr.len();
+    let mut depth_limit = 2 * (usize::BITS - arr.len().leading_zeros()) as usize;
+
+    loop {
+        if high - low == 1 {
+            return arr[low];
+        }
+
+        let pivot = if depth_limit == 0 {
+            median_of_medians::pivot(&mut arr[low..high], counter)
+        } else {
+            depth_limit -= 1;
+            median_of_three(&arr[low..high], counter)
+        };
+
+        let (less_end, greater_start) = partition_around(&mut arr[low..high], pivot, counter);
+
+        if k < low + less_end {
+            high = low + less_end;
+        } else if k >= low + greater_start {
+            low += greater_start;
+        } else {
+            return pivot;
+        }
+    }
+}
+
+fn median_of_three(arr: &[i32], counter: &mut PerformanceCounter) -> i32 {
+    let a = arr[0];
+    let b = arr[arr.len() / 2];
+    let c = arr[arr.len() - 1];
+
+    if counter.compare(&a, &b) == Ordering::Less {
+        if counter.compare(&b, &c) == Ordering::Less {
+            b
+        } else if counter.compare(&a, &c) == Ordering::Less {
+            c
+        } else {
+            a
+        }
+    } else if counter.compare(&a, &c) == Ordering::Less {
+        a
+    } else if counter.compare(&b, &c) == Ordering::Less {
+        c
+    } else {
+        b
+    }
+}
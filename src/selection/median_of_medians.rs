@@ -0,0 +1,42 @@
+use super::{insertion_sort, partition_around, PerformanceCounter};
+
+const GROUP_SIZE: usize = 5;
+
+pub fn select(arr: &mut [i32], k: usize, counter: &mut PerformanceCounter) -> i32 {
+    assert!(k < arr.len(), "k must be smaller than the array length");
+
+    let mut low = 0;
+    let mut high = arr.len();
+
+    loop {
+        let range = &mut arr[low..high];
+        if range.len() <= GROUP_SIZE {
+            insertion_sort(range, counter);
+            return range[k - low];
+        }
+
+        let pivot = pivot(range, counter);
+        let (less_end, greater_start) = partition_around(range, pivot, counter);
+
+        if k < low + less_end {
+            high = low + less_end;
+        } else if k >= low + greater_start {
+            low += greater_start;
+        } else {
+            return pivot;
+        }
+    }
+}
+
+pub fn pivot(arr: &mut [i32], counter: &mut PerformanceCounter) -> i32 {
+    let groups = arr.len().div_ceil(GROUP_SIZE);
+
+    for group in 0..groups {
+        let start = group * GROUP_SIZE;
+        let end = (start + GROUP_SIZE).min(arr.len());
+        insertion_sort(&mut arr[start..end], counter);
+        counter.swap(arr, group, start + (end - start - 1) / 2);
+    }
+
+    select(&mut arr[..groups], (groups - 1) / 2, counter)
+}
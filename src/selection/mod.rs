@@ -0,0 +1,271 @@
+pub mod quickselect;
+pub mod introselect;
+pub mod floyd_rivest;
+pub mod median_of_medians;
+pub mod top_k;
+
+use crate::prelude::*;
+use crate::sort::{quick_sort, PerformanceCounter};
+use std::cmp::Ordering;
+use std::time::{Duration, Instant};
+use rand::prelude::*;
+use rand::rng;
+use prettytable::{Table, Row, Cell};
+
+#[derive(Debug, Clone)]
+pub struct SelectionMetrics {
+    pub algorithm_name: String,
+    pub array_size: usize,
+    pub k: usize,
+    pub value: i32,
+    pub correct: bool,
+    pub comparisons: usize,
+    pub swaps: usize,
+    pub duration: Duration,
+    pub theoretical_complexity: String,
+}
+
+pub struct SelectionCoordinator {
+    last_results: Vec<SelectionMetrics>,
+}
+
+impl SelectionCoordinator {
+    pub fn new() -> Self {
+        Self {
+            last_results: Vec::new(),
+        }
+    }
+
+    pub fn run_benchmarks(&mut self, array_size: usize, k: Option<usize>, iterations: usize) -> Result<Vec<SelectionMetrics>> {
+        if array_size == 0 {
+            return Err(Error::validation("Array size must be greater than 0"));
+        }
+
+        if iterations == 0 {
+            return Err(Error::validation("Iterations must be greater than 0"));
+        }
+
+        let k = k.unwrap_or(array_size / 2);
+        if k >= array_size {
+            return Err(Error::validation(format!("k must be smaller than the array size ({})", array_size)));
+        }
+
+        println!("Running selection benchmarks...");
+        println!("Array size: {} | k: {} ({})", array_size, k, if k == array_size / 2 { "median" } else { "k-th smallest" });
+        println!("Iterations per algorithm: {}", iterations);
+        println!("{}", "=".repeat(80));
+
+        let mut rng = rng();
+        let test_array: Vec<i32> = (0..array_size)
+            .map(|_| rng.random_range(1..=array_size as i32 * 10))
+            .collect();
+
+        let mut sorted = test_array.clone();
+        sorted.sort_unstable();
+        let expected = sorted[k];
+
+        let results = vec![
+            self.benchmark_algorithm("Quick Sort + Index", &test_array, k, expected, iterations, |arr, k, counter| {
+                quick_sort::sort(arr, counter);
+                arr[k]
+            }),
+            self.benchmark_algorithm("Quickselect", &test_array, k, expected, iterations, quickselect::select),
+            self.benchmark_algorithm("Introselect", &test_array, k, expected, iterations, introselect::select),
+            self.benchmark_algorithm("Floyd-Rivest", &test_array, k, expected, iterations, floyd_rivest::select),
+            self.benchmark_algorithm("Median of Medians", &test_array, k, expected, iterations, median_of_medians::select),
+            self.benchmark_algorithm("Heap Top-k", &test_array, k, expected, iterations, top_k::select),
+        ];
+
+        self.last_results = results.clone();
+        self.display_results(&results);
+        Ok(results)
+    }
+
+    fn benchmark_algorithm<F>(&self, name: &str, original_array: &[i32], k: usize, expected: i32, iterations: usize, select_fn: F) -> SelectionMetrics
+    where
+        F: Fn(&mut [i32], usize, &mut PerformanceCounter) -> i32,
+    {
+        let mut total_comparisons = 0;
+        let mut total_swaps = 0;
+        let mut total_duration = Duration::default();
+        let mut correct = true;
+        let mut value = expected;
+
+        for _ in 0..iterations {
+            let mut test_array = original_array.to_vec();
+            let mut counter = PerformanceCounter::new();
+
+            let start = Instant::now();
+            value = select_fn(&mut test_array, k, &mut counter);
+            total_duration += start.elapsed();
+
+            total_comparisons += counter.comparisons;
+            total_swaps += counter.swaps;
+            correct &= value == expected;
+        }
+
+        SelectionMetrics {
+            algorithm_name: name.to_string(),
+            array_size: original_array.len(),
+            k,
+            value,
+            correct,
+            comparisons: total_comparisons / iterations,
+            swaps: total_swaps / iterations,
+            duration: total_duration / iterations as u32,
+            theoretical_complexity: theoretical_complexity(name).to_string(),
+        }
+    }
+
+    fn display_results(&self, results: &[SelectionMetrics]) {
+        let mut table = Table::new();
+
+        table.add_row(Row::new(vec![
+            Cell::new("Algorithm"),
+            Cell::new("Size"),
+            Cell::new("k"),
+            Cell::new("Value"),
+            Cell::new("Correct"),
+            Cell::new("Comparisons"),
+            Cell::new("Swaps"),
+            Cell::new("Time (μs)"),
+            Cell::new("Big O Time"),
+        ]));
+
+        for metric in results {
+            table.add_row(Row::new(vec![
+                Cell::new(&metric.algorithm_name),
+                Cell::new(&format!("{}", metric.array_size)),
+                Cell::new(&format!("{}", metric.k)),
+                Cell::new(&format!("{}", metric.value)),
+                Cell::new(&format!("{}", metric.correct)),
+                Cell::new(&format!("{}", metric.comparisons)),
+                Cell::new(&format!("{}", metric.swaps)),
+                Cell::new(&format!("{}", metric.duration.as_micros())),
+                Cell::new(&metric.theoretical_complexity),
+            ]));
+        }
+
+        println!("\n{}", table);
+
+        for metric in results.iter().filter(|m| !m.correct) {
+            println!("⚠️  {} returned the wrong k-th element", metric.algorithm_name);
+        }
+
+        if let Some(fastest) = results.iter().min_by_key(|m| m.duration) {
+            println!("🏆 Fastest Algorithm: {} ({}μs)", fastest.algorithm_name, fastest.duration.as_micros());
+        }
+
+        if let Some(fewest) = results.iter().min_by_key(|m| m.comparisons) {
+            println!("🎯 Fewest Comparisons: {} ({} comparisons)", fewest.algorithm_name, fewest.comparisons);
+        }
+    }
+
+    pub fn get_last_results(&self) -> &[SelectionMetrics] {
+        &self.last_results
+    }
+}
+
+impl Default for SelectionCoordinator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn theoretical_complexity(name: &str) -> &'static str {
+    match name {
+        "Quick Sort + Index" => "O(n log n)",
+        "Quickselect" | "Floyd-Rivest" => "O(n) avg",
+        "Introselect" | "Median of Medians" => "O(n)",
+        "Heap Top-k" => "O(n log k)",
+        _ => "Unknown",
+    }
+}
+
+fn partition_around(arr: &mut [i32], pivot: i32, counter: &mut PerformanceCounter) -> (usize, usize) {
+    let mut less_end = 0;
+    let mut index = 0;
+    let mut greater_start = arr.len();
+
+    while index < greater_start {
+        match counter.compare(&arr[index], &pivot) {
+            Ordering::Less => {
+                counter.swap(arr, less_end, index);
+                less_end += 1;
+                index += 1;
+            }
+            Ordering::Greater => {
+                greater_start -= 1;
+                counter.swap(arr, index, greater_start);
+            }
+            Ordering::Equal => index += 1,
+        }
+    }
+
+    (less_end, greater_start)
+}
+
+fn insertion_sort(arr: &mut [i32], counter: &mut PerformanceCounter) {
+    for i in 1..arr.len() {
+        let mut j = i;
+        while j > 0 && counter.compare(&arr[j - 1], &arr[j]) == Ordering::Greater {
+            counter.swap(arr, j - 1, j);
+            j -= 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type SelectFn = fn(&mut [i32], usize, &mut PerformanceCounter) -> i32;
+
+    const ALGORITHMS: [(&str, SelectFn); 5] = [
+        ("quickselect", quickselect::select),
+        ("introselect", introselect::select),
+        ("floyd-rivest", floyd_rivest::select),
+        ("median of medians", median_of_medians::select),
+        ("heap top-k", top_k::select),
+    ];
+
+    fn inputs() -> Vec<Vec<i32>> {
+        let mut rng = StdRng::seed_from_u64(7);
+        vec![
+            vec![42],
+            (0..2_000).collect(),
+            (0..2_000).rev().collect(),
+            vec![3; 1_500],
+            (0..3_000).map(|_| rng.random_range(0..50)).collect(),
+            (0..5_000).map(|_| rng.random_range(-1_000_000..1_000_000)).collect(),
+        ]
+    }
+
+    #[test]
+    fn test_selection_matches_sorted_index() {
+        for input in inputs() {
+            let mut sorted = input.clone();
+            sorted.sort_unstable();
+
+            let n = input.len();
+            for k in [0, n / 3, n / 2, n - 1] {
+                for (name, select) in ALGORITHMS {
+                    let mut arr = input.clone();
+                    let mut counter = PerformanceCounter::new();
+                    assert_eq!(select(&mut arr, k, &mut counter), sorted[k], "{} n={} k={}", name, n, k);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_top_k_returns_largest_descending() {
+        let values = [5, 1, 9, 3, 9, 7, 2, 8];
+        let mut counter = PerformanceCounter::new();
+
+        assert_eq!(top_k::top_k(&values, 3, &mut counter), vec![9, 9, 8]);
+        assert_eq!(top_k::top_k(&values, 0, &mut counter), Vec::<i32>::new());
+        assert_eq!(top_k::top_k(&values, 20, &mut counter).len(), values.len());
+        assert!(counter.comparisons > 0);
+    }
+}
@@ -0,0 +1,27 @@
+use super::{partition_around, PerformanceCounter};
+use rand::Rng;
+
+pub fn select(arr: &mut [i32], k: usize, counter: &mut PerformanceCounter) -> i32 {
+    assert!(k < arr.len(), "k must be smaller than the array length");
+
+    let mut rng = rand::rng();
+    let mut low = 0;
+    let mut high = arr.len();
+
+    loop {
+        if high - low == 1 {
+            return arr[low];
+        }
+
+        let pivot = arr[rng.random_range(low..high)];
+        let (less_end, greater_start) = partition_around(&mut arr[low..high], pivot, counter);
+
+        if k < low + less_end {
+            high = low + less_end;
+        } else if k >= low + greater_start {
+            low += greater_start;
+        } else {
+            return pivot;
+        }
+    }
+}
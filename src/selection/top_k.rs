@@ -0,0 +1,106 @@
+use super::PerformanceCounter;
+use std::cmp::Ordering;
+
+#[derive(Debug, Clone)]
+pub struct TopK {
+    k: usize,
+    largest: bool,
+    heap: Vec<i32>,
+}
+
+impl TopK {
+    pub fn largest(k: usize) -> Self {
+        Self { k, largest: true, heap: Vec::with_capacity(k) }
+    }
+
+    pub fn smallest(k: usize) -> Self {
+        Self { k, largest: false, heap: Vec::with_capacity(k) }
+    }
+
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    pub fn threshold(&self) -> Option<i32> {
+        if self.heap.len() == self.k { self.heap.first().copied() } else { None }
+    }
+
+    fn outranks(&self, a: i32, b: i32, counter: &mut PerformanceCounter) -> bool {
+        let wanted = if self.largest { Ordering::Greater } else { Ordering::Less };
+        counter.compare(&a, &b) == wanted
+    }
+
+    pub fn push(&mut self, value: i32, counter: &mut PerformanceCounter) {
+        if self.k == 0 {
+            return;
+        }
+
+        if self.heap.len() < self.k {
+            self.heap.push(value);
+            self.sift_up(self.heap.len() - 1, counter);
+        } else if self.outranks(value, self.heap[0], counter) {
+            self.heap[0] = value;
+            counter.swaps += 1;
+            self.sift_down(0, counter);
+        }
+    }
+
+    fn sift_up(&mut self, mut index: usize, counter: &mut PerformanceCounter) {
+        while index > 0 {
+            let parent = (index - 1) / 2;
+            if !self.outranks(self.heap[parent], self.heap[index], counter) {
+                return;
+            }
+            counter.swap(&mut self.heap, index, parent);
+            index = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut index: usize, counter: &mut PerformanceCounter) {
+        loop {
+            let mut weakest = index;
+            for child in [2 * index + 1, 2 * index + 2] {
+                if child < self.heap.len() && self.outranks(self.heap[weakest], self.heap[child], counter) {
+                    weakest = child;
+                }
+            }
+
+            if weakest == index {
+                return;
+            }
+            counter.swap(&mut self.heap, index, weakest);
+            index = weakest;
+        }
+    }
+
+    pub fn into_sorted_vec(mut self) -> Vec<i32> {
+        if self.largest {
+            self.heap.sort_unstable_by(|a, b| b.cmp(a));
+        } else {
+            self.heap.sort_unstable();
+        }
+        self.heap
+    }
+}
+
+pub fn top_k(values: &[i32], k: usize, counter: &mut PerformanceCounter) -> Vec<i32> {
+    let mut top = TopK::largest(k);
+    for &value in values {
+        top.push(value, counter);
+    }
+    top.into_sorted_vec()
+}
+
+pub fn select(arr: &mut [i32], k: usize, counter: &mut PerformanceCounter) -> i32 {
+    assert!(k < arr.len(), "k must be smaller than the array length");
+
+    let mut top = TopK::smallest(k + 1);
+    for &value in arr.iter() {
+        top.push(value, counter);
+    }
+    top.threshold().expect("heap holds k + 1 values")
+}
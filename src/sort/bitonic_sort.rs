@@ -0,0 +1,77 @@
+use super::PerformanceCounter;
+
+pub fn sort(arr: &mut [i32], counter: &mut PerformanceCounter) {
+    let n = arr.len();
+    if n > 1 {
+        bitonic_sort(arr, 0, n, true, counter);
+    }
+}
+
+pub fn network(n: usize) -> Vec<(usize, usize)> {
+    let mut comparators = Vec::new();
+    if n > 1 {
+        bitonic_network(&mut comparators, 0, n, true);
+    }
+    comparators
+}
+
+fn bitonic_sort(arr: &mut [i32], low: usize, count: usize, ascending: bool, counter: &mut PerformanceCounter) {
+    if count <= 1 {
+        return;
+    }
+
+    let half = count / 2;
+    bitonic_sort(arr, low, half, !ascending, counter);
+    bitonic_sort(arr, low + half, count - half, ascending, counter);
+    bitonic_merge(arr, low, count, ascending, counter);
+}
+
+fn bitonic_merge(arr: &mut [i32], low: usize, count: usize, ascending: bool, counter: &mut PerformanceCounter) {
+    if count <= 1 {
+        return;
+    }
+
+    let gap = greatest_power_of_two_below(count);
+    for i in low..low + count - gap {
+        let out_of_order = counter.compare(&arr[i], &arr[i + gap]) == std::cmp::Ordering::Greater;
+        if out_of_order == ascending {
+            counter.swap(arr, i, i + gap);
+        }
+    }
+
+    bitonic_merge(arr, low, gap, ascending, counter);
+    bitonic_merge(arr, low + gap, count - gap, ascending, counter);
+}
+
+fn bitonic_network(comparators: &mut Vec<(usize, usize)>, low: usize, count: usize, ascending: bool) {
+    if count <= 1 {
+        return;
+    }
+
+    let half = count / 2;
+    bitonic_network(comparators, low, half, !ascending);
+    bitonic_network(comparators, low + half, count - half, ascending);
+    bitonic_merge_network(comparators, low, count, ascending);
+}
+
+fn bitonic_merge_network(comparators: &mut Vec<(usize, usize)>, low: usize, count: usize, ascending: bool) {
+    if count <= 1 {
+        return;
+    }
+
+    let gap = greatest_power_of_two_below(count);
+    for i in low..low + count - gap {
+        comparators.push(if ascending { (i, i + gap) } else { (i + gap, i) });
+    }
+
+    bitonic_merge_network(comparators, low, gap, ascending);
+    bitonic_merge_network(comparators, low + gap, count - gap, ascending);
+}
+
+fn greatest_power_of_two_below(n: usize) -> usize {
+    let mut power = 1;
+    while power * 2 < n {
+        power *= 2;
+    }
+    power
+}
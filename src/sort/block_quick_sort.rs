@@ -0,0 +1,130 @@
+use super::PerformanceCounter;
+use super::pdq_sort::sort3;
+use std::cmp::Ordering;
+
+const BLOCK_SIZE: usize = 128;
+const INSERTION_SORT_THRESHOLD: usize = 20;
+
+pub fn sort(arr: &mut [i32], counter: &mut PerformanceCounter) {
+    let n = arr.len();
+    if n <= 1 {
+        return;
+    }
+
+    let depth_limit = 2 * (usize::BITS - n.leading_zeros()) as usize;
+    block_quick_sort(arr, depth_limit, counter);
+}
+
+fn block_quick_sort(mut arr: &mut [i32], mut depth_limit: usize, counter: &mut PerformanceCounter) {
+    loop {
+        let n = arr.len();
+        if n <= INSERTION_SORT_THRESHOLD {
+            super::insertion_sort::sort(arr, counter);
+            return;
+        }
+
+        if depth_limit == 0 {
+            super::heap_sort::sort(arr, counter);
+            return;
+        }
+        depth_limit -= 1;
+
+        sort3(arr, n / 2, 0, n - 1, counter);
+        let pivot_pos = partition(arr, counter);
+
+        if pivot_pos == 0 {
+            let equal_end = gather_equal(arr, counter);
+            arr = &mut arr[equal_end..];
+            continue;
+        }
+
+        let (left, right) = arr.split_at_mut(pivot_pos);
+        let right = &mut right[1..];
+
+        if left.len() < right.len() {
+            block_quick_sort(left, depth_limit, counter);
+            arr = right;
+        } else {
+            block_quick_sort(right, depth_limit, counter);
+            arr = left;
+        }
+    }
+}
+
+pub fn partition(arr: &mut [i32], counter: &mut PerformanceCounter) -> usize {
+    let pivot = arr[0];
+    let mut left = 1;
+    let mut right = arr.len();
+
+    let mut offsets_left = [0u8; BLOCK_SIZE];
+    let mut offsets_right = [0u8; BLOCK_SIZE];
+    let mut start_left = 0;
+    let mut start_right = 0;
+    let mut num_left = 0;
+    let mut num_right = 0;
+
+    while right - left > 2 * BLOCK_SIZE {
+        if num_left == 0 {
+            start_left = 0;
+            for i in 0..BLOCK_SIZE {
+                offsets_left[num_left] = i as u8;
+                num_left += (arr[left + i] >= pivot) as usize;
+            }
+            counter.comparisons += BLOCK_SIZE;
+        }
+
+        if num_right == 0 {
+            start_right = 0;
+            for i in 0..BLOCK_SIZE {
+                offsets_right[num_right] = i as u8;
+                num_right += (arr[right - 1 - i] < pivot) as usize;
+            }
+            counter.comparisons += BLOCK_SIZE;
+        }
+
+        let count = num_left.min(num_right);
+        for j in 0..count {
+            let a = left + offsets_left[start_left + j] as usize;
+            let b = right - 1 - offsets_right[start_right + j] as usize;
+            counter.swap(arr, a, b);
+        }
+
+        num_left -= count;
+        num_right -= count;
+        start_left += count;
+        start_right += count;
+
+        if num_left == 0 {
+            left += BLOCK_SIZE;
+        }
+        if num_right == 0 {
+            right -= BLOCK_SIZE;
+        }
+    }
+
+    let mut boundary = left;
+    for i in left..right {
+        if counter.compare(&arr[i], &pivot) == Ordering::Less {
+            counter.swap(arr, boundary, i);
+            boundary += 1;
+        }
+    }
+
+    let pivot_pos = boundary - 1;
+    counter.swap(arr, 0, pivot_pos);
+    pivot_pos
+}
+
+fn gather_equal(arr: &mut [i32], counter: &mut PerformanceCounter) -> usize {
+    let pivot = arr[0];
+    let mut boundary = 1;
+
+    for i in 1..arr.len() {
+        if counter.compare(&arr[i], &pivot) == Ordering::Equal {
+            counter.swap(arr, boundary, i);
+            boundary += 1;
+        }
+    }
+
+    boundary
+}
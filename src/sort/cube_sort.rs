@@ -1,13 +1,17 @@
-use super::PerformanceCounter;
+use super::{HybridThresholds, PerformanceCounter};
 
 pub fn sort(arr: &mut [i32], counter: &mut PerformanceCounter) {
+    sort_with_thresholds(arr, &HybridThresholds::default(), counter);
+}
+
+pub fn sort_with_thresholds(arr: &mut [i32], thresholds: &HybridThresholds, counter: &mut PerformanceCounter) {
     let n = arr.len();
     
-    if n <= 16 {
-        super::insertion_sort::sort(arr, counter);
-    } else if n <= 100 {
+    if n <= thresholds.small_sort {
+        thresholds.kernel.sort(arr, counter);
+    } else if n <= thresholds.shell_sort {
         super::shell_sort::sort(arr, counter);
     } else {
         super::merge_sort::sort(arr, counter);
     }
-}
\ No newline at end of file
+}
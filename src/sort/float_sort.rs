@@ -0,0 +1,144 @@
+use super::{PerformanceCounter, TotalOrder};
+use crate::prelude::*;
+use std::cmp::Ordering;
+
+pub trait RadixFloat: Copy + TotalOrder + std::fmt::Debug {
+    const KEY_BYTES: usize;
+
+    fn to_key(self) -> u64;
+    fn from_key(key: u64) -> Self;
+    fn is_nan(self) -> bool;
+    fn is_sign_negative(self) -> bool;
+}
+
+impl RadixFloat for f32 {
+    const KEY_BYTES: usize = 4;
+
+    fn to_key(self) -> u64 {
+        let bits = self.to_bits();
+        let key = if bits >> 31 == 1 { !bits } else { bits ^ (1 << 31) };
+        key as u64
+    }
+
+    fn from_key(key: u64) -> Self {
+        let key = key as u32;
+        f32::from_bits(if key >> 31 == 1 { key ^ (1 << 31) } else { !key })
+    }
+
+    fn is_nan(self) -> bool {
+        f32::is_nan(self)
+    }
+
+    fn is_sign_negative(self) -> bool {
+        f32::is_sign_negative(self)
+    }
+}
+
+impl RadixFloat for f64 {
+    const KEY_BYTES: usize = 8;
+
+    fn to_key(self) -> u64 {
+        let bits = self.to_bits();
+        if bits >> 63 == 1 { !bits } else { bits ^ (1 << 63) }
+    }
+
+    fn from_key(key: u64) -> Self {
+        f64::from_bits(if key >> 63 == 1 { key ^ (1 << 63) } else { !key })
+    }
+
+    fn is_nan(self) -> bool {
+        f64::is_nan(self)
+    }
+
+    fn is_sign_negative(self) -> bool {
+        f64::is_sign_negative(self)
+    }
+}
+
+pub fn radix_sort<F: RadixFloat>(arr: &mut [F], counter: &mut PerformanceCounter) {
+    let n = arr.len();
+    if n <= 1 {
+        return;
+    }
+
+    let mut keys: Vec<u64> = arr.iter().map(|value| value.to_key()).collect();
+    let mut buffer = vec![0u64; n];
+    counter.allocate_memory(2 * n);
+
+    for byte in 0..F::KEY_BYTES {
+        let shift = byte * 8;
+        let mut counts = [0usize; 256];
+        for &key in &keys {
+            counts[((key >> shift) & 0xFF) as usize] += 1;
+        }
+
+        if counts.contains(&n) {
+            continue;
+        }
+
+        let mut total = 0;
+        for count in counts.iter_mut() {
+            let bucket_size = *count;
+            *count = total;
+            total += bucket_size;
+        }
+
+        for &key in &keys {
+            let digit = ((key >> shift) & 0xFF) as usize;
+            buffer[counts[digit]] = key;
+            counts[digit] += 1;
+        }
+
+        std::mem::swap(&mut keys, &mut buffer);
+        counter.swaps += n;
+    }
+
+    for (slot, &key) in arr.iter_mut().zip(keys.iter()) {
+        *slot = F::from_key(key);
+    }
+}
+
+pub fn partial_cmp_sort<F: RadixFloat + PartialOrd>(arr: &mut [F], counter: &mut PerformanceCounter) {
+    arr.sort_by(|a, b| {
+        counter.comparisons += 1;
+        a.partial_cmp(b).unwrap_or(Ordering::Equal)
+    });
+}
+
+pub fn total_cmp_sort<F: RadixFloat>(arr: &mut [F], counter: &mut PerformanceCounter) {
+    arr.sort_by(|a, b| counter.compare(a, b));
+}
+
+pub fn verify_order<F: RadixFloat>(original: &[F], sorted: &[F]) -> Result<()> {
+    if original.len() != sorted.len() {
+        return Err(Error::validation("Sorted output has a different length"));
+    }
+
+    let mut expected: Vec<u64> = original.iter().map(|value| value.to_key()).collect();
+    let mut actual: Vec<u64> = sorted.iter().map(|value| value.to_key()).collect();
+    expected.sort_unstable();
+    actual.sort_unstable();
+    if expected != actual {
+        return Err(Error::validation("Sorted output is not a permutation of the input"));
+    }
+
+    let negative_nans = sorted.iter().filter(|v| v.is_nan() && v.is_sign_negative()).count();
+    let positive_nans = sorted.iter().filter(|v| v.is_nan() && !v.is_sign_negative()).count();
+
+    if !sorted[..negative_nans].iter().all(|v| v.is_nan() && v.is_sign_negative()) {
+        return Err(Error::validation(format!("{} negative NaNs are not at the front", negative_nans)));
+    }
+
+    if !sorted[sorted.len() - positive_nans..].iter().all(|v| v.is_nan() && !v.is_sign_negative()) {
+        return Err(Error::validation(format!("{} positive NaNs are not at the back", positive_nans)));
+    }
+
+    if let Some(position) = sorted.windows(2).position(|w| w[0].total_order(&w[1]) == Ordering::Greater) {
+        return Err(Error::validation(format!(
+            "Out of order at index {}: {:?} > {:?}",
+            position, sorted[position], sorted[position + 1]
+        )));
+    }
+
+    Ok(())
+}
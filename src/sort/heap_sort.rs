@@ -1,36 +1,43 @@
 use super::PerformanceCounter;
 
 pub fn sort(arr: &mut [i32], counter: &mut PerformanceCounter) {
+    sort_d_ary(arr, 2, counter);
+}
+
+pub fn sort_d_ary(arr: &mut [i32], arity: usize, counter: &mut PerformanceCounter) {
     let n = arr.len();
     if n <= 1 {
         return;
     }
-    
-    for i in (0..n / 2).rev() {
-        heapify(arr, n, i, counter);
+
+    let arity = arity.max(2);
+
+    for i in (0..=(n - 2) / arity).rev() {
+        heapify(arr, n, i, arity, counter);
     }
-    
+
     for i in (1..n).rev() {
         counter.swap(arr, 0, i);
-        heapify(arr, i, 0, counter);
+        heapify(arr, i, 0, arity, counter);
     }
 }
 
-fn heapify(arr: &mut [i32], n: usize, i: usize, counter: &mut PerformanceCounter) {
-    let mut largest = i;
-    let left = 2 * i + 1;
-    let right = 2 * i + 2;
-    
-    if left < n && counter.compare(&arr[left], &arr[largest]) == std::cmp::Ordering::Greater {
-        largest = left;
-    }
-    
-    if right < n && counter.compare(&arr[right], &arr[largest]) == std::cmp::Ordering::Greater {
-        largest = right;
-    }
-    
-    if largest != i {
+fn heapify(arr: &mut [i32], n: usize, mut i: usize, arity: usize, counter: &mut PerformanceCounter) {
+    loop {
+        let first_child = arity * i + 1;
+        let mut largest = i;
+
+        for child in first_child..(first_child + arity).min(n) {
+            if counter.compare(&arr[child], &arr[largest]) == std::cmp::Ordering::Greater {
+                largest = child;
+            }
+        }
+
+        if largest == i {
+            return;
+        }
+
         counter.swap(arr, i, largest);
-        heapify(arr, n, largest, counter);
+        i = largest;
     }
-}
\ No newline at end of file
+}
@@ -1,4 +1,4 @@
-use super::PerformanceCounter;
+use super::{HybridThresholds, PerformanceCounter, SmallSortKernel};
 
 pub fn sort(arr: &mut [i32], counter: &mut PerformanceCounter) {
     let n = arr.len();
@@ -97,22 +97,24 @@ pub fn merge_sort_iterative(arr: &mut [i32], counter: &mut PerformanceCounter) {
 }
 
 pub fn merge_sort_optimised(arr: &mut [i32], counter: &mut PerformanceCounter) {
-    const INSERTION_SORT_THRESHOLD: usize = 16;
-    
+    merge_sort_optimised_with(arr, &HybridThresholds::default(), counter);
+}
+
+pub fn merge_sort_optimised_with(arr: &mut [i32], thresholds: &HybridThresholds, counter: &mut PerformanceCounter) {
     let n = arr.len();
     if n <= 1 {
         return;
     }
     
-    if n <= INSERTION_SORT_THRESHOLD {
-        insertion_sort_simple(arr, counter);
+    if n <= thresholds.small_sort {
+        small_sort(arr, thresholds, counter);
         return;
     }
     
     let mut aux = vec![0; n];
     counter.allocate_memory(n);
     
-    merge_sort_optimised_recursive(arr, &mut aux, 0, n, counter);
+    merge_sort_optimised_recursive(arr, &mut aux, 0, n, thresholds, counter);
 }
 
 fn merge_sort_optimised_recursive(
@@ -120,19 +122,18 @@ fn merge_sort_optimised_recursive(
     aux: &mut [i32], 
     left: usize, 
     right: usize, 
+    thresholds: &HybridThresholds,
     counter: &mut PerformanceCounter
 ) {
-    const INSERTION_SORT_THRESHOLD: usize = 16;
-    
-    if right - left <= INSERTION_SORT_THRESHOLD {
-        insertion_sort_range(arr, left, right, counter);
+    if right - left <= thresholds.small_sort {
+        small_sort(&mut arr[left..right], thresholds, counter);
         return;
     }
     
     let mid = left + (right - left) / 2;
     
-    merge_sort_optimised_recursive(arr, aux, left, mid, counter);
-    merge_sort_optimised_recursive(arr, aux, mid, right, counter);
+    merge_sort_optimised_recursive(arr, aux, left, mid, thresholds, counter);
+    merge_sort_optimised_recursive(arr, aux, mid, right, thresholds, counter);
     
     if counter.compare(&arr[mid - 1], &arr[mid]) != std::cmp::Ordering::Greater {
         return;
@@ -141,27 +142,19 @@ fn merge_sort_optimised_recursive(
     merge(arr, aux, left, mid, right, counter);
 }
 
-fn insertion_sort_simple(arr: &mut [i32], counter: &mut PerformanceCounter) {
-    for i in 1..arr.len() {
-        let key = arr[i];
-        let mut j = i;
-        
-        while j > 0 && counter.compare(&arr[j - 1], &key) == std::cmp::Ordering::Greater {
-            arr[j] = arr[j - 1];
-            counter.swaps += 1;
-            j -= 1;
-        }
-        
-        arr[j] = key;
+fn small_sort(arr: &mut [i32], thresholds: &HybridThresholds, counter: &mut PerformanceCounter) {
+    match thresholds.kernel {
+        SmallSortKernel::Insertion => insertion_sort_simple(arr, counter),
+        kernel => kernel.sort(arr, counter),
     }
 }
 
-fn insertion_sort_range(arr: &mut [i32], left: usize, right: usize, counter: &mut PerformanceCounter) {
-    for i in left + 1..right {
+fn insertion_sort_simple(arr: &mut [i32], counter: &mut PerformanceCounter) {
+    for i in 1..arr.len() {
         let key = arr[i];
         let mut j = i;
         
-        while j > left && counter.compare(&arr[j - 1], &key) == std::cmp::Ordering::Greater {
+        while j > 0 && counter.compare(&arr[j - 1], &key) == std::cmp::Ordering::Greater {
             arr[j] = arr[j - 1];
             counter.swaps += 1;
             j -= 1;
@@ -11,8 +11,20 @@ pub mod bucket_sort;
 pub mod radix_sort;
 pub mod counting_sort;
 pub mod cube_sort;
+pub mod sorting_network;
+pub mod bitonic_sort;
+pub mod odd_even_merge_sort;
+pub mod pdq_sort;
+pub mod block_quick_sort;
+pub mod sample_sort;
+pub mod presortedness;
+pub mod total_order;
+pub mod float_sort;
+pub mod string_sort;
+pub mod record_sort;
 
 use crate::prelude::*;
+pub use total_order::TotalOrder;
 use std::time::{Duration, Instant};
 use rand::prelude::*;
 use rand::rng;
@@ -24,6 +36,7 @@ pub struct SortMetrics {
     pub array_size: usize,
     pub comparisons: usize,
     pub swaps: usize,
+    pub rotations: usize,
     pub memory_allocations: usize,
     pub duration: Duration,
     pub theoretical_time_complexity: String,
@@ -39,6 +52,7 @@ pub struct SortMetrics {
 pub struct PerformanceCounter {
     pub comparisons: usize,
     pub swaps: usize,
+    pub rotations: usize,
     pub memory_allocations: usize,
 }
 
@@ -50,12 +64,13 @@ impl PerformanceCounter {
     pub fn reset(&mut self) {
         self.comparisons = 0;
         self.swaps = 0;
+        self.rotations = 0;
         self.memory_allocations = 0;
     }
     
-    pub fn compare<T: PartialOrd>(&mut self, a: &T, b: &T) -> std::cmp::Ordering {
+    pub fn compare<T: TotalOrder + ?Sized>(&mut self, a: &T, b: &T) -> std::cmp::Ordering {
         self.comparisons += 1;
-        a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal)
+        a.total_order(b)
     }
     
     pub fn swap<T>(&mut self, arr: &mut [T], i: usize, j: usize) {
@@ -63,22 +78,177 @@ impl PerformanceCounter {
         arr.swap(i, j);
     }
     
+    pub fn rotate(&mut self) {
+        self.rotations += 1;
+    }
+    
     pub fn allocate_memory(&mut self, _size: usize) {
         self.memory_allocations += 1;
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SmallSortKernel {
+    Insertion,
+    Network,
+    BranchlessNetwork,
+}
+
+impl SmallSortKernel {
+    pub const ALL: [SmallSortKernel; 3] = [Self::Insertion, Self::Network, Self::BranchlessNetwork];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Insertion => "Insertion",
+            Self::Network => "Network (Batcher > 8)",
+            Self::BranchlessNetwork => "Branchless Network (Batcher > 8)",
+        }
+    }
+
+    pub fn sort(&self, arr: &mut [i32], counter: &mut PerformanceCounter) {
+        match self {
+            Self::Insertion => insertion_sort::sort(arr, counter),
+            Self::Network => sorting_network::sort(arr, counter),
+            Self::BranchlessNetwork => sorting_network::sort_branchless(arr, counter),
+        }
+    }
+}
+
+impl std::str::FromStr for SmallSortKernel {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value.to_lowercase().as_str() {
+            "insertion" => Ok(Self::Insertion),
+            "network" => Ok(Self::Network),
+            "branchless" | "branchless-network" => Ok(Self::BranchlessNetwork),
+            _ => Err(Error::validation(format!("Unknown small sort kernel: {}", value))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HybridThresholds {
+    pub small_sort: usize,
+    pub shell_sort: usize,
+    pub kernel: SmallSortKernel,
+}
+
+impl HybridThresholds {
+    pub fn validate(&self) -> Result<()> {
+        if self.small_sort == 0 {
+            return Err(Error::validation("Small sort threshold must be greater than 0"));
+        }
+
+        if self.shell_sort < self.small_sort {
+            return Err(Error::validation("Shell sort threshold must not be below the small sort threshold"));
+        }
+
+        if self.kernel != SmallSortKernel::Insertion && self.small_sort > sorting_network::MAX_NETWORK_SIZE {
+            return Err(Error::validation(format!(
+                "Sorting network kernels support at most {} elements",
+                sorting_network::MAX_NETWORK_SIZE
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for HybridThresholds {
+    fn default() -> Self {
+        Self {
+            small_sort: 16,
+            shell_sort: 100,
+            kernel: SmallSortKernel::Insertion,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ThresholdMetrics {
+    pub kernel: SmallSortKernel,
+    pub threshold: usize,
+    pub comparisons: usize,
+    pub swaps: usize,
+    pub duration: Duration,
+}
+
+#[derive(Debug, Clone)]
+pub struct AdaptivityMetrics {
+    pub array_type: String,
+    pub presortedness: presortedness::Presortedness,
+    pub algorithm_name: String,
+    pub is_adaptive: bool,
+    pub comparisons: usize,
+    pub duration: Duration,
+}
+
+#[derive(Debug, Clone)]
+pub struct TypedSortMetrics {
+    pub data_type: String,
+    pub algorithm_name: String,
+    pub array_size: usize,
+    pub comparisons: usize,
+    pub duration: Duration,
+    pub correct: bool,
+    pub failure: Option<String>,
+}
+
+const RECORD_SURNAMES: [&str; 12] = [
+    "Adams", "Baker", "Clark", "Davies", "Evans", "Fisher", "Green", "Hughes", "Jones", "Patel", "Smith", "Taylor",
+];
+const STRING_PREFIXES: [&str; 5] = ["", "user_", "data/", "https://", "https://www."];
+
+type SortFn = fn(&mut [i32], &mut PerformanceCounter);
+
+const ADAPTIVITY_ALGORITHMS: [(&str, SortFn); 7] = [
+    ("Insertion Sort", insertion_sort::sort),
+    ("Tim Sort", tim_sort::sort),
+    ("Pdqsort", pdq_sort::sort),
+    (STD_STABLE, std_sort),
+    ("Merge Sort", merge_sort::sort),
+    ("Heap Sort", heap_sort::sort),
+    ("Block Quicksort", block_quick_sort::sort),
+];
+const ADAPTIVITY_CHART_PATH: &str = "assets/charts/sort/presortedness.svg";
+
+const STD_STABLE: &str = "std sort (driftsort)";
+const STD_UNSTABLE: &str = "std sort_unstable (ipnsort)";
+
+pub fn std_sort(arr: &mut [i32], counter: &mut PerformanceCounter) {
+    arr.sort_by(|a, b| counter.compare(a, b));
+}
+
+pub fn std_sort_unstable(arr: &mut [i32], counter: &mut PerformanceCounter) {
+    arr.sort_unstable_by(|a, b| counter.compare(a, b));
+}
+
+const THRESHOLD_SWEEP: [usize; 7] = [4, 8, 12, 16, 20, 24, 32];
+
 pub struct SortCoordinator {
     last_results: Vec<SortMetrics>,
+    thresholds: HybridThresholds,
 }
 
 impl SortCoordinator {
     pub fn new() -> Self {
         Self {
             last_results: Vec::new(),
+            thresholds: HybridThresholds::default(),
         }
     }
 
+    pub fn set_thresholds(&mut self, thresholds: HybridThresholds) -> Result<()> {
+        thresholds.validate()?;
+        self.thresholds = thresholds;
+        Ok(())
+    }
+
+    pub fn thresholds(&self) -> HybridThresholds {
+        self.thresholds
+    }
+
     pub fn generate_random_array(&self, size: usize, min_val: i32, max_val: i32) -> Vec<i32> {
         let mut rng = rng();
         (0..size)
@@ -127,6 +297,7 @@ impl SortCoordinator {
         println!("{}", "=".repeat(80));
 
         let mut results = Vec::new();
+        let thresholds = self.thresholds;
 
         let test_array = self.generate_random_array(array_size, 1, array_size as i32 * 10);
         
@@ -134,15 +305,24 @@ impl SortCoordinator {
         results.push(self.benchmark_algorithm("Insertion Sort", &test_array, iterations, insertion_sort::sort)?);
         results.push(self.benchmark_algorithm("Selection Sort", &test_array, iterations, selection_sort::sort)?);
         results.push(self.benchmark_algorithm("Merge Sort", &test_array, iterations, merge_sort::sort)?);
+        results.push(self.benchmark_algorithm("Optimised Merge Sort", &test_array, iterations, |arr, counter| merge_sort::merge_sort_optimised_with(arr, &thresholds, counter))?);
         results.push(self.benchmark_algorithm("Quick Sort", &test_array, iterations, quick_sort::sort)?);
         results.push(self.benchmark_algorithm("Heap Sort", &test_array, iterations, heap_sort::sort)?);
+        results.push(self.benchmark_algorithm("4-ary Heap Sort", &test_array, iterations, |arr, counter| heap_sort::sort_d_ary(arr, 4, counter))?);
         results.push(self.benchmark_algorithm("Shell Sort", &test_array, iterations, shell_sort::sort)?);
         results.push(self.benchmark_algorithm("Tim Sort", &test_array, iterations, tim_sort::sort)?);
         results.push(self.benchmark_algorithm("Tree Sort", &test_array, iterations, tree_sort::sort)?);
         results.push(self.benchmark_algorithm("Bucket Sort", &test_array, iterations, bucket_sort::sort)?);
         results.push(self.benchmark_algorithm("Radix Sort", &test_array, iterations, radix_sort::sort)?);
         results.push(self.benchmark_algorithm("Counting Sort", &test_array, iterations, counting_sort::sort)?);
-        results.push(self.benchmark_algorithm("Cube Sort", &test_array, iterations, cube_sort::sort)?);
+        results.push(self.benchmark_algorithm("Cube Sort", &test_array, iterations, |arr, counter| cube_sort::sort_with_thresholds(arr, &thresholds, counter))?);
+        results.push(self.benchmark_algorithm("Bitonic Sort", &test_array, iterations, bitonic_sort::sort)?);
+        results.push(self.benchmark_algorithm("Odd-Even Merge Sort", &test_array, iterations, odd_even_merge_sort::sort)?);
+        results.push(self.benchmark_algorithm("Pdqsort", &test_array, iterations, pdq_sort::sort)?);
+        results.push(self.benchmark_algorithm("Block Quicksort", &test_array, iterations, block_quick_sort::sort)?);
+        results.push(self.benchmark_algorithm("Sample Sort", &test_array, iterations, sample_sort::sort)?);
+        results.push(self.benchmark_algorithm(STD_STABLE, &test_array, iterations, std_sort)?);
+        results.push(self.benchmark_algorithm(STD_UNSTABLE, &test_array, iterations, std_sort_unstable)?);
 
         self.last_results = results.clone();
         self.display_results(&results);
@@ -161,6 +341,7 @@ impl SortCoordinator {
     {
         let mut total_comparisons = 0;
         let mut total_swaps = 0;
+        let mut total_rotations = 0;
         let mut total_memory = 0;
         
         let start = Instant::now();
@@ -173,6 +354,7 @@ impl SortCoordinator {
             
             total_comparisons += counter.comparisons;
             total_swaps += counter.swaps;
+            total_rotations += counter.rotations;
             total_memory += counter.memory_allocations;
             
             if !is_sorted(&test_array) {
@@ -183,6 +365,7 @@ impl SortCoordinator {
         let duration = start.elapsed() / iterations as u32;
         let avg_comparisons = total_comparisons / iterations;
         let avg_swaps = total_swaps / iterations;
+        let avg_rotations = total_rotations / iterations;
         let avg_memory = total_memory / iterations;
         
         let (time_complexity, space_complexity, is_stable, is_adaptive, is_in_place) = get_algorithm_properties(name);
@@ -195,6 +378,7 @@ impl SortCoordinator {
             array_size: original_array.len(),
             comparisons: avg_comparisons,
             swaps: avg_swaps,
+            rotations: avg_rotations,
             memory_allocations: avg_memory,
             duration,
             theoretical_time_complexity: time_complexity,
@@ -271,6 +455,19 @@ impl SortCoordinator {
                 fewest_swaps.algorithm_name, fewest_swaps.swaps);
         }
         
+        if let Some(baseline) = results.iter().find(|m| m.algorithm_name == STD_UNSTABLE)
+            && let Some(best) = results.iter().filter(|m| !m.algorithm_name.starts_with("std ")).min_by_key(|m| m.duration)
+        {
+            println!("📏 Std Baseline: {} ({}μs) | best textbook sort {} at {:.2}× baseline time", 
+                baseline.algorithm_name, baseline.duration.as_micros(), best.algorithm_name,
+                best.duration.as_secs_f64() / baseline.duration.as_secs_f64().max(f64::EPSILON));
+        }
+        
+        for balanced in results.iter().filter(|m| m.rotations > 0) {
+            println!("🌀 Tree Rotations: {} ({} rotations)", 
+                balanced.algorithm_name, balanced.rotations);
+        }
+        
         let stable_count = results.iter().filter(|m| m.is_stable).count();
         let in_place_count = results.iter().filter(|m| m.is_in_place).count();
         let adaptive_count = results.iter().filter(|m| m.is_adaptive).count();
@@ -294,6 +491,7 @@ impl SortCoordinator {
                 "O(log n)" => 1,
                 "O(n)" => 2,
                 "O(n log n)" => 3,
+                "O(n log² n)" => 4,
                 "O(n^1.25)" => 4,
                 "O(n²)" => 5,
                 "O(n³)" => 6,
@@ -310,6 +508,384 @@ impl SortCoordinator {
         }
     }
 
+    pub fn run_threshold_benchmarks(&mut self, array_size: usize, iterations: usize) -> Result<Vec<ThresholdMetrics>> {
+        if array_size == 0 {
+            return Err(Error::validation("Array size must be greater than 0"));
+        }
+
+        if iterations == 0 {
+            return Err(Error::validation("Iterations must be greater than 0"));
+        }
+
+        println!("\nTuning hybrid sort thresholds...");
+        println!("Array size: {} | Iterations: {}", array_size, iterations);
+        println!("{}", "=".repeat(80));
+
+        let test_array = self.generate_random_array(array_size, 1, array_size as i32 * 10);
+        let mut results = Vec::new();
+
+        for kernel in SmallSortKernel::ALL {
+            for threshold in THRESHOLD_SWEEP {
+                let thresholds = HybridThresholds {
+                    small_sort: threshold,
+                    kernel,
+                    ..self.thresholds
+                };
+                let metric = self.benchmark_algorithm("Optimised Merge Sort", &test_array, iterations, |arr, counter| {
+                    merge_sort::merge_sort_optimised_with(arr, &thresholds, counter)
+                })?;
+
+                results.push(ThresholdMetrics {
+                    kernel,
+                    threshold,
+                    comparisons: metric.comparisons,
+                    swaps: metric.swaps,
+                    duration: metric.duration,
+                });
+            }
+        }
+
+        self.display_threshold_results(&results);
+        Ok(results)
+    }
+
+    fn display_threshold_results(&self, results: &[ThresholdMetrics]) {
+        let mut table = Table::new();
+
+        table.add_row(Row::new(vec![
+            Cell::new("Small Sort"),
+            Cell::new("Threshold"),
+            Cell::new("Comparisons"),
+            Cell::new("Swaps"),
+            Cell::new("Time (μs)"),
+        ]));
+
+        for metric in results {
+            table.add_row(Row::new(vec![
+                Cell::new(metric.kernel.as_str()),
+                Cell::new(&format!("{}", metric.threshold)),
+                Cell::new(&format!("{}", metric.comparisons)),
+                Cell::new(&format!("{}", metric.swaps)),
+                Cell::new(&format!("{}", metric.duration.as_micros())),
+            ]));
+        }
+
+        println!("\n{}", table);
+
+        if let Some(fastest) = results.iter().min_by_key(|m| m.duration) {
+            println!("🏆 Fastest Threshold: {} ≤ {} ({}μs)", 
+                fastest.kernel.as_str(), fastest.threshold, fastest.duration.as_micros());
+        }
+
+        if let Some(fewest) = results.iter().min_by_key(|m| m.comparisons) {
+            println!("🎯 Fewest Comparisons: {} ≤ {} ({} comparisons)", 
+                fewest.kernel.as_str(), fewest.threshold, fewest.comparisons);
+        }
+    }
+
+    pub fn run_presortedness_analysis(&mut self, array_size: usize, iterations: usize) -> Result<Vec<AdaptivityMetrics>> {
+        if array_size == 0 {
+            return Err(Error::validation("Array size must be greater than 0"));
+        }
+
+        if iterations == 0 {
+            return Err(Error::validation("Iterations must be greater than 0"));
+        }
+
+        println!("\nAnalysing input presortedness...");
+        println!("Array size: {} | Iterations: {}", array_size, iterations);
+        println!("{}", "=".repeat(80));
+
+        let test_arrays = self.generate_test_arrays(array_size);
+        let mut results = Vec::new();
+
+        for (array_type, array) in &test_arrays {
+            let measures = presortedness::analyse(array);
+
+            for (name, sort_fn) in ADAPTIVITY_ALGORITHMS {
+                let metric = self.benchmark_algorithm(name, array, iterations, sort_fn)?;
+
+                results.push(AdaptivityMetrics {
+                    array_type: array_type.clone(),
+                    presortedness: measures.clone(),
+                    algorithm_name: name.to_string(),
+                    is_adaptive: metric.is_adaptive,
+                    comparisons: metric.comparisons,
+                    duration: metric.duration,
+                });
+            }
+        }
+
+        self.display_presortedness_results(&results);
+        self.render_adaptivity_chart(&results)?;
+        Ok(results)
+    }
+
+    fn display_presortedness_results(&self, results: &[AdaptivityMetrics]) {
+        let mut measures_table = Table::new();
+
+        measures_table.add_row(Row::new(vec![
+            Cell::new("Input"),
+            Cell::new("Inversions"),
+            Cell::new("Runs"),
+            Cell::new("Rem"),
+            Cell::new("Osc"),
+            Cell::new("Max Dis"),
+            Cell::new("LIS"),
+            Cell::new("Sortedness"),
+        ]));
+
+        for metric in results.iter().step_by(ADAPTIVITY_ALGORITHMS.len()) {
+            let measures = &metric.presortedness;
+            measures_table.add_row(Row::new(vec![
+                Cell::new(&metric.array_type),
+                Cell::new(&format!("{}", measures.inversions)),
+                Cell::new(&format!("{}", measures.runs)),
+                Cell::new(&format!("{}", measures.rem)),
+                Cell::new(&format!("{}", measures.osc)),
+                Cell::new(&format!("{}", measures.max_displacement)),
+                Cell::new(&format!("{}", measures.longest_increasing_subsequence)),
+                Cell::new(&format!("{:.1}%", measures.sortedness() * 100.0)),
+            ]));
+        }
+
+        println!("\n{}", measures_table);
+
+        let mut header = vec![Cell::new("Input"), Cell::new("Inversions")];
+        header.extend(ADAPTIVITY_ALGORITHMS.iter().map(|(name, _)| Cell::new(name)));
+
+        let mut comparisons_table = Table::new();
+        comparisons_table.add_row(Row::new(header));
+
+        for row in results.chunks(ADAPTIVITY_ALGORITHMS.len()) {
+            let mut cells = vec![
+                Cell::new(&row[0].array_type),
+                Cell::new(&format!("{}", row[0].presortedness.inversions)),
+            ];
+            cells.extend(row.iter().map(|metric| Cell::new(&format!("{}", metric.comparisons))));
+            comparisons_table.add_row(Row::new(cells));
+        }
+
+        println!("Comparisons by input type:");
+        println!("{}", comparisons_table);
+
+        for (name, _) in ADAPTIVITY_ALGORITHMS {
+            let runs: Vec<&AdaptivityMetrics> = results.iter().filter(|m| m.algorithm_name == name).collect();
+            let fewest = runs.iter().map(|m| m.comparisons).min().unwrap_or(0);
+            let most = runs.iter().map(|m| m.comparisons).max().unwrap_or(0);
+            let adaptive = runs.first().is_some_and(|m| m.is_adaptive);
+
+            println!("{} {}: {}-{} comparisons ({:.1}× spread)", 
+                if adaptive { "📈" } else { "📉" }, name, fewest, most, most as f64 / fewest.max(1) as f64);
+        }
+    }
+
+    fn render_adaptivity_chart(&self, results: &[AdaptivityMetrics]) -> Result<()> {
+        let series: Vec<crate::gui::ChartSeries> = ADAPTIVITY_ALGORITHMS.iter()
+            .map(|&(name, _)| {
+                let runs: Vec<&AdaptivityMetrics> = results.iter().filter(|m| m.algorithm_name == name).collect();
+                let adaptive = runs.first().is_some_and(|m| m.is_adaptive);
+                crate::gui::ChartSeries {
+                    name: format!("{} ({})", name, if adaptive { "adaptive" } else { "non-adaptive" }),
+                    highlighted: adaptive,
+                    points: runs.iter()
+                        .map(|m| (m.presortedness.inversions as f64 + 1.0, m.comparisons as f64))
+                        .collect(),
+                }
+            })
+            .collect();
+
+        crate::gui::render_log_log_chart(
+            ADAPTIVITY_CHART_PATH,
+            "Comparisons vs inversions",
+            "Inversions + 1",
+            "Comparisons",
+            &series,
+        )?;
+
+        println!("\n📊 Adaptivity chart saved to {}", ADAPTIVITY_CHART_PATH);
+        Ok(())
+    }
+
+    pub fn run_typed_benchmarks(&mut self, array_size: usize, iterations: usize) -> Result<Vec<TypedSortMetrics>> {
+        if array_size == 0 {
+            return Err(Error::validation("Array size must be greater than 0"));
+        }
+
+        if iterations == 0 {
+            return Err(Error::validation("Iterations must be greater than 0"));
+        }
+
+        println!("\nRunning float, string and record sorting benchmarks...");
+        println!("Array size: {} | Iterations: {}", array_size, iterations);
+        println!("{}", "=".repeat(80));
+
+        let floats = self.generate_float_array(array_size);
+        let singles: Vec<f32> = floats.iter().map(|&value| value as f32).collect();
+        let strings = self.generate_string_array(array_size);
+        let records = self.generate_record_array(array_size);
+        let keys = [
+            record_sort::SortKey::ascending(record_sort::RecordField::Surname),
+            record_sort::SortKey::descending(record_sort::RecordField::Score),
+            record_sort::SortKey::ascending(record_sort::RecordField::Age),
+        ];
+
+        let mut expected_strings = strings.clone();
+        expected_strings.sort();
+        let verify_strings = |_: &[String], sorted: &[String]| {
+            if sorted == expected_strings.as_slice() {
+                Ok(())
+            } else {
+                Err(Error::validation("Strings are not in lexicographic order"))
+            }
+        };
+
+        let results = vec![
+            self.benchmark_typed("f64", "std sort_by(partial_cmp)", &floats, iterations, float_sort::partial_cmp_sort, float_sort::verify_order),
+            self.benchmark_typed("f64", "std sort_by(total_cmp)", &floats, iterations, float_sort::total_cmp_sort, float_sort::verify_order),
+            self.benchmark_typed("f64", "Merge Sort (total order)", &floats, iterations, total_order::merge_sort, float_sort::verify_order),
+            self.benchmark_typed("f64", "Float Radix Sort", &floats, iterations, float_sort::radix_sort, float_sort::verify_order),
+            self.benchmark_typed("f32", "std sort_by(total_cmp)", &singles, iterations, float_sort::total_cmp_sort, float_sort::verify_order),
+            self.benchmark_typed("f32", "Float Radix Sort", &singles, iterations, float_sort::radix_sort, float_sort::verify_order),
+            self.benchmark_typed("String", "std sort", &strings, iterations, |arr, counter| arr.sort_by(|a, b| counter.compare(a, b)), verify_strings),
+            self.benchmark_typed("String", "Merge Sort (total order)", &strings, iterations, total_order::merge_sort, verify_strings),
+            self.benchmark_typed("String", "MSD Radix Sort", &strings, iterations, string_sort::msd_radix_sort, verify_strings),
+            self.benchmark_typed("String", "Multikey Quicksort", &strings, iterations, string_sort::multikey_quicksort, verify_strings),
+            self.benchmark_typed("String", "Burstsort", &strings, iterations, string_sort::burstsort, verify_strings),
+            self.benchmark_typed("Record", "std sort_by (multi-key)", &records, iterations, |arr, counter| record_sort::std_sort(arr, &keys, counter), |original, sorted| record_sort::verify_order(original, sorted, &keys)),
+            self.benchmark_typed("Record", "Multi-key Merge Sort", &records, iterations, |arr, counter| record_sort::multi_key_sort(arr, &keys, counter), |original, sorted| record_sort::verify_order(original, sorted, &keys)),
+            self.benchmark_typed("Record", "LSD Key Passes", &records, iterations, |arr, counter| record_sort::lsd_key_sort(arr, &keys, counter), |original, sorted| record_sort::verify_order(original, sorted, &keys)),
+        ];
+
+        self.display_typed_results(&results);
+        Ok(results)
+    }
+
+    pub fn generate_float_array(&self, size: usize) -> Vec<f64> {
+        let mut rng = rng();
+        (0..size)
+            .map(|_| match rng.random_range(0..100) {
+                0 | 1 => f64::NAN,
+                2 => -f64::NAN,
+                3 => 0.0,
+                4 => -0.0,
+                5 => if rng.random_bool(0.5) { f64::INFINITY } else { f64::NEG_INFINITY },
+                _ => (rng.random_range(-1_000_000.0..1_000_000.0f64) * 100.0).round() / 100.0,
+            })
+            .collect()
+    }
+
+    pub fn generate_string_array(&self, size: usize) -> Vec<String> {
+        let mut rng = rng();
+        (0..size)
+            .map(|_| {
+                let prefix = STRING_PREFIXES[rng.random_range(0..STRING_PREFIXES.len())];
+                let length = rng.random_range(1..=12);
+                let word: String = (0..length).map(|_| rng.random_range(b'a'..=b'j') as char).collect();
+                format!("{}{}", prefix, word)
+            })
+            .collect()
+    }
+
+    pub fn generate_record_array(&self, size: usize) -> Vec<record_sort::Record> {
+        let mut rng = rng();
+        (0..size)
+            .map(|id| record_sort::Record {
+                id,
+                surname: RECORD_SURNAMES[rng.random_range(0..RECORD_SURNAMES.len())].to_string(),
+                score: if rng.random_range(0..50) == 0 { f64::NAN } else { rng.random_range(0..=20) as f64 * 5.0 },
+                age: rng.random_range(18..=70),
+            })
+            .collect()
+    }
+
+    fn benchmark_typed<T, F, V>(
+        &self,
+        data_type: &str,
+        name: &str,
+        original: &[T],
+        iterations: usize,
+        sort_fn: F,
+        verify: V,
+    ) -> TypedSortMetrics
+    where
+        T: Clone,
+        F: Fn(&mut [T], &mut PerformanceCounter),
+        V: Fn(&[T], &[T]) -> Result<()>,
+    {
+        let mut total_comparisons = 0;
+        let mut total_duration = Duration::default();
+        let mut failure = None;
+
+        for _ in 0..iterations {
+            let mut data = original.to_vec();
+            let mut counter = PerformanceCounter::new();
+
+            let start = Instant::now();
+            sort_fn(&mut data, &mut counter);
+            total_duration += start.elapsed();
+            total_comparisons += counter.comparisons;
+
+            if failure.is_none()
+                && let Err(e) = verify(original, &data)
+            {
+                failure = Some(e.to_string());
+            }
+        }
+
+        TypedSortMetrics {
+            data_type: data_type.to_string(),
+            algorithm_name: name.to_string(),
+            array_size: original.len(),
+            comparisons: total_comparisons / iterations,
+            duration: total_duration / iterations as u32,
+            correct: failure.is_none(),
+            failure,
+        }
+    }
+
+    fn display_typed_results(&self, results: &[TypedSortMetrics]) {
+        let mut table = Table::new();
+
+        table.add_row(Row::new(vec![
+            Cell::new("Type"),
+            Cell::new("Algorithm"),
+            Cell::new("Size"),
+            Cell::new("Comparisons"),
+            Cell::new("Time (μs)"),
+            Cell::new("Correct"),
+        ]));
+
+        for metric in results {
+            table.add_row(Row::new(vec![
+                Cell::new(&metric.data_type),
+                Cell::new(&metric.algorithm_name),
+                Cell::new(&format!("{}", metric.array_size)),
+                Cell::new(&format!("{}", metric.comparisons)),
+                Cell::new(&format!("{}", metric.duration.as_micros())),
+                Cell::new(&format!("{}", metric.correct)),
+            ]));
+        }
+
+        println!("\n{}", table);
+        println!("String radix sorts count character inspections as comparisons");
+
+        for metric in results {
+            if let Some(failure) = &metric.failure {
+                println!("⚠️  {} on {}: {}", metric.algorithm_name, metric.data_type, failure);
+            }
+        }
+
+        let mut data_types: Vec<&str> = results.iter().map(|m| m.data_type.as_str()).collect();
+        data_types.dedup();
+
+        for data_type in data_types {
+            if let Some(fastest) = results.iter().filter(|m| m.data_type == data_type && m.correct).min_by_key(|m| m.duration) {
+                println!("🏆 Fastest {} Sort: {} ({}μs)", data_type, fastest.algorithm_name, fastest.duration.as_micros());
+            }
+        }
+    }
+
     pub fn analyse_array_type(&mut self, array_type: &str, size: usize) -> Result<()> {
         let test_arrays = self.generate_test_arrays(size);
         
@@ -321,6 +897,8 @@ impl SortCoordinator {
                 ("Merge Sort", merge_sort::sort as fn(&mut [i32], &mut PerformanceCounter)),
                 ("Tim Sort", tim_sort::sort as fn(&mut [i32], &mut PerformanceCounter)),
                 ("Insertion Sort", insertion_sort::sort as fn(&mut [i32], &mut PerformanceCounter)),
+                ("Pdqsort", pdq_sort::sort as fn(&mut [i32], &mut PerformanceCounter)),
+                (STD_UNSTABLE, std_sort_unstable as fn(&mut [i32], &mut PerformanceCounter)),
             ];
             
             let mut results = Vec::new();
@@ -337,8 +915,8 @@ impl SortCoordinator {
     }
 }
 
-fn is_sorted<T: PartialOrd>(arr: &[T]) -> bool {
-    arr.windows(2).all(|w| w[0] <= w[1])
+fn is_sorted<T: TotalOrder>(arr: &[T]) -> bool {
+    total_order::is_sorted(arr)
 }
 
 fn get_algorithm_properties(name: &str) -> (String, String, bool, bool, bool) {
@@ -347,8 +925,9 @@ fn get_algorithm_properties(name: &str) -> (String, String, bool, bool, bool) {
         "Insertion Sort" => ("O(n²)".to_string(), "O(1)".to_string(), true, true, true),
         "Selection Sort" => ("O(n²)".to_string(), "O(1)".to_string(), false, false, true),
         "Merge Sort" => ("O(n log n)".to_string(), "O(n)".to_string(), true, false, false),
+        "Optimised Merge Sort" => ("O(n log n)".to_string(), "O(n)".to_string(), false, false, false),
         "Quick Sort" => ("O(n log n)".to_string(), "O(log n)".to_string(), false, false, true),
-        "Heap Sort" => ("O(n log n)".to_string(), "O(1)".to_string(), false, false, true),
+        "Heap Sort" | "4-ary Heap Sort" => ("O(n log n)".to_string(), "O(1)".to_string(), false, false, true),
         "Shell Sort" => ("O(n^1.25)".to_string(), "O(1)".to_string(), false, true, true),
         "Tim Sort" => ("O(n log n)".to_string(), "O(n)".to_string(), true, true, false),
         "Tree Sort" => ("O(n log n)".to_string(), "O(n)".to_string(), true, false, false),
@@ -356,6 +935,13 @@ fn get_algorithm_properties(name: &str) -> (String, String, bool, bool, bool) {
         "Radix Sort" => ("O(d × n)".to_string(), "O(n + k)".to_string(), true, false, false),
         "Counting Sort" => ("O(n + k)".to_string(), "O(k)".to_string(), true, false, false),
         "Cube Sort" => ("O(n log n)".to_string(), "O(n)".to_string(), false, false, false),
+        "Pdqsort" => ("O(n log n)".to_string(), "O(log n)".to_string(), false, true, true),
+        "Block Quicksort" => ("O(n log n)".to_string(), "O(log n)".to_string(), false, false, true),
+        "Sample Sort" => ("O(n log n)".to_string(), "O(n)".to_string(), false, false, false),
+        STD_STABLE => ("O(n log n)".to_string(), "O(n)".to_string(), true, true, false),
+        STD_UNSTABLE => ("O(n log n)".to_string(), "O(log n)".to_string(), false, true, true),
+        "Bitonic Sort" => ("O(n log² n)".to_string(), "O(log n)".to_string(), false, false, true),
+        "Odd-Even Merge Sort" => ("O(n log² n)".to_string(), "O(1)".to_string(), false, false, true),
         _ => ("Unknown".to_string(), "Unknown".to_string(), false, false, false),
     }
 }
@@ -364,7 +950,9 @@ fn calculate_theoretical_time_complexity(name: &str, n: usize) -> f64 {
     let n_f = n as f64;
     match name {
         "Bubble Sort" | "Insertion Sort" | "Selection Sort" => n_f * n_f,
-        "Merge Sort" | "Quick Sort" | "Heap Sort" | "Tim Sort" | "Tree Sort" | "Cube Sort" => n_f * n_f.log2(),
+        "Merge Sort" | "Optimised Merge Sort" | "Quick Sort" | "Heap Sort" | "4-ary Heap Sort" | "Tim Sort" | "Tree Sort" | "Cube Sort"
+        | "Pdqsort" | "Block Quicksort" | "Sample Sort" | STD_STABLE | STD_UNSTABLE => n_f * n_f.log2(),
+        "Bitonic Sort" | "Odd-Even Merge Sort" => n_f * n_f.log2().powi(2).max(1.0),
         "Shell Sort" => n_f.powf(1.25),
         "Bucket Sort" | "Radix Sort" | "Counting Sort" => n_f,
         _ => n_f,
@@ -389,4 +977,153 @@ impl Default for SortCoordinator {
     fn default() -> Self {
         Self::new()
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HYBRIDS: [(&str, SortFn); 3] = [
+        ("pdqsort", pdq_sort::sort),
+        ("block quicksort", block_quick_sort::sort),
+        ("sample sort", sample_sort::sort),
+    ];
+
+    fn inputs() -> Vec<(&'static str, Vec<i32>)> {
+        let mut rng = StdRng::seed_from_u64(38);
+        let n = 20_000;
+        vec![
+            ("empty", Vec::new()),
+            ("single", vec![7]),
+            ("sorted", (0..n).collect()),
+            ("reverse", (0..n).rev().collect()),
+            ("all equal", vec![4; n as usize]),
+            ("few unique", (0..n).map(|_| rng.random_range(0..4)).collect()),
+            ("organ pipe", (0..n / 2).chain((0..n / 2).rev()).collect()),
+            ("sawtooth", (0..n).map(|i| i % 97).collect()),
+            ("random", (0..n).map(|_| rng.random_range(i32::MIN..i32::MAX)).collect()),
+        ]
+    }
+
+    #[test]
+    fn test_modern_hybrids_match_std() {
+        for (pattern, input) in inputs() {
+            let mut expected = input.clone();
+            expected.sort_unstable();
+
+            for (name, sort) in HYBRIDS {
+                let mut arr = input.clone();
+                let mut counter = PerformanceCounter::new();
+                sort(&mut arr, &mut counter);
+                assert_eq!(arr, expected, "{} on {}", name, pattern);
+            }
+        }
+    }
+
+    #[test]
+    fn test_presortedness_measures() {
+        let sorted = presortedness::analyse(&[1, 2, 2, 3, 5]);
+        assert_eq!((sorted.inversions, sorted.runs, sorted.rem, sorted.max_displacement), (0, 1, 0, 0));
+        assert_eq!(sorted.sortedness(), 1.0);
+
+        let reverse = presortedness::analyse(&[5, 4, 3, 2, 1]);
+        assert_eq!((reverse.inversions, reverse.runs, reverse.rem, reverse.osc), (10, 5, 4, 0));
+        assert_eq!(reverse.max_displacement, 4);
+        assert_eq!(reverse.sortedness(), 0.0);
+
+        let zigzag = presortedness::analyse(&[1, 4, 2, 5, 3]);
+        assert_eq!((zigzag.inversions, zigzag.runs, zigzag.longest_increasing_subsequence), (3, 3, 3));
+        assert_eq!(zigzag.osc, 6);
+
+        let mut rng = StdRng::seed_from_u64(39);
+        let random: Vec<i32> = (0..300).map(|_| rng.random_range(0..40)).collect();
+        let brute_force = (0..random.len())
+            .flat_map(|i| (i + 1..random.len()).map(move |j| (i, j)))
+            .filter(|&(i, j)| random[i] > random[j])
+            .count() as u64;
+        assert_eq!(presortedness::inversions(&random), brute_force);
+
+        let mut counter = PerformanceCounter::new();
+        let mut arr = random.clone();
+        insertion_sort::sort(&mut arr, &mut counter);
+        assert_eq!(counter.swaps as u64, brute_force);
+    }
+
+    #[test]
+    fn test_float_sorts_place_nans_by_total_order() {
+        let input = vec![3.5, f64::NAN, -0.0, f64::NEG_INFINITY, -f64::NAN, 0.0, -2.0, f64::INFINITY, f64::NAN, 3.5];
+        let singles: Vec<f32> = input.iter().map(|&value| value as f32).collect();
+
+        for sort in [float_sort::radix_sort, float_sort::total_cmp_sort, total_order::merge_sort] as [fn(&mut [f64], &mut PerformanceCounter); 3] {
+            let mut arr = input.clone();
+            sort(&mut arr, &mut PerformanceCounter::new());
+            assert!(float_sort::verify_order(&input, &arr).is_ok(), "{:?}", arr);
+            assert!(arr[0].is_nan() && arr[0].is_sign_negative());
+            assert!(arr[1..3] == [f64::NEG_INFINITY, -2.0]);
+            assert!(arr[3].is_sign_negative() && arr[4].is_sign_positive() && arr[3] == 0.0);
+            assert!(arr[8].is_nan() && arr[9].is_nan());
+        }
+
+        let mut arr = singles.clone();
+        float_sort::radix_sort(&mut arr, &mut PerformanceCounter::new());
+        assert!(float_sort::verify_order(&singles, &arr).is_ok());
+
+        assert!(float_sort::verify_order(&[1.0, f64::NAN], &[f64::NAN, 1.0]).is_err());
+        assert!(float_sort::verify_order(&[-0.0, 0.0], &[0.0, -0.0]).is_err());
+        assert!(float_sort::verify_order(&[1.0, 2.0], &[1.0, 1.0]).is_err());
+    }
+
+    #[test]
+    fn test_string_sorts_match_std() {
+        let coordinator = SortCoordinator::new();
+        let mut inputs = vec![
+            Vec::new(),
+            vec![String::new(), "a".to_string(), String::new(), "ab".to_string(), "aa".to_string()],
+            ["zebra", "über", "apple", "app", "apple", "Äpfel", "appendix", ""].iter().map(|s| s.to_string()).collect(),
+            (0..1_000).map(|i| format!("{}{}", "shared-prefix/".repeat(3), i % 300)).collect(),
+        ];
+        inputs.push(coordinator.generate_string_array(5_000));
+
+        for input in inputs {
+            let mut expected = input.clone();
+            expected.sort();
+
+            for sort in [string_sort::msd_radix_sort, string_sort::multikey_quicksort, string_sort::burstsort, total_order::merge_sort] as [fn(&mut [String], &mut PerformanceCounter); 4] {
+                let mut arr = input.clone();
+                sort(&mut arr, &mut PerformanceCounter::new());
+                assert_eq!(arr, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_record_sorts_are_stable_multi_key() {
+        use record_sort::{RecordField, SortKey};
+
+        let records = SortCoordinator::new().generate_record_array(3_000);
+        let keys = [SortKey::ascending(RecordField::Surname), SortKey::descending(RecordField::Score)];
+
+        for sort in [record_sort::multi_key_sort, record_sort::lsd_key_sort, record_sort::std_sort] {
+            let mut arr = records.clone();
+            sort(&mut arr, &keys, &mut PerformanceCounter::new());
+            assert!(record_sort::verify_order(&records, &arr, &keys).is_ok());
+        }
+
+        let mut unstable = records.clone();
+        unstable.sort_unstable_by(|a, b| record_sort::compare_records(a, b, &keys).then(b.id.cmp(&a.id)));
+        assert!(record_sort::verify_order(&records, &unstable, &keys).is_err());
+    }
+
+    #[test]
+    fn test_pdqsort_is_linear_on_sorted_input() {
+        let mut arr: Vec<i32> = (0..100_000).collect();
+        let mut counter = PerformanceCounter::new();
+        pdq_sort::sort(&mut arr, &mut counter);
+        assert!(counter.comparisons < 3 * arr.len(), "{} comparisons", counter.comparisons);
+
+        let mut counted: Vec<i32> = (0..1_000).rev().collect();
+        let mut std_counter = PerformanceCounter::new();
+        std_sort_unstable(&mut counted, &mut std_counter);
+        assert!(is_sorted(&counted));
+        assert!(std_counter.comparisons > 0);
+    }
+}
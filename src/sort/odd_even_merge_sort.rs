@@ -0,0 +1,46 @@
+use super::PerformanceCounter;
+use super::sorting_network::{compare_exchange, compare_exchange_branchless};
+
+pub fn sort(arr: &mut [i32], counter: &mut PerformanceCounter) {
+    for_each_comparator(arr.len(), |i, j| compare_exchange(arr, i, j, counter));
+}
+
+pub fn sort_branchless(arr: &mut [i32], counter: &mut PerformanceCounter) {
+    for_each_comparator(arr.len(), |i, j| compare_exchange_branchless(arr, i, j, counter));
+}
+
+pub fn network(n: usize) -> Vec<(usize, usize)> {
+    let mut comparators = Vec::new();
+    for_each_comparator(n, |i, j| comparators.push((i, j)));
+    comparators
+}
+
+fn for_each_comparator<F>(n: usize, mut apply: F)
+where
+    F: FnMut(usize, usize),
+{
+    if n <= 1 {
+        return;
+    }
+
+    let size = n.next_power_of_two();
+    let mut p = 1;
+    while p < size {
+        let mut k = p;
+        while k >= 1 {
+            let mut j = k % p;
+            while j + k < size {
+                for i in 0..k.min(size - j - k) {
+                    let a = i + j;
+                    let b = i + j + k;
+                    if a / (2 * p) == b / (2 * p) && b < n {
+                        apply(a, b);
+                    }
+                }
+                j += 2 * k;
+            }
+            k /= 2;
+        }
+        p *= 2;
+    }
+}
@@ -0,0 +1,233 @@
+use super::PerformanceCounter;
+use std::cmp::Ordering;
+
+const INSERTION_SORT_THRESHOLD: usize = 24;
+const NINTHER_THRESHOLD: usize = 128;
+const PARTIAL_INSERTION_SORT_LIMIT: usize = 8;
+
+pub fn sort(arr: &mut [i32], counter: &mut PerformanceCounter) {
+    let n = arr.len();
+    if n <= 1 {
+        return;
+    }
+
+    let bad_allowed = usize::BITS - n.leading_zeros();
+    pdq_loop(arr, None, bad_allowed as usize, counter);
+}
+
+fn pdq_loop(mut arr: &mut [i32], mut predecessor: Option<i32>, mut bad_allowed: usize, counter: &mut PerformanceCounter) {
+    loop {
+        let n = arr.len();
+        if n <= INSERTION_SORT_THRESHOLD {
+            insertion_sort(arr, counter);
+            return;
+        }
+
+        let mid = n / 2;
+        if n > NINTHER_THRESHOLD {
+            sort3(arr, 0, mid, n - 1, counter);
+            sort3(arr, 1, mid - 1, n - 2, counter);
+            sort3(arr, 2, mid + 1, n - 3, counter);
+            sort3(arr, mid - 1, mid, mid + 1, counter);
+            counter.swap(arr, 0, mid);
+        } else {
+            sort3(arr, mid, 0, n - 1, counter);
+        }
+
+        if let Some(pred) = predecessor
+            && counter.compare(&pred, &arr[0]) != Ordering::Less
+        {
+            let pivot_pos = partition_left(arr, counter);
+            arr = &mut arr[pivot_pos + 1..];
+            continue;
+        }
+
+        let (pivot_pos, already_partitioned) = partition_right(arr, counter);
+        let left_size = pivot_pos;
+        let right_size = n - pivot_pos - 1;
+
+        if left_size < n / 8 || right_size < n / 8 {
+            bad_allowed -= 1;
+            if bad_allowed == 0 {
+                super::heap_sort::sort(arr, counter);
+                return;
+            }
+
+            break_patterns(arr, pivot_pos, counter);
+        } else if already_partitioned
+            && partial_insertion_sort(&mut arr[..pivot_pos], counter)
+            && partial_insertion_sort(&mut arr[pivot_pos + 1..], counter)
+        {
+            return;
+        }
+
+        let (left, right) = arr.split_at_mut(pivot_pos);
+        pdq_loop(left, predecessor, bad_allowed, counter);
+        predecessor = Some(right[0]);
+        arr = &mut right[1..];
+    }
+}
+
+fn partition_right(arr: &mut [i32], counter: &mut PerformanceCounter) -> (usize, bool) {
+    let pivot = arr[0];
+    let mut first = 1;
+    let mut last = arr.len();
+
+    while counter.compare(&arr[first], &pivot) == Ordering::Less {
+        first += 1;
+    }
+
+    if first == 1 {
+        while first < last {
+            last -= 1;
+            if counter.compare(&arr[last], &pivot) == Ordering::Less {
+                break;
+            }
+        }
+    } else {
+        loop {
+            last -= 1;
+            if counter.compare(&arr[last], &pivot) == Ordering::Less {
+                break;
+            }
+        }
+    }
+
+    let already_partitioned = first >= last;
+
+    while first < last {
+        counter.swap(arr, first, last);
+        first += 1;
+        while counter.compare(&arr[first], &pivot) == Ordering::Less {
+            first += 1;
+        }
+        last -= 1;
+        while counter.compare(&arr[last], &pivot) != Ordering::Less {
+            last -= 1;
+        }
+    }
+
+    let pivot_pos = first - 1;
+    counter.swap(arr, 0, pivot_pos);
+    (pivot_pos, already_partitioned)
+}
+
+fn partition_left(arr: &mut [i32], counter: &mut PerformanceCounter) -> usize {
+    let pivot = arr[0];
+    let n = arr.len();
+    let mut first = 0;
+    let mut last = n - 1;
+
+    while counter.compare(&pivot, &arr[last]) == Ordering::Less {
+        last -= 1;
+    }
+
+    if last + 1 == n {
+        while first < last {
+            first += 1;
+            if counter.compare(&pivot, &arr[first]) == Ordering::Less {
+                break;
+            }
+        }
+    } else {
+        loop {
+            first += 1;
+            if counter.compare(&pivot, &arr[first]) == Ordering::Less {
+                break;
+            }
+        }
+    }
+
+    while first < last {
+        counter.swap(arr, first, last);
+        last -= 1;
+        while counter.compare(&pivot, &arr[last]) == Ordering::Less {
+            last -= 1;
+        }
+        first += 1;
+        while counter.compare(&pivot, &arr[first]) != Ordering::Less {
+            first += 1;
+        }
+    }
+
+    counter.swap(arr, 0, last);
+    last
+}
+
+fn break_patterns(arr: &mut [i32], pivot_pos: usize, counter: &mut PerformanceCounter) {
+    let n = arr.len();
+    let left_size = pivot_pos;
+    let right_size = n - pivot_pos - 1;
+
+    if left_size >= INSERTION_SORT_THRESHOLD {
+        let quarter = left_size / 4;
+        counter.swap(arr, 0, quarter);
+        counter.swap(arr, pivot_pos - 1, pivot_pos - quarter);
+
+        if left_size > NINTHER_THRESHOLD {
+            counter.swap(arr, 1, quarter + 1);
+            counter.swap(arr, 2, quarter + 2);
+            counter.swap(arr, pivot_pos - 2, pivot_pos - (quarter + 1));
+            counter.swap(arr, pivot_pos - 3, pivot_pos - (quarter + 2));
+        }
+    }
+
+    if right_size >= INSERTION_SORT_THRESHOLD {
+        let quarter = right_size / 4;
+        counter.swap(arr, pivot_pos + 1, pivot_pos + 1 + quarter);
+        counter.swap(arr, n - 1, n - quarter);
+
+        if right_size > NINTHER_THRESHOLD {
+            counter.swap(arr, pivot_pos + 2, pivot_pos + 2 + quarter);
+            counter.swap(arr, pivot_pos + 3, pivot_pos + 3 + quarter);
+            counter.swap(arr, n - 2, n - (1 + quarter));
+            counter.swap(arr, n - 3, n - (2 + quarter));
+        }
+    }
+}
+
+fn partial_insertion_sort(arr: &mut [i32], counter: &mut PerformanceCounter) -> bool {
+    let mut moved = 0;
+
+    for i in 1..arr.len() {
+        let mut j = i;
+        while j > 0 && counter.compare(&arr[j - 1], &arr[j]) == Ordering::Greater {
+            counter.swap(arr, j - 1, j);
+            j -= 1;
+        }
+
+        moved += i - j;
+        if moved > PARTIAL_INSERTION_SORT_LIMIT {
+            return false;
+        }
+    }
+
+    true
+}
+
+fn insertion_sort(arr: &mut [i32], counter: &mut PerformanceCounter) {
+    for i in 1..arr.len() {
+        let key = arr[i];
+        let mut j = i;
+
+        while j > 0 && counter.compare(&arr[j - 1], &key) == Ordering::Greater {
+            arr[j] = arr[j - 1];
+            counter.swaps += 1;
+            j -= 1;
+        }
+
+        arr[j] = key;
+    }
+}
+
+pub fn sort3(arr: &mut [i32], a: usize, b: usize, c: usize, counter: &mut PerformanceCounter) {
+    sort2(arr, a, b, counter);
+    sort2(arr, b, c, counter);
+    sort2(arr, a, b, counter);
+}
+
+fn sort2(arr: &mut [i32], a: usize, b: usize, counter: &mut PerformanceCounter) {
+    if counter.compare(&arr[b], &arr[a]) == Ordering::Less {
+        counter.swap(arr, a, b);
+    }
+}
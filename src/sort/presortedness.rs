@@ -0,0 +1,119 @@
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Presortedness {
+    pub length: usize,
+    pub inversions: u64,
+    pub runs: usize,
+    pub rem: usize,
+    pub osc: u64,
+    pub max_displacement: usize,
+    pub longest_increasing_subsequence: usize,
+}
+
+impl Presortedness {
+    pub fn sortedness(&self) -> f64 {
+        if self.length < 2 {
+            return 1.0;
+        }
+
+        let max_inversions = (self.length as u64 * (self.length as u64 - 1) / 2) as f64;
+        1.0 - self.inversions as f64 / max_inversions
+    }
+}
+
+pub fn analyse(arr: &[i32]) -> Presortedness {
+    let lis = longest_increasing_subsequence(arr);
+
+    Presortedness {
+        length: arr.len(),
+        inversions: inversions(arr),
+        runs: runs(arr),
+        rem: arr.len() - lis,
+        osc: oscillation(arr),
+        max_displacement: max_displacement(arr),
+        longest_increasing_subsequence: lis,
+    }
+}
+
+pub fn inversions(arr: &[i32]) -> u64 {
+    let mut values = arr.to_vec();
+    let mut buffer = vec![0; arr.len()];
+    merge_count(&mut values, &mut buffer)
+}
+
+fn merge_count(arr: &mut [i32], buffer: &mut [i32]) -> u64 {
+    let n = arr.len();
+    if n <= 1 {
+        return 0;
+    }
+
+    let mid = n / 2;
+    let mut count = merge_count(&mut arr[..mid], &mut buffer[..mid]) + merge_count(&mut arr[mid..], &mut buffer[mid..]);
+
+    let (mut i, mut j, mut k) = (0, mid, 0);
+    while i < mid && j < n {
+        if arr[j] < arr[i] {
+            buffer[k] = arr[j];
+            count += (mid - i) as u64;
+            j += 1;
+        } else {
+            buffer[k] = arr[i];
+            i += 1;
+        }
+        k += 1;
+    }
+
+    buffer[k..k + mid - i].copy_from_slice(&arr[i..mid]);
+    k += mid - i;
+    buffer[k..n].copy_from_slice(&arr[j..n]);
+    arr.copy_from_slice(&buffer[..n]);
+
+    count
+}
+
+pub fn runs(arr: &[i32]) -> usize {
+    if arr.is_empty() {
+        return 0;
+    }
+
+    1 + arr.windows(2).filter(|w| w[1] < w[0]).count()
+}
+
+pub fn longest_increasing_subsequence(arr: &[i32]) -> usize {
+    let mut tails: Vec<i32> = Vec::new();
+
+    for &value in arr {
+        let position = tails.partition_point(|&tail| tail <= value);
+        if position == tails.len() {
+            tails.push(value);
+        } else {
+            tails[position] = value;
+        }
+    }
+
+    tails.len()
+}
+
+pub fn oscillation(arr: &[i32]) -> u64 {
+    let mut sorted = arr.to_vec();
+    sorted.sort_unstable();
+
+    arr.windows(2)
+        .map(|w| {
+            let (low, high) = (w[0].min(w[1]), w[0].max(w[1]));
+            let above_low = sorted.partition_point(|&value| value <= low);
+            let below_high = sorted.partition_point(|&value| value < high);
+            below_high.saturating_sub(above_low) as u64
+        })
+        .sum()
+}
+
+pub fn max_displacement(arr: &[i32]) -> usize {
+    let mut order: Vec<usize> = (0..arr.len()).collect();
+    order.sort_by_key(|&index| (arr[index], index));
+
+    order.iter()
+        .enumerate()
+        .map(|(rank, &index)| rank.abs_diff(index))
+        .max()
+        .unwrap_or(0)
+}
@@ -0,0 +1,105 @@
+use super::{total_order, PerformanceCounter, TotalOrder};
+use crate::prelude::*;
+use std::cmp::Ordering;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub id: usize,
+    pub surname: String,
+    pub score: f64,
+    pub age: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordField {
+    Id,
+    Surname,
+    Score,
+    Age,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SortKey {
+    pub field: RecordField,
+    pub descending: bool,
+}
+
+impl SortKey {
+    pub fn ascending(field: RecordField) -> Self {
+        Self { field, descending: false }
+    }
+
+    pub fn descending(field: RecordField) -> Self {
+        Self { field, descending: true }
+    }
+
+    pub fn compare(&self, a: &Record, b: &Record) -> Ordering {
+        let ordering = match self.field {
+            RecordField::Id => a.id.total_order(&b.id),
+            RecordField::Surname => a.surname.total_order(&b.surname),
+            RecordField::Score => a.score.total_order(&b.score),
+            RecordField::Age => a.age.total_order(&b.age),
+        };
+
+        if self.descending { ordering.reverse() } else { ordering }
+    }
+}
+
+pub fn compare_records(a: &Record, b: &Record, keys: &[SortKey]) -> Ordering {
+    keys.iter()
+        .map(|key| key.compare(a, b))
+        .find(|&ordering| ordering != Ordering::Equal)
+        .unwrap_or(Ordering::Equal)
+}
+
+pub fn multi_key_sort(records: &mut [Record], keys: &[SortKey], counter: &mut PerformanceCounter) {
+    total_order::merge_sort_by(records, |a, b| compare_records(a, b, keys), counter);
+}
+
+pub fn lsd_key_sort(records: &mut [Record], keys: &[SortKey], counter: &mut PerformanceCounter) {
+    for key in keys.iter().rev() {
+        total_order::merge_sort_by(records, |a, b| key.compare(a, b), counter);
+    }
+}
+
+pub fn std_sort(records: &mut [Record], keys: &[SortKey], counter: &mut PerformanceCounter) {
+    records.sort_by(|a, b| {
+        counter.comparisons += 1;
+        compare_records(a, b, keys)
+    });
+}
+
+pub fn verify_order(original: &[Record], sorted: &[Record], keys: &[SortKey]) -> Result<()> {
+    if original.len() != sorted.len() {
+        return Err(Error::validation("Sorted output has a different length"));
+    }
+
+    let mut positions = vec![usize::MAX; original.len()];
+    for (position, record) in original.iter().enumerate() {
+        if record.id >= positions.len() || positions[record.id] != usize::MAX {
+            return Err(Error::validation("Record ids must be unique and below the record count"));
+        }
+        positions[record.id] = position;
+    }
+
+    let mut seen = vec![false; original.len()];
+    for record in sorted {
+        if record.id >= seen.len() || std::mem::replace(&mut seen[record.id], true) {
+            return Err(Error::validation("Sorted output is not a permutation of the input"));
+        }
+    }
+
+    for (index, pair) in sorted.windows(2).enumerate() {
+        match compare_records(&pair[0], &pair[1], keys) {
+            Ordering::Greater => {
+                return Err(Error::validation(format!("Records out of order at index {}", index)));
+            }
+            Ordering::Equal if positions[pair[0].id] > positions[pair[1].id] => {
+                return Err(Error::validation(format!("Equal records reordered at index {} (unstable)", index)));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use super::PerformanceCounter;
+
+const BASE_CASE_SIZE: usize = 256;
+const MAX_LOG_BUCKETS: u32 = 8;
+
+pub fn sort(arr: &mut [i32], counter: &mut PerformanceCounter) {
+    let mut buffer = vec![0; arr.len()];
+    counter.allocate_memory(arr.len());
+    let mut seed = 0x9E37_79B9_7F4A_7C15 ^ arr.len() as u64;
+    sample_sort(arr, &mut buffer, &mut seed, counter);
+}
+
+fn sample_sort(arr: &mut [i32], buffer: &mut [i32], seed: &mut u64, counter: &mut PerformanceCounter) {
+    let n = arr.len();
+    if n <= BASE_CASE_SIZE {
+        super::pdq_sort::sort(arr, counter);
+        return;
+    }
+
+    let log_buckets = ((n / BASE_CASE_SIZE).ilog2()).clamp(1, MAX_LOG_BUCKETS);
+    let num_buckets = 1usize << log_buckets;
+    let oversampling = (n.ilog2() as usize / 5).max(1);
+
+    let mut sample: Vec<i32> = (0..oversampling * num_buckets - 1)
+        .map(|_| arr[next_index(seed, n)])
+        .collect();
+    counter.allocate_memory(sample.len());
+    super::pdq_sort::sort(&mut sample, counter);
+
+    let mut splitters: Vec<i32> = (1..num_buckets).map(|i| sample[i * oversampling - 1]).collect();
+    splitters.dedup();
+    let use_equality_buckets = splitters.len() < num_buckets - 1;
+    while splitters.len() < num_buckets - 1 {
+        splitters.push(splitters[splitters.len() - 1]);
+    }
+
+    let mut tree = vec![0; num_buckets];
+    build_tree(&mut tree, &splitters, 1);
+
+    let total_buckets = if use_equality_buckets { 2 * num_buckets } else { num_buckets };
+    let mut bucket_of = vec![0u16; n];
+    let mut sizes = vec![0usize; total_buckets];
+    counter.allocate_memory(n);
+
+    for (slot, &value) in bucket_of.iter_mut().zip(arr.iter()) {
+        let mut node = 1;
+        for _ in 0..log_buckets {
+            node = 2 * node + (tree[node] < value) as usize;
+        }
+        counter.comparisons += log_buckets as usize;
+
+        let mut bucket = node - num_buckets;
+        if use_equality_buckets {
+            let is_equal = bucket < splitters.len() && splitters[bucket] == value;
+            counter.comparisons += 1;
+            bucket = 2 * bucket + is_equal as usize;
+        }
+
+        *slot = bucket as u16;
+        sizes[bucket] += 1;
+    }
+
+    let mut starts = vec![0usize; total_buckets + 1];
+    for (bucket, size) in sizes.iter().enumerate() {
+        starts[bucket + 1] = starts[bucket] + size;
+    }
+
+    let mut cursor = starts.clone();
+    for (&bucket, &value) in bucket_of.iter().zip(arr.iter()) {
+        buffer[cursor[bucket as usize]] = value;
+        cursor[bucket as usize] += 1;
+    }
+    arr.copy_from_slice(&buffer[..n]);
+    counter.swaps += n;
+
+    for bucket in 0..total_buckets {
+        let is_equality_bucket = use_equality_buckets && bucket % 2 == 1;
+        let (start, end) = (starts[bucket], starts[bucket + 1]);
+        if is_equality_bucket || end - start <= 1 {
+            continue;
+        }
+
+        if end - start == n {
+            super::pdq_sort::sort(arr, counter);
+            return;
+        }
+
+        sample_sort(&mut arr[start..end], &mut buffer[start..end], seed, counter);
+    }
+}
+
+fn build_tree(tree: &mut [i32], splitters: &[i32], node: usize) {
+    if splitters.is_empty() {
+        return;
+    }
+
+    let mid = splitters.len() / 2;
+    tree[node] = splitters[mid];
+    build_tree(tree, &splitters[..mid], 2 * node);
+    build_tree(tree, &splitters[mid + 1..], 2 * node + 1);
+}
+
+fn next_index(seed: &mut u64, n: usize) -> usize {
+    *seed ^= *seed << 13;
+    *seed ^= *seed >> 7;
+    *seed ^= *seed << 17;
+    (*seed % n as u64) as usize
+}
@@ -0,0 +1,189 @@
+use super::{odd_even_merge_sort, PerformanceCounter};
+
+pub const MAX_NETWORK_SIZE: usize = 32;
+pub const MAX_SIZE_OPTIMAL: usize = 8;
+
+const SIZE_OPTIMAL_2: &[(usize, usize)] = &[(0, 1)];
+const SIZE_OPTIMAL_3: &[(usize, usize)] = &[(0, 2), (0, 1), (1, 2)];
+const SIZE_OPTIMAL_4: &[(usize, usize)] = &[(0, 1), (2, 3), (0, 2), (1, 3), (1, 2)];
+const SIZE_OPTIMAL_5: &[(usize, usize)] = &[
+    (0, 3), (1, 4), (0, 2), (1, 3), (0, 1), (2, 4), (1, 2), (3, 4), (2, 3),
+];
+const SIZE_OPTIMAL_6: &[(usize, usize)] = &[
+    (0, 5), (1, 3), (2, 4), (1, 2), (3, 4), (0, 3), (2, 5), (0, 1), (2, 3), (4, 5), (1, 2), (3, 4),
+];
+const SIZE_OPTIMAL_7: &[(usize, usize)] = &[
+    (0, 2), (1, 3), (4, 6), (0, 4), (1, 5), (2, 6), (0, 1), (2, 3), (4, 5),
+    (2, 4), (3, 5), (1, 4), (3, 6), (1, 2), (3, 4), (5, 6),
+];
+const SIZE_OPTIMAL_8: &[(usize, usize)] = &[
+    (0, 2), (1, 3), (4, 6), (5, 7), (0, 4), (1, 5), (2, 6), (3, 7), (0, 1), (2, 3),
+    (4, 5), (6, 7), (2, 4), (3, 5), (1, 4), (3, 6), (1, 2), (3, 4), (5, 6),
+];
+
+pub fn size_optimal_network(n: usize) -> Option<&'static [(usize, usize)]> {
+    match n {
+        0 | 1 => Some(&[]),
+        2 => Some(SIZE_OPTIMAL_2),
+        3 => Some(SIZE_OPTIMAL_3),
+        4 => Some(SIZE_OPTIMAL_4),
+        5 => Some(SIZE_OPTIMAL_5),
+        6 => Some(SIZE_OPTIMAL_6),
+        7 => Some(SIZE_OPTIMAL_7),
+        8 => Some(SIZE_OPTIMAL_8),
+        _ => None,
+    }
+}
+
+pub fn network(n: usize) -> Vec<(usize, usize)> {
+    match size_optimal_network(n) {
+        Some(comparators) => comparators.to_vec(),
+        None => odd_even_merge_sort::network(n),
+    }
+}
+
+pub fn is_size_optimal(n: usize) -> bool {
+    size_optimal_network(n).is_some()
+}
+
+pub fn sort(arr: &mut [i32], counter: &mut PerformanceCounter) {
+    match size_optimal_network(arr.len()) {
+        Some(comparators) => {
+            for &(i, j) in comparators {
+                compare_exchange(arr, i, j, counter);
+            }
+        }
+        None => odd_even_merge_sort::sort(arr, counter),
+    }
+}
+
+pub fn sort_branchless(arr: &mut [i32], counter: &mut PerformanceCounter) {
+    match size_optimal_network(arr.len()) {
+        Some(comparators) => {
+            for &(i, j) in comparators {
+                compare_exchange_branchless(arr, i, j, counter);
+            }
+        }
+        None => odd_even_merge_sort::sort_branchless(arr, counter),
+    }
+}
+
+pub fn compare_exchange(arr: &mut [i32], i: usize, j: usize, counter: &mut PerformanceCounter) {
+    if counter.compare(&arr[i], &arr[j]) == std::cmp::Ordering::Greater {
+        counter.swap(arr, i, j);
+    }
+}
+
+pub fn compare_exchange_branchless(arr: &mut [i32], i: usize, j: usize, counter: &mut PerformanceCounter) {
+    let a = arr[i];
+    let b = arr[j];
+    arr[i] = a.min(b);
+    arr[j] = a.max(b);
+    counter.comparisons += 1;
+    counter.swaps += (a > b) as usize;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sort::{bitonic_sort, cube_sort, merge_sort, odd_even_merge_sort, HybridThresholds, SmallSortKernel};
+    use rand::prelude::*;
+
+    const SIZE_OPTIMAL_COMPARATORS: [usize; 9] = [0, 0, 1, 3, 5, 9, 12, 16, 19];
+
+    fn sorts_all_binary_inputs(n: usize, comparators: &[(usize, usize)]) -> bool {
+        (0..1u32 << n).all(|mask| {
+            let mut values: Vec<i32> = (0..n).map(|bit| ((mask >> bit) & 1) as i32).collect();
+            for &(i, j) in comparators {
+                if values[i] > values[j] {
+                    values.swap(i, j);
+                }
+            }
+            values.windows(2).all(|w| w[0] <= w[1])
+        })
+    }
+
+    #[test]
+    fn test_networks_sort_every_binary_input() {
+        for n in 0..=16 {
+            assert!(sorts_all_binary_inputs(n, &network(n)), "network n={}", n);
+            assert!(sorts_all_binary_inputs(n, &odd_even_merge_sort::network(n)), "odd-even n={}", n);
+            assert!(sorts_all_binary_inputs(n, &bitonic_sort::network(n)), "bitonic n={}", n);
+        }
+
+        for (n, &size) in SIZE_OPTIMAL_COMPARATORS.iter().enumerate() {
+            assert!(is_size_optimal(n));
+            assert_eq!(network(n).len(), size, "comparators n={}", n);
+        }
+
+        for n in MAX_SIZE_OPTIMAL + 1..=MAX_NETWORK_SIZE {
+            assert!(!is_size_optimal(n));
+            assert_eq!(network(n), odd_even_merge_sort::network(n), "batcher n={}", n);
+        }
+    }
+
+    #[test]
+    fn test_network_and_hybrid_sorts_match_std() {
+        let mut rng = StdRng::seed_from_u64(37);
+        let kernels = SmallSortKernel::ALL;
+
+        for n in (0..=MAX_NETWORK_SIZE).chain([33, 100, 257, 1_000]) {
+            let input: Vec<i32> = (0..n).map(|_| rng.random_range(-50..50)).collect();
+            let mut expected = input.clone();
+            expected.sort_unstable();
+
+            let mut counter = PerformanceCounter::new();
+            let mut outputs = vec![input.clone(), input.clone(), input.clone(), input.clone()];
+            sort(&mut outputs[0], &mut counter);
+            sort_branchless(&mut outputs[1], &mut counter);
+            bitonic_sort::sort(&mut outputs[2], &mut counter);
+            odd_even_merge_sort::sort(&mut outputs[3], &mut counter);
+
+            for (kernel, threshold) in kernels.into_iter().zip([4, 16, 32]) {
+                let thresholds = HybridThresholds {
+                    small_sort: threshold,
+                    kernel,
+                    ..HybridThresholds::default()
+                };
+                assert!(thresholds.validate().is_ok());
+
+                let mut merged = input.clone();
+                merge_sort::merge_sort_optimised_with(&mut merged, &thresholds, &mut counter);
+                outputs.push(merged);
+
+                let mut cubed = input.clone();
+                cube_sort::sort_with_thresholds(&mut cubed, &thresholds, &mut counter);
+                outputs.push(cubed);
+            }
+
+            for output in outputs {
+                assert_eq!(output, expected, "n={}", n);
+            }
+        }
+    }
+
+    #[test]
+    fn test_branchless_matches_branching_counts() {
+        let mut rng = StdRng::seed_from_u64(11);
+        let input: Vec<i32> = (0..24).map(|_| rng.random_range(0..10)).collect();
+
+        let mut branching = input.clone();
+        let mut branching_counter = PerformanceCounter::new();
+        sort(&mut branching, &mut branching_counter);
+
+        let mut branchless = input;
+        let mut branchless_counter = PerformanceCounter::new();
+        sort_branchless(&mut branchless, &mut branchless_counter);
+
+        assert_eq!(branching, branchless);
+        assert_eq!(branching_counter.comparisons, branchless_counter.comparisons);
+        assert_eq!(branching_counter.swaps, branchless_counter.swaps);
+
+        let invalid = HybridThresholds {
+            small_sort: 64,
+            kernel: SmallSortKernel::Network,
+            ..HybridThresholds::default()
+        };
+        assert!(invalid.validate().is_err());
+    }
+}
@@ -0,0 +1,200 @@
+use super::PerformanceCounter;
+use std::cmp::Ordering;
+
+const INSERTION_SORT_THRESHOLD: usize = 16;
+const BURST_THRESHOLD: usize = 128;
+const ALPHABET_SIZE: usize = 257;
+
+pub fn msd_radix_sort(arr: &mut [String], counter: &mut PerformanceCounter) {
+    let mut buffer = vec![String::new(); arr.len()];
+    counter.allocate_memory(arr.len());
+    msd_radix_sort_from(arr, &mut buffer, 0, counter);
+}
+
+fn msd_radix_sort_from(arr: &mut [String], buffer: &mut [String], depth: usize, counter: &mut PerformanceCounter) {
+    let n = arr.len();
+    if n <= INSERTION_SORT_THRESHOLD {
+        insertion_sort_from(arr, depth, counter);
+        return;
+    }
+
+    let mut starts = [0usize; ALPHABET_SIZE + 1];
+    for s in arr.iter() {
+        starts[char_at(s, depth, counter) + 1] += 1;
+    }
+    let mut total = 0;
+    for start in starts.iter_mut() {
+        total += *start;
+        *start = total;
+    }
+
+    let mut cursor = starts;
+    for s in arr.iter_mut() {
+        let symbol = char_at(s, depth, counter);
+        buffer[cursor[symbol]] = std::mem::take(s);
+        cursor[symbol] += 1;
+    }
+    arr.swap_with_slice(&mut buffer[..n]);
+    counter.swaps += n;
+
+    for symbol in 1..ALPHABET_SIZE {
+        let (start, end) = (starts[symbol], starts[symbol + 1]);
+        if end - start > 1 {
+            msd_radix_sort_from(&mut arr[start..end], &mut buffer[start..end], depth + 1, counter);
+        }
+    }
+}
+
+pub fn multikey_quicksort(arr: &mut [String], counter: &mut PerformanceCounter) {
+    multikey_quicksort_from(arr, 0, counter);
+}
+
+fn multikey_quicksort_from(mut arr: &mut [String], mut depth: usize, counter: &mut PerformanceCounter) {
+    loop {
+        let n = arr.len();
+        if n <= INSERTION_SORT_THRESHOLD {
+            insertion_sort_from(arr, depth, counter);
+            return;
+        }
+
+        let pivot = median_of_three(
+            char_at(&arr[0], depth, counter),
+            char_at(&arr[n / 2], depth, counter),
+            char_at(&arr[n - 1], depth, counter),
+        );
+
+        let (mut less, mut index, mut greater) = (0, 0, n);
+        while index < greater {
+            match char_at(&arr[index], depth, counter).cmp(&pivot) {
+                Ordering::Less => {
+                    counter.swap(arr, less, index);
+                    less += 1;
+                    index += 1;
+                }
+                Ordering::Greater => {
+                    greater -= 1;
+                    counter.swap(arr, index, greater);
+                }
+                Ordering::Equal => index += 1,
+            }
+        }
+
+        let (left, rest) = arr.split_at_mut(less);
+        let (middle, right) = rest.split_at_mut(greater - less);
+        multikey_quicksort_from(left, depth, counter);
+        multikey_quicksort_from(right, depth, counter);
+
+        if pivot == 0 {
+            return;
+        }
+
+        arr = middle;
+        depth += 1;
+    }
+}
+
+enum BurstNode {
+    Bucket(Vec<String>),
+    Trie(Box<BurstTrie>),
+}
+
+struct BurstTrie {
+    ended: Vec<String>,
+    children: Vec<BurstNode>,
+}
+
+impl BurstTrie {
+    fn new(counter: &mut PerformanceCounter) -> Self {
+        counter.allocate_memory(ALPHABET_SIZE - 1);
+        Self {
+            ended: Vec::new(),
+            children: (1..ALPHABET_SIZE).map(|_| BurstNode::Bucket(Vec::new())).collect(),
+        }
+    }
+
+    fn insert(&mut self, s: String, depth: usize, counter: &mut PerformanceCounter) {
+        let symbol = char_at(&s, depth, counter);
+        if symbol == 0 {
+            self.ended.push(s);
+            return;
+        }
+
+        let child = &mut self.children[symbol - 1];
+        match child {
+            BurstNode::Trie(trie) => trie.insert(s, depth + 1, counter),
+            BurstNode::Bucket(bucket) => {
+                bucket.push(s);
+                if bucket.len() > BURST_THRESHOLD {
+                    let mut trie = Box::new(BurstTrie::new(counter));
+                    for s in std::mem::take(bucket) {
+                        trie.insert(s, depth + 1, counter);
+                    }
+                    *child = BurstNode::Trie(trie);
+                }
+            }
+        }
+    }
+
+    fn drain_into(self, output: &mut Vec<String>, depth: usize, counter: &mut PerformanceCounter) {
+        output.extend(self.ended);
+
+        for child in self.children {
+            match child {
+                BurstNode::Trie(trie) => trie.drain_into(output, depth + 1, counter),
+                BurstNode::Bucket(mut bucket) => {
+                    multikey_quicksort_from(&mut bucket, depth + 1, counter);
+                    output.extend(bucket);
+                }
+            }
+        }
+    }
+}
+
+pub fn burstsort(arr: &mut [String], counter: &mut PerformanceCounter) {
+    let mut root = BurstTrie::new(counter);
+    for s in arr.iter_mut() {
+        root.insert(std::mem::take(s), 0, counter);
+    }
+
+    let mut output = Vec::with_capacity(arr.len());
+    root.drain_into(&mut output, 0, counter);
+
+    for (slot, s) in arr.iter_mut().zip(output) {
+        *slot = s;
+    }
+}
+
+fn insertion_sort_from(arr: &mut [String], depth: usize, counter: &mut PerformanceCounter) {
+    for i in 1..arr.len() {
+        let mut j = i;
+        while j > 0 && compare_from(&arr[j - 1], &arr[j], depth, counter) == Ordering::Greater {
+            counter.swap(arr, j - 1, j);
+            j -= 1;
+        }
+    }
+}
+
+fn compare_from(a: &str, b: &str, depth: usize, counter: &mut PerformanceCounter) -> Ordering {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let mut index = depth;
+
+    loop {
+        counter.comparisons += 1;
+        match (a.get(index), b.get(index)) {
+            (Some(x), Some(y)) if x == y => index += 1,
+            (Some(x), Some(y)) => return x.cmp(y),
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+        }
+    }
+}
+
+fn char_at(s: &str, depth: usize, counter: &mut PerformanceCounter) -> usize {
+    counter.comparisons += 1;
+    s.as_bytes().get(depth).map_or(0, |&byte| byte as usize + 1)
+}
+
+fn median_of_three(a: usize, b: usize, c: usize) -> usize {
+    a.max(b).min(a.min(b).max(c))
+}
@@ -0,0 +1,140 @@
+use super::PerformanceCounter;
+use std::cmp::Ordering;
+
+pub trait TotalOrder {
+    fn total_order(&self, other: &Self) -> Ordering;
+}
+
+macro_rules! impl_total_order_for_ord {
+    ($($t:ty),*) => {
+        $(
+            impl TotalOrder for $t {
+                fn total_order(&self, other: &Self) -> Ordering {
+                    self.cmp(other)
+                }
+            }
+        )*
+    };
+}
+
+impl_total_order_for_ord!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, bool, char, str, String);
+
+impl TotalOrder for f32 {
+    fn total_order(&self, other: &Self) -> Ordering {
+        self.total_cmp(other)
+    }
+}
+
+impl TotalOrder for f64 {
+    fn total_order(&self, other: &Self) -> Ordering {
+        self.total_cmp(other)
+    }
+}
+
+impl<T: TotalOrder + ?Sized> TotalOrder for &T {
+    fn total_order(&self, other: &Self) -> Ordering {
+        (**self).total_order(*other)
+    }
+}
+
+impl<T: TotalOrder> TotalOrder for [T] {
+    fn total_order(&self, other: &Self) -> Ordering {
+        for (a, b) in self.iter().zip(other.iter()) {
+            match a.total_order(b) {
+                Ordering::Equal => continue,
+                ordering => return ordering,
+            }
+        }
+        self.len().cmp(&other.len())
+    }
+}
+
+impl<T: TotalOrder> TotalOrder for Vec<T> {
+    fn total_order(&self, other: &Self) -> Ordering {
+        self.as_slice().total_order(other.as_slice())
+    }
+}
+
+impl<T: TotalOrder> TotalOrder for Option<T> {
+    fn total_order(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Some(a), Some(b)) => a.total_order(b),
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) => Ordering::Less,
+            (Some(_), None) => Ordering::Greater,
+        }
+    }
+}
+
+impl<A: TotalOrder, B: TotalOrder> TotalOrder for (A, B) {
+    fn total_order(&self, other: &Self) -> Ordering {
+        self.0.total_order(&other.0).then_with(|| self.1.total_order(&other.1))
+    }
+}
+
+impl<A: TotalOrder, B: TotalOrder, C: TotalOrder> TotalOrder for (A, B, C) {
+    fn total_order(&self, other: &Self) -> Ordering {
+        self.0.total_order(&other.0)
+            .then_with(|| self.1.total_order(&other.1))
+            .then_with(|| self.2.total_order(&other.2))
+    }
+}
+
+pub fn is_sorted<T: TotalOrder>(arr: &[T]) -> bool {
+    arr.windows(2).all(|w| w[0].total_order(&w[1]) != Ordering::Greater)
+}
+
+pub fn merge_sort<T: TotalOrder + Clone>(arr: &mut [T], counter: &mut PerformanceCounter) {
+    merge_sort_by(arr, |a, b| a.total_order(b), counter);
+}
+
+pub fn merge_sort_by<T, F>(arr: &mut [T], mut compare: F, counter: &mut PerformanceCounter)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if arr.len() <= 1 {
+        return;
+    }
+
+    let mut buffer = arr.to_vec();
+    counter.allocate_memory(arr.len());
+    merge_sort_recursive(arr, &mut buffer, &mut compare, counter);
+}
+
+fn merge_sort_recursive<T, F>(arr: &mut [T], buffer: &mut [T], compare: &mut F, counter: &mut PerformanceCounter)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let n = arr.len();
+    if n <= 1 {
+        return;
+    }
+
+    let mid = n / 2;
+    merge_sort_recursive(&mut arr[..mid], &mut buffer[..mid], compare, counter);
+    merge_sort_recursive(&mut arr[mid..], &mut buffer[mid..], compare, counter);
+
+    counter.comparisons += 1;
+    if compare(&arr[mid - 1], &arr[mid]) != Ordering::Greater {
+        return;
+    }
+
+    buffer[..n].clone_from_slice(arr);
+    let (mut i, mut j) = (0, mid);
+    for slot in arr.iter_mut() {
+        let take_right = j < n && (i >= mid || {
+            counter.comparisons += 1;
+            compare(&buffer[j], &buffer[i]) == Ordering::Less
+        });
+
+        if take_right {
+            *slot = buffer[j].clone();
+            j += 1;
+        } else {
+            *slot = buffer[i].clone();
+            i += 1;
+        }
+    }
+}
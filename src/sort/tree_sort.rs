@@ -1,78 +1,72 @@
 use super::PerformanceCounter;
+use crate::search_tree;
+use crate::search_tree::avl_tree::AvlTree;
 
-#[derive(Debug)]
-struct TreeNode {
-    value: i32,
-    left: Option<Box<TreeNode>>,
-    right: Option<Box<TreeNode>>,
-}
-
-impl TreeNode {
-    fn new(value: i32) -> Self {
-        TreeNode {
-            value,
-            left: None,
-            right: None,
-        }
+pub fn sort(arr: &mut [i32], counter: &mut PerformanceCounter) {
+    if arr.is_empty() {
+        return;
     }
 
-    fn insert(&mut self, value: i32, counter: &mut PerformanceCounter) {
-        match counter.compare(&value, &self.value) {
-            std::cmp::Ordering::Less | std::cmp::Ordering::Equal => {
-                match self.left {
-                    None => {
-                        self.left = Some(Box::new(TreeNode::new(value)));
-                        counter.allocate_memory(1);
-                    }
-                    Some(ref mut left) => {
-                        left.insert(value, counter);
-                    }
-                }
-            }
-            std::cmp::Ordering::Greater => {
-                match self.right {
-                    None => {
-                        self.right = Some(Box::new(TreeNode::new(value)));
-                        counter.allocate_memory(1);
-                    }
-                    Some(ref mut right) => {
-                        right.insert(value, counter);
-                    }
-                }
-            }
-        }
+    let mut tree: AvlTree<i32, usize> = AvlTree::new();
+    let mut tree_counter = search_tree::PerformanceCounter::new();
+
+    for &value in arr.iter() {
+        tree.insert_or_merge(value, 1, |count, one| *count += one, &mut tree_counter);
     }
 
-    fn inorder_traversal(&self, result: &mut Vec<i32>) {
-        if let Some(ref left) = self.left {
-            left.inorder_traversal(result);
-        }
-        result.push(self.value);
-        if let Some(ref right) = self.right {
-            right.inorder_traversal(result);
+    let mut index = 0;
+    for (&value, &count) in tree.entries() {
+        for _ in 0..count {
+            arr[index] = value;
+            counter.swaps += 1;
+            index += 1;
         }
     }
+
+    counter.comparisons += tree_counter.comparisons;
+    counter.rotations += tree_counter.rotations;
+    counter.memory_allocations += tree_counter.memory_allocations;
 }
 
-pub fn sort(arr: &mut [i32], counter: &mut PerformanceCounter) {
-    if arr.is_empty() {
-        return;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::*;
+
+    fn sort_and_count(mut input: Vec<i32>) -> (Vec<i32>, PerformanceCounter) {
+        let mut counter = PerformanceCounter::new();
+        sort(&mut input, &mut counter);
+        (input, counter)
     }
 
-    let mut root = TreeNode::new(arr[0]);
-    counter.allocate_memory(1);
+    #[test]
+    fn test_duplicate_heavy_input() {
+        let mut rng = StdRng::seed_from_u64(34);
+        let input: Vec<i32> = (0..5_000).map(|_| rng.random_range(0..8)).collect();
+        let mut expected = input.clone();
+        expected.sort();
 
-    for &value in arr.iter().skip(1) {
-        root.insert(value, counter);
+        let (sorted, counter) = sort_and_count(input);
+        assert_eq!(sorted, expected);
+        assert_eq!(counter.memory_allocations, 8);
+        assert_eq!(counter.swaps, 5_000);
     }
 
-    let mut sorted_values = Vec::new();
-    root.inorder_traversal(&mut sorted_values);
+    #[test]
+    fn test_sorted_and_reverse_input_report_rotations() {
+        let ascending: Vec<i32> = (0..1_024).collect();
+        let descending: Vec<i32> = ascending.iter().rev().copied().collect();
 
-    for (i, value) in sorted_values.into_iter().enumerate() {
-        if i < arr.len() {
-            arr[i] = value;
-            counter.swaps += 1;
+        for input in [ascending.clone(), descending] {
+            let (sorted, counter) = sort_and_count(input);
+            assert_eq!(sorted, ascending);
+            assert!(counter.rotations >= 1_000, "rotations {}", counter.rotations);
+            assert!(counter.comparisons <= 1_024 * 11, "comparisons {}", counter.comparisons);
         }
+
+        let (sorted, counter) = sort_and_count(vec![5; 100]);
+        assert_eq!(sorted, vec![5; 100]);
+        assert_eq!(counter.rotations, 0);
+        assert!(sort_and_count(Vec::new()).0.is_empty());
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,127 @@
+use std::collections::{BTreeMap, VecDeque};
+
+#[derive(Debug, Clone, Default)]
+struct TrieNode {
+    transitions: BTreeMap<u8, usize>,
+    failure: usize,
+    outputs: Vec<usize>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AhoCorasick {
+    nodes: Vec<TrieNode>,
+    pattern_lengths: Vec<usize>,
+}
+
+impl AhoCorasick {
+    pub fn build(patterns: &[&str]) -> Self {
+        let mut automaton = Self {
+            nodes: vec![TrieNode::default()],
+            pattern_lengths: Vec::with_capacity(patterns.len()),
+        };
+
+        for (index, pattern) in patterns.iter().enumerate() {
+            automaton.pattern_lengths.push(pattern.len());
+            if pattern.is_empty() {
+                continue;
+            }
+
+            let mut current = 0;
+            for &byte in pattern.as_bytes() {
+                current = match automaton.nodes[current].transitions.get(&byte) {
+                    Some(&next) => next,
+                    None => {
+                        let next = automaton.nodes.len();
+                        automaton.nodes.push(TrieNode::default());
+                        automaton.nodes[current].transitions.insert(byte, next);
+                        next
+                    }
+                };
+            }
+            automaton.nodes[current].outputs.push(index);
+        }
+
+        automaton.build_failure_links();
+        automaton
+    }
+
+    fn build_failure_links(&mut self) {
+        let mut queue = VecDeque::new();
+
+        let root_children: Vec<usize> = self.nodes[0].transitions.values().copied().collect();
+        for child in root_children {
+            self.nodes[child].failure = 0;
+            queue.push_back(child);
+        }
+
+        while let Some(current) = queue.pop_front() {
+            let transitions: Vec<(u8, usize)> = self.nodes[current]
+                .transitions
+                .iter()
+                .map(|(&byte, &next)| (byte, next))
+                .collect();
+
+            for (byte, next) in transitions {
+                let mut fallback = self.nodes[current].failure;
+                while fallback != 0 && !self.nodes[fallback].transitions.contains_key(&byte) {
+                    fallback = self.nodes[fallback].failure;
+                }
+
+                let failure = match self.nodes[fallback].transitions.get(&byte) {
+                    Some(&target) if target != next => target,
+                    _ => 0,
+                };
+
+                self.nodes[next].failure = failure;
+                let inherited = self.nodes[failure].outputs.clone();
+                self.nodes[next].outputs.extend(inherited);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    pub fn pattern_count(&self) -> usize {
+        self.pattern_lengths.len()
+    }
+
+    pub fn state_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn find_all(&self, text: &str) -> (Vec<(usize, usize)>, usize) {
+        let mut matches = Vec::new();
+        let mut comparisons = 0;
+        let mut current = 0;
+
+        for (i, &byte) in text.as_bytes().iter().enumerate() {
+            loop {
+                comparisons += 1;
+                if let Some(&next) = self.nodes[current].transitions.get(&byte) {
+                    current = next;
+                    break;
+                }
+                if current == 0 {
+                    break;
+                }
+                current = self.nodes[current].failure;
+            }
+
+            for &pattern in &self.nodes[current].outputs {
+                matches.push((pattern, i + 1 - self.pattern_lengths[pattern]));
+            }
+        }
+
+        matches.sort_unstable_by_key(|&(pattern, position)| (position, pattern));
+        (matches, comparisons)
+    }
+}
+
+pub fn search(text: &str, pattern: &str) -> (Vec<usize>, usize) {
+    let automaton = AhoCorasick::build(&[pattern]);
+    let (matches, comparisons) = automaton.find_all(text);
+    (matches.into_iter().map(|(_, position)| position).collect(), comparisons)
+}
+
+pub fn search_multiple(text: &str, patterns: &[&str]) -> (Vec<(usize, usize)>, usize) {
+    AhoCorasick::build(patterns).find_all(text)
+}
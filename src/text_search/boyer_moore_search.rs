@@ -0,0 +1,121 @@
+pub fn search(text: &str, pattern: &str) -> (Vec<usize>, usize) {
+    let text = text.as_bytes();
+    let pattern = pattern.as_bytes();
+    let mut matches = Vec::new();
+    let mut comparisons = 0;
+
+    let m = pattern.len();
+    let n = text.len();
+    if m == 0 || m > n {
+        return (matches, comparisons);
+    }
+
+    let bad_character = build_bad_character_table(pattern);
+    let good_suffix = build_good_suffix_table(pattern);
+
+    let mut shift = 0;
+    while shift <= n - m {
+        let mut j = m;
+        while j > 0 {
+            comparisons += 1;
+            if pattern[j - 1] != text[shift + j - 1] {
+                break;
+            }
+            j -= 1;
+        }
+
+        if j == 0 {
+            matches.push(shift);
+            shift += good_suffix[0];
+        } else {
+            let mismatched = text[shift + j - 1];
+            let bad_character_shift = match bad_character[mismatched as usize] {
+                Some(last) if last < j - 1 => j - 1 - last,
+                Some(_) => 1,
+                None => j,
+            };
+            shift += bad_character_shift.max(good_suffix[j]);
+        }
+    }
+
+    (matches, comparisons)
+}
+
+pub fn search_horspool(text: &str, pattern: &str) -> (Vec<usize>, usize) {
+    let text = text.as_bytes();
+    let pattern = pattern.as_bytes();
+    let mut matches = Vec::new();
+    let mut comparisons = 0;
+
+    let m = pattern.len();
+    let n = text.len();
+    if m == 0 || m > n {
+        return (matches, comparisons);
+    }
+
+    let mut shift_table = [m; 256];
+    for (i, &byte) in pattern.iter().enumerate().take(m - 1) {
+        shift_table[byte as usize] = m - 1 - i;
+    }
+
+    let mut shift = 0;
+    while shift <= n - m {
+        let mut j = m;
+        while j > 0 {
+            comparisons += 1;
+            if pattern[j - 1] != text[shift + j - 1] {
+                break;
+            }
+            j -= 1;
+        }
+
+        if j == 0 {
+            matches.push(shift);
+        }
+        shift += shift_table[text[shift + m - 1] as usize];
+    }
+
+    (matches, comparisons)
+}
+
+fn build_bad_character_table(pattern: &[u8]) -> [Option<usize>; 256] {
+    let mut table = [None; 256];
+    for (i, &byte) in pattern.iter().enumerate() {
+        table[byte as usize] = Some(i);
+    }
+    table
+}
+
+fn build_good_suffix_table(pattern: &[u8]) -> Vec<usize> {
+    let m = pattern.len();
+    let mut shift = vec![0; m + 1];
+    let mut border = vec![0; m + 1];
+
+    let mut i = m;
+    let mut j = m + 1;
+    border[i] = j;
+
+    while i > 0 {
+        while j <= m && pattern[i - 1] != pattern[j - 1] {
+            if shift[j] == 0 {
+                shift[j] = j - i;
+            }
+            j = border[j];
+        }
+        i -= 1;
+        j -= 1;
+        border[i] = j;
+    }
+
+    j = border[0];
+    for (i, entry) in shift.iter_mut().enumerate() {
+        if *entry == 0 {
+            *entry = j;
+        }
+        if i == j {
+            j = border[j];
+        }
+    }
+
+    shift
+}
@@ -0,0 +1,357 @@
+use crate::prelude::*;
+use super::suffix_array::build_suffix_array;
+use std::io::{Read, Write};
+
+const SENTINEL: u8 = 0;
+const OCC_INTERVAL: usize = 64;
+const SA_SAMPLE_RATE: usize = 32;
+const RANK_BLOCK_WORDS: usize = 8;
+const MAGIC: &[u8; 4] = b"FMIX";
+
+#[derive(Debug, Clone)]
+struct RankBitVector {
+    words: Vec<u64>,
+    block_ranks: Vec<usize>,
+    len: usize,
+}
+
+impl RankBitVector {
+    fn from_bits(bits: impl ExactSizeIterator<Item = bool>) -> Self {
+        let len = bits.len();
+        let mut words = vec![0u64; len.div_ceil(64)];
+        for (i, bit) in bits.enumerate() {
+            if bit {
+                words[i / 64] |= 1 << (i % 64);
+            }
+        }
+        Self::from_words(words, len)
+    }
+
+    fn from_words(words: Vec<u64>, len: usize) -> Self {
+        let mut block_ranks = Vec::with_capacity(words.len() / RANK_BLOCK_WORDS + 1);
+        let mut rank = 0;
+        for (i, word) in words.iter().enumerate() {
+            if i.is_multiple_of(RANK_BLOCK_WORDS) {
+                block_ranks.push(rank);
+            }
+            rank += word.count_ones() as usize;
+        }
+        Self { words, block_ranks, len }
+    }
+
+    fn get(&self, i: usize) -> bool {
+        self.words[i / 64] >> (i % 64) & 1 == 1
+    }
+
+    fn rank(&self, i: usize) -> usize {
+        let word = i / 64;
+        let block = word / RANK_BLOCK_WORDS;
+        let mut rank = self.block_ranks[block];
+        for &full in &self.words[block * RANK_BLOCK_WORDS..word] {
+            rank += full.count_ones() as usize;
+        }
+        if !i.is_multiple_of(64) {
+            rank += (self.words[word] & ((1 << (i % 64)) - 1)).count_ones() as usize;
+        }
+        rank
+    }
+
+    fn count_ones(&self) -> usize {
+        self.words.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    fn memory_bytes(&self) -> usize {
+        self.words.len() * std::mem::size_of::<u64>() + self.block_ranks.len() * std::mem::size_of::<usize>()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FmIndex {
+    bwt: Vec<u8>,
+    symbol_index: Vec<Option<usize>>,
+    first_occurrence: Vec<usize>,
+    occ_checkpoints: Vec<u32>,
+    sampled_rows: RankBitVector,
+    sa_samples: Vec<usize>,
+}
+
+impl FmIndex {
+    pub fn build(text: &str) -> Result<Self> {
+        let bytes = text.as_bytes();
+        if bytes.contains(&SENTINEL) {
+            return Err(Error::validation("Text must not contain NUL bytes to build an FM-index"));
+        }
+        if bytes.len() >= u32::MAX as usize {
+            return Err(Error::validation("Text is too large for an FM-index"));
+        }
+
+        let mut terminated = bytes.to_vec();
+        terminated.push(SENTINEL);
+
+        let suffixes = build_suffix_array(&terminated);
+        let n = terminated.len();
+
+        let bwt: Vec<u8> = suffixes
+            .iter()
+            .map(|&suffix| if suffix == 0 { terminated[n - 1] } else { terminated[suffix - 1] })
+            .collect();
+
+        let sampled_rows = RankBitVector::from_bits(suffixes.iter().map(|&suffix| suffix.is_multiple_of(SA_SAMPLE_RATE)));
+        let sa_samples: Vec<usize> = suffixes
+            .iter()
+            .copied()
+            .filter(|&suffix| suffix.is_multiple_of(SA_SAMPLE_RATE))
+            .collect();
+
+        Ok(Self::from_parts(bwt, sampled_rows, sa_samples))
+    }
+
+    fn from_parts(bwt: Vec<u8>, sampled_rows: RankBitVector, sa_samples: Vec<usize>) -> Self {
+        let mut counts = [0usize; 256];
+        for &byte in &bwt {
+            counts[byte as usize] += 1;
+        }
+
+        let mut symbol_index = vec![None; 256];
+        let mut first_occurrence = Vec::new();
+        let mut total = 0;
+        for (byte, &count) in counts.iter().enumerate() {
+            if count > 0 {
+                symbol_index[byte] = Some(first_occurrence.len());
+                first_occurrence.push(total);
+                total += count;
+            }
+        }
+
+        let sigma = first_occurrence.len();
+        let blocks = bwt.len() / OCC_INTERVAL + 1;
+        let mut occ_checkpoints = vec![0; blocks * sigma];
+        let mut running = vec![0u32; sigma];
+
+        for (i, &byte) in bwt.iter().enumerate() {
+            if i.is_multiple_of(OCC_INTERVAL) {
+                let block = i / OCC_INTERVAL;
+                occ_checkpoints[block * sigma..(block + 1) * sigma].copy_from_slice(&running);
+            }
+            if let Some(symbol) = symbol_index[byte as usize] {
+                running[symbol] += 1;
+            }
+        }
+        if bwt.len().is_multiple_of(OCC_INTERVAL) {
+            let block = bwt.len() / OCC_INTERVAL;
+            occ_checkpoints[block * sigma..(block + 1) * sigma].copy_from_slice(&running);
+        }
+
+        Self {
+            bwt,
+            symbol_index,
+            first_occurrence,
+            occ_checkpoints,
+            sampled_rows,
+            sa_samples,
+        }
+    }
+
+    pub fn text_len(&self) -> usize {
+        self.bwt.len().saturating_sub(1)
+    }
+
+    pub fn alphabet_size(&self) -> usize {
+        self.first_occurrence.len()
+    }
+
+    pub fn bwt(&self) -> &[u8] {
+        &self.bwt
+    }
+
+    pub fn memory_bytes(&self) -> usize {
+        let word = std::mem::size_of::<usize>();
+        self.bwt.len()
+            + self.symbol_index.len() * std::mem::size_of::<Option<usize>>()
+            + self.first_occurrence.len() * word
+            + self.occ_checkpoints.len() * std::mem::size_of::<u32>()
+            + self.sampled_rows.memory_bytes()
+            + self.sa_samples.len() * word
+    }
+
+    fn occ(&self, symbol: usize, byte: u8, position: usize, comparisons: &mut usize) -> usize {
+        let sigma = self.first_occurrence.len();
+        let block = position / OCC_INTERVAL;
+        let mut count = self.occ_checkpoints[block * sigma + symbol] as usize;
+
+        for &candidate in &self.bwt[block * OCC_INTERVAL..position] {
+            *comparisons += 1;
+            if candidate == byte {
+                count += 1;
+            }
+        }
+
+        count
+    }
+
+    fn backward_search(&self, pattern: &[u8], comparisons: &mut usize) -> std::ops::Range<usize> {
+        if pattern.is_empty() {
+            return 0..0;
+        }
+
+        let mut low = 0;
+        let mut high = self.bwt.len();
+
+        for &byte in pattern.iter().rev() {
+            let Some(symbol) = self.symbol_index[byte as usize] else {
+                return 0..0;
+            };
+
+            low = self.first_occurrence[symbol] + self.occ(symbol, byte, low, comparisons);
+            high = self.first_occurrence[symbol] + self.occ(symbol, byte, high, comparisons);
+
+            if low >= high {
+                return 0..0;
+            }
+        }
+
+        low..high
+    }
+
+    pub fn count(&self, pattern: &str) -> (usize, usize) {
+        let mut comparisons = 0;
+        let range = self.backward_search(pattern.as_bytes(), &mut comparisons);
+        (range.len(), comparisons)
+    }
+
+    pub fn locate(&self, pattern: &str) -> (Vec<usize>, usize) {
+        let mut comparisons = 0;
+        let range = self.backward_search(pattern.as_bytes(), &mut comparisons);
+
+        let mut positions: Vec<usize> = range
+            .map(|row| self.resolve_row(row, &mut comparisons))
+            .collect();
+        positions.sort_unstable();
+
+        (positions, comparisons)
+    }
+
+    fn resolve_row(&self, mut row: usize, comparisons: &mut usize) -> usize {
+        let mut steps = 0;
+
+        while !self.sampled_rows.get(row) {
+            let byte = self.bwt[row];
+            let symbol = self.symbol_index[byte as usize].unwrap_or(0);
+            row = self.first_occurrence[symbol] + self.occ(symbol, byte, row, comparisons);
+            steps += 1;
+        }
+
+        self.sa_samples[self.sampled_rows.rank(row)] + steps
+    }
+
+    pub fn save(&self, path: &str) -> Result<()> {
+        let mut buffer = Vec::with_capacity(self.bwt.len() * 2 + 16);
+        buffer.extend_from_slice(MAGIC);
+        buffer.extend_from_slice(&(self.bwt.len() as u64).to_le_bytes());
+        buffer.extend_from_slice(&self.bwt);
+        for &word in &self.sampled_rows.words {
+            buffer.extend_from_slice(&word.to_le_bytes());
+        }
+        buffer.extend_from_slice(&(self.sa_samples.len() as u64).to_le_bytes());
+        for &sample in &self.sa_samples {
+            buffer.extend_from_slice(&(sample as u64).to_le_bytes());
+        }
+
+        let mut file = std::fs::File::create(path)?;
+        file.write_all(&buffer)?;
+        Ok(())
+    }
+
+    pub fn load(path: &str) -> Result<Self> {
+        let mut buffer = Vec::new();
+        std::fs::File::open(path)?.read_to_end(&mut buffer)?;
+
+        let mut reader = IndexReader { buffer: &buffer, offset: 0 };
+        if reader.take(MAGIC.len())? != MAGIC {
+            return Err(Error::validation(format!("{} is not an FM-index file", path)));
+        }
+
+        let length = reader.read_u64()? as usize;
+        let bwt = reader.take(length)?.to_vec();
+        let words = (0..length.div_ceil(64))
+            .map(|_| reader.read_u64())
+            .collect::<Result<Vec<_>>>()?;
+        let sampled_rows = RankBitVector::from_words(words, length);
+
+        let sample_count = reader.read_u64()? as usize;
+        let sa_samples = (0..sample_count)
+            .map(|_| reader.read_u64().map(|sample| sample as usize))
+            .collect::<Result<Vec<_>>>()?;
+
+        if sampled_rows.count_ones() != sa_samples.len() {
+            return Err(Error::validation(format!("{} has inconsistent suffix array samples", path)));
+        }
+
+        let index = Self::from_parts(bwt, sampled_rows, sa_samples);
+        if !index.is_consistent() {
+            return Err(Error::validation(format!("{} is not a valid FM-index", path)));
+        }
+        Ok(index)
+    }
+
+    fn is_consistent(&self) -> bool {
+        let n = self.bwt.len();
+        let Some(first_row) = self.bwt.iter().position(|&byte| byte == SENTINEL) else {
+            return false;
+        };
+        if n >= u32::MAX as usize
+            || self.bwt.iter().filter(|&&byte| byte == SENTINEL).count() != 1
+            || self.sampled_rows.len != n
+            || self.sa_samples.iter().any(|&sample| sample >= n)
+        {
+            return false;
+        }
+
+        let mut seen = [0usize; 256];
+        let lf: Vec<usize> = self.bwt.iter().map(|&byte| {
+            let symbol = self.symbol_index[byte as usize].expect("byte occurs in the BWT");
+            seen[byte as usize] += 1;
+            self.first_occurrence[symbol] + seen[byte as usize] - 1
+        }).collect();
+
+        let mut visited = vec![false; n];
+        let mut row = first_row;
+        for step in 0..n {
+            if std::mem::replace(&mut visited[row], true) {
+                return false;
+            }
+
+            let position = (n - step) % n;
+            if self.sampled_rows.get(row) && self.sa_samples[self.sampled_rows.rank(row)] != position {
+                return false;
+            }
+            row = lf[row];
+        }
+
+        row == first_row && self.sampled_rows.get(first_row)
+    }
+}
+
+struct IndexReader<'a> {
+    buffer: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> IndexReader<'a> {
+    fn take(&mut self, length: usize) -> Result<&'a [u8]> {
+        let end = self.offset.checked_add(length)
+            .filter(|&end| end <= self.buffer.len())
+            .ok_or_else(|| Error::validation("FM-index file is truncated"))?;
+
+        let slice = &self.buffer[self.offset..end];
+        self.offset = end;
+        Ok(slice)
+    }
+
+    fn read_u64(&mut self) -> Result<u64> {
+        let bytes = self.take(8)?;
+        let mut array = [0u8; 8];
+        array.copy_from_slice(bytes);
+        Ok(u64::from_le_bytes(array))
+    }
+}
@@ -0,0 +1,56 @@
+pub fn search(text: &str, pattern: &str) -> (Vec<usize>, usize) {
+    let text = text.as_bytes();
+    let pattern = pattern.as_bytes();
+    let mut matches = Vec::new();
+
+    if pattern.is_empty() || pattern.len() > text.len() {
+        return (matches, 0);
+    }
+
+    let (failure, mut comparisons) = build_failure_table(pattern);
+    let mut matched = 0;
+
+    for (i, &byte) in text.iter().enumerate() {
+        loop {
+            comparisons += 1;
+            if pattern[matched] == byte {
+                matched += 1;
+                break;
+            }
+            if matched == 0 {
+                break;
+            }
+            matched = failure[matched - 1];
+        }
+
+        if matched == pattern.len() {
+            matches.push(i + 1 - pattern.len());
+            matched = failure[matched - 1];
+        }
+    }
+
+    (matches, comparisons)
+}
+
+pub fn build_failure_table(pattern: &[u8]) -> (Vec<usize>, usize) {
+    let mut failure = vec![0; pattern.len()];
+    let mut comparisons = 0;
+    let mut length = 0;
+
+    for i in 1..pattern.len() {
+        loop {
+            comparisons += 1;
+            if pattern[i] == pattern[length] {
+                length += 1;
+                break;
+            }
+            if length == 0 {
+                break;
+            }
+            length = failure[length - 1];
+        }
+        failure[i] = length;
+    }
+
+    (failure, comparisons)
+}
@@ -0,0 +1,547 @@
+pub mod naive_search;
+pub mod kmp_search;
+pub mod boyer_moore_search;
+pub mod rabin_karp_search;
+pub mod z_search;
+pub mod aho_corasick_search;
+pub mod suffix_array;
+pub mod fm_index;
+
+use crate::prelude::*;
+use std::time::{Duration, Instant};
+use prettytable::{Table, Row, Cell};
+
+#[derive(Debug, Clone)]
+pub struct TextSearchMetrics {
+    pub algorithm_name: String,
+    pub pattern_found: bool,
+    pub matches: usize,
+    pub comparisons: usize,
+    pub duration: Duration,
+    pub theoretical_complexity: String,
+    pub actual_complexity: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct IndexMetrics {
+    pub algorithm_name: String,
+    pub matches: usize,
+    pub build_duration: Duration,
+    pub memory_bytes: usize,
+    pub comparisons: usize,
+    pub query_duration: Duration,
+}
+
+pub struct TextSearchCoordinator {
+    text: String,
+}
+
+impl TextSearchCoordinator {
+    pub fn new() -> Self {
+        Self {
+            text: String::new(),
+        }
+    }
+
+    pub async fn load_text(&mut self, file_path: &str) -> Result<()> {
+        println!("Loading text from: {}", file_path);
+        let content = tokio::fs::read_to_string(file_path).await
+            .map_err(|e| Error::Generic(format!("Failed to read file {}: {}", file_path, e)))?;
+
+        self.set_text(content)?;
+
+        println!("✓ Loaded {} bytes of text", self.text.len());
+        Ok(())
+    }
+
+    pub fn set_text(&mut self, text: String) -> Result<()> {
+        if text.is_empty() {
+            return Err(Error::validation("Text must not be empty"));
+        }
+
+        self.text = text;
+        Ok(())
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn run_benchmarks(&self, patterns: &[String], iterations: usize) -> Result<Vec<TextSearchMetrics>> {
+        if self.text.is_empty() {
+            return Err(Error::Generic("No text loaded. Load a text file first.".to_string()));
+        }
+
+        if patterns.is_empty() || patterns.iter().any(|p| p.is_empty()) {
+            return Err(Error::input("At least one non-empty pattern is required"));
+        }
+
+        if iterations == 0 {
+            return Err(Error::validation("Iterations must be greater than 0"));
+        }
+
+        println!("\nRunning text search benchmarks for patterns: {:?}", patterns);
+        println!("Text length: {} bytes", self.text.len());
+        println!("Iterations per algorithm: {}", iterations);
+        println!("{}", "=".repeat(60));
+
+        let results = vec![
+            self.benchmark_single_pattern("Naive Search", patterns, iterations, naive_search::search),
+            self.benchmark_single_pattern("KMP Search", patterns, iterations, kmp_search::search),
+            self.benchmark_single_pattern("Boyer-Moore", patterns, iterations, boyer_moore_search::search),
+            self.benchmark_single_pattern("Boyer-Moore-Horspool", patterns, iterations, boyer_moore_search::search_horspool),
+            self.benchmark_single_pattern("Rabin-Karp", patterns, iterations, rabin_karp_search::search),
+            self.benchmark_single_pattern("Z-Algorithm", patterns, iterations, z_search::search),
+            self.benchmark_aho_corasick(patterns, iterations),
+        ];
+
+        self.display_results(&results);
+        Ok(results)
+    }
+
+    fn benchmark_single_pattern<F>(&self, name: &str, patterns: &[String], iterations: usize, search_fn: F) -> TextSearchMetrics
+    where
+        F: Fn(&str, &str) -> (Vec<usize>, usize),
+    {
+        let mut total_comparisons = 0;
+        let mut matches = 0;
+
+        let start = Instant::now();
+
+        for _ in 0..iterations {
+            matches = 0;
+            for pattern in patterns {
+                let (positions, comparisons) = search_fn(&self.text, pattern);
+                total_comparisons += comparisons;
+                matches += positions.len();
+            }
+        }
+
+        let duration = start.elapsed();
+        let avg_comparisons = total_comparisons / iterations;
+        let (theoretical_complexity, theoretical_ops) = self.theoretical_cost(name, patterns);
+
+        TextSearchMetrics {
+            algorithm_name: name.to_string(),
+            pattern_found: matches > 0,
+            matches,
+            comparisons: avg_comparisons,
+            duration,
+            theoretical_complexity,
+            actual_complexity: avg_comparisons as f64 / theoretical_ops,
+        }
+    }
+
+    fn benchmark_aho_corasick(&self, patterns: &[String], iterations: usize) -> TextSearchMetrics {
+        let pattern_refs: Vec<&str> = patterns.iter().map(|p| p.as_str()).collect();
+        let mut total_comparisons = 0;
+        let mut matches = 0;
+
+        let start = Instant::now();
+
+        for _ in 0..iterations {
+            let (found, comparisons) = aho_corasick_search::search_multiple(&self.text, &pattern_refs);
+            total_comparisons += comparisons;
+            matches = found.len();
+        }
+
+        let duration = start.elapsed();
+        let avg_comparisons = total_comparisons / iterations;
+        let (theoretical_complexity, theoretical_ops) = self.theoretical_cost("Aho-Corasick", patterns);
+
+        TextSearchMetrics {
+            algorithm_name: "Aho-Corasick".to_string(),
+            pattern_found: matches > 0,
+            matches,
+            comparisons: avg_comparisons,
+            duration,
+            theoretical_complexity,
+            actual_complexity: avg_comparisons as f64 / theoretical_ops,
+        }
+    }
+
+    fn theoretical_cost(&self, name: &str, patterns: &[String]) -> (String, f64) {
+        let n = self.text.len() as f64;
+        let k = patterns.len() as f64;
+        let total_m: f64 = patterns.iter().map(|p| p.len() as f64).sum();
+
+        match name {
+            "Naive Search" => ("O(n·m)".to_string(), (n * total_m).max(1.0)),
+            "KMP Search" | "Z-Algorithm" => ("O(n + m)".to_string(), k * n + total_m),
+            "Boyer-Moore" => ("O(n/m) best".to_string(), (k * n).max(1.0)),
+            "Boyer-Moore-Horspool" => ("O(n/m) avg".to_string(), (k * n).max(1.0)),
+            "Rabin-Karp" => ("O(n + m) avg".to_string(), k * n + total_m),
+            "Aho-Corasick" => ("O(n + Σm + z)".to_string(), n + total_m),
+            _ => ("Unknown".to_string(), n.max(1.0)),
+        }
+    }
+
+    fn display_results(&self, results: &[TextSearchMetrics]) {
+        let mut table = Table::new();
+
+        table.add_row(Row::new(vec![
+            Cell::new("Algorithm"),
+            Cell::new("Found"),
+            Cell::new("Matches"),
+            Cell::new("Comparisons"),
+            Cell::new("Duration (μs)"),
+            Cell::new("Big O"),
+            Cell::new("Actual/Theoretical"),
+            Cell::new("Efficiency"),
+        ]));
+
+        for metric in results {
+            let efficiency = if metric.actual_complexity <= 1.5 {
+                "Excellent"
+            } else if metric.actual_complexity <= 3.0 {
+                "Good"
+            } else {
+                "Needs Optimisation"
+            };
+
+            table.add_row(Row::new(vec![
+                Cell::new(&metric.algorithm_name),
+                Cell::new(&format!("{}", metric.pattern_found)),
+                Cell::new(&format!("{}", metric.matches)),
+                Cell::new(&format!("{}", metric.comparisons)),
+                Cell::new(&format!("{:.2}", metric.duration.as_micros())),
+                Cell::new(&metric.theoretical_complexity),
+                Cell::new(&format!("{:.2}", metric.actual_complexity)),
+                Cell::new(efficiency),
+            ]));
+        }
+
+        println!("\n{}", table);
+
+        if let Some(fastest) = results.iter().min_by_key(|m| m.duration) {
+            println!("🏆 Fastest Algorithm: {} ({:.2}μs)",
+                fastest.algorithm_name, fastest.duration.as_micros());
+        }
+
+        if let Some(most_efficient) = results.iter().min_by_key(|m| m.comparisons) {
+            println!("🎯 Most Efficient: {} ({} comparisons)",
+                most_efficient.algorithm_name, most_efficient.comparisons);
+        }
+    }
+
+    pub fn run_index_benchmarks(&self, patterns: &[String], iterations: usize, save_path: Option<&str>) -> Result<Vec<IndexMetrics>> {
+        if self.text.is_empty() {
+            return Err(Error::Generic("No text loaded. Load a text file first.".to_string()));
+        }
+
+        if patterns.is_empty() || patterns.iter().any(|p| p.is_empty()) {
+            return Err(Error::input("At least one non-empty pattern is required"));
+        }
+
+        if iterations == 0 {
+            return Err(Error::validation("Iterations must be greater than 0"));
+        }
+
+        println!("\nRunning full-text index benchmarks for patterns: {:?}", patterns);
+        println!("Text length: {} bytes", self.text.len());
+        println!("Iterations per query: {}", iterations);
+        println!("{}", "=".repeat(60));
+
+        let start = Instant::now();
+        let mut sorted_words: Vec<String> = self.text.split_whitespace().map(|w| w.to_string()).collect();
+        sorted_words.sort();
+        let words_build = start.elapsed();
+        let words_memory = sorted_words.iter().map(|w| w.capacity() + std::mem::size_of::<String>()).sum();
+
+        let start = Instant::now();
+        let suffix_array = suffix_array::SuffixArray::build(&self.text);
+        let suffix_array_build = start.elapsed();
+
+        let start = Instant::now();
+        let fm_index = fm_index::FmIndex::build(&self.text)?;
+        let fm_index_build = start.elapsed();
+
+        if let Some(path) = save_path {
+            fm_index.save(path)?;
+            println!("✓ Saved FM-index to {}", path);
+        }
+
+        let results = vec![
+            self.benchmark_index_query("Linear Scan", Duration::ZERO, self.text.len(), patterns, iterations, |pattern| {
+                let (positions, comparisons) = naive_search::search(&self.text, pattern);
+                (positions.len(), comparisons)
+            }),
+            self.benchmark_index_query("Binary Scan (Words)", words_build, words_memory, patterns, iterations, |pattern| {
+                count_sorted_words(&sorted_words, pattern)
+            }),
+            self.benchmark_index_query("Suffix Array", suffix_array_build, suffix_array.memory_bytes(), patterns, iterations, |pattern| {
+                let (positions, comparisons) = suffix_array.locate(pattern);
+                (positions.len(), comparisons)
+            }),
+            self.benchmark_index_query("FM-Index (Count)", fm_index_build, fm_index.memory_bytes(), patterns, iterations, |pattern| {
+                fm_index.count(pattern)
+            }),
+            self.benchmark_index_query("FM-Index (Locate)", fm_index_build, fm_index.memory_bytes(), patterns, iterations, |pattern| {
+                let (positions, comparisons) = fm_index.locate(pattern);
+                (positions.len(), comparisons)
+            }),
+        ];
+
+        self.display_index_results(&results, self.text.len());
+        Ok(results)
+    }
+
+    pub fn run_saved_index_queries(&self, path: &str, patterns: &[String], iterations: usize) -> Result<Vec<IndexMetrics>> {
+        if patterns.is_empty() || patterns.iter().any(|p| p.is_empty()) {
+            return Err(Error::input("At least one non-empty pattern is required"));
+        }
+
+        if iterations == 0 {
+            return Err(Error::validation("Iterations must be greater than 0"));
+        }
+
+        let start = Instant::now();
+        let fm_index = fm_index::FmIndex::load(path)?;
+        let load_duration = start.elapsed();
+
+        println!("\nQuerying saved FM-index {} for patterns: {:?}", path, patterns);
+        println!("Indexed text length: {} bytes", fm_index.text_len());
+        println!("Load time: {:.2} ms", load_duration.as_secs_f64() * 1000.0);
+        println!("Iterations per query: {}", iterations);
+        println!("{}", "=".repeat(60));
+
+        let results = vec![
+            self.benchmark_index_query("FM-Index (Count)", load_duration, fm_index.memory_bytes(), patterns, iterations, |pattern| {
+                fm_index.count(pattern)
+            }),
+            self.benchmark_index_query("FM-Index (Locate)", load_duration, fm_index.memory_bytes(), patterns, iterations, |pattern| {
+                let (positions, comparisons) = fm_index.locate(pattern);
+                (positions.len(), comparisons)
+            }),
+        ];
+
+        self.display_index_results(&results, fm_index.text_len());
+        Ok(results)
+    }
+
+    fn benchmark_index_query<F>(&self, name: &str, build_duration: Duration, memory_bytes: usize, patterns: &[String], iterations: usize, query_fn: F) -> IndexMetrics
+    where
+        F: Fn(&str) -> (usize, usize),
+    {
+        let mut total_comparisons = 0;
+        let mut matches = 0;
+
+        let start = Instant::now();
+
+        for _ in 0..iterations {
+            matches = 0;
+            for pattern in patterns {
+                let (count, comparisons) = query_fn(pattern);
+                total_comparisons += comparisons;
+                matches += count;
+            }
+        }
+
+        IndexMetrics {
+            algorithm_name: name.to_string(),
+            matches,
+            build_duration,
+            memory_bytes,
+            comparisons: total_comparisons / iterations,
+            query_duration: start.elapsed() / iterations as u32,
+        }
+    }
+
+    fn display_index_results(&self, results: &[IndexMetrics], text_len: usize) {
+        let mut table = Table::new();
+
+        table.add_row(Row::new(vec![
+            Cell::new("Index"),
+            Cell::new("Matches"),
+            Cell::new("Build (ms)"),
+            Cell::new("Memory (KB)"),
+            Cell::new("Bytes/Char"),
+            Cell::new("Comparisons"),
+            Cell::new("Query (μs)"),
+        ]));
+
+        let text_len = text_len.max(1) as f64;
+        for metric in results {
+            table.add_row(Row::new(vec![
+                Cell::new(&metric.algorithm_name),
+                Cell::new(&format!("{}", metric.matches)),
+                Cell::new(&format!("{:.2}", metric.build_duration.as_secs_f64() * 1000.0)),
+                Cell::new(&format!("{:.1}", metric.memory_bytes as f64 / 1024.0)),
+                Cell::new(&format!("{:.2}", metric.memory_bytes as f64 / text_len)),
+                Cell::new(&format!("{}", metric.comparisons)),
+                Cell::new(&format!("{}", metric.query_duration.as_micros())),
+            ]));
+        }
+
+        println!("\n{}", table);
+        if results.iter().any(|m| m.algorithm_name.starts_with("Binary Scan")) {
+            println!("Note: Binary Scan matches whole words only; the other indexes match substrings.");
+        }
+
+        if let Some(fastest) = results.iter().min_by_key(|m| m.query_duration) {
+            println!("🏆 Fastest Query: {} ({}μs)",
+                fastest.algorithm_name, fastest.query_duration.as_micros());
+        }
+
+        if let Some(smallest) = results.iter().filter(|m| m.build_duration > Duration::ZERO).min_by_key(|m| m.memory_bytes) {
+            println!("💾 Smallest Index: {} ({:.1} KB)",
+                smallest.algorithm_name, smallest.memory_bytes as f64 / 1024.0);
+        }
+    }
+
+    pub fn get_stats(&self) -> String {
+        format!(
+            "Text Statistics:\n  Bytes loaded: {}\n  Lines: {}\n  Words: {}",
+            self.text.len(),
+            self.text.lines().count(),
+            self.text.split_whitespace().count()
+        )
+    }
+}
+
+fn count_sorted_words(sorted_words: &[String], word: &str) -> (usize, usize) {
+    let (found, mut comparisons, index) = crate::search::binary_search::search_with_insertion_point(sorted_words, word);
+    let Some(index) = found else {
+        return (0, comparisons);
+    };
+
+    let mut first = index;
+    while first > 0 {
+        comparisons += 1;
+        if sorted_words[first - 1] != word {
+            break;
+        }
+        first -= 1;
+    }
+
+    let mut last = index + 1;
+    while last < sorted_words.len() {
+        comparisons += 1;
+        if sorted_words[last] != word {
+            break;
+        }
+        last += 1;
+    }
+
+    (last - first, comparisons)
+}
+
+impl Default for TextSearchCoordinator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "abracadabra abracadabra cadabra";
+
+    fn expected(pattern: &str) -> Vec<usize> {
+        naive_search::search(TEXT, pattern).0
+    }
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("{}_{}", std::process::id(), name))
+    }
+
+    #[test]
+    fn test_single_pattern_algorithms_agree() {
+        for pattern in ["abra", "cad", "a", "abracadabra", "zzz", "ra c"] {
+            let reference = expected(pattern);
+            assert_eq!(kmp_search::search(TEXT, pattern).0, reference, "KMP {}", pattern);
+            assert_eq!(boyer_moore_search::search(TEXT, pattern).0, reference, "BM {}", pattern);
+            assert_eq!(boyer_moore_search::search_horspool(TEXT, pattern).0, reference, "BMH {}", pattern);
+            assert_eq!(rabin_karp_search::search(TEXT, pattern).0, reference, "RK {}", pattern);
+            assert_eq!(z_search::search(TEXT, pattern).0, reference, "Z {}", pattern);
+            assert_eq!(aho_corasick_search::search(TEXT, pattern).0, reference, "AC {}", pattern);
+        }
+    }
+
+    #[test]
+    fn test_aho_corasick_overlapping_patterns() {
+        let (matches, _) = aho_corasick_search::search_multiple("ushers", &["he", "she", "his", "hers"]);
+        assert_eq!(matches, vec![(1, 1), (0, 2), (3, 2)]);
+    }
+
+    #[test]
+    fn test_suffix_array_matches_naive() {
+        let index = suffix_array::SuffixArray::build(TEXT);
+        let mut sorted: Vec<usize> = (0..TEXT.len()).collect();
+        sorted.sort_by_key(|&i| &TEXT[i..]);
+        assert_eq!(index.suffixes(), sorted.as_slice());
+
+        for pattern in ["abra", "cad", "a", "zzz", "ra c"] {
+            assert_eq!(index.locate(pattern).0, expected(pattern), "SA {}", pattern);
+        }
+        assert_eq!(index.longest_repeated_substring(), Some("abracadabra "));
+    }
+
+    #[test]
+    fn test_fm_index_count_locate_and_reload() {
+        let index = fm_index::FmIndex::build(TEXT).unwrap();
+
+        for pattern in ["abra", "cad", "a", "zzz", "ra c", "abracadabra cadabra"] {
+            let reference = expected(pattern);
+            assert_eq!(index.count(pattern).0, reference.len(), "FM count {}", pattern);
+            assert_eq!(index.locate(pattern).0, reference, "FM locate {}", pattern);
+        }
+
+        let path = temp_path("fm_index_roundtrip.fmi");
+        let path = path.to_str().unwrap();
+        index.save(path).unwrap();
+        let reloaded = fm_index::FmIndex::load(path);
+        std::fs::remove_file(path).ok();
+        let reloaded = reloaded.unwrap();
+
+        assert_eq!(reloaded.bwt(), index.bwt());
+        assert_eq!(reloaded.locate("abra").0, expected("abra"));
+    }
+
+    #[test]
+    fn test_fm_index_load_rejects_unsampled_and_corrupt_files() {
+        let index = fm_index::FmIndex::build(TEXT).unwrap();
+        let length = index.bwt().len();
+
+        let mut unsampled = b"FMIX".to_vec();
+        unsampled.extend_from_slice(&(length as u64).to_le_bytes());
+        unsampled.extend_from_slice(index.bwt());
+        unsampled.extend(std::iter::repeat_n(0u8, length.div_ceil(64) * 8));
+        unsampled.extend_from_slice(&0u64.to_le_bytes());
+        let unsampled_path = temp_path("fm_index_unsampled.fmi");
+        std::fs::write(&unsampled_path, &unsampled).unwrap();
+
+        let saved_path = temp_path("fm_index_corrupt.fmi");
+        index.save(saved_path.to_str().unwrap()).unwrap();
+        let mut corrupt = std::fs::read(&saved_path).unwrap();
+        let last_sample = corrupt.len() - 8;
+        corrupt[last_sample] ^= 1;
+        std::fs::write(&saved_path, &corrupt).unwrap();
+
+        let unsampled = fm_index::FmIndex::load(unsampled_path.to_str().unwrap());
+        let corrupt = fm_index::FmIndex::load(saved_path.to_str().unwrap());
+        std::fs::remove_file(unsampled_path).ok();
+        std::fs::remove_file(saved_path).ok();
+
+        assert!(unsampled.is_err());
+        assert!(corrupt.is_err());
+    }
+
+    #[test]
+    fn test_fm_index_smaller_than_suffix_array() {
+        let text = TEXT.repeat(200);
+        let fm = fm_index::FmIndex::build(&text).unwrap();
+        let sa = suffix_array::SuffixArray::build(&text);
+
+        assert!(fm.memory_bytes() * 2 < sa.memory_bytes());
+        assert_eq!(fm.locate("cadabra").0, sa.locate("cadabra").0);
+    }
+
+    #[test]
+    fn test_pattern_longer_than_text() {
+        assert!(kmp_search::search("ab", "abc").0.is_empty());
+        assert!(boyer_moore_search::search("ab", "abc").0.is_empty());
+        assert!(z_search::search("ab", "abc").0.is_empty());
+    }
+}
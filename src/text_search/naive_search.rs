@@ -0,0 +1,54 @@
+pub fn search(text: &str, pattern: &str) -> (Vec<usize>, usize) {
+    let text = text.as_bytes();
+    let pattern = pattern.as_bytes();
+    let mut matches = Vec::new();
+    let mut comparisons = 0;
+
+    if pattern.is_empty() || pattern.len() > text.len() {
+        return (matches, comparisons);
+    }
+
+    for start in 0..=text.len() - pattern.len() {
+        let mut j = 0;
+        while j < pattern.len() {
+            comparisons += 1;
+            if text[start + j] != pattern[j] {
+                break;
+            }
+            j += 1;
+        }
+
+        if j == pattern.len() {
+            matches.push(start);
+        }
+    }
+
+    (matches, comparisons)
+}
+
+pub fn search_first(text: &str, pattern: &str) -> (Option<usize>, usize) {
+    let text = text.as_bytes();
+    let pattern = pattern.as_bytes();
+    let mut comparisons = 0;
+
+    if pattern.is_empty() || pattern.len() > text.len() {
+        return (None, comparisons);
+    }
+
+    for start in 0..=text.len() - pattern.len() {
+        let mut j = 0;
+        while j < pattern.len() {
+            comparisons += 1;
+            if text[start + j] != pattern[j] {
+                break;
+            }
+            j += 1;
+        }
+
+        if j == pattern.len() {
+            return (Some(start), comparisons);
+        }
+    }
+
+    (None, comparisons)
+}
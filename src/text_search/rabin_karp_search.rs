@@ -0,0 +1,52 @@
+const BASE: u64 = 256;
+const MODULUS: u64 = 1_000_000_007;
+
+pub fn search(text: &str, pattern: &str) -> (Vec<usize>, usize) {
+    let text = text.as_bytes();
+    let pattern = pattern.as_bytes();
+    let mut matches = Vec::new();
+    let mut comparisons = 0;
+
+    let m = pattern.len();
+    let n = text.len();
+    if m == 0 || m > n {
+        return (matches, comparisons);
+    }
+
+    let mut high_order = 1;
+    for _ in 1..m {
+        high_order = (high_order * BASE) % MODULUS;
+    }
+
+    let pattern_hash = hash(pattern);
+    let mut window_hash = hash(&text[..m]);
+
+    for start in 0..=n - m {
+        if window_hash == pattern_hash {
+            let mut j = 0;
+            while j < m {
+                comparisons += 1;
+                if text[start + j] != pattern[j] {
+                    break;
+                }
+                j += 1;
+            }
+
+            if j == m {
+                matches.push(start);
+            }
+        }
+
+        if start + m < n {
+            let outgoing = (text[start] as u64 * high_order) % MODULUS;
+            window_hash = (window_hash + MODULUS - outgoing) % MODULUS;
+            window_hash = (window_hash * BASE + text[start + m] as u64) % MODULUS;
+        }
+    }
+
+    (matches, comparisons)
+}
+
+fn hash(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0, |acc, &byte| (acc * BASE + byte as u64) % MODULUS)
+}
@@ -0,0 +1,206 @@
+use std::cmp::Ordering;
+
+#[derive(Debug, Clone)]
+pub struct SuffixArray {
+    text: Vec<u8>,
+    suffixes: Vec<usize>,
+    lcp: Vec<usize>,
+}
+
+impl SuffixArray {
+    pub fn build(text: &str) -> Self {
+        let text = text.as_bytes().to_vec();
+        let suffixes = build_suffix_array(&text);
+        let lcp = build_lcp_array(&text, &suffixes);
+
+        Self {
+            text,
+            suffixes,
+            lcp,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.suffixes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.suffixes.is_empty()
+    }
+
+    pub fn suffixes(&self) -> &[usize] {
+        &self.suffixes
+    }
+
+    pub fn lcp(&self) -> &[usize] {
+        &self.lcp
+    }
+
+    pub fn memory_bytes(&self) -> usize {
+        self.text.len()
+            + self.suffixes.len() * std::mem::size_of::<usize>()
+            + self.lcp.len() * std::mem::size_of::<usize>()
+    }
+
+    pub fn longest_repeated_substring(&self) -> Option<&str> {
+        let (index, &length) = self.lcp.iter().enumerate().max_by_key(|&(_, &length)| length)?;
+        if length == 0 {
+            return None;
+        }
+
+        let start = self.suffixes[index];
+        std::str::from_utf8(&self.text[start..start + length]).ok()
+    }
+
+    pub fn count(&self, pattern: &str) -> (usize, usize) {
+        let (range, comparisons) = self.find_range(pattern.as_bytes());
+        (range.len(), comparisons)
+    }
+
+    pub fn locate(&self, pattern: &str) -> (Vec<usize>, usize) {
+        let (range, comparisons) = self.find_range(pattern.as_bytes());
+        let mut positions = self.suffixes[range].to_vec();
+        positions.sort_unstable();
+        (positions, comparisons)
+    }
+
+    fn find_range(&self, pattern: &[u8]) -> (std::ops::Range<usize>, usize) {
+        let mut comparisons = 0;
+
+        if pattern.is_empty() {
+            return (0..0, comparisons);
+        }
+
+        let lower = self.partition_point(pattern, &mut comparisons, |ordering| ordering == Ordering::Less);
+        let upper = self.partition_point(pattern, &mut comparisons, |ordering| ordering != Ordering::Greater);
+
+        (lower..upper, comparisons)
+    }
+
+    fn partition_point<F>(&self, pattern: &[u8], comparisons: &mut usize, goes_left: F) -> usize
+    where
+        F: Fn(Ordering) -> bool,
+    {
+        let mut left = 0;
+        let mut right = self.suffixes.len();
+
+        while left < right {
+            let mid = left + (right - left) / 2;
+            let ordering = self.compare_prefix(self.suffixes[mid], pattern, comparisons);
+
+            if goes_left(ordering) {
+                left = mid + 1;
+            } else {
+                right = mid;
+            }
+        }
+
+        left
+    }
+
+    fn compare_prefix(&self, start: usize, pattern: &[u8], comparisons: &mut usize) -> Ordering {
+        let suffix = &self.text[start..];
+
+        for (i, &byte) in pattern.iter().enumerate() {
+            if i >= suffix.len() {
+                return Ordering::Less;
+            }
+
+            *comparisons += 1;
+            match suffix[i].cmp(&byte) {
+                Ordering::Equal => continue,
+                other => return other,
+            }
+        }
+
+        Ordering::Equal
+    }
+}
+
+pub fn build_suffix_array(text: &[u8]) -> Vec<usize> {
+    let n = text.len();
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let mut suffixes: Vec<usize> = (0..n).collect();
+    let mut rank: Vec<usize> = text.iter().map(|&byte| byte as usize).collect();
+    let mut next_rank = vec![0; n];
+    let mut classes = 256;
+    let mut k = 1;
+
+    loop {
+        let second_key = |i: usize, rank: &[usize]| if i + k < n { rank[i + k] + 1 } else { 0 };
+
+        let by_second = counting_sort(&suffixes, classes + 1, |i| second_key(i, &rank));
+        suffixes = counting_sort(&by_second, classes, |i| rank[i]);
+
+        next_rank[suffixes[0]] = 0;
+        for i in 1..n {
+            let current = suffixes[i];
+            let previous = suffixes[i - 1];
+            let differs = rank[current] != rank[previous]
+                || second_key(current, &rank) != second_key(previous, &rank);
+            next_rank[current] = next_rank[previous] + usize::from(differs);
+        }
+
+        std::mem::swap(&mut rank, &mut next_rank);
+        classes = rank[suffixes[n - 1]] + 1;
+
+        if classes == n || k >= n {
+            break;
+        }
+        k *= 2;
+    }
+
+    suffixes
+}
+
+fn counting_sort<F>(items: &[usize], key_range: usize, key: F) -> Vec<usize>
+where
+    F: Fn(usize) -> usize,
+{
+    let mut counts = vec![0; key_range + 1];
+    for &item in items {
+        counts[key(item) + 1] += 1;
+    }
+    for i in 1..counts.len() {
+        counts[i] += counts[i - 1];
+    }
+
+    let mut sorted = vec![0; items.len()];
+    for &item in items {
+        let slot = &mut counts[key(item)];
+        sorted[*slot] = item;
+        *slot += 1;
+    }
+    sorted
+}
+
+pub fn build_lcp_array(text: &[u8], suffixes: &[usize]) -> Vec<usize> {
+    let n = suffixes.len();
+    let mut rank = vec![0; n];
+    for (i, &suffix) in suffixes.iter().enumerate() {
+        rank[suffix] = i;
+    }
+
+    let mut lcp = vec![0; n];
+    let mut h = 0;
+
+    for i in 0..n {
+        if rank[i] == 0 {
+            h = 0;
+            continue;
+        }
+
+        let j = suffixes[rank[i] - 1];
+        while i + h < n && j + h < n && text[i + h] == text[j + h] {
+            h += 1;
+        }
+
+        lcp[rank[i]] = h;
+        h = h.saturating_sub(1);
+    }
+
+    lcp
+}
@@ -0,0 +1,54 @@
+pub fn search(text: &str, pattern: &str) -> (Vec<usize>, usize) {
+    let text = text.as_bytes();
+    let pattern = pattern.as_bytes();
+    let mut matches = Vec::new();
+
+    let m = pattern.len();
+    if m == 0 || m > text.len() {
+        return (matches, 0);
+    }
+
+    let mut combined = Vec::with_capacity(m + 1 + text.len());
+    combined.extend_from_slice(pattern);
+    combined.push(0);
+    combined.extend_from_slice(text);
+
+    let (z, comparisons) = z_array(&combined);
+
+    for (i, &length) in z.iter().enumerate().skip(m + 1) {
+        if length >= m {
+            matches.push(i - m - 1);
+        }
+    }
+
+    (matches, comparisons)
+}
+
+pub fn z_array(bytes: &[u8]) -> (Vec<usize>, usize) {
+    let n = bytes.len();
+    let mut z = vec![0; n];
+    let mut comparisons = 0;
+    let mut left = 0;
+    let mut right = 0;
+
+    for i in 1..n {
+        if i < right {
+            z[i] = (right - i).min(z[i - left]);
+        }
+
+        while i + z[i] < n {
+            comparisons += 1;
+            if bytes[z[i]] != bytes[i + z[i]] {
+                break;
+            }
+            z[i] += 1;
+        }
+
+        if i + z[i] > right {
+            left = i;
+            right = i + z[i];
+        }
+    }
+
+    (z, comparisons)
+}
@@ -135,6 +135,7 @@ impl MenuDisplay {
             ("Merge Sort", "O(n log n)", "O(n)", "Yes", "No", "No"),
             ("Quick Sort", "O(n log n)", "O(log n)", "No", "No", "Yes"),
             ("Heap Sort", "O(n log n)", "O(1)", "No", "No", "Yes"),
+            ("4-ary Heap Sort", "O(n log n)", "O(1)", "No", "No", "Yes"),
             ("Shell Sort", "O(n^1.25)", "O(1)", "No", "Yes", "Yes"),
             ("Tim Sort", "O(n log n)", "O(n)", "Yes", "Yes", "No"),
             ("Tree Sort", "O(n log n)", "O(n)", "Yes", "No", "No"),
@@ -142,6 +143,11 @@ impl MenuDisplay {
             ("Radix Sort", "O(d × n)", "O(n + k)", "Yes", "No", "No"),
             ("Counting Sort", "O(n + k)", "O(k)", "Yes", "No", "No"),
             ("Cube Sort", "O(n log n)", "O(n)", "No", "No", "No"),
+            ("Bitonic Sort", "O(n log² n)", "O(log n)", "No", "No", "Yes"),
+            ("Odd-Even Merge", "O(n log² n)", "O(1)", "No", "No", "Yes"),
+            ("Pdqsort", "O(n log n)", "O(log n)", "No", "Yes", "Yes"),
+            ("Block Quicksort", "O(n log n)", "O(log n)", "No", "No", "Yes"),
+            ("Sample Sort", "O(n log n)", "O(n)", "No", "No", "No"),
         ];
         
         println!("{:<15} {:<12} {:<12} {:<8} {:<10} {:<10}", 